@@ -1,25 +1,34 @@
 use parol::build::Builder;
 
 fn main() {
+    generate("skill", "SkillGrammar", "skill_grammar");
+    generate("skill_en", "SkillEnGrammar", "skill_en_grammar");
+    generate("leader_skill", "LeaderSkillGrammar", "leader_skill_grammar");
+    generate("enemy_skill", "EnemySkillGrammar", "enemy_skill_grammar");
+}
+
+/// `src/{name}.par`からパーサとセマンティックアクションのトレイトを生成する
+fn generate(name: &str, user_type_name: &str, user_trait_module_name: &str) {
     Builder::with_explicit_output_dir("src")
-        .grammar_file("src/skill.par")
-        .expanded_grammar_output_file("skill-exp.par")
-        .parser_output_file("skill_parser.rs")
-        .actions_output_file("skill_grammar_trait_tmp.rs")
+        .grammar_file(format!("src/{name}.par"))
+        .expanded_grammar_output_file(format!("{name}-exp.par"))
+        .parser_output_file(format!("{name}_parser.rs"))
+        .actions_output_file(format!("{name}_grammar_trait_tmp.rs"))
         .enable_auto_generation()
-        .user_type_name("SkillGrammar")
-        .user_trait_module_name("skill_grammar")
+        .user_type_name(user_type_name)
+        .user_trait_module_name(user_trait_module_name)
         .generate_parser()
         .unwrap();
 
-    replace()
+    replace(name)
 }
 
-fn replace() {
+fn replace(name: &str) {
     use std::fs::File;
     use std::io::prelude::*;
 
-    let mut grammar_trait = File::open("./src/skill_grammar_trait_tmp.rs").unwrap();
+    let tmp_file_name = format!("./src/{name}_grammar_trait_tmp.rs");
+    let mut grammar_trait = File::open(&tmp_file_name).unwrap();
 
     let mut contents = String::new();
     grammar_trait.read_to_string(&mut contents).unwrap();
@@ -29,13 +38,20 @@ fn replace() {
 
     contents = contents.replace("use parol_runtime::parol_macros", "use parol_macros");
 
-    let mut new_file = File::create("./src/skill_grammar_trait.rs").unwrap();
+    // 置換でuseの並びが崩れるので、rustfmtと同じ順になるようにparol_macrosを先頭側へ移す
+    let macros_import =
+        "#[allow(unused_imports)]\nuse parol_macros::{pop_and_reverse_item, pop_item};\n";
+    if contents.contains(macros_import) {
+        contents = contents.replacen(macros_import, "", 1).replacen(
+            "use derive_builder::Builder;\n",
+            &format!("use derive_builder::Builder;\n{macros_import}"),
+            1,
+        );
+    }
+
+    let mut new_file = File::create(format!("./src/{name}_grammar_trait.rs")).unwrap();
 
     new_file.write_all(contents.as_bytes()).unwrap();
 
-    std::process::Command::new("rm")
-        .arg("./src/skill_grammar_trait_tmp.rs")
-        .spawn()
-        .unwrap();
-    ()
+    std::fs::remove_file(tmp_file_name).unwrap();
 }
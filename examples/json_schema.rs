@@ -0,0 +1,13 @@
+//! パース結果のJSON Schemaを標準出力へ書き出す
+//!
+//! ```text
+//! cargo run --example json_schema --features schema > schema/skills.schema.json
+//! ```
+
+use pad_skill_parser::json_schema::skills_schema;
+
+fn main() {
+    let schema = serde_json::to_string_pretty(&skills_schema()).unwrap();
+
+    println!("{schema}");
+}
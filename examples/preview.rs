@@ -0,0 +1,68 @@
+//! スキルを空の盤面へ適用した結果を端末に表示する
+//!
+//! ```text
+//! cargo run --example preview -- [--emoji] [--size 7x6] <スキル本文>
+//! ```
+//!
+//! `--svg`を指定すると、形状生成スキルが埋めるマスをSVGで出力する。
+
+use pad_skill_parser::board::Board;
+use pad_skill_parser::schema::Size;
+use pad_skill_parser::simulator::Simulator;
+use pad_skill_parser::skill_grammar::SkillGrammar;
+use pad_skill_parser::skill_parser::parse;
+use pad_skill_parser::terminal::TerminalStyle;
+
+const USAGE: &str = "usage: preview [--emoji] [--svg] [--size 7x6] <スキル本文>";
+
+fn main() -> miette::Result<()> {
+    let mut style = TerminalStyle::Ansi;
+    let mut svg = false;
+    let mut size = Size::standard();
+    let mut input = String::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--emoji" => style = TerminalStyle::Emoji,
+            "--svg" => svg = true,
+            "--size" => match args.next().as_deref().and_then(parse_size) {
+                Some(value) => size = value,
+                None => usage("--size expects `WIDTHxHEIGHT` with positive numbers"),
+            },
+            _ => input.push_str(&arg),
+        }
+    }
+
+    let grammar = &mut SkillGrammar::new();
+    parse(&input, "preview", grammar)?;
+
+    if svg {
+        for skill in grammar.skill_list.iter() {
+            if let Some(svg) = skill.effect.to_svg(&size)? {
+                print!("{svg}");
+            }
+        }
+        return Ok(());
+    }
+
+    let mut simulator = Simulator::new(Board::new(size), 0);
+    simulator.apply_skills(&grammar.skill_list)?;
+
+    println!("{}", simulator.board.render(&style));
+    Ok(())
+}
+
+/// `7x6`または`7×6`。幅、高さが0の盤面は表示できないので受け付けない
+fn parse_size(value: &str) -> Option<Size> {
+    let (width, height) = value.split_once(['x', '×'])?;
+    let (width, height) = (width.parse().ok()?, height.parse().ok()?);
+
+    (width > 0 && height > 0).then_some(Size(width, height))
+}
+
+/// 引数の誤りを表示して終了する
+fn usage(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    std::process::exit(2);
+}
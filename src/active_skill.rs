@@ -0,0 +1,177 @@
+//! スキル名とターン数を持つスキル
+//!
+//! Wikiやデータベースのスキルの一覧の形式から、スキル名、ターン数、効果をまとめて読み込む。
+//! 項目は`/`か改行で区切り、項目名と値は`:`で区切る。
+//! 項目名で始まらない部分は前の項目の値の続きとして読むので、効果やスキル名は複数行や`/`を含んでもよい。
+//!
+//! ```text
+//! スキル名: ○○ / ターン: 12→6 / 効果: 全ドロップを火と光に変化。
+//! ```
+//!
+//! ```text
+//! スキル名: ○○
+//! ターン: 8
+//! 効果: 1ターンの間、落ちコンなし。
+//! ```
+//!
+//! ```text
+//! スキル名: ○○ / ターン: 8
+//! 効果: ドロップのロックを解除し、
+//! 右端1列を光に変化。
+//! ```
+//!
+//! * 項目名は`スキル名`(`名前`)、`ターン`(`ターン数`)、`効果`(`スキル効果`)
+//! * ターンは`最大→最短`か、短縮できない場合は1つ。`->`も受け付ける
+//! * 全角の`／`、`：`、数字も受け付ける
+//!
+//! 継承スキル(アシスト)は`with_assist()`でベースのスキルと組み合わせる。
+
+use std::fmt;
+use std::str::FromStr;
+
+use miette::{bail, Result};
+
+use super::japanese::skills_to_japanese;
+use super::skill::Skill;
+use super::skill_grammar::SkillGrammar;
+
+/// 項目の区切り
+const SEPARATORS: [char; 3] = ['/', '／', '\n'];
+
+/// スキル名とターン数を持つスキル
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActiveSkill {
+    /// スキル名
+    pub name: String,
+    /// スキルレベル1のターン数
+    pub max_cooldown: usize,
+    /// スキルレベル最大のターン数
+    pub min_cooldown: usize,
+    /// 効果。スキル本文をパースした結果
+    pub effects: Vec<Skill>,
+}
+
+impl ActiveSkill {
+    /// スキルの一覧の形式の1件をパースする
+    pub fn parse(input: &str) -> Result<Self> {
+        let (mut name, mut cooldown, mut effects) = (None, None, None);
+
+        for (key, value) in items(input)? {
+            let value = value.trim_end_matches(SEPARATORS).trim();
+
+            match key {
+                Key::Name => name = Some(value.to_owned()),
+                Key::Cooldown => cooldown = Some(parse_cooldown(value)?),
+                Key::Effects => effects = Some(SkillGrammar::parse_skills(value)?),
+            }
+        }
+
+        let (Some(name), Some((max_cooldown, min_cooldown)), Some(effects)) =
+            (name, cooldown, effects)
+        else {
+            bail!("active skill requires スキル名, ターン and 効果: {input:?}");
+        };
+
+        Ok(ActiveSkill {
+            name,
+            max_cooldown,
+            min_cooldown,
+            effects,
+        })
+    }
+
+    /// 継承スキル(アシスト)を付けたスキル。ゲーム内と同じく、ターン数は合計し、ベースの効果の後にアシストの効果を発動する
+    /// スキル名は`ベース+アシスト`
+    pub fn with_assist(self: &Self, assist: &ActiveSkill) -> ActiveSkill {
+        ActiveSkill {
+            name: format!("{}+{}", self.name, assist.name),
+            max_cooldown: self.max_cooldown + assist.max_cooldown,
+            min_cooldown: self.min_cooldown + assist.min_cooldown,
+            effects: [self.effects.as_slice(), assist.effects.as_slice()].concat(),
+        }
+    }
+}
+
+/// 項目名
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    /// `スキル名`、`名前`
+    Name,
+    /// `ターン`、`ターン数`
+    Cooldown,
+    /// `効果`、`スキル効果`
+    Effects,
+}
+
+/// 項目名で始まる部分を項目とし、(項目名, 値)に分ける。値は区切りを含んだまま返す
+fn items(input: &str) -> Result<Vec<(Key, String)>> {
+    let mut items: Vec<(Key, String)> = Vec::new();
+
+    for part in input.split_inclusive(SEPARATORS) {
+        match (key_value(part), items.last_mut()) {
+            (Some((key, value)), _) => items.push((key, value.to_owned())),
+            (None, Some((_, value))) => value.push_str(part),
+            (None, None) if part.trim_matches(SEPARATORS).trim().is_empty() => {}
+            (None, None) => bail!("expected `key: value` but found {:?}", part.trim()),
+        }
+    }
+    Ok(items)
+}
+
+/// `効果: ...`のように項目名で始まっていれば(項目名, 値)
+fn key_value(part: &str) -> Option<(Key, &str)> {
+    let (key, value) = part.split_once([':', '：'])?;
+
+    let key = match key.trim() {
+        "スキル名" | "名前" => Key::Name,
+        "ターン" | "ターン数" => Key::Cooldown,
+        "効果" | "スキル効果" => Key::Effects,
+        _ => return None,
+    };
+    Some((key, value))
+}
+
+/// `12→6`、`8`
+fn parse_cooldown(value: &str) -> Result<(usize, usize)> {
+    let value = super::normalize::normalize(value);
+    let value = value.trim_end_matches('。');
+
+    let turns = value
+        .split("→")
+        .flat_map(|s| s.split("->"))
+        .map(|s| s.trim_end_matches("ターン").parse::<usize>())
+        .collect::<Result<Vec<_>, _>>();
+
+    match turns.as_deref() {
+        Ok([turns]) => Ok((*turns, *turns)),
+        Ok([max, min]) if min <= max => Ok((*max, *min)),
+        _ => bail!("invalid cooldown {value:?}"),
+    }
+}
+
+impl FromStr for ActiveSkill {
+    type Err = miette::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ActiveSkill::parse(s)
+    }
+}
+
+/// スキルの一覧の形式。`parse()`で読み込める
+impl fmt::Display for ActiveSkill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cooldown = match self.max_cooldown == self.min_cooldown {
+            true => self.max_cooldown.to_string(),
+            false => format!("{}→{}", self.max_cooldown, self.min_cooldown),
+        };
+
+        write!(
+            f,
+            "スキル名: {} / ターン: {cooldown} / 効果: {}",
+            self.name,
+            skills_to_japanese(&self.effects)
+        )
+    }
+}
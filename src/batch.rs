@@ -0,0 +1,204 @@
+//! モンスターのデータセットの一括パース
+//!
+//! `batch`フィーチャーで有効になる。
+//! 1レコードは(モンスターID、名前、スキル本文)で、JSONLかCSVで読み込む。
+//!
+//! ```text
+//! {"id": 1, "name": "ティラ", "skill": "火ドロップを木ドロップに変化。"}
+//! ```
+//!
+//! ```text
+//! id,name,skill
+//! 1,ティラ,火ドロップを木ドロップに変化。
+//! ```
+//!
+//! レコードは1件ずつ独立してパースし、結果をJSONLで1行ずつ書き出す。
+//! 読み込みやパースに失敗したレコードは`error`を持つ行になり、以降のレコードは処理を続ける。
+//! ただし入力自体が読めなくなった(I/Oエラー)場合は、そのエラーを1行出力して読み込みを終える。
+//!
+//! ```text
+//! {"id":1,"name":"ティラ","skills":[...]}
+//! {"line":3,"error":"..."}
+//! ```
+
+use std::io::{BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::skill::Skill;
+use super::skill_grammar::SkillGrammar;
+
+/// 入力の形式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputFormat {
+    /// 1行に1レコードのJSON
+    Jsonl,
+    /// ヘッダ行(`id,name,skill`)付きのCSV
+    Csv,
+}
+
+/// 入力の1レコード
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// モンスターID
+    pub id: usize,
+    /// モンスター名
+    pub name: String,
+    /// スキル本文
+    pub skill: String,
+}
+
+/// 1レコード分の結果
+/// * Ok: パース結果
+/// * Err: レコードが読み込めなかった、またはパースに失敗した
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RecordResult {
+    Ok {
+        id: usize,
+        name: String,
+        skills: Vec<Skill>,
+    },
+    Err {
+        /// 読み込めたレコードならモンスターID
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<usize>,
+        /// 入力の行番号(1始まり)
+        line: usize,
+        error: String,
+    },
+}
+
+impl RecordResult {
+    pub fn is_ok(self: &Self) -> bool {
+        match self {
+            Self::Ok { .. } => true,
+            Self::Err { .. } => false,
+        }
+    }
+}
+
+/// 一括パースの件数
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub ok: usize,
+    pub failed: usize,
+}
+
+/// 1レコードをパースする
+pub fn parse_record(record: Record, line: usize) -> RecordResult {
+    match SkillGrammar::parse_skills(&record.skill).map_err(|report| report.to_string()) {
+        Ok(skills) => RecordResult::Ok {
+            id: record.id,
+            name: record.name,
+            skills,
+        },
+        Err(error) => RecordResult::Err {
+            id: Some(record.id),
+            line,
+            error,
+        },
+    }
+}
+
+/// 入力を読み込んでレコード毎の結果を返す。レコードは読み込みながら1件ずつパースする
+pub fn parse_records<'r, R: BufRead + 'r>(
+    reader: R,
+    format: InputFormat,
+) -> impl Iterator<Item = RecordResult> + 'r {
+    read_records(reader, format).map(|(line, record)| match record {
+        Ok(record) => parse_record(record, line),
+        Err(error) => RecordResult::Err {
+            id: None,
+            line,
+            error,
+        },
+    })
+}
+
+/// 入力を読み込み、結果をJSONLで1件ずつ書き出す
+pub fn run<R: BufRead, W: Write>(
+    reader: R,
+    format: InputFormat,
+    writer: &mut W,
+) -> std::io::Result<Summary> {
+    let mut summary = Summary::default();
+
+    for result in parse_records(reader, format) {
+        match result.is_ok() {
+            true => summary.ok += 1,
+            false => summary.failed += 1,
+        }
+        serde_json::to_writer(&mut *writer, &result)?;
+        writeln!(writer)?;
+    }
+    Ok(summary)
+}
+
+/// (行番号, レコード)を順に読み込む。空行は読み飛ばす。
+/// UTF-8として読めない行はその行だけのエラーにする。I/Oエラーは同じ読み込みを繰り返しても失敗し続けるので、最初の1件を返して終える
+fn read_records<'r, R: BufRead + 'r>(
+    reader: R,
+    format: InputFormat,
+) -> Box<dyn Iterator<Item = (usize, Result<Record, String>)> + 'r> {
+    match format {
+        InputFormat::Jsonl => Box::new(
+            reader
+                .split(b'\n')
+                .enumerate()
+                .scan(false, |failed, (idx, line)| {
+                    if *failed {
+                        return None;
+                    }
+                    *failed = line.is_err();
+                    Some((
+                        idx + 1,
+                        line.map_err(|e| e.to_string()).and_then(decode_line),
+                    ))
+                })
+                .filter(|(_, line)| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+                .map(|(line_no, line)| {
+                    let record =
+                        line.and_then(|l| serde_json::from_str(&l).map_err(|e| e.to_string()));
+                    (line_no, record)
+                }),
+        ),
+        InputFormat::Csv => {
+            let mut reader = csv::Reader::from_reader(reader);
+            let headers = match reader.headers() {
+                Ok(headers) => headers.clone(),
+                Err(e) => return Box::new(std::iter::once((1, Err(e.to_string())))),
+            };
+
+            Box::new(reader.into_records().scan(false, move |failed, record| {
+                if *failed {
+                    return None;
+                }
+                Some(match record {
+                    Ok(record) => (
+                        csv_line(record.position()),
+                        record
+                            .deserialize(Some(&headers))
+                            .map_err(|e| e.to_string()),
+                    ),
+                    Err(e) => {
+                        *failed = e.is_io_error();
+                        (csv_line(e.position()), Err(e.to_string()))
+                    }
+                })
+            }))
+        }
+    }
+}
+
+/// 改行を除いた1行をUTF-8の文字列にする
+fn decode_line(mut line: Vec<u8>) -> Result<String, String> {
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    String::from_utf8(line).map_err(|e| e.to_string())
+}
+
+fn csv_line(position: Option<&csv::Position>) -> usize {
+    position.map_or(0, |position| position.line() as usize)
+}
@@ -0,0 +1,360 @@
+//! スキル本文をパースして表示する
+//!
+//! ```text
+//! pad-skill parse "火ドロップを木ドロップに変化。"
+//! pad-skill parse --format json < skills.txt
+//! pad-skill parse --lang en --format ja "Change Wood orbs to Water orbs"
+//! pad-skill batch monsters.csv > results.jsonl
+//! pad-skill coverage corpus.txt
+//! pad-skill query "generates(light)>=10 and effect:DropUnLock" corpus.txt
+//! pad-skill diff "火と水ドロップを2個ずつ生成。" "火と水ドロップを3個ずつ生成。"
+//! pad-skill diff --snapshot old.jsonl new.jsonl
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use miette::IntoDiagnostic;
+
+use pad_skill_parser::batch::{self, InputFormat, RecordResult};
+use pad_skill_parser::coverage::CoverageReport;
+use pad_skill_parser::diff::SkillDiff;
+use pad_skill_parser::english::skills_to_english;
+use pad_skill_parser::japanese::skills_to_japanese;
+use pad_skill_parser::lenient::parse_lenient;
+use pad_skill_parser::query::Query;
+use pad_skill_parser::skill::Skill;
+use pad_skill_parser::skill_en_grammar::SkillEnGrammar;
+use pad_skill_parser::skill_en_parser;
+use pad_skill_parser::skill_grammar::SkillGrammar;
+
+const FILE_NAME: &str = "<input>";
+
+#[derive(Parser)]
+#[command(
+    name = "pad-skill",
+    version,
+    about = "パズドラのスキル本文をパースする"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// スキル本文をパースして表示する
+    Parse(ParseArgs),
+    /// JSONL、CSVのレコードを一括でパースし、結果をJSONLで出力する
+    Batch(BatchArgs),
+    /// 1行1スキルのコーパスに対する文法のカバレッジを表示する
+    Coverage(CoverageArgs),
+    /// 1行1スキルのコーパスから、クエリに一致するスキル本文を出力する
+    Query(QueryArgs),
+    /// 2つのスキル本文、またはモンスター毎の2つのデータセットの意味的な差分を表示する
+    Diff(DiffArgs),
+}
+
+#[derive(clap::Args)]
+struct ParseArgs {
+    /// 出力形式
+    #[arg(short, long, value_enum, default_value_t = Format::Debug)]
+    format: Format,
+
+    /// スキル本文の言語
+    #[arg(short, long, value_enum, default_value_t = Lang::Ja)]
+    lang: Lang,
+
+    /// パースできなかった文を`Unrecognized`として、パースできた文のスキルを出力する(日本語のみ。`--lang en`とは併用できない)
+    #[arg(long)]
+    lenient: bool,
+
+    /// スキル本文。省略した場合は標準入力から1行ずつ読み込む
+    text: Vec<String>,
+}
+
+#[derive(clap::Args)]
+struct BatchArgs {
+    /// 入力の形式。省略した場合は拡張子が`.csv`ならCSV、それ以外はJSONL
+    #[arg(short, long, value_enum)]
+    input_format: Option<BatchFormat>,
+
+    /// 入力ファイル。省略した場合は標準入力から読み込む
+    file: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct CoverageArgs {
+    /// コーパスのファイル。省略した場合は標準入力から読み込む
+    file: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct QueryArgs {
+    /// クエリ。`generates(light)>=10 and effect:DropUnLock`など
+    query: String,
+
+    /// コーパスのファイル。省略した場合は標準入力から読み込む
+    file: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct DiffArgs {
+    /// BEFORE、AFTERを`batch`と同じ形式のファイルとして、モンスターID毎に比較する
+    #[arg(long)]
+    snapshot: bool,
+
+    /// 修正前のスキル本文。`--snapshot`の場合はファイル
+    before: String,
+
+    /// 修正後のスキル本文。`--snapshot`の場合はファイル
+    after: String,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BatchFormat {
+    Jsonl,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `Vec<Skill>`のDebug表示
+    Debug,
+    /// JSON
+    Json,
+    /// 日本語のスキル本文
+    Ja,
+    /// 英語のスキル本文
+    En,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Lang {
+    Ja,
+    En,
+}
+
+fn main() -> miette::Result<()> {
+    match Cli::parse().command {
+        Command::Parse(args) => run_parse(args),
+        Command::Batch(args) => run_batch(args),
+        Command::Coverage(args) => run_coverage(args),
+        Command::Query(args) => run_query(args),
+        Command::Diff(args) => run_diff(args),
+    }
+}
+
+fn run_parse(args: ParseArgs) -> miette::Result<()> {
+    // 英語の文法は文単位の回復に対応していない
+    if args.lenient && matches!(args.lang, Lang::En) {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "`--lenient` can't be used with `--lang en`",
+            )
+            .exit();
+    }
+
+    let inputs = if args.text.is_empty() {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<_>, _>>()
+            .into_diagnostic()?
+    } else {
+        vec![args.text.join(" ")]
+    };
+
+    for input in inputs.iter().map(|line| line.trim()) {
+        if input.is_empty() {
+            continue;
+        }
+
+        let skills = if args.lenient {
+            parse_lenient(input)
+        } else {
+            parse(input, args.lang)?
+        };
+        println!("{}", render(&skills, args.format)?);
+    }
+    Ok(())
+}
+
+/// レコード毎の失敗は出力に含め、件数を標準エラーへ表示する
+fn run_batch(args: BatchArgs) -> miette::Result<()> {
+    let is_csv = args
+        .file
+        .as_ref()
+        .is_some_and(|path| path.extension().is_some_and(|ext| ext == "csv"));
+    let format = match (args.input_format, is_csv) {
+        (Some(BatchFormat::Jsonl), _) | (None, false) => InputFormat::Jsonl,
+        (Some(BatchFormat::Csv), _) | (None, true) => InputFormat::Csv,
+    };
+
+    let stdout = &mut std::io::stdout().lock();
+    let summary = match &args.file {
+        Some(path) => {
+            let file = File::open(path).into_diagnostic()?;
+            batch::run(BufReader::new(file), format, stdout)
+        }
+        None => batch::run(std::io::stdin().lock(), format, stdout),
+    }
+    .into_diagnostic()?;
+
+    eprintln!("ok: {}, failed: {}", summary.ok, summary.failed);
+    Ok(())
+}
+
+fn run_coverage(args: CoverageArgs) -> miette::Result<()> {
+    let corpus = match &args.file {
+        Some(path) => std::fs::read_to_string(path).into_diagnostic()?,
+        None => std::io::read_to_string(std::io::stdin()).into_diagnostic()?,
+    };
+
+    print!("{}", CoverageReport::from_corpus(corpus.lines()));
+    Ok(())
+}
+
+/// パースに失敗した本文は読み飛ばし、件数を標準エラーへ表示する
+fn run_query(args: QueryArgs) -> miette::Result<()> {
+    let query = Query::parse(&args.query)?;
+    let corpus = match &args.file {
+        Some(path) => std::fs::read_to_string(path).into_diagnostic()?,
+        None => std::io::read_to_string(std::io::stdin()).into_diagnostic()?,
+    };
+
+    let (mut matched, mut failed) = (0, 0);
+    for input in corpus.lines().map(str::trim).filter(|s| !s.is_empty()) {
+        match SkillGrammar::parse_skills(input) {
+            Ok(skills) if query.matches(&skills) => {
+                matched += 1;
+                println!("{input}");
+            }
+            Ok(_) => {}
+            Err(_) => failed += 1,
+        }
+    }
+
+    eprintln!("matched: {matched}, failed: {failed}");
+    Ok(())
+}
+
+fn run_diff(args: DiffArgs) -> miette::Result<()> {
+    if args.snapshot {
+        return run_diff_snapshot(&args.before, &args.after);
+    }
+
+    let before = SkillGrammar::parse_skills(&args.before)?;
+    let after = SkillGrammar::parse_skills(&args.after)?;
+    print!("{}", SkillDiff::new(&before, &after));
+    Ok(())
+}
+
+/// 変化のあったモンスター毎に差分を表示する
+/// どちらかでパースに失敗したモンスターは差分を表示せず、標準エラーへ失敗した側を表示する
+fn run_diff_snapshot(before: &str, after: &str) -> miette::Result<()> {
+    let before = read_snapshot(before)?;
+    let after = read_snapshot(after)?;
+
+    let ids: BTreeSet<&usize> = before
+        .monsters
+        .keys()
+        .chain(after.monsters.keys())
+        .chain(&before.failed)
+        .chain(&after.failed)
+        .collect();
+    let mut changed = 0;
+    for id in ids {
+        let sides = match (before.failed.contains(id), after.failed.contains(id)) {
+            (true, true) => Some("before and after"),
+            (true, false) => Some("before"),
+            (false, true) => Some("after"),
+            (false, false) => None,
+        };
+        if let Some(sides) = sides {
+            eprintln!("#{id}: failed to parse {sides}");
+            continue;
+        }
+
+        let (name, before_skills, after_skills) =
+            match (before.monsters.get(id), after.monsters.get(id)) {
+                (Some((_, b)), Some((name, a))) => (name, b.as_slice(), a.as_slice()),
+                (None, Some((name, a))) => (name, &[][..], a.as_slice()),
+                (Some((name, b)), None) => (name, b.as_slice(), &[][..]),
+                (None, None) => continue,
+            };
+
+        let diff = SkillDiff::new(before_skills, after_skills);
+        if !diff.is_empty() {
+            changed += 1;
+            print!("#{id} {name}\n{diff}");
+        }
+    }
+
+    let failed: BTreeSet<&usize> = before.failed.iter().chain(&after.failed).collect();
+    eprintln!(
+        "changed: {changed}, failed: {}",
+        failed.len() + before.unreadable + after.unreadable
+    );
+    Ok(())
+}
+
+/// モンスターID毎のデータセット
+#[derive(Default)]
+struct Snapshot {
+    /// モンスターID毎の(名前、スキル)
+    monsters: BTreeMap<usize, (String, Vec<Skill>)>,
+    /// パースに失敗したモンスターID
+    failed: BTreeSet<usize>,
+    /// レコードとして解釈できず、モンスターIDも分からない行の数
+    unreadable: usize,
+}
+
+fn read_snapshot(path: &str) -> miette::Result<Snapshot> {
+    let format = match path.ends_with(".csv") {
+        true => InputFormat::Csv,
+        false => InputFormat::Jsonl,
+    };
+    // 読み込めないファイルはレコードの失敗ではなく、diff全体のエラーにする
+    let input = std::fs::read_to_string(path).into_diagnostic()?;
+
+    let mut snapshot = Snapshot::default();
+    for result in batch::parse_records(input.as_bytes(), format) {
+        match result {
+            RecordResult::Ok { id, name, skills } => {
+                snapshot.monsters.insert(id, (name, skills));
+            }
+            RecordResult::Err { id: Some(id), .. } => {
+                snapshot.failed.insert(id);
+            }
+            RecordResult::Err { id: None, .. } => snapshot.unreadable += 1,
+        }
+    }
+    Ok(snapshot)
+}
+
+fn parse(input: &str, lang: Lang) -> miette::Result<Vec<Skill>> {
+    match lang {
+        Lang::Ja => SkillGrammar::parse_skills(input),
+        Lang::En => {
+            let grammar = &mut SkillEnGrammar::new();
+            skill_en_parser::parse(input, FILE_NAME, grammar)?;
+
+            Ok(grammar.skill_list.to_vec())
+        }
+    }
+}
+
+fn render(skills: &[Skill], format: Format) -> miette::Result<String> {
+    match format {
+        Format::Debug => Ok(format!("{skills:#?}")),
+        Format::Json => serde_json::to_string_pretty(skills).into_diagnostic(),
+        Format::Ja => Ok(skills_to_japanese(skills)),
+        Format::En => Ok(skills_to_english(skills)),
+    }
+}
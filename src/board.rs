@@ -0,0 +1,184 @@
+use miette::bail;
+
+use super::schema::*;
+
+/// 盤面上の1マス
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cell {
+    /// ドロップ。Noneは空きマス
+    pub drop: Option<Drop>,
+    /// ロック状態
+    pub locked: bool,
+    /// 強化ドロップ
+    pub powered_up: bool,
+    /// 雲で覆われている
+    pub cloud: bool,
+    /// テープで操作不可になっている
+    pub tape: bool,
+    /// ルーレット。ドロップが一定時間毎に切り替わる
+    pub roulette: bool,
+}
+
+impl Cell {
+    pub fn new(drop: Drop) -> Self {
+        Cell {
+            drop: Some(drop),
+            ..Default::default()
+        }
+    }
+
+    /// ドロップを置き換える。ロック、強化は置き換え前のドロップと一緒に消える
+    pub fn replace(self: &mut Self, drop: Drop) {
+        self.drop = Some(drop);
+        self.locked = false;
+        self.powered_up = false;
+    }
+
+    /// ドロップを消して空きマスにする
+    pub fn clear(self: &mut Self) {
+        self.drop = None;
+        self.locked = false;
+        self.powered_up = false;
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.drop.is_none()
+    }
+}
+
+/// 盤面。マスは左上から横方向に並べて保持する
+#[derive(Clone, Debug, PartialEq)]
+pub struct Board {
+    pub size: Size,
+    cells: Vec<Cell>,
+}
+
+impl Board {
+    /// 全て空きマスの盤面
+    pub fn new(size: Size) -> Self {
+        let cells = vec![Cell::default(); size.width() * size.height()];
+
+        Board { size, cells }
+    }
+
+    /// 行毎のドロップから盤面を作る。行の長さが揃っていない場合はエラー
+    pub fn from_rows(rows: Vec<Vec<Cell>>) -> miette::Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "row {}: expected {width} cells, found {}.",
+                row + 1,
+                rows[row].len()
+            );
+        }
+
+        Ok(Board {
+            size: Size(width, height),
+            cells: rows.concat(),
+        })
+    }
+
+    pub fn width(self: &Self) -> usize {
+        self.size.width()
+    }
+
+    pub fn height(self: &Self) -> usize {
+        self.size.height()
+    }
+
+    /// 指定したマス。盤面の外はエラー
+    pub fn get(self: &Self, row: usize, col: usize) -> miette::Result<&Cell> {
+        let idx = self.index(row, col)?;
+        Ok(&self.cells[idx])
+    }
+
+    pub fn get_mut(self: &mut Self, row: usize, col: usize) -> miette::Result<&mut Cell> {
+        let idx = self.index(row, col)?;
+        Ok(&mut self.cells[idx])
+    }
+
+    /// 盤面から求めた位置のマス。盤面の外を指定するのは呼び出し側の誤りなのでパニックする
+    pub(crate) fn cell(self: &Self, row: usize, col: usize) -> &Cell {
+        &self.cells[row * self.width() + col]
+    }
+
+    pub(crate) fn cell_mut(self: &mut Self, row: usize, col: usize) -> &mut Cell {
+        let idx = row * self.width() + col;
+        &mut self.cells[idx]
+    }
+
+    /// 左上から横方向に全てのマスを返す
+    pub fn cells(self: &Self) -> &[Cell] {
+        &self.cells
+    }
+
+    pub fn cells_mut(self: &mut Self) -> &mut [Cell] {
+        &mut self.cells
+    }
+
+    /// 上の段から順に返す。列の無い盤面では空の段になる
+    pub fn rows(self: &Self) -> Vec<&[Cell]> {
+        let width = self.width();
+
+        (0..self.height())
+            .map(|row| &self.cells[row * width..(row + 1) * width])
+            .collect()
+    }
+
+    /// (row, col)の一覧
+    pub fn positions(self: &Self) -> Vec<(usize, usize)> {
+        (0..self.height())
+            .flat_map(|row| (0..self.width()).map(move |col| (row, col)))
+            .collect()
+    }
+
+    /// 指定したドロップの個数
+    pub fn count(self: &Self, drop: &Drop) -> usize {
+        self.cells
+            .iter()
+            .filter(|cell| cell.drop.as_ref() == Some(drop))
+            .count()
+    }
+
+    /// 盤面上のドロップ毎の個数。盤面に存在しないドロップは含まない
+    pub fn counts(self: &Self) -> Vec<(Drop, usize)> {
+        let mut result: Vec<(Drop, usize)> = Vec::new();
+
+        self.cells
+            .iter()
+            .filter_map(|cell| cell.drop.as_ref())
+            .for_each(|drop| match result.iter_mut().find(|(d, _)| d == drop) {
+                Some((_, qty)) => *qty += 1,
+                None => result.push((drop.clone(), 1)),
+            });
+
+        result
+    }
+
+    /// 正の値は先頭から、負の値は末尾から数えたN番目を0始まりの添字に変換する
+    /// * 1: 1番目
+    /// * -1: 最後
+    pub fn resolve_idx(idx: isize, len: usize) -> Option<usize> {
+        if idx > 0 && (idx as usize) <= len {
+            Some(idx as usize - 1)
+        } else if idx < 0 && idx.unsigned_abs() <= len {
+            Some(len - idx.unsigned_abs())
+        } else {
+            None
+        }
+    }
+
+    fn index(self: &Self, row: usize, col: usize) -> miette::Result<usize> {
+        if row >= self.height() || col >= self.width() {
+            bail!(
+                "({row}, {col}) is out of the {}x{} board.",
+                self.width(),
+                self.height()
+            );
+        }
+
+        Ok(row * self.width() + col)
+    }
+}
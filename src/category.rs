@@ -0,0 +1,202 @@
+//! スキルの分類
+//!
+//! パース結果の`SkillEffect`から、bookの章立てに沿った分類(変換、生成、盤面干渉、ドロップ目覚め、敵への効果)と、
+//! 全消し可能、ロック解除付きといった補助的なタグを導出する。
+//!
+//! ```text
+//! ドロップのロックを解除し、火と回復を6個ずつ生成。 → {生成, ロック解除付き}
+//! 全ドロップを火と光に変化。                       → {変換, 全消し可能}
+//! ```
+
+use std::fmt;
+
+use super::guarantee::BoardAnalysis;
+use super::schema::Size;
+use super::skill::*;
+
+/// スキルの分類とタグ
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Category {
+    /// 変換。単色変換、全ドロップ変化
+    Change,
+    /// 生成。ランダム生成、指定型生成
+    Generate,
+    /// 盤面干渉。ロック解除、ドロップ強化、雲、盤面サイズ変更など
+    BoardIntervention,
+    /// ドロップ目覚め。強化、ロック、釘ドロップの目覚めを含む
+    Falloff,
+    /// 敵への効果。吸収無効、ダメージ無効貫通、属性変更
+    TowardsTheEnemy,
+    /// 全消し可能。スキル使用後の盤面が、各ドロップを3個以上ずつ含むドロップだけで構成される
+    CanClearBoard,
+    /// ロック解除付き。ロック解除と他の効果を併せ持つ
+    WithUnlock,
+}
+
+impl Category {
+    /// 全ての分類とタグ
+    pub fn all() -> [Category; 7] {
+        [
+            Category::Change,
+            Category::Generate,
+            Category::BoardIntervention,
+            Category::Falloff,
+            Category::TowardsTheEnemy,
+            Category::CanClearBoard,
+            Category::WithUnlock,
+        ]
+    }
+
+    pub fn to_japanese(self: &Self) -> &'static str {
+        match self {
+            Category::Change => "変換",
+            Category::Generate => "生成",
+            Category::BoardIntervention => "盤面干渉",
+            Category::Falloff => "ドロップ目覚め",
+            Category::TowardsTheEnemy => "敵への効果",
+            Category::CanClearBoard => "全消し可能",
+            Category::WithUnlock => "ロック解除付き",
+        }
+    }
+
+    /// 分類に当たらない効果(`Other`、`Unrecognized`)は`None`
+    pub fn of(effect: &SkillEffect) -> Option<Self> {
+        match effect {
+            SkillEffect::ChangeDropAToB(_, _) | SkillEffect::ChangeAllOfBoard(_) => {
+                Some(Category::Change)
+            }
+            SkillEffect::GenRandomDrop(_, _) | SkillEffect::DropShapeGen(_) => {
+                Some(Category::Generate)
+            }
+            SkillEffect::DropUnLock
+            | SkillEffect::DropLock(_)
+            | SkillEffect::DropRefresh
+            | SkillEffect::DropPowerUp(_)
+            | SkillEffect::DropsNotFalling
+            | SkillEffect::GenRoulette(_)
+            | SkillEffect::GenCloud(_, _)
+            | SkillEffect::GenTeap(_)
+            | SkillEffect::ChangeBoardSize(_) => Some(Category::BoardIntervention),
+            SkillEffect::DropFalloff(_, _)
+            | SkillEffect::PowerupDropFalloff(_)
+            | SkillEffect::FallLockDrop(_)
+            | SkillEffect::FallNailDropEasierToFalloff(_) => Some(Category::Falloff),
+            SkillEffect::NullificationDamageAbsorption
+            | SkillEffect::NullificationAttributeAbsorption
+            | SkillEffect::PenetrationDamageNullification
+            | SkillEffect::EnemyAttributeChange(_) => Some(Category::TowardsTheEnemy),
+            SkillEffect::Other | SkillEffect::Unrecognized { .. } => None,
+        }
+    }
+
+    fn bit(self: &Self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_japanese())
+    }
+}
+
+/// 分類とタグの集合
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CategorySet(u8);
+
+impl CategorySet {
+    pub const fn empty() -> Self {
+        CategorySet(0)
+    }
+
+    pub fn insert(self: &mut Self, category: Category) {
+        self.0 |= category.bit();
+    }
+
+    pub fn contains(self: &Self, category: Category) -> bool {
+        self.0 & category.bit() != 0
+    }
+
+    pub fn len(self: &Self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.0 == 0
+    }
+
+    /// `Category::all()`の順序で列挙する
+    pub fn iter(self: &Self) -> impl Iterator<Item = Category> + '_ {
+        Category::all()
+            .into_iter()
+            .filter(move |category| self.contains(*category))
+    }
+}
+
+impl FromIterator<Category> for CategorySet {
+    fn from_iter<T: IntoIterator<Item = Category>>(iter: T) -> Self {
+        let mut set = CategorySet::empty();
+        iter.into_iter().for_each(|category| set.insert(category));
+        set
+    }
+}
+
+impl fmt::Debug for CategorySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CategorySet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CategorySet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Category>::deserialize(deserializer)
+            .map(|categories| categories.into_iter().collect())
+    }
+}
+
+impl Skill {
+    /// スキル1つ分の分類。ロック解除付きは複数のスキルの組み合わせのため`categories()`で判定する
+    pub fn category(self: &Self) -> CategorySet {
+        categories(std::slice::from_ref(self))
+    }
+}
+
+/// 1つのスキル本文をパースしたスキル全体の分類
+/// * ロック解除は単独なら盤面干渉、他の効果と併せ持つならロック解除付きとする
+/// * 全消し可能は`can_clear_board()`
+pub fn categories(skills: &[Skill]) -> CategorySet {
+    let is_unlock = |skill: &Skill| skill.effect == SkillEffect::DropUnLock;
+    let with_unlock = skills.iter().any(is_unlock)
+        && skills
+            .iter()
+            .any(|skill| !is_unlock(skill) && Category::of(&skill.effect).is_some());
+
+    let mut set: CategorySet = skills
+        .iter()
+        .filter(|skill| !(with_unlock && is_unlock(skill)))
+        .filter_map(|skill| Category::of(&skill.effect))
+        .collect();
+
+    if with_unlock {
+        set.insert(Category::WithUnlock);
+    }
+    if can_clear_board(skills) {
+        set.insert(Category::CanClearBoard);
+    }
+    set
+}
+
+/// 6×5の盤面で、スキル使用後の盤面が各ドロップを3個以上ずつ含むドロップだけで構成されるか
+/// 盤面のドロップ数の範囲は`BoardAnalysis`で求める
+pub fn can_clear_board(skills: &[Skill]) -> bool {
+    BoardAnalysis::analyze(skills, &Size::standard()).can_clear()
+}
@@ -0,0 +1,223 @@
+//! スキル本文のコーパスに対する文法のカバレッジ
+//!
+//! コーパスの各行をパースし、成功件数、失敗箇所(予期しないトークンと失敗した規則)、
+//! 生成された`SkillEffect`、`ShapeType`のバリアント毎の件数を集計する。
+//! 失敗箇所は規則とトークンでまとめ、件数の多い順に並べるので、
+//! 次にどの表現へ対応すればどれだけカバレッジが上がるかが分かる。
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use parol_runtime::errors::ParserError;
+
+use super::normalize::normalize;
+use super::skill::*;
+use super::skill_grammar::SkillGrammar;
+
+/// パースに失敗したスキル本文
+#[derive(Clone, Debug, PartialEq)]
+pub struct Failure {
+    /// スキル本文
+    pub input: String,
+    /// 正規化(`normalize::normalize`)したスキル本文。パーサはこちらを読む
+    pub normalized: String,
+    /// 予期しないトークンの文字列
+    pub token: Option<String>,
+    /// 予期しないトークンの、正規化したスキル本文での位置(バイト単位)
+    pub offset: Option<usize>,
+    /// 失敗した文法の規則
+    pub rule: Option<String>,
+    /// エラーメッセージの1行目
+    pub message: String,
+}
+
+impl Failure {
+    /// エラーの位置は正規化した本文に対するものなので、トークンも正規化した本文から取り出す
+    fn new(input: &str, normalized: String, report: miette::Report) -> Self {
+        match report.downcast_ref::<ParserError>() {
+            Some(ParserError::PredictionErrorWithExpectations {
+                cause,
+                error_location,
+                ..
+            }) => {
+                let offset = error_location.offset();
+                let token = normalized
+                    .get(offset..offset + error_location.len())
+                    .map(str::to_owned);
+
+                Failure {
+                    input: input.to_owned(),
+                    normalized,
+                    token,
+                    offset: Some(offset),
+                    rule: failed_rule(cause),
+                    message: first_line(cause),
+                }
+            }
+            Some(ParserError::UnprocessedInput { last_token, .. }) => Failure {
+                input: input.to_owned(),
+                token: normalized.get(last_token.offset()..).map(str::to_owned),
+                normalized,
+                offset: Some(last_token.offset()),
+                rule: None,
+                message: report.to_string(),
+            },
+            _ => Failure {
+                input: input.to_owned(),
+                normalized,
+                token: None,
+                offset: None,
+                rule: None,
+                message: first_line(&report.to_string()),
+            },
+        }
+    }
+}
+
+/// `at non-terminal "X"`があればX、無ければ`Current production is:`の規則名
+fn failed_rule(cause: &str) -> Option<String> {
+    let non_terminal = cause
+        .split("at non-terminal \"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next());
+
+    let production = cause
+        .split("Current production is:\n")
+        .nth(1)
+        .and_then(|rest| rest.split("*/").nth(1))
+        .and_then(|rest| rest.split(':').next());
+
+    non_terminal
+        .or(production)
+        .map(|rule| rule.trim().to_owned())
+}
+
+fn first_line(message: &str) -> String {
+    message.lines().next().unwrap_or_default().trim().to_owned()
+}
+
+/// カバレッジの集計結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoverageReport {
+    /// パースした本文の件数
+    pub total: usize,
+    /// パースに成功した件数
+    pub parsed: usize,
+    /// パースに失敗した本文
+    pub failures: Vec<Failure>,
+    /// `SkillEffect`のバリアント毎の件数
+    pub effects: BTreeMap<String, usize>,
+    /// `ShapeType`のバリアント毎の件数
+    pub shapes: BTreeMap<String, usize>,
+}
+
+impl CoverageReport {
+    /// コーパスの各本文をパースして集計する。空行は読み飛ばす
+    pub fn from_corpus<'a, I>(corpus: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut report = CoverageReport::default();
+
+        for input in corpus.into_iter().map(str::trim).filter(|s| !s.is_empty()) {
+            report.total += 1;
+
+            let normalized = normalize(input);
+
+            match SkillGrammar::parse_skills(&normalized) {
+                Ok(skills) => {
+                    report.parsed += 1;
+                    report.count(&skills);
+                }
+                Err(error) => report.failures.push(Failure::new(input, normalized, error)),
+            }
+        }
+        report
+    }
+
+    fn count(self: &mut Self, skills: &[Skill]) {
+        for skill in skills {
+            *self
+                .effects
+                .entry(skill.effect.name().to_owned())
+                .or_default() += 1;
+
+            if let SkillEffect::DropShapeGen(shapes) = &skill.effect {
+                for shape in shapes {
+                    *self.shapes.entry(shape.name().to_owned()).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// 失敗した件数
+    pub fn failed(self: &Self) -> usize {
+        self.failures.len()
+    }
+
+    /// 成功率(0.0〜1.0)
+    pub fn ratio(self: &Self) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => self.parsed as f64 / total as f64,
+        }
+    }
+
+    /// 失敗を(規則, トークン)でまとめた件数。件数の多い順
+    pub fn failure_groups(self: &Self) -> Vec<((String, String), usize)> {
+        let mut groups: BTreeMap<(String, String), usize> = BTreeMap::new();
+
+        for failure in &self.failures {
+            let key = (
+                failure.rule.clone().unwrap_or_else(|| "-".to_owned()),
+                failure
+                    .token
+                    .clone()
+                    .unwrap_or_else(|| failure.message.clone()),
+            );
+            *groups.entry(key).or_default() += 1;
+        }
+
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        groups
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = |count: usize| match self.total {
+            0 => 0.0,
+            total => count as f64 * 100.0 / total as f64,
+        };
+
+        writeln!(
+            f,
+            "parsed: {}/{} ({:.1}%), failed: {}",
+            self.parsed,
+            self.total,
+            percent(self.parsed),
+            self.failed()
+        )?;
+
+        if !self.failures.is_empty() {
+            writeln!(f, "\nfailures (rule, token, count, +coverage):")?;
+            for ((rule, token), count) in self.failure_groups() {
+                writeln!(f, "  {rule}\t{token}\t{count}\t+{:.1}%", percent(count))?;
+            }
+        }
+
+        writeln!(f, "\nSkillEffect:")?;
+        for (name, count) in &self.effects {
+            writeln!(f, "  {name}\t{count}")?;
+        }
+
+        if !self.shapes.is_empty() {
+            writeln!(f, "\nShapeType:")?;
+            for (name, count) in &self.shapes {
+                writeln!(f, "  {name}\t{count}")?;
+            }
+        }
+        Ok(())
+    }
+}
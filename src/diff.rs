@@ -0,0 +1,328 @@
+//! 2つのスキルリストの意味的な差分
+//!
+//! スキル本文の修正(上方修正など)の前後のパース結果を比較し、効果毎に何が変わったかを列挙する。
+//! 効果は`SkillEffect`のバリアントの並びの最長共通部分列で対応付け、対応付かない効果は追加、削除とする。
+//!
+//! ```text
+//! ランダムで火と水ドロップを2個ずつ生成。 → ドロップのロックを解除し、ランダムで火、水、木ドロップを3個ずつ生成。
+//!
+//! + ドロップのロック状態を解除。
+//! ~ ランダムで火と水ドロップを2個ずつ生成。 → ランダムで火、水、木ドロップを3個ずつ生成。
+//!   除外: 木ドロップを追加
+//!   火: 2個 → 3個
+//!   水: 2個 → 3個
+//!   木: 追加(3個)
+//! ```
+
+use std::fmt;
+
+use super::drop_set::DropSet;
+use super::japanese::drops_to_japanese;
+use super::schema::*;
+use super::skill::*;
+
+/// 効果1つ分の差分
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// 修正後にだけある効果
+    Added(Skill),
+    /// 修正前にだけある効果
+    Removed(Skill),
+    /// 同じ種類の効果の内容の変化
+    Modified {
+        before: Skill,
+        after: Skill,
+        details: Vec<Detail>,
+    },
+}
+
+/// 同じ種類の効果の中で変化した項目
+#[derive(Clone, Debug, PartialEq)]
+pub enum Detail {
+    /// ドロップ毎の個数。ランダム生成の生成数。生成しないドロップは0個とする
+    Quantity(Drop, usize, usize),
+    /// 個数。ルーレット生成の個数
+    Count(usize, usize),
+    /// 効果の対象に追加されたドロップ
+    /// * 0: 対象の種類。`変換元`、`変換先`など
+    DropsAdded(&'static str, Drops),
+    /// 効果の対象から削除されたドロップ
+    /// * 0: 対象の種類。`変換元`、`変換先`など
+    DropsRemoved(&'static str, Drops),
+    /// 追加された形状
+    ShapesAdded(Vec<ShapeType>),
+    /// 削除された形状
+    ShapesRemoved(Vec<ShapeType>),
+    /// 効果ターン数
+    Turns(Option<usize>, Option<usize>),
+    /// 副次効果
+    SubEffect(Option<SubEffect>, Option<SubEffect>),
+    /// 上記の項目に分けられない効果の変化
+    Effect(SkillEffect, SkillEffect),
+}
+
+/// 2つのスキルリストの差分。変化のない効果は含まない
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkillDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SkillDiff {
+    /// 修正前後のスキルリストを比較する
+    pub fn new(before: &[Skill], after: &[Skill]) -> Self {
+        let mut changes = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        for (bi, aj) in align(before, after) {
+            changes.extend(before[i..bi].iter().cloned().map(Change::Removed));
+            changes.extend(after[j..aj].iter().cloned().map(Change::Added));
+
+            // ドロップや形状の並び替えだけなら、項目が空になるので変化として扱わない
+            let (b, a) = (&before[bi], &after[aj]);
+            let details = details(b, a);
+            if !details.is_empty() {
+                changes.push(Change::Modified {
+                    before: b.clone(),
+                    after: a.clone(),
+                    details,
+                });
+            }
+            (i, j) = (bi + 1, aj + 1);
+        }
+        changes.extend(before[i..].iter().cloned().map(Change::Removed));
+        changes.extend(after[j..].iter().cloned().map(Change::Added));
+
+        SkillDiff { changes }
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// `SkillEffect`のバリアントが同じ効果の組を、最長共通部分列で前から順に求める
+fn align(before: &[Skill], after: &[Skill]) -> Vec<(usize, usize)> {
+    let before: Vec<&str> = before.iter().map(|s| s.effect.name()).collect();
+    let after: Vec<&str> = after.iter().map(|s| s.effect.name()).collect();
+
+    // lcs[i][j]: before[i..]とafter[j..]の最長共通部分列の長さ
+    let mut lcs = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lcs[i][j] = match before[i] == after[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() && j < after.len() {
+        if before[i] == after[j] {
+            pairs.push((i, j));
+            (i, j) = (i + 1, j + 1);
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// 同じ種類の効果の変化した項目
+fn details(before: &Skill, after: &Skill) -> Vec<Detail> {
+    let mut details = effect_details(&before.effect, &after.effect)
+        .unwrap_or_else(|| vec![Detail::Effect(before.effect.clone(), after.effect.clone())]);
+
+    if before.turns_of_apply != after.turns_of_apply {
+        details.push(Detail::Turns(before.turns_of_apply, after.turns_of_apply));
+    }
+    if before.sub_effects != after.sub_effects {
+        details.push(Detail::SubEffect(
+            before.sub_effects.clone(),
+            after.sub_effects.clone(),
+        ));
+    }
+    details
+}
+
+/// 効果の変化を項目に分ける。項目に分けられない場合は`None`
+fn effect_details(before: &SkillEffect, after: &SkillEffect) -> Option<Vec<Detail>> {
+    if before == after {
+        return Some(Vec::new());
+    }
+
+    match (before, after) {
+        (SkillEffect::ChangeDropAToB(bf, bt), SkillEffect::ChangeDropAToB(af, at)) => Some(
+            [
+                drops_details("変換元", *bf, *af),
+                drops_details("変換先", set(bt), set(at)),
+            ]
+            .concat(),
+        ),
+        (SkillEffect::ChangeAllOfBoard(b), SkillEffect::ChangeAllOfBoard(a)) => {
+            Some(drops_details("変化先", set(b), set(a)))
+        }
+        (SkillEffect::GenRandomDrop(be, bt), SkillEffect::GenRandomDrop(ae, at)) => {
+            Some([drops_details("除外", *be, *ae), quantity_details(bt, at)].concat())
+        }
+        (SkillEffect::DropShapeGen(b), SkillEffect::DropShapeGen(a)) => {
+            let removed: Vec<ShapeType> = b.iter().filter(|s| !a.contains(s)).cloned().collect();
+            let added: Vec<ShapeType> = a.iter().filter(|s| !b.contains(s)).cloned().collect();
+
+            // 同じ形状の並び替えは変化として扱わない
+            let mut details = Vec::new();
+            if !removed.is_empty() {
+                details.push(Detail::ShapesRemoved(removed));
+            }
+            if !added.is_empty() {
+                details.push(Detail::ShapesAdded(added));
+            }
+            Some(details)
+        }
+        (SkillEffect::DropLock(b), SkillEffect::DropLock(a))
+        | (SkillEffect::DropPowerUp(b), SkillEffect::DropPowerUp(a))
+        | (SkillEffect::FallLockDrop(b), SkillEffect::FallLockDrop(a)) => {
+            Some(drops_details("対象", set(b), set(a)))
+        }
+        (SkillEffect::DropFalloff(b, bv), SkillEffect::DropFalloff(a, av)) if bv == av => {
+            Some(drops_details("対象", set(b), set(a)))
+        }
+        (SkillEffect::GenRoulette(b), SkillEffect::GenRoulette(a)) => {
+            Some(vec![Detail::Count(*b, *a)])
+        }
+        _ => None,
+    }
+}
+
+fn set(drops: &Drops) -> DropSet {
+    DropSet::from(drops.as_slice())
+}
+
+/// ドロップの集合の追加、削除
+fn drops_details(role: &'static str, before: DropSet, after: DropSet) -> Vec<Detail> {
+    let mut details = Vec::new();
+
+    let removed = before - after;
+    if !removed.is_empty() {
+        details.push(Detail::DropsRemoved(role, removed.to_drops()));
+    }
+    let added = after - before;
+    if !added.is_empty() {
+        details.push(Detail::DropsAdded(role, added.to_drops()));
+    }
+    details
+}
+
+/// ドロップ毎の生成数の変化。修正前、修正後の順に出現したドロップの順序で並べる
+fn quantity_details(before: &GenDropsWithQty, after: &GenDropsWithQty) -> Vec<Detail> {
+    let qty = |list: &GenDropsWithQty, drop: &Drop| -> usize {
+        list.iter()
+            .filter(|(d, _)| d == drop)
+            .map(|(_, qty)| qty)
+            .sum()
+    };
+
+    let mut drops: Drops = Vec::new();
+    for (drop, _) in before.iter().chain(after) {
+        if !drops.contains(drop) {
+            drops.push(drop.clone());
+        }
+    }
+
+    drops
+        .into_iter()
+        .filter_map(|drop| {
+            let (b, a) = (qty(before, &drop), qty(after, &drop));
+            (b != a).then_some(Detail::Quantity(drop, b, a))
+        })
+        .collect()
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(skill) => writeln!(f, "+ {skill}"),
+            Change::Removed(skill) => writeln!(f, "- {skill}"),
+            Change::Modified {
+                before,
+                after,
+                details,
+            } => {
+                writeln!(f, "~ {before} → {after}")?;
+                details
+                    .iter()
+                    .try_for_each(|detail| writeln!(f, "  {detail}"))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Detail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let drops = |drops: &Drops| drops_to_japanese(drops);
+        let shapes = |shapes: &[ShapeType]| {
+            shapes
+                .iter()
+                .map(ShapeType::to_japanese)
+                .collect::<Vec<_>>()
+                .join("、")
+        };
+
+        match self {
+            Detail::Quantity(drop, 0, after) => {
+                write!(f, "{}: 追加({after}個)", drop.to_japanese())
+            }
+            Detail::Quantity(drop, before, 0) => {
+                write!(f, "{}: 削除({before}個)", drop.to_japanese())
+            }
+            Detail::Quantity(drop, before, after) => {
+                write!(f, "{}: {before}個 → {after}個", drop.to_japanese())
+            }
+            Detail::Count(before, after) => write!(f, "個数: {before}個 → {after}個"),
+            Detail::DropsAdded(role, d) => write!(f, "{role}: {}を追加", drops(d)),
+            Detail::DropsRemoved(role, d) => write!(f, "{role}: {}を削除", drops(d)),
+            Detail::ShapesAdded(s) => write!(f, "形状: {}を追加", shapes(s)),
+            Detail::ShapesRemoved(s) => write!(f, "形状: {}を削除", shapes(s)),
+            Detail::Turns(before, after) => {
+                let turns = |turns: &Option<usize>| match turns {
+                    Some(turns) => format!("{turns}ターン"),
+                    None => "なし".to_owned(),
+                };
+                write!(f, "ターン数: {} → {}", turns(before), turns(after))
+            }
+            Detail::SubEffect(before, after) => {
+                let sub_effect = |sub_effect: &Option<SubEffect>| match sub_effect {
+                    Some(sub_effect) => sub_effect.to_japanese(),
+                    None => "なし".to_owned(),
+                };
+                write!(
+                    f,
+                    "副次効果: {} → {}",
+                    sub_effect(before),
+                    sub_effect(after)
+                )
+            }
+            Detail::Effect(before, after) => {
+                let effect = |effect: &SkillEffect| {
+                    Skill {
+                        effect: effect.clone(),
+                        ..Default::default()
+                    }
+                    .to_japanese()
+                };
+                write!(f, "効果: {} → {}", effect(before), effect(after))
+            }
+        }
+    }
+}
+
+impl fmt::Display for SkillDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.changes
+            .iter()
+            .try_for_each(|change| write!(f, "{change}"))
+    }
+}
@@ -0,0 +1,228 @@
+//! 順序を持たないドロップの集合
+//!
+//! `Drops`(`Vec<Drop>`)は`火と木`と`木と火`が等しくならず、重複も持てる。
+//! 変換元や生成時の除外ドロップのように順序に意味が無い箇所では`DropSet`を使う。
+//! 10種類のドロップを1ビットずつ持つビット集合で、列挙は常にビットの順序(`Drop::all()`の順序)になる。
+//! ドロップの一覧(`Drop::all()`、5属性、5属性＋回復)はここで定義したものから作る。
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+
+use super::schema::*;
+
+/// ドロップの集合
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DropSet(u16);
+
+/// 10種類全てのビット
+const ALL_BITS: u16 = (1 << 10) - 1;
+
+/// ビットの位置の順に並べたドロップ。`bit()`と同じ順序にする
+const ORDER: [Drop; 10] = [
+    Drop::Colored(Color::Fire),
+    Drop::Colored(Color::Water),
+    Drop::Colored(Color::Wood),
+    Drop::Colored(Color::Lightning),
+    Drop::Colored(Color::Dark),
+    Drop::NonColored(NonColoredDrop::Recovery),
+    Drop::NonColored(NonColoredDrop::Disturb),
+    Drop::NonColored(NonColoredDrop::Bomb),
+    Drop::NonColored(NonColoredDrop::Poison),
+    Drop::NonColored(NonColoredDrop::DeadlyPoison),
+];
+
+impl DropSet {
+    /// 空集合
+    pub const fn empty() -> Self {
+        DropSet(0)
+    }
+
+    /// 5属性＋回復、お邪魔、爆弾、毒、猛毒
+    pub const fn all() -> Self {
+        DropSet(ALL_BITS)
+    }
+
+    /// 5属性(火、水、木、光、闇)
+    pub const fn five_attributes() -> Self {
+        DropSet(0b11111)
+    }
+
+    /// 5属性＋回復。通常時に落ちてくるドロップで、全ドロップ強化の対象
+    pub const fn five_attributes_and_recovery() -> Self {
+        DropSet(0b111111)
+    }
+
+    /// `ORDER`での位置をビットの位置とする
+    fn bit(drop: &Drop) -> u16 {
+        let idx = match drop {
+            Drop::Colored(Color::Fire) => 0,
+            Drop::Colored(Color::Water) => 1,
+            Drop::Colored(Color::Wood) => 2,
+            Drop::Colored(Color::Lightning) => 3,
+            Drop::Colored(Color::Dark) => 4,
+            Drop::NonColored(NonColoredDrop::Recovery) => 5,
+            Drop::NonColored(NonColoredDrop::Disturb) => 6,
+            Drop::NonColored(NonColoredDrop::Bomb) => 7,
+            Drop::NonColored(NonColoredDrop::Poison) => 8,
+            Drop::NonColored(NonColoredDrop::DeadlyPoison) => 9,
+        };
+        1 << idx
+    }
+
+    pub fn insert(self: &mut Self, drop: &Drop) {
+        self.0 |= Self::bit(drop);
+    }
+
+    pub fn remove(self: &mut Self, drop: &Drop) {
+        self.0 &= !Self::bit(drop);
+    }
+
+    pub fn contains(self: &Self, drop: &Drop) -> bool {
+        self.0 & Self::bit(drop) != 0
+    }
+
+    pub fn len(self: &Self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.0 == 0
+    }
+
+    /// 和集合
+    pub fn union(self: &Self, other: &Self) -> Self {
+        DropSet(self.0 | other.0)
+    }
+
+    /// 積集合
+    pub fn intersection(self: &Self, other: &Self) -> Self {
+        DropSet(self.0 & other.0)
+    }
+
+    /// 差集合
+    pub fn difference(self: &Self, other: &Self) -> Self {
+        DropSet(self.0 & !other.0)
+    }
+
+    /// 10種類のドロップに対する補集合
+    pub fn complement(self: &Self) -> Self {
+        DropSet(!self.0 & ALL_BITS)
+    }
+
+    pub fn is_subset(self: &Self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// `Drop::all()`の順序で列挙する
+    pub fn iter(self: &Self) -> impl Iterator<Item = Drop> + '_ {
+        ORDER
+            .iter()
+            .filter(move |drop| self.contains(drop))
+            .cloned()
+    }
+
+    /// `Drop::all()`の順序のドロップリスト
+    pub fn to_drops(self: &Self) -> Drops {
+        self.iter().collect()
+    }
+}
+
+impl FromIterator<Drop> for DropSet {
+    fn from_iter<T: IntoIterator<Item = Drop>>(iter: T) -> Self {
+        let mut set = DropSet::empty();
+        iter.into_iter().for_each(|drop| set.insert(&drop));
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Drop> for DropSet {
+    fn from_iter<T: IntoIterator<Item = &'a Drop>>(iter: T) -> Self {
+        let mut set = DropSet::empty();
+        iter.into_iter().for_each(|drop| set.insert(drop));
+        set
+    }
+}
+
+impl From<Drops> for DropSet {
+    fn from(drops: Drops) -> Self {
+        drops.into_iter().collect()
+    }
+}
+
+impl From<&[Drop]> for DropSet {
+    fn from(drops: &[Drop]) -> Self {
+        drops.iter().collect()
+    }
+}
+
+impl From<Drop> for DropSet {
+    fn from(drop: Drop) -> Self {
+        DropSet(Self::bit(&drop))
+    }
+}
+
+impl BitOr for DropSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+impl BitAnd for DropSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl Sub for DropSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(&rhs)
+    }
+}
+
+impl Not for DropSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl fmt::Debug for DropSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// JSONでは`Drop::all()`の順序に並べたドロップの配列
+#[cfg(feature = "serde")]
+impl serde::Serialize for DropSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DropSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Drops::deserialize(deserializer).map(DropSet::from)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for DropSet {
+    fn schema_name() -> String {
+        "DropSet".to_owned()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = gen.subschema_for::<Drops>().into_object();
+        schema.array().unique_items = Some(true);
+        schema.into()
+    }
+}
@@ -0,0 +1,54 @@
+//! 敵スキル
+//!
+//! 敵スキルの盤面への効果の多くは、スキルと同じ言い回しで書かれる。
+//! 本文を`。`で区切った文毎に、敵スキルの文法(`enemy_skill.par`)でパースできなければスキルの文法でパースする。
+//! ロック目覚め(`DropsEasierToFalloffOrFallLockDrop`)、雲、テープ、盤面サイズ変更(`BoardChange`)、
+//! 指定型生成(`ShapeType`)、変換などはスキルと同じ`Skill`になる。
+//!
+//! ```text
+//! 1ターンの間、火ドロップがロック状態で落ちてくる。3ターンの間、ドロップ操作時間が2秒減少。スキル遅延(2ターン)。
+//! ```
+//!
+//! は`Board(FallLockDrop)`、`MoveTimeDecrease(Some(3), 2.0)`、`SkillDelay(Some(2))`の3つになる。
+
+use super::enemy_skill_grammar::EnemySkillGrammar;
+use super::skill::*;
+use super::skill_grammar::SkillGrammar;
+
+const PERIOD: char = '。';
+
+/// 敵スキルの効果
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum EnemyEffect {
+    /// 盤面、落ちてくるドロップへの効果。スキルの文法でパースした結果
+    Board(Skill),
+    /// ドロップ操作時間の減少
+    /// * 0: ターン数
+    /// * 1: 減少する秒数
+    MoveTimeDecrease(Option<usize>, f64),
+    /// スキル遅延
+    /// * 0: 遅延するターン数。`スキル遅延`のみの場合は`None`
+    SkillDelay(Option<usize>),
+}
+
+/// 敵スキル本文を正規化(`normalize::normalize`)してから、文毎にパースする
+/// どちらの文法でもパースできない文があればエラーを返す
+pub fn parse_enemy_skill(input: &str) -> miette::Result<Vec<EnemyEffect>> {
+    let input = super::normalize::normalize(input);
+    let mut effects = Vec::new();
+
+    for sentence in input.split_inclusive(PERIOD) {
+        match EnemySkillGrammar::parse_enemy_effect(sentence) {
+            Ok(effect) => effects.push(effect),
+            Err(_) => effects.extend(
+                SkillGrammar::parse_skills(sentence)?
+                    .into_iter()
+                    .map(EnemyEffect::Board),
+            ),
+        }
+    }
+    Ok(effects)
+}
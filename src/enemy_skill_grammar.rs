@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use super::enemy_skill::EnemyEffect;
+use super::enemy_skill_grammar_trait as ast;
+use super::enemy_skill_grammar_trait::EnemySkillGrammarTrait;
+
+/// 敵スキル本文のうち、スキルの文法に無い文のセマンティックアクション
+#[derive(Debug, Default, PartialEq)]
+pub struct EnemySkillGrammar<'t> {
+    pub effect: Option<EnemyEffect>,
+    pd: PhantomData<&'t str>,
+}
+
+impl EnemySkillGrammar<'_> {
+    pub fn new() -> Self {
+        EnemySkillGrammar {
+            effect: None,
+            pd: PhantomData,
+        }
+    }
+
+    /// 正規化済みの1文をパースする
+    pub fn parse_enemy_effect(sentence: &str) -> miette::Result<EnemyEffect> {
+        let grammar = &mut EnemySkillGrammar::new();
+        super::enemy_skill_parser::parse(sentence, "<input>", grammar)?;
+
+        grammar
+            .effect
+            .take()
+            .ok_or_else(|| miette::miette!("no enemy effect in {sentence:?}"))
+    }
+}
+
+/// `2`、`1.5`
+fn number(arg: &ast::Number) -> f64 {
+    let text = match arg {
+        ast::Number::Integer(number) => number.integer.integer.text(),
+        ast::Number::Decimal(number) => number.decimal.decimal.text(),
+    };
+    text.parse::<f64>().unwrap()
+}
+
+/// ターン数
+fn integer(arg: &ast::Integer) -> miette::Result<usize> {
+    let text = arg.integer.text();
+
+    text.parse::<usize>()
+        .map_err(|e| miette::miette!("invalid number of turns {text:?}: {e}"))
+}
+
+impl<'t> EnemySkillGrammarTrait<'t> for EnemySkillGrammar<'t> {
+    fn enemy_skill_line(&mut self, arg: &ast::EnemySkillLine<'t>) -> miette::Result<()> {
+        let turns = arg
+            .enemy_skill_line_opt
+            .as_ref()
+            .map(|opt| integer(&opt.turns_prefix.integer))
+            .transpose()?;
+
+        let effect = match &*arg.enemy_stmt {
+            ast::EnemyStmt::MoveTimeStmt(stmt) => {
+                EnemyEffect::MoveTimeDecrease(turns, number(&stmt.move_time_stmt.number))
+            }
+            ast::EnemyStmt::SkillDelayStmt(stmt) => {
+                let delay = match &*stmt.skill_delay_stmt.skill_delay_body {
+                    ast::SkillDelayBody::WordDelaySkillDelayBodyOpt(body) => body
+                        .skill_delay_body_opt
+                        .as_ref()
+                        .map(|opt| integer(&opt.integer))
+                        .transpose()?,
+                    ast::SkillDelayBody::ParticleIntegerWordTurnWordDelay(body) => {
+                        Some(integer(&body.integer)?)
+                    }
+                };
+                EnemyEffect::SkillDelay(delay)
+            }
+        };
+
+        self.effect = Some(effect);
+        Ok(())
+    }
+}
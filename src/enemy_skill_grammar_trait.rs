@@ -0,0 +1,1480 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use derive_builder::Builder;
+#[allow(unused_imports)]
+use parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::Token;
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::miette::{bail, miette, IntoDiagnostic, Result};
+use parol_runtime::parser::{ParseTreeStackEntry, ParseTreeType, UserActionsTrait};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait EnemySkillGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'EnemySkillLine'
+    fn enemy_skill_line(&mut self, _arg: &EnemySkillLine<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TurnsPrefix'
+    fn turns_prefix(&mut self, _arg: &TurnsPrefix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnemyStmt'
+    fn enemy_stmt(&mut self, _arg: &EnemyStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'MoveTimeStmt'
+    fn move_time_stmt(&mut self, _arg: &MoveTimeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SkillDelayStmt'
+    fn skill_delay_stmt(&mut self, _arg: &SkillDelayStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SkillDelayBody'
+    fn skill_delay_body(&mut self, _arg: &SkillDelayBody<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Number'
+    fn number(&mut self, _arg: &Number<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Particle'
+    fn particle(&mut self, _arg: &Particle<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordTurn'
+    fn word_turn(&mut self, _arg: &WordTurn<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordBetween'
+    fn word_between(&mut self, _arg: &WordBetween<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordMoveTime'
+    fn word_move_time(&mut self, _arg: &WordMoveTime<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSecond'
+    fn word_second(&mut self, _arg: &WordSecond<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordDecrease'
+    fn word_decrease(&mut self, _arg: &WordDecrease<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSkill'
+    fn word_skill(&mut self, _arg: &WordSkill<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordDelay'
+    fn word_delay(&mut self, _arg: &WordDelay<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'No'
+    fn no(&mut self, _arg: &No<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Ga'
+    fn ga(&mut self, _arg: &Ga<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Wo'
+    fn wo(&mut self, _arg: &Wo<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LParen'
+    fn l_paren(&mut self, _arg: &LParen<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RParen'
+    fn r_paren(&mut self, _arg: &RParen<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Camma'
+    fn camma(&mut self, _arg: &Camma<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Period'
+    fn period(&mut self, _arg: &Period<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Decimal'
+    fn decimal(&mut self, _arg: &Decimal<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Integer'
+    fn integer(&mut self, _arg: &Integer<'t>) -> Result<()> {
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 4
+///
+/// EnemyStmt: MoveTimeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnemyStmtMoveTimeStmt<'t> {
+    pub move_time_stmt: Box<MoveTimeStmt<'t>>,
+}
+
+///
+/// Type derived for production 5
+///
+/// EnemyStmt: SkillDelayStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnemyStmtSkillDelayStmt<'t> {
+    pub skill_delay_stmt: Box<SkillDelayStmt<'t>>,
+}
+
+///
+/// Type derived for production 8
+///
+/// SkillDelayBody: WordDelay SkillDelayBodyOpt /* Option */;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillDelayBodyWordDelaySkillDelayBodyOpt<'t> {
+    pub word_delay: Box<WordDelay<'t>>,
+    pub skill_delay_body_opt: Option<Box<SkillDelayBodyOpt<'t>>>,
+}
+
+///
+/// Type derived for production 9
+///
+/// SkillDelayBody: Particle Integer WordTurn WordDelay;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillDelayBodyParticleIntegerWordTurnWordDelay<'t> {
+    pub particle: Box<Particle<'t>>,
+    pub integer: Box<Integer<'t>>,
+    pub word_turn: Box<WordTurn<'t>>,
+    pub word_delay: Box<WordDelay<'t>>,
+}
+
+///
+/// Type derived for production 12
+///
+/// Number: Integer;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NumberInteger<'t> {
+    pub integer: Box<Integer<'t>>,
+}
+
+///
+/// Type derived for production 13
+///
+/// Number: Decimal;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NumberDecimal<'t> {
+    pub decimal: Box<Decimal<'t>>,
+}
+
+///
+/// Type derived for production 14
+///
+/// Particle: Ga;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ParticleGa<'t> {
+    pub ga: Box<Ga<'t>>,
+}
+
+///
+/// Type derived for production 15
+///
+/// Particle: Wo;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ParticleWo<'t> {
+    pub wo: Box<Wo<'t>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Camma
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Camma<'t> {
+    pub camma: Token<'t>, /* 、 */
+}
+
+///
+/// Type derived for non-terminal Decimal
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Decimal<'t> {
+    pub decimal: Token<'t>, /* [0-9]+\.[0-9]+ */
+}
+
+///
+/// Type derived for non-terminal EnemySkillLine
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnemySkillLine<'t> {
+    pub enemy_skill_line_opt: Option<Box<EnemySkillLineOpt<'t>>>,
+    pub enemy_stmt: Box<EnemyStmt<'t>>,
+    pub period: Box<Period<'t>>,
+}
+
+///
+/// Type derived for non-terminal EnemySkillLineOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnemySkillLineOpt<'t> {
+    pub turns_prefix: Box<TurnsPrefix<'t>>,
+}
+
+///
+/// Type derived for non-terminal EnemyStmt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EnemyStmt<'t> {
+    MoveTimeStmt(EnemyStmtMoveTimeStmt<'t>),
+    SkillDelayStmt(EnemyStmtSkillDelayStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal Ga
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Ga<'t> {
+    pub ga: Token<'t>, /* が */
+}
+
+///
+/// Type derived for non-terminal Integer
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Integer<'t> {
+    pub integer: Token<'t>, /* [0-9]+ */
+}
+
+///
+/// Type derived for non-terminal LParen
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LParen<'t> {
+    pub l_paren: Token<'t>, /* ( */
+}
+
+///
+/// Type derived for non-terminal MoveTimeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MoveTimeStmt<'t> {
+    pub word_move_time: Box<WordMoveTime<'t>>,
+    pub particle: Box<Particle<'t>>,
+    pub number: Box<Number<'t>>,
+    pub word_second: Box<WordSecond<'t>>,
+    pub word_decrease: Box<WordDecrease<'t>>,
+}
+
+///
+/// Type derived for non-terminal No
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct No<'t> {
+    pub no: Token<'t>, /* の */
+}
+
+///
+/// Type derived for non-terminal Number
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Number<'t> {
+    Integer(NumberInteger<'t>),
+    Decimal(NumberDecimal<'t>),
+}
+
+///
+/// Type derived for non-terminal Particle
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Particle<'t> {
+    Ga(ParticleGa<'t>),
+    Wo(ParticleWo<'t>),
+}
+
+///
+/// Type derived for non-terminal Period
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Period<'t> {
+    pub period: Token<'t>, /* 。 */
+}
+
+///
+/// Type derived for non-terminal RParen
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RParen<'t> {
+    pub r_paren: Token<'t>, /* ) */
+}
+
+///
+/// Type derived for non-terminal SkillDelayBody
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum SkillDelayBody<'t> {
+    WordDelaySkillDelayBodyOpt(SkillDelayBodyWordDelaySkillDelayBodyOpt<'t>),
+    ParticleIntegerWordTurnWordDelay(SkillDelayBodyParticleIntegerWordTurnWordDelay<'t>),
+}
+
+///
+/// Type derived for non-terminal SkillDelayBodyOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillDelayBodyOpt<'t> {
+    pub l_paren: Box<LParen<'t>>,
+    pub integer: Box<Integer<'t>>,
+    pub word_turn: Box<WordTurn<'t>>,
+    pub r_paren: Box<RParen<'t>>,
+}
+
+///
+/// Type derived for non-terminal SkillDelayStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillDelayStmt<'t> {
+    pub word_skill: Box<WordSkill<'t>>,
+    pub skill_delay_body: Box<SkillDelayBody<'t>>,
+}
+
+///
+/// Type derived for non-terminal TurnsPrefix
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TurnsPrefix<'t> {
+    pub integer: Box<Integer<'t>>,
+    pub word_turn: Box<WordTurn<'t>>,
+    pub no: Box<No<'t>>,
+    pub word_between: Box<WordBetween<'t>>,
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for non-terminal Wo
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Wo<'t> {
+    pub wo: Token<'t>, /* を */
+}
+
+///
+/// Type derived for non-terminal WordBetween
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordBetween<'t> {
+    pub word_between: Token<'t>, /* 間 */
+}
+
+///
+/// Type derived for non-terminal WordDecrease
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordDecrease<'t> {
+    pub word_decrease: Token<'t>, /* 減少 */
+}
+
+///
+/// Type derived for non-terminal WordDelay
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordDelay<'t> {
+    pub word_delay: Token<'t>, /* 遅延 */
+}
+
+///
+/// Type derived for non-terminal WordMoveTime
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordMoveTime<'t> {
+    pub word_move_time: Token<'t>, /* ドロップ操作時間 */
+}
+
+///
+/// Type derived for non-terminal WordSecond
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSecond<'t> {
+    pub word_second: Token<'t>, /* 秒 */
+}
+
+///
+/// Type derived for non-terminal WordSkill
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSkill<'t> {
+    pub word_skill: Token<'t>, /* スキル */
+}
+
+///
+/// Type derived for non-terminal WordTurn
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordTurn<'t> {
+    pub word_turn: Token<'t>, /* ターン */
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Camma(Camma<'t>),
+    Decimal(Decimal<'t>),
+    EnemySkillLine(EnemySkillLine<'t>),
+    EnemySkillLineOpt(Option<Box<EnemySkillLineOpt<'t>>>),
+    EnemyStmt(EnemyStmt<'t>),
+    Ga(Ga<'t>),
+    Integer(Integer<'t>),
+    LParen(LParen<'t>),
+    MoveTimeStmt(MoveTimeStmt<'t>),
+    No(No<'t>),
+    Number(Number<'t>),
+    Particle(Particle<'t>),
+    Period(Period<'t>),
+    RParen(RParen<'t>),
+    SkillDelayBody(SkillDelayBody<'t>),
+    SkillDelayBodyOpt(Option<Box<SkillDelayBodyOpt<'t>>>),
+    SkillDelayStmt(SkillDelayStmt<'t>),
+    TurnsPrefix(TurnsPrefix<'t>),
+    Wo(Wo<'t>),
+    WordBetween(WordBetween<'t>),
+    WordDecrease(WordDecrease<'t>),
+    WordDelay(WordDelay<'t>),
+    WordMoveTime(WordMoveTime<'t>),
+    WordSecond(WordSecond<'t>),
+    WordSkill(WordSkill<'t>),
+    WordTurn(WordTurn<'t>),
+}
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct EnemySkillGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn EnemySkillGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `EnemySkillGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> EnemySkillGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn EnemySkillGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {}: {:?}", context, item);
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        if !self.item_stack.is_empty() {
+            let item = self.item_stack.pop();
+            if let Some(ref item) = item {
+                trace!("pop     {}: {:?}", context, item);
+            }
+            item
+        } else {
+            None
+        }
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {:?}", s))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// EnemySkillLine: EnemySkillLineOpt /* Option */ EnemyStmt Period;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_skill_line(
+        &mut self,
+        _enemy_skill_line_opt: &ParseTreeStackEntry<'t>,
+        _enemy_stmt: &ParseTreeStackEntry<'t>,
+        _period: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = pop_item!(self, period, Period, context);
+        let enemy_stmt = pop_item!(self, enemy_stmt, EnemyStmt, context);
+        let enemy_skill_line_opt =
+            pop_item!(self, enemy_skill_line_opt, EnemySkillLineOpt, context);
+        let enemy_skill_line_built = EnemySkillLineBuilder::default()
+            .enemy_skill_line_opt(enemy_skill_line_opt)
+            .enemy_stmt(Box::new(enemy_stmt))
+            .period(Box::new(period))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .enemy_skill_line(&enemy_skill_line_built)?;
+        self.push(ASTType::EnemySkillLine(enemy_skill_line_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// EnemySkillLineOpt /* Option<T>::Some */: TurnsPrefix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_skill_line_opt_0(
+        &mut self,
+        _turns_prefix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let turns_prefix = pop_item!(self, turns_prefix, TurnsPrefix, context);
+        let enemy_skill_line_opt_0_built = EnemySkillLineOptBuilder::default()
+            .turns_prefix(Box::new(turns_prefix))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::EnemySkillLineOpt(Some(Box::new(enemy_skill_line_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// EnemySkillLineOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_skill_line_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::EnemySkillLineOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// TurnsPrefix: Integer WordTurn No WordBetween Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn turns_prefix(
+        &mut self,
+        _integer: &ParseTreeStackEntry<'t>,
+        _word_turn: &ParseTreeStackEntry<'t>,
+        _no: &ParseTreeStackEntry<'t>,
+        _word_between: &ParseTreeStackEntry<'t>,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let word_between = pop_item!(self, word_between, WordBetween, context);
+        let no = pop_item!(self, no, No, context);
+        let word_turn = pop_item!(self, word_turn, WordTurn, context);
+        let integer = pop_item!(self, integer, Integer, context);
+        let turns_prefix_built = TurnsPrefixBuilder::default()
+            .integer(Box::new(integer))
+            .word_turn(Box::new(word_turn))
+            .no(Box::new(no))
+            .word_between(Box::new(word_between))
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.turns_prefix(&turns_prefix_built)?;
+        self.push(ASTType::TurnsPrefix(turns_prefix_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// EnemyStmt: MoveTimeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_stmt_0(
+        &mut self,
+        _move_time_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let move_time_stmt = pop_item!(self, move_time_stmt, MoveTimeStmt, context);
+        let enemy_stmt_0_built = EnemyStmtMoveTimeStmtBuilder::default()
+            .move_time_stmt(Box::new(move_time_stmt))
+            .build()
+            .into_diagnostic()?;
+        let enemy_stmt_0_built = EnemyStmt::MoveTimeStmt(enemy_stmt_0_built);
+        // Calling user action here
+        self.user_grammar.enemy_stmt(&enemy_stmt_0_built)?;
+        self.push(ASTType::EnemyStmt(enemy_stmt_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// EnemyStmt: SkillDelayStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_stmt_1(
+        &mut self,
+        _skill_delay_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let skill_delay_stmt = pop_item!(self, skill_delay_stmt, SkillDelayStmt, context);
+        let enemy_stmt_1_built = EnemyStmtSkillDelayStmtBuilder::default()
+            .skill_delay_stmt(Box::new(skill_delay_stmt))
+            .build()
+            .into_diagnostic()?;
+        let enemy_stmt_1_built = EnemyStmt::SkillDelayStmt(enemy_stmt_1_built);
+        // Calling user action here
+        self.user_grammar.enemy_stmt(&enemy_stmt_1_built)?;
+        self.push(ASTType::EnemyStmt(enemy_stmt_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// MoveTimeStmt: WordMoveTime Particle Number WordSecond WordDecrease;
+    ///
+    #[parol_runtime::function_name::named]
+    fn move_time_stmt(
+        &mut self,
+        _word_move_time: &ParseTreeStackEntry<'t>,
+        _particle: &ParseTreeStackEntry<'t>,
+        _number: &ParseTreeStackEntry<'t>,
+        _word_second: &ParseTreeStackEntry<'t>,
+        _word_decrease: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_decrease = pop_item!(self, word_decrease, WordDecrease, context);
+        let word_second = pop_item!(self, word_second, WordSecond, context);
+        let number = pop_item!(self, number, Number, context);
+        let particle = pop_item!(self, particle, Particle, context);
+        let word_move_time = pop_item!(self, word_move_time, WordMoveTime, context);
+        let move_time_stmt_built = MoveTimeStmtBuilder::default()
+            .word_move_time(Box::new(word_move_time))
+            .particle(Box::new(particle))
+            .number(Box::new(number))
+            .word_second(Box::new(word_second))
+            .word_decrease(Box::new(word_decrease))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.move_time_stmt(&move_time_stmt_built)?;
+        self.push(ASTType::MoveTimeStmt(move_time_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// SkillDelayStmt: WordSkill SkillDelayBody;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_delay_stmt(
+        &mut self,
+        _word_skill: &ParseTreeStackEntry<'t>,
+        _skill_delay_body: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let skill_delay_body = pop_item!(self, skill_delay_body, SkillDelayBody, context);
+        let word_skill = pop_item!(self, word_skill, WordSkill, context);
+        let skill_delay_stmt_built = SkillDelayStmtBuilder::default()
+            .word_skill(Box::new(word_skill))
+            .skill_delay_body(Box::new(skill_delay_body))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .skill_delay_stmt(&skill_delay_stmt_built)?;
+        self.push(ASTType::SkillDelayStmt(skill_delay_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// SkillDelayBody: WordDelay SkillDelayBodyOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_delay_body_0(
+        &mut self,
+        _word_delay: &ParseTreeStackEntry<'t>,
+        _skill_delay_body_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let skill_delay_body_opt =
+            pop_item!(self, skill_delay_body_opt, SkillDelayBodyOpt, context);
+        let word_delay = pop_item!(self, word_delay, WordDelay, context);
+        let skill_delay_body_0_built = SkillDelayBodyWordDelaySkillDelayBodyOptBuilder::default()
+            .word_delay(Box::new(word_delay))
+            .skill_delay_body_opt(skill_delay_body_opt)
+            .build()
+            .into_diagnostic()?;
+        let skill_delay_body_0_built =
+            SkillDelayBody::WordDelaySkillDelayBodyOpt(skill_delay_body_0_built);
+        // Calling user action here
+        self.user_grammar
+            .skill_delay_body(&skill_delay_body_0_built)?;
+        self.push(ASTType::SkillDelayBody(skill_delay_body_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// SkillDelayBody: Particle Integer WordTurn WordDelay;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_delay_body_1(
+        &mut self,
+        _particle: &ParseTreeStackEntry<'t>,
+        _integer: &ParseTreeStackEntry<'t>,
+        _word_turn: &ParseTreeStackEntry<'t>,
+        _word_delay: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_delay = pop_item!(self, word_delay, WordDelay, context);
+        let word_turn = pop_item!(self, word_turn, WordTurn, context);
+        let integer = pop_item!(self, integer, Integer, context);
+        let particle = pop_item!(self, particle, Particle, context);
+        let skill_delay_body_1_built =
+            SkillDelayBodyParticleIntegerWordTurnWordDelayBuilder::default()
+                .particle(Box::new(particle))
+                .integer(Box::new(integer))
+                .word_turn(Box::new(word_turn))
+                .word_delay(Box::new(word_delay))
+                .build()
+                .into_diagnostic()?;
+        let skill_delay_body_1_built =
+            SkillDelayBody::ParticleIntegerWordTurnWordDelay(skill_delay_body_1_built);
+        // Calling user action here
+        self.user_grammar
+            .skill_delay_body(&skill_delay_body_1_built)?;
+        self.push(ASTType::SkillDelayBody(skill_delay_body_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 10:
+    ///
+    /// SkillDelayBodyOpt /* Option<T>::Some */: LParen Integer WordTurn RParen;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_delay_body_opt_0(
+        &mut self,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _integer: &ParseTreeStackEntry<'t>,
+        _word_turn: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let word_turn = pop_item!(self, word_turn, WordTurn, context);
+        let integer = pop_item!(self, integer, Integer, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let skill_delay_body_opt_0_built = SkillDelayBodyOptBuilder::default()
+            .l_paren(Box::new(l_paren))
+            .integer(Box::new(integer))
+            .word_turn(Box::new(word_turn))
+            .r_paren(Box::new(r_paren))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SkillDelayBodyOpt(Some(Box::new(skill_delay_body_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
+    /// SkillDelayBodyOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_delay_body_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SkillDelayBodyOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
+    /// Number: Integer;
+    ///
+    #[parol_runtime::function_name::named]
+    fn number_0(
+        &mut self,
+        _integer: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let integer = pop_item!(self, integer, Integer, context);
+        let number_0_built = NumberIntegerBuilder::default()
+            .integer(Box::new(integer))
+            .build()
+            .into_diagnostic()?;
+        let number_0_built = Number::Integer(number_0_built);
+        // Calling user action here
+        self.user_grammar.number(&number_0_built)?;
+        self.push(ASTType::Number(number_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// Number: Decimal;
+    ///
+    #[parol_runtime::function_name::named]
+    fn number_1(
+        &mut self,
+        _decimal: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let decimal = pop_item!(self, decimal, Decimal, context);
+        let number_1_built = NumberDecimalBuilder::default()
+            .decimal(Box::new(decimal))
+            .build()
+            .into_diagnostic()?;
+        let number_1_built = Number::Decimal(number_1_built);
+        // Calling user action here
+        self.user_grammar.number(&number_1_built)?;
+        self.push(ASTType::Number(number_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// Particle: Ga;
+    ///
+    #[parol_runtime::function_name::named]
+    fn particle_0(
+        &mut self,
+        _ga: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ga = pop_item!(self, ga, Ga, context);
+        let particle_0_built = ParticleGaBuilder::default()
+            .ga(Box::new(ga))
+            .build()
+            .into_diagnostic()?;
+        let particle_0_built = Particle::Ga(particle_0_built);
+        // Calling user action here
+        self.user_grammar.particle(&particle_0_built)?;
+        self.push(ASTType::Particle(particle_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// Particle: Wo;
+    ///
+    #[parol_runtime::function_name::named]
+    fn particle_1(
+        &mut self,
+        _wo: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wo = pop_item!(self, wo, Wo, context);
+        let particle_1_built = ParticleWoBuilder::default()
+            .wo(Box::new(wo))
+            .build()
+            .into_diagnostic()?;
+        let particle_1_built = Particle::Wo(particle_1_built);
+        // Calling user action here
+        self.user_grammar.particle(&particle_1_built)?;
+        self.push(ASTType::Particle(particle_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 16:
+    ///
+    /// WordTurn: 'ターン';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_turn(
+        &mut self,
+        word_turn: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_turn = word_turn.token(parse_tree)?.clone();
+        let word_turn_built = WordTurnBuilder::default()
+            .word_turn(word_turn)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_turn(&word_turn_built)?;
+        self.push(ASTType::WordTurn(word_turn_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 17:
+    ///
+    /// WordBetween: '間';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_between(
+        &mut self,
+        word_between: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_between = word_between.token(parse_tree)?.clone();
+        let word_between_built = WordBetweenBuilder::default()
+            .word_between(word_between)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_between(&word_between_built)?;
+        self.push(ASTType::WordBetween(word_between_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 18:
+    ///
+    /// WordMoveTime: 'ドロップ操作時間';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_move_time(
+        &mut self,
+        word_move_time: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_move_time = word_move_time.token(parse_tree)?.clone();
+        let word_move_time_built = WordMoveTimeBuilder::default()
+            .word_move_time(word_move_time)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_move_time(&word_move_time_built)?;
+        self.push(ASTType::WordMoveTime(word_move_time_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 19:
+    ///
+    /// WordSecond: '秒';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_second(
+        &mut self,
+        word_second: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_second = word_second.token(parse_tree)?.clone();
+        let word_second_built = WordSecondBuilder::default()
+            .word_second(word_second)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_second(&word_second_built)?;
+        self.push(ASTType::WordSecond(word_second_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// WordDecrease: '減少';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_decrease(
+        &mut self,
+        word_decrease: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_decrease = word_decrease.token(parse_tree)?.clone();
+        let word_decrease_built = WordDecreaseBuilder::default()
+            .word_decrease(word_decrease)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_decrease(&word_decrease_built)?;
+        self.push(ASTType::WordDecrease(word_decrease_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 21:
+    ///
+    /// WordSkill: 'スキル';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_skill(
+        &mut self,
+        word_skill: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_skill = word_skill.token(parse_tree)?.clone();
+        let word_skill_built = WordSkillBuilder::default()
+            .word_skill(word_skill)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_skill(&word_skill_built)?;
+        self.push(ASTType::WordSkill(word_skill_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// WordDelay: '遅延';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_delay(
+        &mut self,
+        word_delay: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_delay = word_delay.token(parse_tree)?.clone();
+        let word_delay_built = WordDelayBuilder::default()
+            .word_delay(word_delay)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_delay(&word_delay_built)?;
+        self.push(ASTType::WordDelay(word_delay_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
+    ///
+    /// No: 'の';
+    ///
+    #[parol_runtime::function_name::named]
+    fn no(
+        &mut self,
+        no: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let no = no.token(parse_tree)?.clone();
+        let no_built = NoBuilder::default().no(no).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.no(&no_built)?;
+        self.push(ASTType::No(no_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// Ga: 'が';
+    ///
+    #[parol_runtime::function_name::named]
+    fn ga(
+        &mut self,
+        ga: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ga = ga.token(parse_tree)?.clone();
+        let ga_built = GaBuilder::default().ga(ga).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.ga(&ga_built)?;
+        self.push(ASTType::Ga(ga_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
+    ///
+    /// Wo: 'を';
+    ///
+    #[parol_runtime::function_name::named]
+    fn wo(
+        &mut self,
+        wo: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wo = wo.token(parse_tree)?.clone();
+        let wo_built = WoBuilder::default().wo(wo).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.wo(&wo_built)?;
+        self.push(ASTType::Wo(wo_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 26:
+    ///
+    /// LParen: '(';
+    ///
+    #[parol_runtime::function_name::named]
+    fn l_paren(
+        &mut self,
+        l_paren: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_paren = l_paren.token(parse_tree)?.clone();
+        let l_paren_built = LParenBuilder::default()
+            .l_paren(l_paren)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.l_paren(&l_paren_built)?;
+        self.push(ASTType::LParen(l_paren_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 27:
+    ///
+    /// RParen: ')';
+    ///
+    #[parol_runtime::function_name::named]
+    fn r_paren(
+        &mut self,
+        r_paren: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = r_paren.token(parse_tree)?.clone();
+        let r_paren_built = RParenBuilder::default()
+            .r_paren(r_paren)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.r_paren(&r_paren_built)?;
+        self.push(ASTType::RParen(r_paren_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 28:
+    ///
+    /// Camma: '、';
+    ///
+    #[parol_runtime::function_name::named]
+    fn camma(
+        &mut self,
+        camma: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = camma.token(parse_tree)?.clone();
+        let camma_built = CammaBuilder::default()
+            .camma(camma)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.camma(&camma_built)?;
+        self.push(ASTType::Camma(camma_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 29:
+    ///
+    /// Period: '。';
+    ///
+    #[parol_runtime::function_name::named]
+    fn period(
+        &mut self,
+        period: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = period.token(parse_tree)?.clone();
+        let period_built = PeriodBuilder::default()
+            .period(period)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.period(&period_built)?;
+        self.push(ASTType::Period(period_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 30:
+    ///
+    /// Decimal: "[0-9]+\.[0-9]+";
+    ///
+    #[parol_runtime::function_name::named]
+    fn decimal(
+        &mut self,
+        decimal: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let decimal = decimal.token(parse_tree)?.clone();
+        let decimal_built = DecimalBuilder::default()
+            .decimal(decimal)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.decimal(&decimal_built)?;
+        self.push(ASTType::Decimal(decimal_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 31:
+    ///
+    /// Integer: "[0-9]+";
+    ///
+    #[parol_runtime::function_name::named]
+    fn integer(
+        &mut self,
+        integer: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let integer = integer.token(parse_tree)?.clone();
+        let integer_built = IntegerBuilder::default()
+            .integer(integer)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.integer(&integer_built)?;
+        self.push(ASTType::Integer(integer_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for EnemySkillGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item EnemySkillGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeStackEntry<'t>],
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.enemy_skill_line(&children[0], &children[1], &children[2], parse_tree),
+            1 => self.enemy_skill_line_opt_0(&children[0], parse_tree),
+            2 => self.enemy_skill_line_opt_1(parse_tree),
+            3 => self.turns_prefix(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            4 => self.enemy_stmt_0(&children[0], parse_tree),
+            5 => self.enemy_stmt_1(&children[0], parse_tree),
+            6 => self.move_time_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            7 => self.skill_delay_stmt(&children[0], &children[1], parse_tree),
+            8 => self.skill_delay_body_0(&children[0], &children[1], parse_tree),
+            9 => self.skill_delay_body_1(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            10 => self.skill_delay_body_opt_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            11 => self.skill_delay_body_opt_1(parse_tree),
+            12 => self.number_0(&children[0], parse_tree),
+            13 => self.number_1(&children[0], parse_tree),
+            14 => self.particle_0(&children[0], parse_tree),
+            15 => self.particle_1(&children[0], parse_tree),
+            16 => self.word_turn(&children[0], parse_tree),
+            17 => self.word_between(&children[0], parse_tree),
+            18 => self.word_move_time(&children[0], parse_tree),
+            19 => self.word_second(&children[0], parse_tree),
+            20 => self.word_decrease(&children[0], parse_tree),
+            21 => self.word_skill(&children[0], parse_tree),
+            22 => self.word_delay(&children[0], parse_tree),
+            23 => self.no(&children[0], parse_tree),
+            24 => self.ga(&children[0], parse_tree),
+            25 => self.wo(&children[0], parse_tree),
+            26 => self.l_paren(&children[0], parse_tree),
+            27 => self.r_paren(&children[0], parse_tree),
+            28 => self.camma(&children[0], parse_tree),
+            29 => self.period(&children[0], parse_tree),
+            30 => self.decimal(&children[0], parse_tree),
+            31 => self.integer(&children[0], parse_tree),
+            _ => Err(miette!("Unhandled production number: {}", prod_num)),
+        }
+    }
+}
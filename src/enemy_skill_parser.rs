@@ -0,0 +1,518 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::{TokenStream, Tokenizer};
+use parol_runtime::miette::Result;
+use parol_runtime::once_cell::sync::Lazy;
+#[allow(unused_imports)]
+use parol_runtime::parser::{
+    DFATransition, LLKParser, LookaheadDFA, ParseTreeType, ParseType, Production,
+};
+use std::cell::RefCell;
+use std::path::Path;
+
+use crate::enemy_skill_grammar::EnemySkillGrammar;
+use crate::enemy_skill_grammar_trait::EnemySkillGrammarAuto;
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+
+pub const TERMINALS: &[&str; 22] = &[
+    /*  0 */ UNMATCHABLE_TOKEN,
+    /*  1 */ UNMATCHABLE_TOKEN,
+    /*  2 */ UNMATCHABLE_TOKEN,
+    /*  3 */ UNMATCHABLE_TOKEN,
+    /*  4 */ UNMATCHABLE_TOKEN,
+    /*  5 */ r###"ターン"###,
+    /*  6 */ r###"間"###,
+    /*  7 */ r###"ドロップ操作時間"###,
+    /*  8 */ r###"秒"###,
+    /*  9 */ r###"減少"###,
+    /* 10 */ r###"スキル"###,
+    /* 11 */ r###"遅延"###,
+    /* 12 */ r###"の"###,
+    /* 13 */ r###"が"###,
+    /* 14 */ r###"を"###,
+    /* 15 */ r###"\("###,
+    /* 16 */ r###"\)"###,
+    /* 17 */ r###"、"###,
+    /* 18 */ r###"。"###,
+    /* 19 */ r###"[0-9]+\.[0-9]+"###,
+    /* 20 */ r###"[0-9]+"###,
+    /* 21 */ ERROR_TOKEN,
+];
+
+pub const TERMINAL_NAMES: &[&str; 22] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "WordTurn",
+    /*  6 */ "WordBetween",
+    /*  7 */ "WordMoveTime",
+    /*  8 */ "WordSecond",
+    /*  9 */ "WordDecrease",
+    /* 10 */ "WordSkill",
+    /* 11 */ "WordDelay",
+    /* 12 */ "No",
+    /* 13 */ "Ga",
+    /* 14 */ "Wo",
+    /* 15 */ "LParen",
+    /* 16 */ "RParen",
+    /* 17 */ "Camma",
+    /* 18 */ "Period",
+    /* 19 */ "Decimal",
+    /* 20 */ "Integer",
+    /* 21 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[usize; 16]) = (
+    &[
+        /*  0 */ UNMATCHABLE_TOKEN,
+        /*  1 */ NEW_LINE_TOKEN,
+        /*  2 */ WHITESPACE_TOKEN,
+        /*  3 */ UNMATCHABLE_TOKEN,
+        /*  4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[
+        5,  /* WordTurn */
+        6,  /* WordBetween */
+        7,  /* WordMoveTime */
+        8,  /* WordSecond */
+        9,  /* WordDecrease */
+        10, /* WordSkill */
+        11, /* WordDelay */
+        12, /* No */
+        13, /* Ga */
+        14, /* Wo */
+        15, /* LParen */
+        16, /* RParen */
+        17, /* Camma */
+        18, /* Period */
+        19, /* Decimal */
+        20, /* Integer */
+    ],
+);
+
+const MAX_K: usize = 1;
+
+pub const NON_TERMINALS: &[&str; 26] = &[
+    /*  0 */ "Camma",
+    /*  1 */ "Decimal",
+    /*  2 */ "EnemySkillLine",
+    /*  3 */ "EnemySkillLineOpt",
+    /*  4 */ "EnemyStmt",
+    /*  5 */ "Ga",
+    /*  6 */ "Integer",
+    /*  7 */ "LParen",
+    /*  8 */ "MoveTimeStmt",
+    /*  9 */ "No",
+    /* 10 */ "Number",
+    /* 11 */ "Particle",
+    /* 12 */ "Period",
+    /* 13 */ "RParen",
+    /* 14 */ "SkillDelayBody",
+    /* 15 */ "SkillDelayBodyOpt",
+    /* 16 */ "SkillDelayStmt",
+    /* 17 */ "TurnsPrefix",
+    /* 18 */ "Wo",
+    /* 19 */ "WordBetween",
+    /* 20 */ "WordDecrease",
+    /* 21 */ "WordDelay",
+    /* 22 */ "WordMoveTime",
+    /* 23 */ "WordSecond",
+    /* 24 */ "WordSkill",
+    /* 25 */ "WordTurn",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 26] = &[
+    /* 0 - "Camma" */
+    LookaheadDFA {
+        states: &[Some(28)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "Decimal" */
+    LookaheadDFA {
+        states: &[Some(30)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "EnemySkillLine" */
+    LookaheadDFA {
+        states: &[Some(0)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "EnemySkillLineOpt" */
+    LookaheadDFA {
+        states: &[None, Some(1), Some(2)],
+        transitions: &[
+            DFATransition(0, 7, 2),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 20, 1),
+        ],
+        k: 1,
+    },
+    /* 4 - "EnemyStmt" */
+    LookaheadDFA {
+        states: &[None, Some(4), Some(5)],
+        transitions: &[DFATransition(0, 7, 1), DFATransition(0, 10, 2)],
+        k: 1,
+    },
+    /* 5 - "Ga" */
+    LookaheadDFA {
+        states: &[Some(24)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "Integer" */
+    LookaheadDFA {
+        states: &[Some(31)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "LParen" */
+    LookaheadDFA {
+        states: &[Some(26)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "MoveTimeStmt" */
+    LookaheadDFA {
+        states: &[Some(6)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "No" */
+    LookaheadDFA {
+        states: &[Some(23)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 10 - "Number" */
+    LookaheadDFA {
+        states: &[None, Some(12), Some(13)],
+        transitions: &[DFATransition(0, 19, 2), DFATransition(0, 20, 1)],
+        k: 1,
+    },
+    /* 11 - "Particle" */
+    LookaheadDFA {
+        states: &[None, Some(14), Some(15)],
+        transitions: &[DFATransition(0, 13, 1), DFATransition(0, 14, 2)],
+        k: 1,
+    },
+    /* 12 - "Period" */
+    LookaheadDFA {
+        states: &[Some(29)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 13 - "RParen" */
+    LookaheadDFA {
+        states: &[Some(27)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 14 - "SkillDelayBody" */
+    LookaheadDFA {
+        states: &[None, Some(8), Some(9)],
+        transitions: &[
+            DFATransition(0, 11, 1),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+        ],
+        k: 1,
+    },
+    /* 15 - "SkillDelayBodyOpt" */
+    LookaheadDFA {
+        states: &[None, Some(10), Some(11)],
+        transitions: &[DFATransition(0, 15, 1), DFATransition(0, 18, 2)],
+        k: 1,
+    },
+    /* 16 - "SkillDelayStmt" */
+    LookaheadDFA {
+        states: &[Some(7)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 17 - "TurnsPrefix" */
+    LookaheadDFA {
+        states: &[Some(3)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 18 - "Wo" */
+    LookaheadDFA {
+        states: &[Some(25)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 19 - "WordBetween" */
+    LookaheadDFA {
+        states: &[Some(17)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 20 - "WordDecrease" */
+    LookaheadDFA {
+        states: &[Some(20)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 21 - "WordDelay" */
+    LookaheadDFA {
+        states: &[Some(22)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "WordMoveTime" */
+    LookaheadDFA {
+        states: &[Some(18)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 23 - "WordSecond" */
+    LookaheadDFA {
+        states: &[Some(19)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 24 - "WordSkill" */
+    LookaheadDFA {
+        states: &[Some(21)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 25 - "WordTurn" */
+    LookaheadDFA {
+        states: &[Some(16)],
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 32] = &[
+    // 0 - EnemySkillLine: EnemySkillLineOpt /* Option */ EnemyStmt Period;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(12), ParseType::N(4), ParseType::N(3)],
+    },
+    // 1 - EnemySkillLineOpt: TurnsPrefix;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(17)],
+    },
+    // 2 - EnemySkillLineOpt: ;
+    Production {
+        lhs: 3,
+        production: &[],
+    },
+    // 3 - TurnsPrefix: Integer WordTurn No WordBetween Camma;
+    Production {
+        lhs: 17,
+        production: &[
+            ParseType::N(0),
+            ParseType::N(19),
+            ParseType::N(9),
+            ParseType::N(25),
+            ParseType::N(6),
+        ],
+    },
+    // 4 - EnemyStmt: MoveTimeStmt;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(8)],
+    },
+    // 5 - EnemyStmt: SkillDelayStmt;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(16)],
+    },
+    // 6 - MoveTimeStmt: WordMoveTime Particle Number WordSecond WordDecrease;
+    Production {
+        lhs: 8,
+        production: &[
+            ParseType::N(20),
+            ParseType::N(23),
+            ParseType::N(10),
+            ParseType::N(11),
+            ParseType::N(22),
+        ],
+    },
+    // 7 - SkillDelayStmt: WordSkill SkillDelayBody;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(14), ParseType::N(24)],
+    },
+    // 8 - SkillDelayBody: WordDelay SkillDelayBodyOpt /* Option */;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(15), ParseType::N(21)],
+    },
+    // 9 - SkillDelayBody: Particle Integer WordTurn WordDelay;
+    Production {
+        lhs: 14,
+        production: &[
+            ParseType::N(21),
+            ParseType::N(25),
+            ParseType::N(6),
+            ParseType::N(11),
+        ],
+    },
+    // 10 - SkillDelayBodyOpt: LParen Integer WordTurn RParen;
+    Production {
+        lhs: 15,
+        production: &[
+            ParseType::N(13),
+            ParseType::N(25),
+            ParseType::N(6),
+            ParseType::N(7),
+        ],
+    },
+    // 11 - SkillDelayBodyOpt: ;
+    Production {
+        lhs: 15,
+        production: &[],
+    },
+    // 12 - Number: Integer;
+    Production {
+        lhs: 10,
+        production: &[ParseType::N(6)],
+    },
+    // 13 - Number: Decimal;
+    Production {
+        lhs: 10,
+        production: &[ParseType::N(1)],
+    },
+    // 14 - Particle: Ga;
+    Production {
+        lhs: 11,
+        production: &[ParseType::N(5)],
+    },
+    // 15 - Particle: Wo;
+    Production {
+        lhs: 11,
+        production: &[ParseType::N(18)],
+    },
+    // 16 - WordTurn: 'ターン';
+    Production {
+        lhs: 25,
+        production: &[ParseType::T(5)],
+    },
+    // 17 - WordBetween: '間';
+    Production {
+        lhs: 19,
+        production: &[ParseType::T(6)],
+    },
+    // 18 - WordMoveTime: 'ドロップ操作時間';
+    Production {
+        lhs: 22,
+        production: &[ParseType::T(7)],
+    },
+    // 19 - WordSecond: '秒';
+    Production {
+        lhs: 23,
+        production: &[ParseType::T(8)],
+    },
+    // 20 - WordDecrease: '減少';
+    Production {
+        lhs: 20,
+        production: &[ParseType::T(9)],
+    },
+    // 21 - WordSkill: 'スキル';
+    Production {
+        lhs: 24,
+        production: &[ParseType::T(10)],
+    },
+    // 22 - WordDelay: '遅延';
+    Production {
+        lhs: 21,
+        production: &[ParseType::T(11)],
+    },
+    // 23 - No: 'の';
+    Production {
+        lhs: 9,
+        production: &[ParseType::T(12)],
+    },
+    // 24 - Ga: 'が';
+    Production {
+        lhs: 5,
+        production: &[ParseType::T(13)],
+    },
+    // 25 - Wo: 'を';
+    Production {
+        lhs: 18,
+        production: &[ParseType::T(14)],
+    },
+    // 26 - LParen: '(';
+    Production {
+        lhs: 7,
+        production: &[ParseType::T(15)],
+    },
+    // 27 - RParen: ')';
+    Production {
+        lhs: 13,
+        production: &[ParseType::T(16)],
+    },
+    // 28 - Camma: '、';
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(17)],
+    },
+    // 29 - Period: '。';
+    Production {
+        lhs: 12,
+        production: &[ParseType::T(18)],
+    },
+    // 30 - Decimal: "[0-9]+\.[0-9]+";
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(19)],
+    },
+    // 31 - Integer: "[0-9]+";
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(20)],
+    },
+];
+
+static TOKENIZERS: Lazy<Vec<(&'static str, Tokenizer)>> = Lazy::new(|| {
+    vec![(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+    )]
+});
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut EnemySkillGrammar<'t>,
+) -> Result<Tree<ParseTreeType<'t>>>
+where
+    T: AsRef<Path>,
+{
+    let mut llk_parser = LLKParser::new(
+        2,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let token_stream =
+        RefCell::new(TokenStream::new(input, file_name, &TOKENIZERS, MAX_K).unwrap());
+    // Initialize wrapper
+    let mut user_actions = EnemySkillGrammarAuto::new(user_actions);
+    let result = llk_parser.parse(token_stream, &mut user_actions);
+    match result {
+        Ok(()) => Ok(llk_parser.parse_tree),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,459 @@
+//! スキルを英語(北米版の表記)のスキル本文へ変換する
+//!
+//! ```text
+//! Change Wood orbs to Water orbs
+//! Create 5 Fire orbs at random
+//! For 1 turn, no skyfall combos
+//! ```
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::skill::*;
+use super::wording::*;
+
+/// スキルのリストを英語のスキル本文へ変換する。スキル毎に`; `で区切る
+pub fn skills_to_english(skills: &[Skill]) -> String {
+    let text = skills.iter().map(sentence).collect::<Vec<_>>().join("; ");
+
+    capitalize(&text)
+}
+
+/// 1スキル分の文。先頭は小文字のまま返す
+fn sentence(skill: &Skill) -> String {
+    let body = effect_to_english(&skill.effect);
+
+    let body = match stated_turns(skill) {
+        Some(turns) => format!("for {}, {body}", plural(turns, "turn")),
+        None => body,
+    };
+
+    match &skill.sub_effects {
+        Some(SubEffect::Floor(floor, SubEffectAttribute::IfApply)) => {
+            format!("if {}, {body}", floor_to_english(*floor))
+        }
+        Some(SubEffect::HitPoint(hp, SubEffectAttribute::IfApply)) => {
+            format!("if {}, {body}", hit_point_to_english(*hp))
+        }
+        Some(SubEffect::Floor(floor, SubEffectAttribute::Available)) => {
+            format!("{body} (usable {})", floor_to_english(*floor))
+        }
+        Some(SubEffect::HitPoint(hp, SubEffectAttribute::Available)) => {
+            format!("{body} (usable {})", hit_point_to_english(*hp))
+        }
+        Some(SubEffect::Reserve(turns)) => {
+            format!("after {}, {body}", plural(*turns, "turn"))
+        }
+        None => body,
+    }
+}
+
+/// `on battle 6 or later`、`on battle 5 or earlier`
+fn floor_to_english(floor: isize) -> String {
+    match Threshold::from(floor) {
+        Threshold::AtLeast(floor) => format!("on battle {floor} or later"),
+        Threshold::AtMost(floor) => format!("on battle {floor} or earlier"),
+    }
+}
+
+/// `when HP is 80% or more`、`when HP is 50% or less`
+fn hit_point_to_english(hp: isize) -> String {
+    match Threshold::from(hp) {
+        Threshold::AtLeast(hp) => format!("when HP is {hp}% or more"),
+        Threshold::AtMost(hp) => format!("when HP is {hp}% or less"),
+    }
+}
+
+/// スキル効果の本文
+fn effect_to_english(effect: &SkillEffect) -> String {
+    match effect {
+        SkillEffect::Other => String::new(),
+        // パースできなかった文は翻訳せずにそのまま出力する
+        SkillEffect::Unrecognized { text, .. } => text.to_owned(),
+        SkillEffect::ChangeDropAToB(from, to) => {
+            let random = if to.len() > 1 { " at random" } else { "" };
+
+            format!(
+                "change {} to {}{random}",
+                orbs_to_english(&from.to_drops()),
+                orbs_to_english(to)
+            )
+        }
+        SkillEffect::ChangeAllOfBoard(drops) => {
+            format!("change all orbs to {}", orbs_to_english(drops))
+        }
+        SkillEffect::GenRandomDrop(exc, to) => gen_random_drop_to_english(exc, to),
+        SkillEffect::DropShapeGen(shapes) => shapes_to_english(shapes),
+        SkillEffect::DropUnLock => "unlock all orbs".to_owned(),
+        SkillEffect::DropLock(drops) if *drops == Drop::all() => "lock all orbs".to_owned(),
+        SkillEffect::DropLock(drops) => format!("lock {}", orbs_to_english(drops)),
+        SkillEffect::DropRefresh => "replace all orbs".to_owned(),
+        SkillEffect::DropPowerUp(drops)
+            if *drops == DropSet::five_attributes_and_recovery().to_drops() =>
+        {
+            "enhance all orbs".to_owned()
+        }
+        SkillEffect::DropPowerUp(drops) => format!("enhance {}", orbs_to_english(drops)),
+        SkillEffect::DropFalloff(drops, VolumeVariation::Only) => {
+            format!("only {} will appear", orbs_to_english(drops))
+        }
+        SkillEffect::DropFalloff(drops, volume) => format!(
+            "{} are {}more likely to appear",
+            orbs_to_english(drops),
+            volume.to_english()
+        ),
+        SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::Num(rate)) => {
+            format!("enhanced orbs have a {rate}% chance to appear")
+        }
+        SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::VolumeVariation(
+            VolumeVariation::Only,
+        )) => "only enhanced orbs will appear".to_owned(),
+        SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::VolumeVariation(volume)) => {
+            format!(
+                "enhanced orbs are {}more likely to appear",
+                volume.to_english()
+            )
+        }
+        SkillEffect::FallLockDrop(drops) if *drops == Drop::all() => {
+            "all orbs appear locked".to_owned()
+        }
+        SkillEffect::FallLockDrop(drops) => format!("{} appear locked", orbs_to_english(drops)),
+        SkillEffect::FallNailDropEasierToFalloff(VolumeVariation::Only) => {
+            "only nail orbs will appear".to_owned()
+        }
+        SkillEffect::FallNailDropEasierToFalloff(volume) => {
+            format!("nail orbs are {}more likely to appear", volume.to_english())
+        }
+        SkillEffect::DropsNotFalling => "no skyfall combos".to_owned(),
+        SkillEffect::GenRoulette(qty) => {
+            format!("create {} at random", plural(*qty, "roulette orb"))
+        }
+        SkillEffect::GenCloud(position, size) => format!(
+            "create a {} cloud {}",
+            size.to_english(),
+            position.to_english()
+        ),
+        SkillEffect::GenTeap(position) => {
+            format!("the {} becomes unmovable", position_to_english(position))
+        }
+        SkillEffect::ChangeBoardSize(size) => format!("change the board to {}", size.to_english()),
+        SkillEffect::NullificationDamageAbsorption => "voids damage absorption".to_owned(),
+        SkillEffect::NullificationAttributeAbsorption => "voids attribute absorption".to_owned(),
+        SkillEffect::PenetrationDamageNullification => "pierces through damage void".to_owned(),
+        SkillEffect::EnemyAttributeChange(color) => {
+            format!("change all enemies to {} attribute", color.to_english())
+        }
+    }
+}
+
+/// ランダム生成。`at random`か`from non-X orbs`(`gen_random_source()`)
+fn gen_random_drop_to_english(exc: &DropSet, to: &GenDropsWithQty) -> String {
+    let other = gen_random_source(exc, to);
+
+    let source = if other.is_empty() {
+        "at random".to_owned()
+    } else {
+        let names: Vec<String> = other
+            .iter()
+            .map(|drop| format!("non-{}", drop.to_english()))
+            .collect();
+
+        format!("from {} orbs", join_and(&names))
+    };
+
+    format!("create {} {source}", gen_quantity_to_english(to))
+}
+
+/// 生成数が同じなら`2 each of Fire and Water orbs`、異なるなら`11 Wood, 7 Dark and 5 Heal orbs`
+fn gen_quantity_to_english(to: &GenDropsWithQty) -> String {
+    let to_drops: Drops = to.iter().map(|(drop, _)| drop.clone()).collect();
+
+    match to.as_slice() {
+        [(drop, qty)] => plural(*qty, &format!("{} orb", drop.to_english())),
+        _ if to.windows(2).all(|pair| pair[0].1 == pair[1].1) => {
+            format!("{} each of {}", to[0].1, orbs_to_english(&to_drops))
+        }
+        _ => {
+            let names: Vec<String> = to
+                .iter()
+                .map(|(drop, qty)| format!("{qty} {}", drop.to_english()))
+                .collect();
+
+            format!("{} orbs", join_and(&names))
+        }
+    }
+}
+
+/// 形状生成
+fn shapes_to_english(shapes: &[ShapeType]) -> String {
+    // 縦、横の生成は連続する同じドロップ毎に位置をまとめ、`, and`で繋げる
+    let mut sentences: Vec<String> = Vec::new();
+    let mut row_col: Vec<(Drop, Vec<String>)> = Vec::new();
+
+    for shape in shapes {
+        match shape {
+            ShapeType::Col(idx, drop) | ShapeType::Row(idx, drop) => {
+                let position = row_col_position(shape, *idx);
+
+                match row_col.last_mut() {
+                    Some((d, positions)) if d == drop => positions.push(position),
+                    _ => row_col.push((drop.clone(), vec![position])),
+                }
+            }
+            other => {
+                sentences.extend(row_col_to_english(&row_col));
+                row_col.clear();
+                sentences.push(other.to_english());
+            }
+        }
+    }
+    sentences.extend(row_col_to_english(&row_col));
+
+    sentences.join("; ")
+}
+
+/// `change the top row to Fire orbs, and the bottom row to Wood orbs`
+fn row_col_to_english(row_col: &[(Drop, Vec<String>)]) -> Option<String> {
+    let blocks: Vec<String> = row_col
+        .iter()
+        .map(|(drop, positions)| {
+            format!(
+                "{} to {}",
+                join_and(positions),
+                orbs_to_english(std::slice::from_ref(drop))
+            )
+        })
+        .collect();
+
+    match blocks.is_empty() {
+        true => None,
+        false => Some(format!("change {}", blocks.join(", and "))),
+    }
+}
+
+/// `the leftmost column`、`the 2nd row from the bottom`
+fn row_col_position(shape: &ShapeType, idx: ShapeGenIdx) -> String {
+    let is_row = match shape {
+        ShapeType::Row(_, _) => true,
+        _ => false,
+    };
+
+    match (is_row, idx) {
+        (false, 1) => "the leftmost column".to_owned(),
+        (false, -1) => "the rightmost column".to_owned(),
+        (true, 1) => "the top row".to_owned(),
+        (true, -1) => "the bottom row".to_owned(),
+        (false, idx) if idx.is_positive() => {
+            format!("the {} column from the left", ordinal(idx.unsigned_abs()))
+        }
+        (false, idx) => format!("the {} column from the right", ordinal(idx.unsigned_abs())),
+        (true, idx) if idx.is_positive() => {
+            format!("the {} row from the top", ordinal(idx.unsigned_abs()))
+        }
+        (true, idx) => format!("the {} row from the bottom", ordinal(idx.unsigned_abs())),
+    }
+}
+
+impl Skill {
+    /// 英語のスキル本文へ変換する
+    pub fn to_english(self: &Self) -> String {
+        skills_to_english(std::slice::from_ref(self))
+    }
+}
+
+impl ShapeType {
+    /// 形状生成1つ分の英語の本文。先頭は小文字
+    pub fn to_english(self: &Self) -> String {
+        let orbs = |drop: &Drop| orbs_to_english(std::slice::from_ref(drop));
+
+        match self {
+            ShapeType::Col(idx, drop) | ShapeType::Row(idx, drop) => {
+                format!("change {} to {}", row_col_position(self, *idx), orbs(drop))
+            }
+            ShapeType::LShape(drop, qty) => {
+                format!("create {} of {}", plural(*qty, "L shape"), orbs(drop))
+            }
+            ShapeType::ZShape(drop) => format!("create a Z shape of {}", orbs(drop)),
+            ShapeType::SmallCrossShape(drop, qty) => {
+                format!("create {} of {}", plural(*qty, "cross"), orbs(drop))
+            }
+            ShapeType::CrossShape(drop) => {
+                format!("create a large cross of {} across the board", orbs(drop))
+            }
+            ShapeType::Square(drop, size, qty) => format!(
+                "create {} of {}",
+                plural(*qty, &format!("{size}x{size} square")),
+                orbs(drop)
+            ),
+            ShapeType::ShapeOfBoardPerimeter(drop) => {
+                format!("change the outer edges of the board to {}", orbs(drop))
+            }
+            ShapeType::ShapeOfBoardTop(drop, qty) => format!(
+                "create {} at the top of the board",
+                plural(*qty, &format!("{} orb", drop.to_english()))
+            ),
+            ShapeType::ShapeOfBoardCenter(drop) => {
+                format!("change the center of the board to {}", orbs(drop))
+            }
+            ShapeType::ShapeOfBoardBottom(drop, qty) => format!(
+                "create {} at the bottom of the board",
+                plural(*qty, &format!("{} orb", drop.to_english()))
+            ),
+            ShapeType::ShapeOfBoardCorners(drop, qty) => format!(
+                "create {} in each corner of the board",
+                plural(*qty, &format!("{} orb", drop.to_english()))
+            ),
+            ShapeType::ShapeOfSpiderweb(web, center) => format!(
+                "create a spiderweb of {} with {} at its center",
+                orbs(web),
+                orbs(center)
+            ),
+            ShapeType::ShapeOfCrescentMoon(drop) => {
+                format!("create a crescent moon of {}", orbs(drop))
+            }
+            ShapeType::ShapeOfOblique(drop) => {
+                format!("create a diagonal line of {}", orbs(drop))
+            }
+            ShapeType::ShapeOfSomeKind(drop, kind, qty) => format!(
+                "create {} of {}",
+                plural(*qty, &format!("{kind} shape")),
+                orbs(drop)
+            ),
+        }
+    }
+}
+
+impl Drop {
+    /// ドロップ名。`orb`は付けない
+    pub fn to_english(self: &Self) -> &'static str {
+        match self {
+            Drop::Colored(color) => color.to_english(),
+            Drop::NonColored(drop) => drop.to_english(),
+        }
+    }
+}
+
+impl Color {
+    pub fn to_english(self: &Self) -> &'static str {
+        match self {
+            Color::Fire => "Fire",
+            Color::Water => "Water",
+            Color::Wood => "Wood",
+            Color::Lightning => "Light",
+            Color::Dark => "Dark",
+        }
+    }
+}
+
+impl NonColoredDrop {
+    pub fn to_english(self: &Self) -> &'static str {
+        match self {
+            NonColoredDrop::Recovery => "Heal",
+            NonColoredDrop::Disturb => "Jammer",
+            NonColoredDrop::Bomb => "Bomb",
+            NonColoredDrop::Poison => "Poison",
+            NonColoredDrop::DeadlyPoison => "Mortal Poison",
+        }
+    }
+}
+
+impl VolumeVariation {
+    /// `more likely to appear`の前に付く語。通常は空
+    pub fn to_english(self: &Self) -> &'static str {
+        match self {
+            VolumeVariation::LittleMore => "very slightly ",
+            VolumeVariation::Little => "slightly ",
+            VolumeVariation::Normal => "",
+            VolumeVariation::Only => "only ",
+        }
+    }
+}
+
+impl Size {
+    /// `7x6`
+    pub fn to_english(self: &Self) -> String {
+        format!("{}x{}", self.0, self.1)
+    }
+}
+
+impl BoardPosition {
+    /// `at random`、`in the top row`
+    pub fn to_english(self: &Self) -> String {
+        match self {
+            BoardPosition::Random => "at random".to_owned(),
+            other => format!("in the {}", position_to_english(other)),
+        }
+    }
+}
+
+/// `the`に続く位置。`leftmost column`、`2nd row from the top`。`BoardPosition::Random`は`board`
+fn position_to_english(position: &BoardPosition) -> String {
+    match position {
+        BoardPosition::Random => "board".to_owned(),
+        BoardPosition::Row(1) => "top row".to_owned(),
+        BoardPosition::Row(-1) => "bottom row".to_owned(),
+        BoardPosition::Col(1) => "leftmost column".to_owned(),
+        BoardPosition::Col(-1) => "rightmost column".to_owned(),
+        BoardPosition::Row(idx) if idx.is_positive() => {
+            format!("{} row from the top", ordinal(idx.unsigned_abs()))
+        }
+        BoardPosition::Row(idx) => {
+            format!("{} row from the bottom", ordinal(idx.unsigned_abs()))
+        }
+        BoardPosition::Col(idx) if idx.is_positive() => {
+            format!("{} column from the left", ordinal(idx.unsigned_abs()))
+        }
+        BoardPosition::Col(idx) => {
+            format!("{} column from the right", ordinal(idx.unsigned_abs()))
+        }
+    }
+}
+
+/// `Fire, Water and Wood orbs`。1色なら`Fire orbs`
+pub fn orbs_to_english(drops: &[Drop]) -> String {
+    let names: Vec<String> = drops
+        .iter()
+        .map(|drop| drop.to_english().to_owned())
+        .collect();
+
+    format!("{} orbs", join_and(&names))
+}
+
+/// `A, B and C`
+fn join_and(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+/// `1 turn`、`2 turns`
+fn plural(qty: usize, noun: &str) -> String {
+    match (qty, noun.ends_with('s')) {
+        (1, _) => format!("1 {noun}"),
+        (_, true) => format!("{qty} {noun}es"),
+        (_, false) => format!("{qty} {noun}s"),
+    }
+}
+
+/// `1st`、`2nd`、`3rd`、`4th`
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{n}{suffix}")
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,261 @@
+//! スキル使用直後の盤面に存在するドロップ数の範囲
+//!
+//! 使用前の盤面は分からないものとして、スキル使用直後の各ドロップの最小数と最大数を求める。
+//! 最小数が「必ずある」ドロップ数になる。
+//!
+//! * 使用前の盤面は5属性+回復の6種類で構成され、ロック状態のドロップは無いものとする
+//! * 全ドロップ変化は各ドロップを3個(盤面に収まらない場合は均等)ずつ置き、残りはランダム
+//! * ランダム生成は除外するドロップ以外のマスへ優先して生成し、足りない場合は除外するドロップのマスへ生成する。
+//!   生成数の合計がマス数を超える場合は、先に指定されているドロップから生成する
+//! * 指定型生成は`ShapeType::mask()`のマスを上書きする。形が分からない場合は、全てのドロップを0〜マス数とする
+//! * 変換先が1種類の単色変換は変換元の全てのマスを変換先にし、複数種類の場合は変換先のいずれかにする
+//!
+//! ```text
+//! 全ドロップを回復に変化し、火、水、光を9個ずつ生成。(6×5)
+//! 火: 9..=9, 水: 9..=9, 光: 9..=9, 回復: 3..=3
+//! ```
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::simulator::MIN_DROPS_OF_BOARD_CHANGE;
+use super::skill::*;
+
+/// ドロップ数の範囲
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CountRange {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl CountRange {
+    pub fn new(min: usize, max: usize) -> Self {
+        CountRange { min, max }
+    }
+
+    pub fn exact(count: usize) -> Self {
+        CountRange::new(count, count)
+    }
+}
+
+/// スキル使用直後の盤面の分析結果
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardAnalysis {
+    /// スキル使用直後の盤面サイズ。盤面サイズ変更があれば変更後のサイズ
+    pub size: Size,
+    /// `Drop::all()`の順序の、ドロップ毎の範囲
+    pub counts: Vec<(Drop, CountRange)>,
+}
+
+impl BoardAnalysis {
+    /// 分析する盤面サイズ。5×4、6×5、7×6
+    pub fn sizes() -> [Size; 3] {
+        [Size(5, 4), Size::standard(), Size(7, 6)]
+    }
+
+    /// 5属性+回復が0〜マス数ずつある、分からない盤面
+    pub fn unknown(size: Size) -> Self {
+        let cells = size.width() * size.height();
+        let initial = DropSet::five_attributes_and_recovery();
+
+        let counts = Drop::all()
+            .into_iter()
+            .map(|drop| match initial.contains(&drop) {
+                true => (drop, CountRange::new(0, cells)),
+                false => (drop, CountRange::exact(0)),
+            })
+            .collect();
+
+        BoardAnalysis { size, counts }
+    }
+
+    /// 指定サイズの分からない盤面へスキルを使用した直後の盤面
+    pub fn analyze(skills: &[Skill], size: &Size) -> Self {
+        let mut analysis = Self::unknown(size.clone());
+        skills
+            .iter()
+            .for_each(|skill| analysis.apply(&skill.effect));
+        analysis
+    }
+
+    /// 5×4、6×5、7×6の盤面毎の分析結果
+    pub fn analyze_sizes(skills: &[Skill]) -> Vec<Self> {
+        Self::sizes()
+            .iter()
+            .map(|size| Self::analyze(skills, size))
+            .collect()
+    }
+
+    pub fn get(self: &Self, drop: &Drop) -> CountRange {
+        self.counts
+            .iter()
+            .find(|(d, _)| d == drop)
+            .map(|(_, range)| *range)
+            .unwrap_or_default()
+    }
+
+    /// マス数
+    pub fn cells(self: &Self) -> usize {
+        self.size.width() * self.size.height()
+    }
+
+    /// 必ず組めるコンボ数。各ドロップの最小数を3個ずつ消した場合の合計
+    pub fn guaranteed_combos(self: &Self) -> usize {
+        self.counts.iter().map(|(_, range)| range.min / 3).sum()
+    }
+
+    /// 全消し可能か。盤面にあり得るドロップが全て3個以上ある
+    pub fn can_clear(self: &Self) -> bool {
+        self.counts
+            .iter()
+            .all(|(_, range)| range.max == 0 || range.min >= MIN_DROPS_OF_BOARD_CHANGE)
+    }
+
+    fn apply(self: &mut Self, effect: &SkillEffect) {
+        match effect {
+            SkillEffect::ChangeAllOfBoard(drops) => self.change_all_of_board(drops),
+            SkillEffect::GenRandomDrop(exc, to) => self.gen_random_drop(exc, to),
+            SkillEffect::DropShapeGen(_) => self.drop_shape_gen(effect),
+            SkillEffect::ChangeDropAToB(from, to) => self.change_drop_a_to_b(from, to),
+            SkillEffect::DropRefresh => *self = Self::unknown(self.size.clone()),
+            SkillEffect::ChangeBoardSize(size) => *self = Self::unknown(size.clone()),
+            _ => (),
+        }
+        self.tighten();
+    }
+
+    /// ドロップの集合の合計数の範囲
+    fn total(self: &Self, drops: &DropSet) -> CountRange {
+        let cells = self.cells();
+        let (inside, outside): (Vec<_>, Vec<_>) = self
+            .counts
+            .iter()
+            .partition(|(drop, _)| drops.contains(drop));
+
+        let sum = |list: &[&(Drop, CountRange)], f: fn(&CountRange) -> usize| -> usize {
+            list.iter().map(|(_, range)| f(range)).sum()
+        };
+
+        CountRange::new(
+            sum(&inside, |r| r.min).max(cells.saturating_sub(sum(&outside, |r| r.max))),
+            sum(&inside, |r| r.max).min(cells.saturating_sub(sum(&outside, |r| r.min))),
+        )
+    }
+
+    /// 合計がマス数になることを使って範囲を狭める
+    fn tighten(self: &mut Self) {
+        let cells = self.cells();
+        let (min_sum, max_sum): (usize, usize) = self
+            .counts
+            .iter()
+            .fold((0, 0), |(min, max), (_, r)| (min + r.min, max + r.max));
+
+        for (_, range) in self.counts.iter_mut() {
+            let others_max = max_sum - range.max;
+            let others_min = min_sum - range.min;
+
+            range.min = range.min.max(cells.saturating_sub(others_max));
+            range.max = range.max.min(cells.saturating_sub(others_min));
+        }
+    }
+
+    fn change_all_of_board(self: &mut Self, drops: &Drops) {
+        if drops.is_empty() {
+            return;
+        }
+
+        let cells = self.cells();
+        let to = DropSet::from(drops.as_slice());
+        let guaranteed = MIN_DROPS_OF_BOARD_CHANGE.min(cells / to.len());
+        let rest = cells - guaranteed * to.len();
+
+        for (drop, range) in self.counts.iter_mut() {
+            *range = match to.contains(drop) {
+                true => CountRange::new(guaranteed, guaranteed + rest),
+                false => CountRange::exact(0),
+            };
+        }
+    }
+
+    fn gen_random_drop(self: &mut Self, exc: &DropSet, to: &GenDropsWithQty) {
+        let cells = self.cells();
+        let candidates = self.total(&exc.complement());
+
+        // 生成数の合計がマス数を超える場合は、先に指定されているドロップから生成する
+        let mut generated: Vec<(Drop, usize)> = Vec::new();
+        let mut rest = cells;
+        for (drop, qty) in to {
+            let placed = (*qty).min(rest);
+            rest -= placed;
+            generated.push((drop.clone(), placed));
+        }
+        let total: usize = generated.iter().map(|(_, qty)| qty).sum();
+
+        // 除外するドロップのマスへ生成する数
+        let overflow_max = total.saturating_sub(candidates.min);
+
+        for (drop, range) in self.counts.iter_mut() {
+            let placed: usize = generated
+                .iter()
+                .filter(|(d, _)| d == drop)
+                .map(|(_, qty)| qty)
+                .sum();
+
+            let retained = match exc.contains(drop) {
+                true => CountRange::new(range.min.saturating_sub(overflow_max), range.max),
+                false => CountRange::new(
+                    range.min.saturating_sub(total),
+                    range.max.min(candidates.max.saturating_sub(total)),
+                ),
+            };
+
+            *range = CountRange::new(placed + retained.min, (placed + retained.max).min(cells));
+        }
+    }
+
+    fn drop_shape_gen(self: &mut Self, effect: &SkillEffect) {
+        let cells = self.cells();
+
+        // 形が分からない場合は、どのドロップも0〜マス数のいずれかになり得る
+        let Ok(mask) = effect.shape_mask(&self.size) else {
+            self.counts
+                .iter_mut()
+                .for_each(|(_, range)| *range = CountRange::new(0, cells));
+            return;
+        };
+
+        // 重なるマスは後の形状で上書きする
+        let mut masked: Vec<((usize, usize), Drop)> = Vec::new();
+        for (row, col, drop) in mask {
+            masked.retain(|(position, _)| *position != (row, col));
+            masked.push(((row, col), drop));
+        }
+        let total = masked.len();
+
+        for (drop, range) in self.counts.iter_mut() {
+            let placed = masked.iter().filter(|(_, d)| d == drop).count();
+
+            *range = CountRange::new(
+                placed + range.min.saturating_sub(total),
+                placed + range.max.min(cells - total),
+            );
+        }
+    }
+
+    fn change_drop_a_to_b(self: &mut Self, from: &DropSet, to: &Drops) {
+        let changed = self.total(from);
+        let to = DropSet::from(to.as_slice());
+
+        for (drop, range) in self.counts.iter_mut() {
+            let kept = match from.contains(drop) {
+                true => CountRange::exact(0),
+                false => *range,
+            };
+
+            *range = match (to.contains(drop), to.len()) {
+                (true, 1) => CountRange::new(kept.min + changed.min, kept.max + changed.max),
+                (true, _) => CountRange::new(kept.min, kept.max + changed.max),
+                (false, _) => kept,
+            };
+        }
+    }
+}
@@ -0,0 +1,723 @@
+//! スキルを日本語のスキル本文へ変換する
+//!
+//! 出力はゲーム内の表記に揃えており、パーサへ渡すと元のスキルに戻る。
+//! 1行のスキル本文から複数のスキルがパースされる場合(ロック解除+変換など)は、
+//! `skills_to_japanese`で元の1行にまとめ直す。
+
+use std::fmt;
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::skill::*;
+use super::wording::*;
+
+/// スキルのリストをスキル本文へ変換する。
+/// 1行にまとめられるスキル同士はまとめ、それ以外は1スキル1文で出力する。
+pub fn skills_to_japanese(skills: &[Skill]) -> String {
+    let mut result = String::new();
+    let mut rest = skills;
+
+    while !rest.is_empty() {
+        let (sentence, used) = sentence(rest);
+        result.push_str(&sentence);
+        rest = &rest[used..];
+    }
+
+    result
+}
+
+/// 先頭から1文にまとめられるスキルを変換し、変換した文と使用したスキル数を返す
+fn sentence(skills: &[Skill]) -> (String, usize) {
+    let first = &skills[0];
+    let second = skills.get(1);
+
+    let (body, used) = match (&first.effect, second.map(|skill| &skill.effect)) {
+        // ロック解除と同時に行う変換、生成
+        (SkillEffect::DropUnLock, Some(next)) if is_combinable_with_unlock(first, &skills[1]) => {
+            let (body, used) = without_turns(&skills[1..]);
+
+            match next {
+                SkillEffect::ChangeAllOfBoard(_) => (
+                    format!(
+                        "全ドロップのロックを解除し、{}",
+                        body.trim_start_matches("全ドロップを")
+                    ),
+                    used + 1,
+                ),
+                _ => (format!("ドロップのロックを解除し、{body}"), used + 1),
+            }
+        }
+        _ if first.turns_of_apply.is_some() => with_turns(skills),
+        _ => without_turns(skills),
+    };
+
+    (format!("{}{body}。", sub_effect_prefix(first)), used)
+}
+
+/// ロック解除の後ろに続けて記載できるスキルか
+fn is_combinable_with_unlock(unlock: &Skill, next: &Skill) -> bool {
+    let is_target = match next.effect {
+        SkillEffect::ChangeDropAToB(_, _)
+        | SkillEffect::ChangeAllOfBoard(_)
+        | SkillEffect::GenRandomDrop(_, _)
+        | SkillEffect::DropShapeGen(_) => true,
+        _ => false,
+    };
+
+    is_target
+        && unlock.turns_of_apply.is_none()
+        && next.turns_of_apply.is_none()
+        && unlock.sub_effects == next.sub_effects
+}
+
+/// ターン数の指定がないスキルを変換する
+fn without_turns(skills: &[Skill]) -> (String, usize) {
+    let first = &skills[0];
+    let second = skills
+        .get(1)
+        .filter(|skill| skill.turns_of_apply.is_none() && skill.sub_effects == first.sub_effects)
+        .map(|skill| &skill.effect);
+
+    match (&first.effect, second) {
+        // 2色目の単色変換
+        (SkillEffect::ChangeDropAToB(a, b), Some(SkillEffect::ChangeDropAToB(c, d)))
+            if b.len() == 1 && d.len() == 1 =>
+        {
+            (
+                format!(
+                    "{}を{}に、{}を{}に変化",
+                    drops_to_japanese(&a.to_drops()),
+                    drops_to_japanese(b),
+                    drops_to_japanese(&c.to_drops()),
+                    drops_to_japanese(d)
+                ),
+                2,
+            )
+        }
+        // 陣→ランダム生成
+        (SkillEffect::ChangeAllOfBoard(drops), Some(SkillEffect::GenRandomDrop(exc, to)))
+            if exc.is_empty() && is_same_qty(to) =>
+        {
+            (
+                format!(
+                    "全ドロップを{}に変化し、{}",
+                    drops_to_japanese(drops),
+                    gen_block_to_japanese(to)
+                ),
+                2,
+            )
+        }
+        _ => (effect_to_japanese(&first.effect), 1),
+    }
+}
+
+/// ターン数の指定があるスキルを変換する
+fn with_turns(skills: &[Skill]) -> (String, usize) {
+    let first = &skills[0];
+    let second = skills
+        .get(1)
+        .filter(|skill| {
+            skill.turns_of_apply == first.turns_of_apply && skill.sub_effects == first.sub_effects
+        })
+        .map(|skill| &skill.effect);
+
+    let (body, used) = match (&first.effect, second) {
+        // ドロップ目覚め+強化ドロップ目覚め
+        (
+            SkillEffect::DropFalloff(drops, a),
+            Some(SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::VolumeVariation(b))),
+        ) if a == b && *a != VolumeVariation::Only => (
+            format!(
+                "{}、強化ドロップが{}落ちやすくなる",
+                drops_to_japanese(drops),
+                a.to_japanese()
+            ),
+            2,
+        ),
+        // 強化ドロップ目覚め+ドロップ目覚め
+        (
+            SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::VolumeVariation(a)),
+            Some(SkillEffect::DropFalloff(drops, b)),
+        ) if a == b && *a == VolumeVariation::Little => (
+            format!(
+                "強化ドロップと{}が{}落ちやすくなる",
+                drops_to_japanese(drops),
+                a.to_japanese()
+            ),
+            2,
+        ),
+        (
+            SkillEffect::NullificationDamageAbsorption,
+            Some(SkillEffect::NullificationAttributeAbsorption),
+        ) => ("ダメージ吸収と属性吸収を無効化".to_owned(), 2),
+        _ => (effect_to_japanese(&first.effect), 1),
+    };
+
+    (format!("{}{body}", turns_prefix(first)), used)
+}
+
+/// `Nターンの間、`
+fn turns_prefix(skill: &Skill) -> String {
+    match stated_turns(skill) {
+        Some(turns) => format!("{turns}ターンの間、"),
+        None => String::new(),
+    }
+}
+
+/// 副次効果のうち、本文の前に記載するもの
+fn sub_effect_prefix(skill: &Skill) -> String {
+    match &skill.sub_effects {
+        Some(SubEffect::Floor(floor, SubEffectAttribute::IfApply)) => {
+            format!("{}の場合、", floor_to_japanese(*floor))
+        }
+        Some(SubEffect::HitPoint(hp, SubEffectAttribute::IfApply)) => {
+            format!("{}の場合、", hit_point_to_japanese(*hp))
+        }
+        Some(SubEffect::Floor(floor, SubEffectAttribute::Available)) => {
+            format!("{}で使用可能。", floor_to_japanese(*floor))
+        }
+        Some(SubEffect::HitPoint(hp, SubEffectAttribute::Available)) => {
+            format!("{}で使用可能。", hit_point_to_japanese(*hp))
+        }
+        Some(SubEffect::Reserve(turns)) => format!("{turns}ターン後に発動。"),
+        None => String::new(),
+    }
+}
+
+/// `バトル6以降`、`バトル5以前`
+fn floor_to_japanese(floor: isize) -> String {
+    match Threshold::from(floor) {
+        Threshold::AtLeast(floor) => format!("バトル{floor}以降"),
+        Threshold::AtMost(floor) => format!("バトル{floor}以前"),
+    }
+}
+
+/// `HP80%以上`、`HP50%以下`
+fn hit_point_to_japanese(hp: isize) -> String {
+    match Threshold::from(hp) {
+        Threshold::AtLeast(hp) => format!("HP{hp}%以上"),
+        Threshold::AtMost(hp) => format!("HP{hp}%以下"),
+    }
+}
+
+/// スキル効果の本文。ターン数と句点は含まない
+fn effect_to_japanese(effect: &SkillEffect) -> String {
+    match effect {
+        SkillEffect::Other => String::new(),
+        // パースできなかった文はそのまま出力する
+        SkillEffect::Unrecognized { text, .. } => text.to_owned(),
+        SkillEffect::ChangeDropAToB(from, to) => {
+            let random = if to.len() > 1 { "ランダムで" } else { "" };
+
+            format!(
+                "{}を{random}{}に変化",
+                drops_to_japanese(&from.to_drops()),
+                drops_to_japanese(to)
+            )
+        }
+        SkillEffect::ChangeAllOfBoard(drops) => {
+            format!("全ドロップを{}に変化", drops_to_japanese(drops))
+        }
+        SkillEffect::GenRandomDrop(exc, to) => gen_random_drop_to_japanese(exc, to),
+        SkillEffect::DropShapeGen(shapes) => shapes_to_japanese(shapes),
+        SkillEffect::DropUnLock => "ドロップのロック状態を解除".to_owned(),
+        SkillEffect::DropLock(drops) if *drops == Drop::all() => "全ドロップをロック".to_owned(),
+        SkillEffect::DropLock(drops) => format!("{}をロック", drops_to_japanese(drops)),
+        SkillEffect::DropRefresh => "ランダムでドロップを入れ替える".to_owned(),
+        SkillEffect::DropPowerUp(drops) if is_five_attribute_and_recovery(drops) => {
+            "全ドロップを強化".to_owned()
+        }
+        SkillEffect::DropPowerUp(drops) => format!("{}を強化", drops_to_japanese(drops)),
+        SkillEffect::DropFalloff(drops, VolumeVariation::Only) => {
+            format!("{}のみ落ちてくる", drops_to_japanese(drops))
+        }
+        SkillEffect::DropFalloff(drops, volume) => format!(
+            "{}が{}落ちやすくなる",
+            drops_to_japanese(drops),
+            volume.to_japanese()
+        ),
+        SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::Num(rate)) => {
+            format!("強化ドロップが{rate}%の確率で落ちてくる")
+        }
+        SkillEffect::PowerupDropFalloff(PowerupDropFalloffKind::VolumeVariation(volume)) => {
+            format!("強化ドロップが{}落ちやすくなる", volume.to_japanese())
+        }
+        SkillEffect::FallLockDrop(drops) if *drops == Drop::all() => {
+            "全ドロップがロック状態で落ちてくる".to_owned()
+        }
+        SkillEffect::FallLockDrop(drops) => {
+            format!("{}がロック状態で落ちてくる", drops_to_japanese(drops))
+        }
+        SkillEffect::FallNailDropEasierToFalloff(volume) => {
+            format!("釘ドロップが{}落ちやすくなる", volume.to_japanese())
+        }
+        SkillEffect::DropsNotFalling => "落ちコンなし".to_owned(),
+        SkillEffect::GenRoulette(qty) => format!("ランダムでルーレットを{qty}個生成"),
+        SkillEffect::GenCloud(position, size) => format!(
+            "{}に{}の雲が発生",
+            cloud_position_to_japanese(position),
+            size.to_japanese()
+        ),
+        SkillEffect::GenTeap(position) => {
+            format!("{}が操作不可になる", tape_position_to_japanese(position))
+        }
+        SkillEffect::ChangeBoardSize(size) => format!("盤面を{}マスにする", size.to_japanese()),
+        SkillEffect::NullificationDamageAbsorption => "ダメージ吸収を無効化".to_owned(),
+        SkillEffect::NullificationAttributeAbsorption => "属性吸収を無効化".to_owned(),
+        SkillEffect::PenetrationDamageNullification => "ダメージ無効を貫通".to_owned(),
+        SkillEffect::EnemyAttributeChange(color) => {
+            format!("敵全体が{}属性に変化", color.to_japanese())
+        }
+    }
+}
+
+/// ランダム生成。`ランダムで`か`X以外から`(`gen_random_source()`)
+fn gen_random_drop_to_japanese(exc: &DropSet, to: &GenDropsWithQty) -> String {
+    let other = gen_random_source(exc, to);
+
+    if other.is_empty() {
+        format!("ランダムで{}", gen_blocks_to_japanese(to))
+    } else {
+        format!(
+            "{}以外から{}",
+            drops_to_japanese(&other.to_drops()),
+            gen_blocks_to_japanese(to)
+        )
+    }
+}
+
+/// 生成数が同じドロップ毎にまとめる。`火と水を2個ずつ、回復を3個生成`
+fn gen_blocks_to_japanese(to: &GenDropsWithQty) -> String {
+    let mut blocks: Vec<GenDropsWithQty> = Vec::new();
+
+    for (drop, qty) in to {
+        match blocks.last_mut() {
+            Some(block) if block[0].1 == *qty => block.push((drop.clone(), *qty)),
+            _ => blocks.push(vec![(drop.clone(), *qty)]),
+        }
+    }
+
+    let blocks = blocks
+        .iter()
+        .map(|block| gen_block_quantity(block))
+        .collect::<Vec<_>>()
+        .join("、");
+
+    format!("{blocks}生成")
+}
+
+/// 生成数が全て同じドロップの生成。`火と水を2個ずつ生成`
+fn gen_block_to_japanese(to: &GenDropsWithQty) -> String {
+    format!("{}生成", gen_block_quantity(to))
+}
+
+/// `火と水を2個ずつ`
+fn gen_block_quantity(block: &[(Drop, usize)]) -> String {
+    let drops: Drops = block.iter().map(|(drop, _)| drop.clone()).collect();
+    let each = if block.len() > 1 { "ずつ" } else { "" };
+
+    format!("{}を{}個{each}", drops_to_japanese(&drops), block[0].1)
+}
+
+fn is_same_qty(to: &GenDropsWithQty) -> bool {
+    to.windows(2).all(|pair| pair[0].1 == pair[1].1)
+}
+
+/// 全ドロップ強化の対象(5属性+回復)か
+fn is_five_attribute_and_recovery(drops: &Drops) -> bool {
+    *drops == DropSet::five_attributes_and_recovery().to_drops()
+}
+
+/// ドロップのリスト。ゲーム内の表記と同じく、末尾のドロップにのみ`ドロップ`を付ける。
+/// * 1色: `火ドロップ`
+/// * 2色: `火と水ドロップ`
+/// * 3色以上: `火、水、木ドロップ`
+/// * 5属性を含む: `5属性+回復ドロップ`
+pub fn drops_to_japanese(drops: &[Drop]) -> String {
+    let five_attribute = DropSet::five_attributes().to_drops();
+
+    let body = if drops.starts_with(&five_attribute) {
+        let rest = drops[5..]
+            .iter()
+            .map(|drop| format!("+{}", drop.to_japanese()))
+            .collect::<String>();
+
+        format!("5属性{rest}")
+    } else {
+        let names: Vec<&str> = drops.iter().map(Drop::to_japanese).collect();
+
+        if names.len() == 2 {
+            names.join("と")
+        } else {
+            names.join("、")
+        }
+    };
+
+    format!("{body}ドロップ")
+}
+
+/// 形状生成
+fn shapes_to_japanese(shapes: &[ShapeType]) -> String {
+    let is_row_col = |shape: &ShapeType| match shape {
+        ShapeType::Row(_, _) | ShapeType::Col(_, _) => true,
+        _ => false,
+    };
+
+    if shapes.iter().all(is_row_col) {
+        row_col_to_japanese(shapes)
+    } else {
+        shapes
+            .iter()
+            .map(ShapeType::to_japanese)
+            .collect::<Vec<_>>()
+            .join("、")
+    }
+}
+
+/// 縦、横の生成位置をまとめたもの
+#[derive(Clone, Debug, PartialEq)]
+struct RowColRun {
+    is_row: bool,
+    /// 開始位置。正なら左(上)から、負なら右(下)から
+    start: isize,
+    /// 列(段)数
+    count: usize,
+    drop: Drop,
+    /// 両端の生成
+    both_side: bool,
+}
+
+impl RowColRun {
+    fn is_side(self: &Self) -> bool {
+        self.start.abs() == 1
+    }
+
+    /// `左端1列`、`最上段横2列`
+    fn side_to_japanese(self: &Self) -> String {
+        match (self.is_row, self.both_side, self.start.is_positive()) {
+            (false, true, _) if self.count == 1 => "両端1列".to_owned(),
+            (false, true, _) => format!("両端縦{}列", self.count),
+            (false, false, true) if self.count == 1 => "左端1列".to_owned(),
+            (false, false, true) => format!("左端縦{}列", self.count),
+            (false, false, false) if self.count == 1 => "右端1列".to_owned(),
+            (false, false, false) => format!("右端縦{}列", self.count),
+            (true, _, true) => format!("最上段横{}列", self.count),
+            (true, _, false) => format!("最下段横{}列", self.count),
+        }
+    }
+
+    /// `左から2列目`、`下から2段目`
+    fn center_position_to_japanese(self: &Self) -> String {
+        let idx = self.start.unsigned_abs();
+
+        match (self.is_row, self.start.is_positive()) {
+            (false, true) => format!("左から{idx}列目"),
+            (false, false) => format!("右から{idx}列目"),
+            (true, true) => format!("上から{idx}段目"),
+            (true, false) => format!("下から{idx}段目"),
+        }
+    }
+
+    /// `縦1列`、`横2列`
+    fn center_count_to_japanese(self: &Self) -> String {
+        let direction = if self.is_row { "横" } else { "縦" };
+
+        format!("{direction}{}列", self.count)
+    }
+}
+
+/// 縦、横の生成
+fn row_col_to_japanese(shapes: &[ShapeType]) -> String {
+    let runs = row_col_runs(shapes);
+    let mut blocks: Vec<(String, Drop)> = Vec::new();
+    let mut idx = 0;
+
+    while idx < runs.len() {
+        let run = &runs[idx];
+        let next = runs
+            .get(idx + 1)
+            .filter(|next| next.drop == run.drop && next.is_row == run.is_row && !run.both_side);
+
+        match next {
+            // 左端2列と右端2列
+            Some(next) if run.is_side() && next.is_side() && !next.both_side => {
+                blocks.push((
+                    format!("{}と{}", run.side_to_japanese(), next.side_to_japanese()),
+                    run.drop.clone(),
+                ));
+                idx += 2;
+            }
+            // 左から2列目と右から2列目縦1列
+            Some(next) if !run.is_side() && !next.is_side() && run.count == next.count => {
+                blocks.push((
+                    format!(
+                        "{}と{}{}",
+                        run.center_position_to_japanese(),
+                        next.center_position_to_japanese(),
+                        run.center_count_to_japanese()
+                    ),
+                    run.drop.clone(),
+                ));
+                idx += 2;
+            }
+            _ => {
+                let position = if run.is_side() {
+                    run.side_to_japanese()
+                } else {
+                    format!(
+                        "{}{}",
+                        run.center_position_to_japanese(),
+                        run.center_count_to_japanese()
+                    )
+                };
+
+                blocks.push((position, run.drop.clone()));
+                idx += 1;
+            }
+        }
+    }
+
+    let blocks = blocks
+        .iter()
+        .map(|(position, drop)| {
+            format!(
+                "{position}を{}に",
+                drops_to_japanese(std::slice::from_ref(drop))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("、");
+
+    format!("{blocks}変化")
+}
+
+/// 生成位置を、連続する列(段)毎にまとめる
+fn row_col_runs(shapes: &[ShapeType]) -> Vec<RowColRun> {
+    let entries: Vec<(bool, isize, Drop)> = shapes
+        .iter()
+        .map(|shape| match shape {
+            ShapeType::Row(idx, drop) => (true, *idx, drop.clone()),
+            ShapeType::Col(idx, drop) => (false, *idx, drop.clone()),
+            _ => panic!(
+                "from japanese::row_col_runs(). Expected Row or Col {:?}",
+                shape
+            ),
+        })
+        .collect();
+
+    let mut runs: Vec<RowColRun> = Vec::new();
+    let mut idx = 0;
+
+    while idx < entries.len() {
+        let (is_row, start, drop) = entries[idx].clone();
+
+        // 両端縦N列: 1, -1, 2, -2, ...
+        let both_side_count = if !is_row && start == 1 {
+            entries[idx..]
+                .chunks(2)
+                .enumerate()
+                .take_while(|(n, pair)| {
+                    let n = *n as isize + 1;
+                    pair.len() == 2
+                        && pair[0] == (false, n, drop.clone())
+                        && pair[1] == (false, -n, drop.clone())
+                })
+                .count()
+        } else {
+            0
+        };
+
+        if both_side_count > 0 {
+            runs.push(RowColRun {
+                is_row,
+                start,
+                count: both_side_count,
+                drop,
+                both_side: true,
+            });
+            idx += both_side_count * 2;
+            continue;
+        }
+
+        // 端から離れる方向へ連続する列(段)
+        let step = start.signum();
+        let count = entries[idx..]
+            .iter()
+            .enumerate()
+            .take_while(|(n, (row, i, d))| {
+                *row == is_row && *i == start + step * *n as isize && *d == drop
+            })
+            .count();
+
+        runs.push(RowColRun {
+            is_row,
+            start,
+            count,
+            drop,
+            both_side: false,
+        });
+        idx += count;
+    }
+
+    runs
+}
+
+/// 雲の発生位置
+fn cloud_position_to_japanese(position: &BoardPosition) -> String {
+    match position {
+        BoardPosition::Random => "盤面".to_owned(),
+        BoardPosition::Row(1) => "最上段".to_owned(),
+        BoardPosition::Row(-1) => "最下段".to_owned(),
+        BoardPosition::Col(1) => "左端".to_owned(),
+        BoardPosition::Col(-1) => "右端".to_owned(),
+        other => position_to_japanese(other),
+    }
+}
+
+/// テープの発生位置
+fn tape_position_to_japanese(position: &BoardPosition) -> String {
+    match position {
+        BoardPosition::Random => "盤面".to_owned(),
+        BoardPosition::Row(1) => "最上段横1列".to_owned(),
+        BoardPosition::Row(-1) => "最下段横1列".to_owned(),
+        BoardPosition::Col(1) => "左端1列".to_owned(),
+        BoardPosition::Col(-1) => "右端1列".to_owned(),
+        other => position_to_japanese(other),
+    }
+}
+
+/// 端以外の位置。現在のところ該当するスキルはない
+fn position_to_japanese(position: &BoardPosition) -> String {
+    match position {
+        BoardPosition::Row(idx) if idx.is_positive() => format!("上から{idx}段目"),
+        BoardPosition::Row(idx) => format!("下から{}段目", idx.unsigned_abs()),
+        BoardPosition::Col(idx) if idx.is_positive() => format!("左から{idx}列目"),
+        BoardPosition::Col(idx) => format!("右から{}列目", idx.unsigned_abs()),
+        BoardPosition::Random => "盤面".to_owned(),
+    }
+}
+
+impl Skill {
+    /// スキル本文へ変換する。句点まで含む
+    pub fn to_japanese(self: &Self) -> String {
+        skills_to_japanese(std::slice::from_ref(self))
+    }
+}
+
+impl fmt::Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_japanese())
+    }
+}
+
+impl SubEffect {
+    /// `HP80%以上の場合`、`バトル6以降で使用可能`、`2ターン後に発動`。句読点は含まない
+    pub fn to_japanese(self: &Self) -> String {
+        match self {
+            SubEffect::Floor(floor, SubEffectAttribute::IfApply) => {
+                format!("{}の場合", floor_to_japanese(*floor))
+            }
+            SubEffect::HitPoint(hp, SubEffectAttribute::IfApply) => {
+                format!("{}の場合", hit_point_to_japanese(*hp))
+            }
+            SubEffect::Floor(floor, SubEffectAttribute::Available) => {
+                format!("{}で使用可能", floor_to_japanese(*floor))
+            }
+            SubEffect::HitPoint(hp, SubEffectAttribute::Available) => {
+                format!("{}で使用可能", hit_point_to_japanese(*hp))
+            }
+            SubEffect::Reserve(turns) => format!("{turns}ターン後に発動"),
+        }
+    }
+}
+
+impl ShapeType {
+    /// 形状生成1つ分の本文。句点は含まない
+    pub fn to_japanese(self: &Self) -> String {
+        let drop = |drop: &Drop| drops_to_japanese(std::slice::from_ref(drop));
+
+        match self {
+            ShapeType::Col(_, _) | ShapeType::Row(_, _) => {
+                row_col_to_japanese(std::slice::from_ref(self))
+            }
+            ShapeType::LShape(d, qty) => format!("L字型に{}を{qty}つ生成", drop(d)),
+            ShapeType::ZShape(d) => format!("盤面上にZ字型に{}を生成", drop(d)),
+            ShapeType::SmallCrossShape(d, qty) => format!("十字型に{}を{qty}つ生成", drop(d)),
+            ShapeType::CrossShape(d) => format!("十字型に{}を生成", drop(d)),
+            ShapeType::Square(d, size, qty) => {
+                format!("{size}×{size}の正方形に{}を{qty}つ生成", drop(d))
+            }
+            ShapeType::ShapeOfBoardPerimeter(d) => format!("盤面外周を{}に変化", drop(d)),
+            ShapeType::ShapeOfBoardTop(d, qty) => format!("盤面上部に{}を{qty}個生成", drop(d)),
+            ShapeType::ShapeOfBoardCenter(d) => format!("盤面中央を{}に変化", drop(d)),
+            ShapeType::ShapeOfBoardBottom(d, qty) => {
+                format!("盤面下部に{}を{qty}個生成", drop(d))
+            }
+            ShapeType::ShapeOfBoardCorners(d, qty) => {
+                format!("盤面4隅に{}を{qty}個ずつ生成", drop(d))
+            }
+            ShapeType::ShapeOfSpiderweb(web, center) => format!(
+                "蜘蛛の巣状に{}を生成",
+                drops_to_japanese(&[web.clone(), center.clone()])
+            ),
+            ShapeType::ShapeOfCrescentMoon(d) => format!("三日月状に{}を生成", drop(d)),
+            ShapeType::ShapeOfOblique(d) => format!("盤面上に斜めに{}を生成", drop(d)),
+            ShapeType::ShapeOfSomeKind(d, kind, qty) => {
+                format!("{kind}の形に{}を{qty}個生成", drop(d))
+            }
+        }
+    }
+}
+
+impl Drop {
+    /// ドロップ名。`ドロップ`は付けない
+    pub fn to_japanese(self: &Self) -> &'static str {
+        match self {
+            Drop::Colored(color) => color.to_japanese(),
+            Drop::NonColored(drop) => drop.to_japanese(),
+        }
+    }
+}
+
+impl Color {
+    pub fn to_japanese(self: &Self) -> &'static str {
+        match self {
+            Color::Fire => "火",
+            Color::Water => "水",
+            Color::Wood => "木",
+            Color::Lightning => "光",
+            Color::Dark => "闇",
+        }
+    }
+}
+
+impl NonColoredDrop {
+    pub fn to_japanese(self: &Self) -> &'static str {
+        match self {
+            NonColoredDrop::Recovery => "回復",
+            NonColoredDrop::Disturb => "お邪魔",
+            NonColoredDrop::Bomb => "爆弾",
+            NonColoredDrop::Poison => "毒",
+            NonColoredDrop::DeadlyPoison => "猛毒",
+        }
+    }
+}
+
+impl VolumeVariation {
+    /// `落ちやすくなる`の前に付く語。通常は空
+    pub fn to_japanese(self: &Self) -> &'static str {
+        match self {
+            VolumeVariation::LittleMore => "ほんの少し",
+            VolumeVariation::Little => "少し",
+            VolumeVariation::Normal => "",
+            VolumeVariation::Only => "のみ",
+        }
+    }
+}
+
+impl Size {
+    /// `7×6`
+    pub fn to_japanese(self: &Self) -> String {
+        format!("{}×{}", self.0, self.1)
+    }
+}
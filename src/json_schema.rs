@@ -0,0 +1,17 @@
+//! スキルのJSON Schema
+//!
+//! `schema`フィーチャーで有効になる。JSONの形は`serde`フィーチャーと同じ。
+//! リポジトリには`schema/skills.schema.json`として生成済みのものを置いている。
+//!
+//! ```text
+//! cargo run --example json_schema --features schema > schema/skills.schema.json
+//! ```
+
+use schemars::schema::RootSchema;
+
+use super::skill::Skill;
+
+/// パース結果(`Vec<Skill>`)のJSON Schema
+pub fn skills_schema() -> RootSchema {
+    schemars::schema_for!(Vec<Skill>)
+}
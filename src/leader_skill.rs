@@ -0,0 +1,119 @@
+//! リーダースキル
+//!
+//! スキル(`Skill`)とは別の文法(`leader_skill.par`)でパースする。
+//! 属性、ドロップ、盤面サイズは`schema.rs`の型を使う。
+//!
+//! ```text
+//! 【7×6マス】火属性のHPと攻撃力が2倍。6コンボ以上で攻撃力が10倍、ダメージを50%軽減。
+//! ```
+
+use super::schema::*;
+
+/// リーダースキル1つ分
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LeaderSkill {
+    /// 盤面サイズ。`【7×6マス】`
+    pub board_size: Option<Size>,
+    /// 本文の順序の効果
+    pub effects: Vec<LeaderEffect>,
+}
+
+/// リーダースキルの効果
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LeaderEffect {
+    /// HP、攻撃力、回復力の倍率
+    /// * 0: 発動条件
+    /// * 1: 対象のモンスター
+    /// * 2: 倍率
+    Multiplier(LeaderCondition, LeaderTarget, Stats),
+    /// 受けるダメージの軽減
+    /// * 0: 発動条件
+    /// * 1: 軽減率(%)。`12.5%`のような小数もある
+    DamageReduction(LeaderCondition, f64),
+    /// ドロップ操作時間の延長
+    /// * 0: 発動条件
+    /// * 1: 延長する秒数
+    TimeExtension(LeaderCondition, f64),
+}
+
+/// 効果の発動条件
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LeaderCondition {
+    /// 常時
+    #[default]
+    Always,
+    /// Nコンボ以上
+    Combos(usize),
+    /// 指定したドロップの同時攻撃。`火と水の同時攻撃`
+    ColorMatch(Drops),
+    /// N色以上の同時攻撃。`4色以上同時攻撃`
+    Colors(usize),
+}
+
+/// 倍率の対象のモンスター
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LeaderTarget {
+    /// 全てのモンスター
+    #[default]
+    All,
+    /// いずれかの属性のモンスター
+    Attributes(Vec<Color>),
+    /// いずれかのタイプのモンスター
+    Types(Vec<MonsterType>),
+}
+
+/// モンスターのタイプ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MonsterType {
+    Dragon,
+    God,
+    Devil,
+    Machine,
+    Balanced,
+    Attacker,
+    Physical,
+    Healer,
+    Evolve,
+    Awaken,
+    Enhance,
+    Vendor,
+}
+
+/// HP、攻撃力、回復力の倍率。指定の無いステータスは1倍
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Stats {
+    pub hp: f64,
+    pub attack: f64,
+    pub recovery: f64,
+}
+
+impl Stats {
+    pub fn new(hp: f64, attack: f64, recovery: f64) -> Self {
+        Stats {
+            hp,
+            attack,
+            recovery,
+        }
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Stats::new(1.0, 1.0, 1.0)
+    }
+}
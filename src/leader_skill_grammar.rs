@@ -0,0 +1,283 @@
+use std::marker::PhantomData;
+
+use miette::bail;
+
+use super::leader_skill::*;
+use super::leader_skill_grammar_trait as ast;
+use super::leader_skill_grammar_trait::LeaderSkillGrammarTrait;
+use super::schema::*;
+
+/// リーダースキル本文のセマンティックアクション
+///
+/// 1文の中の句は前の句の条件と対象を引き継ぐ。
+/// * 条件の無い句は、前の句の条件を引き継ぐ。`6コンボ以上で攻撃力が10倍、ダメージを50%軽減。`
+/// * 条件も対象も無い倍率の句は、前の倍率の句の対象も引き継ぐ。`ドラゴンタイプのHPが1.5倍、攻撃力は2倍。`
+#[derive(Debug, Default, PartialEq)]
+pub struct LeaderSkillGrammar<'t> {
+    pub leader_skill: LeaderSkill,
+    pd: PhantomData<&'t str>,
+}
+
+/// 属性、タイプ、同時攻撃のドロップとして読める名前
+#[derive(Clone, Debug, PartialEq)]
+enum Name {
+    Drop(Drop),
+    Type(MonsterType),
+}
+
+/// 1つの句。条件と対象は省略されていれば`None`
+struct Clause {
+    condition: Option<LeaderCondition>,
+    target: Option<LeaderTarget>,
+    body: Body,
+}
+
+enum Body {
+    Stats(Stats),
+    DamageReduction(f64),
+    TimeExtension(f64),
+}
+
+impl LeaderSkillGrammar<'_> {
+    pub fn new() -> Self {
+        LeaderSkillGrammar {
+            leader_skill: LeaderSkill::default(),
+            pd: PhantomData,
+        }
+    }
+
+    /// リーダースキル本文を正規化(`normalize::normalize`)してからパースする
+    pub fn parse_leader_skill(input: &str) -> miette::Result<LeaderSkill> {
+        let input = super::normalize::normalize(input);
+
+        let grammar = &mut LeaderSkillGrammar::new();
+        super::leader_skill_parser::parse(&input, "<input>", grammar)?;
+
+        Ok(grammar.leader_skill.clone())
+    }
+
+    fn clause(arg: &ast::LeaderClause) -> miette::Result<Clause> {
+        match arg {
+            ast::LeaderClause::NameListNameListClause(clause) => {
+                let names = names(&clause.name_list);
+
+                match &*clause.name_list_clause {
+                    ast::NameListClause::NameSuffixNoStatsStmt(stmt) => Ok(Clause {
+                        condition: None,
+                        target: Some(target(names, &stmt.name_suffix)?),
+                        body: Body::Stats(stats(&stmt.stats_stmt)),
+                    }),
+                    ast::NameListClause::NoWordSimultaneousDeLeaderBody(stmt) => {
+                        let drops = names
+                            .into_iter()
+                            .map(|name| match name {
+                                Name::Drop(drop) => Ok(drop),
+                                Name::Type(ty) => bail!("{ty:?} is not a drop"),
+                            })
+                            .collect::<miette::Result<Drops>>()?;
+
+                        Self::body(LeaderCondition::ColorMatch(drops), &stmt.leader_body)
+                    }
+                }
+            }
+            ast::LeaderClause::CountConditionDeLeaderBody(clause) => {
+                let count = integer(&clause.count_condition.number)?;
+                let condition = match &*clause.count_condition.count_condition_suffix {
+                    ast::CountConditionSuffix::WordComboCountConditionSuffixOpt(_) => {
+                        LeaderCondition::Combos(count)
+                    }
+                    ast::CountConditionSuffix::WordColorsCountConditionSuffixOpt0WordSimultaneous(
+                        _,
+                    ) => LeaderCondition::Colors(count),
+                };
+
+                Self::body(condition, &clause.leader_body)
+            }
+            ast::LeaderClause::PlainBody(clause) => Ok(Clause {
+                condition: None,
+                target: None,
+                body: body(&clause.plain_body),
+            }),
+        }
+    }
+
+    /// 条件の後ろの句
+    fn body(condition: LeaderCondition, arg: &ast::LeaderBody) -> miette::Result<Clause> {
+        match arg {
+            ast::LeaderBody::NameListNameSuffixNoStatsStmt(stmt) => Ok(Clause {
+                condition: Some(condition),
+                target: Some(target(names(&stmt.name_list), &stmt.name_suffix)?),
+                body: Body::Stats(stats(&stmt.stats_stmt)),
+            }),
+            ast::LeaderBody::PlainBody(stmt) => Ok(Clause {
+                condition: Some(condition),
+                target: None,
+                body: body(&stmt.plain_body),
+            }),
+        }
+    }
+}
+
+fn body(arg: &ast::PlainBody) -> Body {
+    match arg {
+        ast::PlainBody::StatsStmt(stmt) => Body::Stats(stats(&stmt.stats_stmt)),
+        ast::PlainBody::DamageReductionStmt(stmt) => {
+            Body::DamageReduction(number(&stmt.damage_reduction_stmt.number))
+        }
+        ast::PlainBody::TimeExtensionStmt(stmt) => {
+            Body::TimeExtension(number(&stmt.time_extension_stmt.number))
+        }
+    }
+}
+
+/// 属性、タイプの対象
+fn target(names: Vec<Name>, suffix: &ast::NameSuffix) -> miette::Result<LeaderTarget> {
+    match suffix {
+        ast::NameSuffix::WordAttribute(_) => names
+            .into_iter()
+            .map(|name| match name {
+                Name::Drop(Drop::Colored(color)) => Ok(color),
+                name => bail!("{name:?} is not an attribute"),
+            })
+            .collect::<miette::Result<_>>()
+            .map(LeaderTarget::Attributes),
+        ast::NameSuffix::WordType(_) => names
+            .into_iter()
+            .map(|name| match name {
+                Name::Type(ty) => Ok(ty),
+                // 回復タイプ
+                Name::Drop(Drop::NonColored(NonColoredDrop::Recovery)) => Ok(MonsterType::Healer),
+                name => bail!("{name:?} is not a type"),
+            })
+            .collect::<miette::Result<_>>()
+            .map(LeaderTarget::Types),
+    }
+}
+
+fn stats(arg: &ast::StatsStmt) -> Stats {
+    let times = number(&arg.number);
+
+    match &*arg.stats {
+        ast::Stats::WordAllParameters(_) => Stats::new(times, times, times),
+        ast::Stats::StatStatsList(list) => {
+            let mut stats = Stats::default();
+            let all = std::iter::once(&*list.stat).chain(list.stats_list.iter().map(|s| &*s.stat));
+
+            for stat in all {
+                match stat {
+                    ast::Stat::WordHp(_) => stats.hp = times,
+                    ast::Stat::WordAttack(_) => stats.attack = times,
+                    ast::Stat::WordRecovery(_) => stats.recovery = times,
+                }
+            }
+            stats
+        }
+    }
+}
+
+fn names(arg: &ast::NameList) -> Vec<Name> {
+    std::iter::once(&*arg.name)
+        .chain(arg.name_list_list.iter().map(|item| &*item.name))
+        .map(name)
+        .collect()
+}
+
+fn name(arg: &ast::Name) -> Name {
+    match arg {
+        ast::Name::Color(color) => Name::Drop(Drop::Colored(match &*color.color {
+            ast::Color::Fire(_) => Color::Fire,
+            ast::Color::Water(_) => Color::Water,
+            ast::Color::Wood(_) => Color::Wood,
+            ast::Color::Lightning(_) => Color::Lightning,
+            ast::Color::Dark(_) => Color::Dark,
+        })),
+        ast::Name::NonColoredDrop(drop) => {
+            Name::Drop(Drop::NonColored(match &*drop.non_colored_drop {
+                ast::NonColoredDrop::Recovery(_) => NonColoredDrop::Recovery,
+                ast::NonColoredDrop::Disturb(_) => NonColoredDrop::Disturb,
+                ast::NonColoredDrop::Bomb(_) => NonColoredDrop::Bomb,
+                ast::NonColoredDrop::DeadlyPoison(_) => NonColoredDrop::DeadlyPoison,
+                ast::NonColoredDrop::Poison(_) => NonColoredDrop::Poison,
+            }))
+        }
+        ast::Name::MonsterType(ty) => Name::Type(match &*ty.monster_type {
+            ast::MonsterType::Dragon(_) => MonsterType::Dragon,
+            ast::MonsterType::God(_) => MonsterType::God,
+            ast::MonsterType::Devil(_) => MonsterType::Devil,
+            ast::MonsterType::Machine(_) => MonsterType::Machine,
+            ast::MonsterType::Balanced(_) => MonsterType::Balanced,
+            ast::MonsterType::Attacker(_) => MonsterType::Attacker,
+            ast::MonsterType::Physical(_) => MonsterType::Physical,
+            ast::MonsterType::Evolve(_) => MonsterType::Evolve,
+            ast::MonsterType::Awaken(_) => MonsterType::Awaken,
+            ast::MonsterType::Enhance(_) => MonsterType::Enhance,
+            ast::MonsterType::Vendor(_) => MonsterType::Vendor,
+        }),
+    }
+}
+
+/// `2`、`1.5`
+fn number(arg: &ast::Number) -> f64 {
+    arg.number.text().parse::<f64>().unwrap()
+}
+
+/// 盤面のサイズ、コンボ数、色数。`Number`は小数も受け付けるので、整数でなければエラー
+fn integer(arg: &ast::Number) -> miette::Result<usize> {
+    let text = arg.number.text();
+
+    match text.parse::<usize>() {
+        Ok(value) => Ok(value),
+        Err(_) => bail!("expected an integer but found {text:?}"),
+    }
+}
+
+impl<'t> LeaderSkillGrammarTrait<'t> for LeaderSkillGrammar<'t> {
+    /// 【7×6マス】
+    fn board_size_prefix(&mut self, arg: &ast::BoardSizePrefix<'t>) -> miette::Result<()> {
+        let width = integer(&arg.size.number)?;
+        let height = integer(&arg.size.number0)?;
+
+        self.leader_skill.board_size = Some(Size(width, height));
+        Ok(())
+    }
+
+    fn leader_sentence(&mut self, arg: &ast::LeaderSentence<'t>) -> miette::Result<()> {
+        let clauses = std::iter::once(&*arg.leader_clause).chain(
+            arg.leader_sentence_list
+                .iter()
+                .map(|item| &*item.leader_clause),
+        );
+
+        let mut condition = LeaderCondition::Always;
+        let mut target = LeaderTarget::All;
+
+        for clause in clauses {
+            let clause = Self::clause(clause)?;
+
+            if let Body::Stats(_) = clause.body {
+                target = match (&clause.condition, clause.target) {
+                    (_, Some(t)) => t,
+                    (None, None) => target,
+                    (Some(_), None) => LeaderTarget::All,
+                };
+            }
+            if let Some(c) = clause.condition {
+                condition = c;
+            }
+
+            let effect = match clause.body {
+                Body::Stats(stats) => {
+                    LeaderEffect::Multiplier(condition.clone(), target.clone(), stats)
+                }
+                Body::DamageReduction(percent) => {
+                    LeaderEffect::DamageReduction(condition.clone(), percent)
+                }
+                Body::TimeExtension(seconds) => {
+                    LeaderEffect::TimeExtension(condition.clone(), seconds)
+                }
+            };
+            self.leader_skill.effects.push(effect);
+        }
+        Ok(())
+    }
+}
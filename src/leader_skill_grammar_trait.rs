@@ -0,0 +1,5348 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use derive_builder::Builder;
+#[allow(unused_imports)]
+use parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::Token;
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::miette::{bail, miette, IntoDiagnostic, Result};
+use parol_runtime::parser::{ParseTreeStackEntry, ParseTreeType, UserActionsTrait};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait LeaderSkillGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'LeaderSkillLines'
+    fn leader_skill_lines(&mut self, _arg: &LeaderSkillLines<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'BoardSizePrefix'
+    fn board_size_prefix(&mut self, _arg: &BoardSizePrefix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LeaderSentence'
+    fn leader_sentence(&mut self, _arg: &LeaderSentence<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LeaderClause'
+    fn leader_clause(&mut self, _arg: &LeaderClause<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NameListClause'
+    fn name_list_clause(&mut self, _arg: &NameListClause<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NameSuffix'
+    fn name_suffix(&mut self, _arg: &NameSuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CountCondition'
+    fn count_condition(&mut self, _arg: &CountCondition<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CountConditionSuffix'
+    fn count_condition_suffix(&mut self, _arg: &CountConditionSuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LeaderBody'
+    fn leader_body(&mut self, _arg: &LeaderBody<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PlainBody'
+    fn plain_body(&mut self, _arg: &PlainBody<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'StatsStmt'
+    fn stats_stmt(&mut self, _arg: &StatsStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Stats'
+    fn stats(&mut self, _arg: &Stats<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Stat'
+    fn stat(&mut self, _arg: &Stat<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'StatsParticle'
+    fn stats_particle(&mut self, _arg: &StatsParticle<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DamageReductionStmt'
+    fn damage_reduction_stmt(&mut self, _arg: &DamageReductionStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TimeExtensionStmt'
+    fn time_extension_stmt(&mut self, _arg: &TimeExtensionStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TimeParticle'
+    fn time_particle(&mut self, _arg: &TimeParticle<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NameList'
+    fn name_list(&mut self, _arg: &NameList<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NameSep'
+    fn name_sep(&mut self, _arg: &NameSep<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Name'
+    fn name(&mut self, _arg: &Name<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Color'
+    fn color(&mut self, _arg: &Color<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NonColoredDrop'
+    fn non_colored_drop(&mut self, _arg: &NonColoredDrop<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'MonsterType'
+    fn monster_type(&mut self, _arg: &MonsterType<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Size'
+    fn size(&mut self, _arg: &Size<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAttribute'
+    fn word_attribute(&mut self, _arg: &WordAttribute<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordType'
+    fn word_type(&mut self, _arg: &WordType<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSimultaneous'
+    fn word_simultaneous(&mut self, _arg: &WordSimultaneous<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCombo'
+    fn word_combo(&mut self, _arg: &WordCombo<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordColors'
+    fn word_colors(&mut self, _arg: &WordColors<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordOrMore'
+    fn word_or_more(&mut self, _arg: &WordOrMore<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordHp'
+    fn word_hp(&mut self, _arg: &WordHp<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAttack'
+    fn word_attack(&mut self, _arg: &WordAttack<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRecovery'
+    fn word_recovery(&mut self, _arg: &WordRecovery<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAllParameters'
+    fn word_all_parameters(&mut self, _arg: &WordAllParameters<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordTimes'
+    fn word_times(&mut self, _arg: &WordTimes<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordReceive'
+    fn word_receive(&mut self, _arg: &WordReceive<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordDamage'
+    fn word_damage(&mut self, _arg: &WordDamage<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordReduce'
+    fn word_reduce(&mut self, _arg: &WordReduce<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordMoveTime'
+    fn word_move_time(&mut self, _arg: &WordMoveTime<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSecond'
+    fn word_second(&mut self, _arg: &WordSecond<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordExtend'
+    fn word_extend(&mut self, _arg: &WordExtend<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordMass'
+    fn word_mass(&mut self, _arg: &WordMass<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Fire'
+    fn fire(&mut self, _arg: &Fire<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Water'
+    fn water(&mut self, _arg: &Water<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Wood'
+    fn wood(&mut self, _arg: &Wood<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Lightning'
+    fn lightning(&mut self, _arg: &Lightning<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Dark'
+    fn dark(&mut self, _arg: &Dark<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Recovery'
+    fn recovery(&mut self, _arg: &Recovery<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Disturb'
+    fn disturb(&mut self, _arg: &Disturb<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Bomb'
+    fn bomb(&mut self, _arg: &Bomb<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeadlyPoison'
+    fn deadly_poison(&mut self, _arg: &DeadlyPoison<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Poison'
+    fn poison(&mut self, _arg: &Poison<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Dragon'
+    fn dragon(&mut self, _arg: &Dragon<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'God'
+    fn god(&mut self, _arg: &God<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Devil'
+    fn devil(&mut self, _arg: &Devil<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Machine'
+    fn machine(&mut self, _arg: &Machine<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Balanced'
+    fn balanced(&mut self, _arg: &Balanced<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Attacker'
+    fn attacker(&mut self, _arg: &Attacker<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Physical'
+    fn physical(&mut self, _arg: &Physical<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Evolve'
+    fn evolve(&mut self, _arg: &Evolve<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Awaken'
+    fn awaken(&mut self, _arg: &Awaken<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Enhance'
+    fn enhance(&mut self, _arg: &Enhance<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Vendor'
+    fn vendor(&mut self, _arg: &Vendor<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Wo'
+    fn wo(&mut self, _arg: &Wo<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'No'
+    fn no(&mut self, _arg: &No<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'De'
+    fn de(&mut self, _arg: &De<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'And'
+    fn and(&mut self, _arg: &And<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Ga'
+    fn ga(&mut self, _arg: &Ga<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Ha'
+    fn ha(&mut self, _arg: &Ha<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LBracket'
+    fn l_bracket(&mut self, _arg: &LBracket<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RBracket'
+    fn r_bracket(&mut self, _arg: &RBracket<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Camma'
+    fn camma(&mut self, _arg: &Camma<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Period'
+    fn period(&mut self, _arg: &Period<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Multi'
+    fn multi(&mut self, _arg: &Multi<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Percent'
+    fn percent(&mut self, _arg: &Percent<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Number'
+    fn number(&mut self, _arg: &Number<'t>) -> Result<()> {
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 9
+///
+/// LeaderClause: NameList NameListClause;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderClauseNameListNameListClause<'t> {
+    pub name_list: Box<NameList<'t>>,
+    pub name_list_clause: Box<NameListClause<'t>>,
+}
+
+///
+/// Type derived for production 10
+///
+/// LeaderClause: CountCondition De LeaderBody;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderClauseCountConditionDeLeaderBody<'t> {
+    pub count_condition: Box<CountCondition<'t>>,
+    pub de: Box<De<'t>>,
+    pub leader_body: Box<LeaderBody<'t>>,
+}
+
+///
+/// Type derived for production 11
+///
+/// LeaderClause: PlainBody;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderClausePlainBody<'t> {
+    pub plain_body: Box<PlainBody<'t>>,
+}
+
+///
+/// Type derived for production 12
+///
+/// NameListClause: NameSuffix No StatsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameListClauseNameSuffixNoStatsStmt<'t> {
+    pub name_suffix: Box<NameSuffix<'t>>,
+    pub no: Box<No<'t>>,
+    pub stats_stmt: Box<StatsStmt<'t>>,
+}
+
+///
+/// Type derived for production 13
+///
+/// NameListClause: No WordSimultaneous De LeaderBody;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameListClauseNoWordSimultaneousDeLeaderBody<'t> {
+    pub no: Box<No<'t>>,
+    pub word_simultaneous: Box<WordSimultaneous<'t>>,
+    pub de: Box<De<'t>>,
+    pub leader_body: Box<LeaderBody<'t>>,
+}
+
+///
+/// Type derived for production 14
+///
+/// NameSuffix: WordAttribute;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameSuffixWordAttribute<'t> {
+    pub word_attribute: Box<WordAttribute<'t>>,
+}
+
+///
+/// Type derived for production 15
+///
+/// NameSuffix: WordType;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameSuffixWordType<'t> {
+    pub word_type: Box<WordType<'t>>,
+}
+
+///
+/// Type derived for production 17
+///
+/// CountConditionSuffix: WordCombo CountConditionSuffixOpt /* Option */;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CountConditionSuffixWordComboCountConditionSuffixOpt<'t> {
+    pub word_combo: Box<WordCombo<'t>>,
+    pub count_condition_suffix_opt: Option<Box<CountConditionSuffixOpt<'t>>>,
+}
+
+///
+/// Type derived for production 18
+///
+/// CountConditionSuffix: WordColors CountConditionSuffixOpt0 /* Option */ WordSimultaneous;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CountConditionSuffixWordColorsCountConditionSuffixOpt0WordSimultaneous<'t> {
+    pub word_colors: Box<WordColors<'t>>,
+    pub count_condition_suffix_opt0: Option<Box<CountConditionSuffixOpt0<'t>>>,
+    pub word_simultaneous: Box<WordSimultaneous<'t>>,
+}
+
+///
+/// Type derived for production 23
+///
+/// LeaderBody: NameList NameSuffix No StatsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderBodyNameListNameSuffixNoStatsStmt<'t> {
+    pub name_list: Box<NameList<'t>>,
+    pub name_suffix: Box<NameSuffix<'t>>,
+    pub no: Box<No<'t>>,
+    pub stats_stmt: Box<StatsStmt<'t>>,
+}
+
+///
+/// Type derived for production 24
+///
+/// LeaderBody: PlainBody;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderBodyPlainBody<'t> {
+    pub plain_body: Box<PlainBody<'t>>,
+}
+
+///
+/// Type derived for production 25
+///
+/// PlainBody: StatsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct PlainBodyStatsStmt<'t> {
+    pub stats_stmt: Box<StatsStmt<'t>>,
+}
+
+///
+/// Type derived for production 26
+///
+/// PlainBody: DamageReductionStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct PlainBodyDamageReductionStmt<'t> {
+    pub damage_reduction_stmt: Box<DamageReductionStmt<'t>>,
+}
+
+///
+/// Type derived for production 27
+///
+/// PlainBody: TimeExtensionStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct PlainBodyTimeExtensionStmt<'t> {
+    pub time_extension_stmt: Box<TimeExtensionStmt<'t>>,
+}
+
+///
+/// Type derived for production 29
+///
+/// Stats: Stat StatsList /* Vec */;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsStatStatsList<'t> {
+    pub stat: Box<Stat<'t>>,
+    pub stats_list: Vec<StatsList<'t>>,
+}
+
+///
+/// Type derived for production 32
+///
+/// Stats: WordAllParameters;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsWordAllParameters<'t> {
+    pub word_all_parameters: Box<WordAllParameters<'t>>,
+}
+
+///
+/// Type derived for production 33
+///
+/// Stat: WordHp;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatWordHp<'t> {
+    pub word_hp: Box<WordHp<'t>>,
+}
+
+///
+/// Type derived for production 34
+///
+/// Stat: WordAttack;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatWordAttack<'t> {
+    pub word_attack: Box<WordAttack<'t>>,
+}
+
+///
+/// Type derived for production 35
+///
+/// Stat: WordRecovery;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatWordRecovery<'t> {
+    pub word_recovery: Box<WordRecovery<'t>>,
+}
+
+///
+/// Type derived for production 36
+///
+/// StatsParticle: Ga;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsParticleGa<'t> {
+    pub ga: Box<Ga<'t>>,
+}
+
+///
+/// Type derived for production 37
+///
+/// StatsParticle: Ha;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsParticleHa<'t> {
+    pub ha: Box<Ha<'t>>,
+}
+
+///
+/// Type derived for production 42
+///
+/// TimeParticle: Wo;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TimeParticleWo<'t> {
+    pub wo: Box<Wo<'t>>,
+}
+
+///
+/// Type derived for production 43
+///
+/// TimeParticle: Ga;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TimeParticleGa<'t> {
+    pub ga: Box<Ga<'t>>,
+}
+
+///
+/// Type derived for production 49
+///
+/// NameSep: And;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameSepAnd<'t> {
+    pub and: Box<And<'t>>,
+}
+
+///
+/// Type derived for production 50
+///
+/// NameSep: Camma;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameSepCamma<'t> {
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for production 51
+///
+/// Name: Color;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameColor<'t> {
+    pub color: Box<Color<'t>>,
+}
+
+///
+/// Type derived for production 52
+///
+/// Name: NonColoredDrop;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameNonColoredDrop<'t> {
+    pub non_colored_drop: Box<NonColoredDrop<'t>>,
+}
+
+///
+/// Type derived for production 53
+///
+/// Name: MonsterType;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameMonsterType<'t> {
+    pub monster_type: Box<MonsterType<'t>>,
+}
+
+///
+/// Type derived for production 54
+///
+/// Color: Fire;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorFire<'t> {
+    pub fire: Box<Fire<'t>>,
+}
+
+///
+/// Type derived for production 55
+///
+/// Color: Water;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorWater<'t> {
+    pub water: Box<Water<'t>>,
+}
+
+///
+/// Type derived for production 56
+///
+/// Color: Wood;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorWood<'t> {
+    pub wood: Box<Wood<'t>>,
+}
+
+///
+/// Type derived for production 57
+///
+/// Color: Lightning;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorLightning<'t> {
+    pub lightning: Box<Lightning<'t>>,
+}
+
+///
+/// Type derived for production 58
+///
+/// Color: Dark;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorDark<'t> {
+    pub dark: Box<Dark<'t>>,
+}
+
+///
+/// Type derived for production 59
+///
+/// NonColoredDrop: Recovery;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropRecovery<'t> {
+    pub recovery: Box<Recovery<'t>>,
+}
+
+///
+/// Type derived for production 60
+///
+/// NonColoredDrop: Disturb;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropDisturb<'t> {
+    pub disturb: Box<Disturb<'t>>,
+}
+
+///
+/// Type derived for production 61
+///
+/// NonColoredDrop: Bomb;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropBomb<'t> {
+    pub bomb: Box<Bomb<'t>>,
+}
+
+///
+/// Type derived for production 62
+///
+/// NonColoredDrop: DeadlyPoison;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropDeadlyPoison<'t> {
+    pub deadly_poison: Box<DeadlyPoison<'t>>,
+}
+
+///
+/// Type derived for production 63
+///
+/// NonColoredDrop: Poison;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropPoison<'t> {
+    pub poison: Box<Poison<'t>>,
+}
+
+///
+/// Type derived for production 64
+///
+/// MonsterType: Dragon;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeDragon<'t> {
+    pub dragon: Box<Dragon<'t>>,
+}
+
+///
+/// Type derived for production 65
+///
+/// MonsterType: God;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeGod<'t> {
+    pub god: Box<God<'t>>,
+}
+
+///
+/// Type derived for production 66
+///
+/// MonsterType: Devil;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeDevil<'t> {
+    pub devil: Box<Devil<'t>>,
+}
+
+///
+/// Type derived for production 67
+///
+/// MonsterType: Machine;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeMachine<'t> {
+    pub machine: Box<Machine<'t>>,
+}
+
+///
+/// Type derived for production 68
+///
+/// MonsterType: Balanced;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeBalanced<'t> {
+    pub balanced: Box<Balanced<'t>>,
+}
+
+///
+/// Type derived for production 69
+///
+/// MonsterType: Attacker;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeAttacker<'t> {
+    pub attacker: Box<Attacker<'t>>,
+}
+
+///
+/// Type derived for production 70
+///
+/// MonsterType: Physical;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypePhysical<'t> {
+    pub physical: Box<Physical<'t>>,
+}
+
+///
+/// Type derived for production 71
+///
+/// MonsterType: Evolve;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeEvolve<'t> {
+    pub evolve: Box<Evolve<'t>>,
+}
+
+///
+/// Type derived for production 72
+///
+/// MonsterType: Awaken;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeAwaken<'t> {
+    pub awaken: Box<Awaken<'t>>,
+}
+
+///
+/// Type derived for production 73
+///
+/// MonsterType: Enhance;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeEnhance<'t> {
+    pub enhance: Box<Enhance<'t>>,
+}
+
+///
+/// Type derived for production 74
+///
+/// MonsterType: Vendor;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MonsterTypeVendor<'t> {
+    pub vendor: Box<Vendor<'t>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal And
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct And<'t> {
+    pub and: Token<'t>, /* と */
+}
+
+///
+/// Type derived for non-terminal Attacker
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Attacker<'t> {
+    pub attacker: Token<'t>, /* 攻撃 */
+}
+
+///
+/// Type derived for non-terminal Awaken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Awaken<'t> {
+    pub awaken: Token<'t>, /* 能力覚醒用 */
+}
+
+///
+/// Type derived for non-terminal Balanced
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Balanced<'t> {
+    pub balanced: Token<'t>, /* バランス */
+}
+
+///
+/// Type derived for non-terminal BoardSizePrefix
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct BoardSizePrefix<'t> {
+    pub l_bracket: Box<LBracket<'t>>,
+    pub size: Box<Size<'t>>,
+    pub word_mass: Box<WordMass<'t>>,
+    pub r_bracket: Box<RBracket<'t>>,
+}
+
+///
+/// Type derived for non-terminal Bomb
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Bomb<'t> {
+    pub bomb: Token<'t>, /* 爆弾 */
+}
+
+///
+/// Type derived for non-terminal Camma
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Camma<'t> {
+    pub camma: Token<'t>, /* 、 */
+}
+
+///
+/// Type derived for non-terminal Color
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Color<'t> {
+    Fire(ColorFire<'t>),
+    Water(ColorWater<'t>),
+    Wood(ColorWood<'t>),
+    Lightning(ColorLightning<'t>),
+    Dark(ColorDark<'t>),
+}
+
+///
+/// Type derived for non-terminal CountCondition
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CountCondition<'t> {
+    pub number: Box<Number<'t>>,
+    pub count_condition_suffix: Box<CountConditionSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal CountConditionSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum CountConditionSuffix<'t> {
+    WordComboCountConditionSuffixOpt(CountConditionSuffixWordComboCountConditionSuffixOpt<'t>),
+    WordColorsCountConditionSuffixOpt0WordSimultaneous(
+        CountConditionSuffixWordColorsCountConditionSuffixOpt0WordSimultaneous<'t>,
+    ),
+}
+
+///
+/// Type derived for non-terminal CountConditionSuffixOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CountConditionSuffixOpt<'t> {
+    pub word_or_more: Box<WordOrMore<'t>>,
+}
+
+///
+/// Type derived for non-terminal CountConditionSuffixOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CountConditionSuffixOpt0<'t> {
+    pub word_or_more: Box<WordOrMore<'t>>,
+}
+
+///
+/// Type derived for non-terminal DamageReductionStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DamageReductionStmt<'t> {
+    pub damage_reduction_stmt_opt: Option<Box<DamageReductionStmtOpt<'t>>>,
+    pub word_damage: Box<WordDamage<'t>>,
+    pub wo: Box<Wo<'t>>,
+    pub number: Box<Number<'t>>,
+    pub percent: Box<Percent<'t>>,
+    pub word_reduce: Box<WordReduce<'t>>,
+}
+
+///
+/// Type derived for non-terminal DamageReductionStmtOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DamageReductionStmtOpt<'t> {
+    pub word_receive: Box<WordReceive<'t>>,
+}
+
+///
+/// Type derived for non-terminal Dark
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Dark<'t> {
+    pub dark: Token<'t>, /* 闇 */
+}
+
+///
+/// Type derived for non-terminal De
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct De<'t> {
+    pub de: Token<'t>, /* で */
+}
+
+///
+/// Type derived for non-terminal DeadlyPoison
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DeadlyPoison<'t> {
+    pub deadly_poison: Token<'t>, /* 猛毒 */
+}
+
+///
+/// Type derived for non-terminal Devil
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Devil<'t> {
+    pub devil: Token<'t>, /* 悪魔 */
+}
+
+///
+/// Type derived for non-terminal Disturb
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Disturb<'t> {
+    pub disturb: Token<'t>, /* お邪魔 */
+}
+
+///
+/// Type derived for non-terminal Dragon
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Dragon<'t> {
+    pub dragon: Token<'t>, /* ドラゴン */
+}
+
+///
+/// Type derived for non-terminal Enhance
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Enhance<'t> {
+    pub enhance: Token<'t>, /* 強化合成用 */
+}
+
+///
+/// Type derived for non-terminal Evolve
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Evolve<'t> {
+    pub evolve: Token<'t>, /* 進化用 */
+}
+
+///
+/// Type derived for non-terminal Fire
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Fire<'t> {
+    pub fire: Token<'t>, /* 火 */
+}
+
+///
+/// Type derived for non-terminal Ga
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Ga<'t> {
+    pub ga: Token<'t>, /* が */
+}
+
+///
+/// Type derived for non-terminal God
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct God<'t> {
+    pub god: Token<'t>, /* 神 */
+}
+
+///
+/// Type derived for non-terminal Ha
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Ha<'t> {
+    pub ha: Token<'t>, /* は */
+}
+
+///
+/// Type derived for non-terminal LBracket
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LBracket<'t> {
+    pub l_bracket: Token<'t>, /* 【 */
+}
+
+///
+/// Type derived for non-terminal LeaderBody
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum LeaderBody<'t> {
+    NameListNameSuffixNoStatsStmt(LeaderBodyNameListNameSuffixNoStatsStmt<'t>),
+    PlainBody(LeaderBodyPlainBody<'t>),
+}
+
+///
+/// Type derived for non-terminal LeaderClause
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum LeaderClause<'t> {
+    NameListNameListClause(LeaderClauseNameListNameListClause<'t>),
+    CountConditionDeLeaderBody(LeaderClauseCountConditionDeLeaderBody<'t>),
+    PlainBody(LeaderClausePlainBody<'t>),
+}
+
+///
+/// Type derived for non-terminal LeaderSentence
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderSentence<'t> {
+    pub leader_clause: Box<LeaderClause<'t>>,
+    pub leader_sentence_list: Vec<LeaderSentenceList<'t>>,
+    pub period: Box<Period<'t>>,
+}
+
+///
+/// Type derived for non-terminal LeaderSentenceList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderSentenceList<'t> {
+    pub camma: Box<Camma<'t>>,
+    pub leader_clause: Box<LeaderClause<'t>>,
+}
+
+///
+/// Type derived for non-terminal LeaderSkillLines
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderSkillLines<'t> {
+    pub leader_skill_lines_opt: Option<Box<LeaderSkillLinesOpt<'t>>>,
+    pub leader_sentence: Box<LeaderSentence<'t>>,
+    pub leader_skill_lines_list: Vec<LeaderSkillLinesList<'t>>,
+}
+
+///
+/// Type derived for non-terminal LeaderSkillLinesList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderSkillLinesList<'t> {
+    pub leader_sentence: Box<LeaderSentence<'t>>,
+}
+
+///
+/// Type derived for non-terminal LeaderSkillLinesOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LeaderSkillLinesOpt<'t> {
+    pub board_size_prefix: Box<BoardSizePrefix<'t>>,
+}
+
+///
+/// Type derived for non-terminal Lightning
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Lightning<'t> {
+    pub lightning: Token<'t>, /* 光 */
+}
+
+///
+/// Type derived for non-terminal Machine
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Machine<'t> {
+    pub machine: Token<'t>, /* マシン */
+}
+
+///
+/// Type derived for non-terminal MonsterType
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum MonsterType<'t> {
+    Dragon(MonsterTypeDragon<'t>),
+    God(MonsterTypeGod<'t>),
+    Devil(MonsterTypeDevil<'t>),
+    Machine(MonsterTypeMachine<'t>),
+    Balanced(MonsterTypeBalanced<'t>),
+    Attacker(MonsterTypeAttacker<'t>),
+    Physical(MonsterTypePhysical<'t>),
+    Evolve(MonsterTypeEvolve<'t>),
+    Awaken(MonsterTypeAwaken<'t>),
+    Enhance(MonsterTypeEnhance<'t>),
+    Vendor(MonsterTypeVendor<'t>),
+}
+
+///
+/// Type derived for non-terminal Multi
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Multi<'t> {
+    pub multi: Token<'t>, /* × */
+}
+
+///
+/// Type derived for non-terminal Name
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Name<'t> {
+    Color(NameColor<'t>),
+    NonColoredDrop(NameNonColoredDrop<'t>),
+    MonsterType(NameMonsterType<'t>),
+}
+
+///
+/// Type derived for non-terminal NameList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameList<'t> {
+    pub name: Box<Name<'t>>,
+    pub name_list_list: Vec<NameListList<'t>>,
+}
+
+///
+/// Type derived for non-terminal NameListClause
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum NameListClause<'t> {
+    NameSuffixNoStatsStmt(NameListClauseNameSuffixNoStatsStmt<'t>),
+    NoWordSimultaneousDeLeaderBody(NameListClauseNoWordSimultaneousDeLeaderBody<'t>),
+}
+
+///
+/// Type derived for non-terminal NameListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameListList<'t> {
+    pub name_list_opt: Option<Box<NameListOpt<'t>>>,
+    pub name: Box<Name<'t>>,
+}
+
+///
+/// Type derived for non-terminal NameListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NameListOpt<'t> {
+    pub name_sep: Box<NameSep<'t>>,
+}
+
+///
+/// Type derived for non-terminal NameSep
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum NameSep<'t> {
+    And(NameSepAnd<'t>),
+    Camma(NameSepCamma<'t>),
+}
+
+///
+/// Type derived for non-terminal NameSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum NameSuffix<'t> {
+    WordAttribute(NameSuffixWordAttribute<'t>),
+    WordType(NameSuffixWordType<'t>),
+}
+
+///
+/// Type derived for non-terminal No
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct No<'t> {
+    pub no: Token<'t>, /* の */
+}
+
+///
+/// Type derived for non-terminal NonColoredDrop
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum NonColoredDrop<'t> {
+    Recovery(NonColoredDropRecovery<'t>),
+    Disturb(NonColoredDropDisturb<'t>),
+    Bomb(NonColoredDropBomb<'t>),
+    DeadlyPoison(NonColoredDropDeadlyPoison<'t>),
+    Poison(NonColoredDropPoison<'t>),
+}
+
+///
+/// Type derived for non-terminal Number
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Number<'t> {
+    pub number: Token<'t>, /* [0-9]+(\.[0-9]+)? */
+}
+
+///
+/// Type derived for non-terminal Percent
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Percent<'t> {
+    pub percent: Token<'t>, /* % */
+}
+
+///
+/// Type derived for non-terminal Period
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Period<'t> {
+    pub period: Token<'t>, /* 。 */
+}
+
+///
+/// Type derived for non-terminal Physical
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Physical<'t> {
+    pub physical: Token<'t>, /* 体力 */
+}
+
+///
+/// Type derived for non-terminal PlainBody
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum PlainBody<'t> {
+    StatsStmt(PlainBodyStatsStmt<'t>),
+    DamageReductionStmt(PlainBodyDamageReductionStmt<'t>),
+    TimeExtensionStmt(PlainBodyTimeExtensionStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal Poison
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Poison<'t> {
+    pub poison: Token<'t>, /* 毒 */
+}
+
+///
+/// Type derived for non-terminal RBracket
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RBracket<'t> {
+    pub r_bracket: Token<'t>, /* 】 */
+}
+
+///
+/// Type derived for non-terminal Recovery
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Recovery<'t> {
+    pub recovery: Token<'t>, /* 回復 */
+}
+
+///
+/// Type derived for non-terminal Size
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Size<'t> {
+    pub number: Box<Number<'t>>,
+    pub multi: Box<Multi<'t>>,
+    pub number0: Box<Number<'t>>,
+}
+
+///
+/// Type derived for non-terminal Stat
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Stat<'t> {
+    WordHp(StatWordHp<'t>),
+    WordAttack(StatWordAttack<'t>),
+    WordRecovery(StatWordRecovery<'t>),
+}
+
+///
+/// Type derived for non-terminal Stats
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Stats<'t> {
+    StatStatsList(StatsStatStatsList<'t>),
+    WordAllParameters(StatsWordAllParameters<'t>),
+}
+
+///
+/// Type derived for non-terminal StatsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsList<'t> {
+    pub and: Box<And<'t>>,
+    pub stat: Box<Stat<'t>>,
+}
+
+///
+/// Type derived for non-terminal StatsParticle
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum StatsParticle<'t> {
+    Ga(StatsParticleGa<'t>),
+    Ha(StatsParticleHa<'t>),
+}
+
+///
+/// Type derived for non-terminal StatsStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct StatsStmt<'t> {
+    pub stats: Box<Stats<'t>>,
+    pub stats_particle: Box<StatsParticle<'t>>,
+    pub number: Box<Number<'t>>,
+    pub word_times: Box<WordTimes<'t>>,
+}
+
+///
+/// Type derived for non-terminal TimeExtensionStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TimeExtensionStmt<'t> {
+    pub word_move_time: Box<WordMoveTime<'t>>,
+    pub time_particle: Box<TimeParticle<'t>>,
+    pub number: Box<Number<'t>>,
+    pub word_second: Box<WordSecond<'t>>,
+    pub word_extend: Box<WordExtend<'t>>,
+}
+
+///
+/// Type derived for non-terminal TimeParticle
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TimeParticle<'t> {
+    Wo(TimeParticleWo<'t>),
+    Ga(TimeParticleGa<'t>),
+}
+
+///
+/// Type derived for non-terminal Vendor
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Vendor<'t> {
+    pub vendor: Token<'t>, /* 売却用 */
+}
+
+///
+/// Type derived for non-terminal Water
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Water<'t> {
+    pub water: Token<'t>, /* 水 */
+}
+
+///
+/// Type derived for non-terminal Wo
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Wo<'t> {
+    pub wo: Token<'t>, /* を */
+}
+
+///
+/// Type derived for non-terminal Wood
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Wood<'t> {
+    pub wood: Token<'t>, /* 木 */
+}
+
+///
+/// Type derived for non-terminal WordAllParameters
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAllParameters<'t> {
+    pub word_all_parameters: Token<'t>, /* 全パラメータ */
+}
+
+///
+/// Type derived for non-terminal WordAttack
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAttack<'t> {
+    pub word_attack: Token<'t>, /* 攻撃力 */
+}
+
+///
+/// Type derived for non-terminal WordAttribute
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAttribute<'t> {
+    pub word_attribute: Token<'t>, /* 属性 */
+}
+
+///
+/// Type derived for non-terminal WordColors
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordColors<'t> {
+    pub word_colors: Token<'t>, /* 色 */
+}
+
+///
+/// Type derived for non-terminal WordCombo
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCombo<'t> {
+    pub word_combo: Token<'t>, /* コンボ */
+}
+
+///
+/// Type derived for non-terminal WordDamage
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordDamage<'t> {
+    pub word_damage: Token<'t>, /* ダメージ */
+}
+
+///
+/// Type derived for non-terminal WordExtend
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordExtend<'t> {
+    pub word_extend: Token<'t>, /* 延長 */
+}
+
+///
+/// Type derived for non-terminal WordHp
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordHp<'t> {
+    pub word_hp: Token<'t>, /* HP */
+}
+
+///
+/// Type derived for non-terminal WordMass
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordMass<'t> {
+    pub word_mass: Token<'t>, /* マス */
+}
+
+///
+/// Type derived for non-terminal WordMoveTime
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordMoveTime<'t> {
+    pub word_move_time: Token<'t>, /* ドロップ操作時間 */
+}
+
+///
+/// Type derived for non-terminal WordOrMore
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordOrMore<'t> {
+    pub word_or_more: Token<'t>, /* 以上 */
+}
+
+///
+/// Type derived for non-terminal WordReceive
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordReceive<'t> {
+    pub word_receive: Token<'t>, /* 受ける */
+}
+
+///
+/// Type derived for non-terminal WordRecovery
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRecovery<'t> {
+    pub word_recovery: Token<'t>, /* 回復力 */
+}
+
+///
+/// Type derived for non-terminal WordReduce
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordReduce<'t> {
+    pub word_reduce: Token<'t>, /* 軽減 */
+}
+
+///
+/// Type derived for non-terminal WordSecond
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSecond<'t> {
+    pub word_second: Token<'t>, /* 秒 */
+}
+
+///
+/// Type derived for non-terminal WordSimultaneous
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSimultaneous<'t> {
+    pub word_simultaneous: Token<'t>, /* 同時攻撃 */
+}
+
+///
+/// Type derived for non-terminal WordTimes
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordTimes<'t> {
+    pub word_times: Token<'t>, /* 倍 */
+}
+
+///
+/// Type derived for non-terminal WordType
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordType<'t> {
+    pub word_type: Token<'t>, /* タイプ */
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    And(And<'t>),
+    Attacker(Attacker<'t>),
+    Awaken(Awaken<'t>),
+    Balanced(Balanced<'t>),
+    BoardSizePrefix(BoardSizePrefix<'t>),
+    Bomb(Bomb<'t>),
+    Camma(Camma<'t>),
+    Color(Color<'t>),
+    CountCondition(CountCondition<'t>),
+    CountConditionSuffix(CountConditionSuffix<'t>),
+    CountConditionSuffixOpt(Option<Box<CountConditionSuffixOpt<'t>>>),
+    CountConditionSuffixOpt0(Option<Box<CountConditionSuffixOpt0<'t>>>),
+    DamageReductionStmt(DamageReductionStmt<'t>),
+    DamageReductionStmtOpt(Option<Box<DamageReductionStmtOpt<'t>>>),
+    Dark(Dark<'t>),
+    De(De<'t>),
+    DeadlyPoison(DeadlyPoison<'t>),
+    Devil(Devil<'t>),
+    Disturb(Disturb<'t>),
+    Dragon(Dragon<'t>),
+    Enhance(Enhance<'t>),
+    Evolve(Evolve<'t>),
+    Fire(Fire<'t>),
+    Ga(Ga<'t>),
+    God(God<'t>),
+    Ha(Ha<'t>),
+    LBracket(LBracket<'t>),
+    LeaderBody(LeaderBody<'t>),
+    LeaderClause(LeaderClause<'t>),
+    LeaderSentence(LeaderSentence<'t>),
+    LeaderSentenceList(Vec<LeaderSentenceList<'t>>),
+    LeaderSkillLines(LeaderSkillLines<'t>),
+    LeaderSkillLinesList(Vec<LeaderSkillLinesList<'t>>),
+    LeaderSkillLinesOpt(Option<Box<LeaderSkillLinesOpt<'t>>>),
+    Lightning(Lightning<'t>),
+    Machine(Machine<'t>),
+    MonsterType(MonsterType<'t>),
+    Multi(Multi<'t>),
+    Name(Name<'t>),
+    NameList(NameList<'t>),
+    NameListClause(NameListClause<'t>),
+    NameListList(Vec<NameListList<'t>>),
+    NameListOpt(Option<Box<NameListOpt<'t>>>),
+    NameSep(NameSep<'t>),
+    NameSuffix(NameSuffix<'t>),
+    No(No<'t>),
+    NonColoredDrop(NonColoredDrop<'t>),
+    Number(Number<'t>),
+    Percent(Percent<'t>),
+    Period(Period<'t>),
+    Physical(Physical<'t>),
+    PlainBody(PlainBody<'t>),
+    Poison(Poison<'t>),
+    RBracket(RBracket<'t>),
+    Recovery(Recovery<'t>),
+    Size(Size<'t>),
+    Stat(Stat<'t>),
+    Stats(Stats<'t>),
+    StatsList(Vec<StatsList<'t>>),
+    StatsParticle(StatsParticle<'t>),
+    StatsStmt(StatsStmt<'t>),
+    TimeExtensionStmt(TimeExtensionStmt<'t>),
+    TimeParticle(TimeParticle<'t>),
+    Vendor(Vendor<'t>),
+    Water(Water<'t>),
+    Wo(Wo<'t>),
+    Wood(Wood<'t>),
+    WordAllParameters(WordAllParameters<'t>),
+    WordAttack(WordAttack<'t>),
+    WordAttribute(WordAttribute<'t>),
+    WordColors(WordColors<'t>),
+    WordCombo(WordCombo<'t>),
+    WordDamage(WordDamage<'t>),
+    WordExtend(WordExtend<'t>),
+    WordHp(WordHp<'t>),
+    WordMass(WordMass<'t>),
+    WordMoveTime(WordMoveTime<'t>),
+    WordOrMore(WordOrMore<'t>),
+    WordReceive(WordReceive<'t>),
+    WordRecovery(WordRecovery<'t>),
+    WordReduce(WordReduce<'t>),
+    WordSecond(WordSecond<'t>),
+    WordSimultaneous(WordSimultaneous<'t>),
+    WordTimes(WordTimes<'t>),
+    WordType(WordType<'t>),
+}
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct LeaderSkillGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn LeaderSkillGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `LeaderSkillGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> LeaderSkillGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn LeaderSkillGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {}: {:?}", context, item);
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        if !self.item_stack.is_empty() {
+            let item = self.item_stack.pop();
+            if let Some(ref item) = item {
+                trace!("pop     {}: {:?}", context, item);
+            }
+            item
+        } else {
+            None
+        }
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {:?}", s))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// LeaderSkillLines: LeaderSkillLinesOpt /* Option */ LeaderSentence LeaderSkillLinesList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_skill_lines(
+        &mut self,
+        _leader_skill_lines_opt: &ParseTreeStackEntry<'t>,
+        _leader_sentence: &ParseTreeStackEntry<'t>,
+        _leader_skill_lines_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let leader_skill_lines_list =
+            pop_and_reverse_item!(self, leader_skill_lines_list, LeaderSkillLinesList, context);
+        let leader_sentence = pop_item!(self, leader_sentence, LeaderSentence, context);
+        let leader_skill_lines_opt =
+            pop_item!(self, leader_skill_lines_opt, LeaderSkillLinesOpt, context);
+        let leader_skill_lines_built = LeaderSkillLinesBuilder::default()
+            .leader_skill_lines_opt(leader_skill_lines_opt)
+            .leader_sentence(Box::new(leader_sentence))
+            .leader_skill_lines_list(leader_skill_lines_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .leader_skill_lines(&leader_skill_lines_built)?;
+        self.push(ASTType::LeaderSkillLines(leader_skill_lines_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// LeaderSkillLinesList /* Vec<T>::Push */: LeaderSentence LeaderSkillLinesList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_skill_lines_list_0(
+        &mut self,
+        _leader_sentence: &ParseTreeStackEntry<'t>,
+        _leader_skill_lines_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut leader_skill_lines_list =
+            pop_item!(self, leader_skill_lines_list, LeaderSkillLinesList, context);
+        let leader_sentence = pop_item!(self, leader_sentence, LeaderSentence, context);
+        let leader_skill_lines_list_0_built = LeaderSkillLinesListBuilder::default()
+            .leader_sentence(Box::new(leader_sentence))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        leader_skill_lines_list.push(leader_skill_lines_list_0_built);
+        self.push(
+            ASTType::LeaderSkillLinesList(leader_skill_lines_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// LeaderSkillLinesList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_skill_lines_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let leader_skill_lines_list_1_built = Vec::new();
+        self.push(
+            ASTType::LeaderSkillLinesList(leader_skill_lines_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// LeaderSkillLinesOpt /* Option<T>::Some */: BoardSizePrefix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_skill_lines_opt_0(
+        &mut self,
+        _board_size_prefix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let board_size_prefix = pop_item!(self, board_size_prefix, BoardSizePrefix, context);
+        let leader_skill_lines_opt_0_built = LeaderSkillLinesOptBuilder::default()
+            .board_size_prefix(Box::new(board_size_prefix))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::LeaderSkillLinesOpt(Some(Box::new(leader_skill_lines_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// LeaderSkillLinesOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_skill_lines_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::LeaderSkillLinesOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// BoardSizePrefix: LBracket Size WordMass RBracket;
+    ///
+    #[parol_runtime::function_name::named]
+    fn board_size_prefix(
+        &mut self,
+        _l_bracket: &ParseTreeStackEntry<'t>,
+        _size: &ParseTreeStackEntry<'t>,
+        _word_mass: &ParseTreeStackEntry<'t>,
+        _r_bracket: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_bracket = pop_item!(self, r_bracket, RBracket, context);
+        let word_mass = pop_item!(self, word_mass, WordMass, context);
+        let size = pop_item!(self, size, Size, context);
+        let l_bracket = pop_item!(self, l_bracket, LBracket, context);
+        let board_size_prefix_built = BoardSizePrefixBuilder::default()
+            .l_bracket(Box::new(l_bracket))
+            .size(Box::new(size))
+            .word_mass(Box::new(word_mass))
+            .r_bracket(Box::new(r_bracket))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .board_size_prefix(&board_size_prefix_built)?;
+        self.push(ASTType::BoardSizePrefix(board_size_prefix_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// LeaderSentence: LeaderClause LeaderSentenceList /* Vec */ Period;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_sentence(
+        &mut self,
+        _leader_clause: &ParseTreeStackEntry<'t>,
+        _leader_sentence_list: &ParseTreeStackEntry<'t>,
+        _period: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = pop_item!(self, period, Period, context);
+        let leader_sentence_list =
+            pop_and_reverse_item!(self, leader_sentence_list, LeaderSentenceList, context);
+        let leader_clause = pop_item!(self, leader_clause, LeaderClause, context);
+        let leader_sentence_built = LeaderSentenceBuilder::default()
+            .leader_clause(Box::new(leader_clause))
+            .leader_sentence_list(leader_sentence_list)
+            .period(Box::new(period))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.leader_sentence(&leader_sentence_built)?;
+        self.push(ASTType::LeaderSentence(leader_sentence_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// LeaderSentenceList /* Vec<T>::Push */: Camma LeaderClause LeaderSentenceList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_sentence_list_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _leader_clause: &ParseTreeStackEntry<'t>,
+        _leader_sentence_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut leader_sentence_list =
+            pop_item!(self, leader_sentence_list, LeaderSentenceList, context);
+        let leader_clause = pop_item!(self, leader_clause, LeaderClause, context);
+        let camma = pop_item!(self, camma, Camma, context);
+        let leader_sentence_list_0_built = LeaderSentenceListBuilder::default()
+            .leader_clause(Box::new(leader_clause))
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        leader_sentence_list.push(leader_sentence_list_0_built);
+        self.push(ASTType::LeaderSentenceList(leader_sentence_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// LeaderSentenceList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_sentence_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let leader_sentence_list_1_built = Vec::new();
+        self.push(
+            ASTType::LeaderSentenceList(leader_sentence_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// LeaderClause: NameList NameListClause;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_clause_0(
+        &mut self,
+        _name_list: &ParseTreeStackEntry<'t>,
+        _name_list_clause: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name_list_clause = pop_item!(self, name_list_clause, NameListClause, context);
+        let name_list = pop_item!(self, name_list, NameList, context);
+        let leader_clause_0_built = LeaderClauseNameListNameListClauseBuilder::default()
+            .name_list(Box::new(name_list))
+            .name_list_clause(Box::new(name_list_clause))
+            .build()
+            .into_diagnostic()?;
+        let leader_clause_0_built = LeaderClause::NameListNameListClause(leader_clause_0_built);
+        // Calling user action here
+        self.user_grammar.leader_clause(&leader_clause_0_built)?;
+        self.push(ASTType::LeaderClause(leader_clause_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 10:
+    ///
+    /// LeaderClause: CountCondition De LeaderBody;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_clause_1(
+        &mut self,
+        _count_condition: &ParseTreeStackEntry<'t>,
+        _de: &ParseTreeStackEntry<'t>,
+        _leader_body: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let leader_body = pop_item!(self, leader_body, LeaderBody, context);
+        let de = pop_item!(self, de, De, context);
+        let count_condition = pop_item!(self, count_condition, CountCondition, context);
+        let leader_clause_1_built = LeaderClauseCountConditionDeLeaderBodyBuilder::default()
+            .count_condition(Box::new(count_condition))
+            .de(Box::new(de))
+            .leader_body(Box::new(leader_body))
+            .build()
+            .into_diagnostic()?;
+        let leader_clause_1_built = LeaderClause::CountConditionDeLeaderBody(leader_clause_1_built);
+        // Calling user action here
+        self.user_grammar.leader_clause(&leader_clause_1_built)?;
+        self.push(ASTType::LeaderClause(leader_clause_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
+    /// LeaderClause: PlainBody;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_clause_2(
+        &mut self,
+        _plain_body: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let plain_body = pop_item!(self, plain_body, PlainBody, context);
+        let leader_clause_2_built = LeaderClausePlainBodyBuilder::default()
+            .plain_body(Box::new(plain_body))
+            .build()
+            .into_diagnostic()?;
+        let leader_clause_2_built = LeaderClause::PlainBody(leader_clause_2_built);
+        // Calling user action here
+        self.user_grammar.leader_clause(&leader_clause_2_built)?;
+        self.push(ASTType::LeaderClause(leader_clause_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
+    /// NameListClause: NameSuffix No StatsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_clause_0(
+        &mut self,
+        _name_suffix: &ParseTreeStackEntry<'t>,
+        _no: &ParseTreeStackEntry<'t>,
+        _stats_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let stats_stmt = pop_item!(self, stats_stmt, StatsStmt, context);
+        let no = pop_item!(self, no, No, context);
+        let name_suffix = pop_item!(self, name_suffix, NameSuffix, context);
+        let name_list_clause_0_built = NameListClauseNameSuffixNoStatsStmtBuilder::default()
+            .name_suffix(Box::new(name_suffix))
+            .no(Box::new(no))
+            .stats_stmt(Box::new(stats_stmt))
+            .build()
+            .into_diagnostic()?;
+        let name_list_clause_0_built =
+            NameListClause::NameSuffixNoStatsStmt(name_list_clause_0_built);
+        // Calling user action here
+        self.user_grammar
+            .name_list_clause(&name_list_clause_0_built)?;
+        self.push(ASTType::NameListClause(name_list_clause_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// NameListClause: No WordSimultaneous De LeaderBody;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_clause_1(
+        &mut self,
+        _no: &ParseTreeStackEntry<'t>,
+        _word_simultaneous: &ParseTreeStackEntry<'t>,
+        _de: &ParseTreeStackEntry<'t>,
+        _leader_body: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let leader_body = pop_item!(self, leader_body, LeaderBody, context);
+        let de = pop_item!(self, de, De, context);
+        let word_simultaneous = pop_item!(self, word_simultaneous, WordSimultaneous, context);
+        let no = pop_item!(self, no, No, context);
+        let name_list_clause_1_built =
+            NameListClauseNoWordSimultaneousDeLeaderBodyBuilder::default()
+                .no(Box::new(no))
+                .word_simultaneous(Box::new(word_simultaneous))
+                .de(Box::new(de))
+                .leader_body(Box::new(leader_body))
+                .build()
+                .into_diagnostic()?;
+        let name_list_clause_1_built =
+            NameListClause::NoWordSimultaneousDeLeaderBody(name_list_clause_1_built);
+        // Calling user action here
+        self.user_grammar
+            .name_list_clause(&name_list_clause_1_built)?;
+        self.push(ASTType::NameListClause(name_list_clause_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// NameSuffix: WordAttribute;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_suffix_0(
+        &mut self,
+        _word_attribute: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attribute = pop_item!(self, word_attribute, WordAttribute, context);
+        let name_suffix_0_built = NameSuffixWordAttributeBuilder::default()
+            .word_attribute(Box::new(word_attribute))
+            .build()
+            .into_diagnostic()?;
+        let name_suffix_0_built = NameSuffix::WordAttribute(name_suffix_0_built);
+        // Calling user action here
+        self.user_grammar.name_suffix(&name_suffix_0_built)?;
+        self.push(ASTType::NameSuffix(name_suffix_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// NameSuffix: WordType;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_suffix_1(
+        &mut self,
+        _word_type: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_type = pop_item!(self, word_type, WordType, context);
+        let name_suffix_1_built = NameSuffixWordTypeBuilder::default()
+            .word_type(Box::new(word_type))
+            .build()
+            .into_diagnostic()?;
+        let name_suffix_1_built = NameSuffix::WordType(name_suffix_1_built);
+        // Calling user action here
+        self.user_grammar.name_suffix(&name_suffix_1_built)?;
+        self.push(ASTType::NameSuffix(name_suffix_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 16:
+    ///
+    /// CountCondition: Number CountConditionSuffix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition(
+        &mut self,
+        _number: &ParseTreeStackEntry<'t>,
+        _count_condition_suffix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let count_condition_suffix =
+            pop_item!(self, count_condition_suffix, CountConditionSuffix, context);
+        let number = pop_item!(self, number, Number, context);
+        let count_condition_built = CountConditionBuilder::default()
+            .number(Box::new(number))
+            .count_condition_suffix(Box::new(count_condition_suffix))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.count_condition(&count_condition_built)?;
+        self.push(ASTType::CountCondition(count_condition_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 17:
+    ///
+    /// CountConditionSuffix: WordCombo CountConditionSuffixOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_0(
+        &mut self,
+        _word_combo: &ParseTreeStackEntry<'t>,
+        _count_condition_suffix_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let count_condition_suffix_opt = pop_item!(
+            self,
+            count_condition_suffix_opt,
+            CountConditionSuffixOpt,
+            context
+        );
+        let word_combo = pop_item!(self, word_combo, WordCombo, context);
+        let count_condition_suffix_0_built =
+            CountConditionSuffixWordComboCountConditionSuffixOptBuilder::default()
+                .word_combo(Box::new(word_combo))
+                .count_condition_suffix_opt(count_condition_suffix_opt)
+                .build()
+                .into_diagnostic()?;
+        let count_condition_suffix_0_built =
+            CountConditionSuffix::WordComboCountConditionSuffixOpt(count_condition_suffix_0_built);
+        // Calling user action here
+        self.user_grammar
+            .count_condition_suffix(&count_condition_suffix_0_built)?;
+        self.push(
+            ASTType::CountConditionSuffix(count_condition_suffix_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 18:
+    ///
+    /// CountConditionSuffix: WordColors CountConditionSuffixOpt0 /* Option */ WordSimultaneous;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_1(
+        &mut self,
+        _word_colors: &ParseTreeStackEntry<'t>,
+        _count_condition_suffix_opt0: &ParseTreeStackEntry<'t>,
+        _word_simultaneous: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_simultaneous = pop_item!(self, word_simultaneous, WordSimultaneous, context);
+        let count_condition_suffix_opt0 = pop_item!(
+            self,
+            count_condition_suffix_opt0,
+            CountConditionSuffixOpt0,
+            context
+        );
+        let word_colors = pop_item!(self, word_colors, WordColors, context);
+        let count_condition_suffix_1_built =
+            CountConditionSuffixWordColorsCountConditionSuffixOpt0WordSimultaneousBuilder::default(
+            )
+            .word_colors(Box::new(word_colors))
+            .count_condition_suffix_opt0(count_condition_suffix_opt0)
+            .word_simultaneous(Box::new(word_simultaneous))
+            .build()
+            .into_diagnostic()?;
+        let count_condition_suffix_1_built =
+            CountConditionSuffix::WordColorsCountConditionSuffixOpt0WordSimultaneous(
+                count_condition_suffix_1_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .count_condition_suffix(&count_condition_suffix_1_built)?;
+        self.push(
+            ASTType::CountConditionSuffix(count_condition_suffix_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 19:
+    ///
+    /// CountConditionSuffixOpt0 /* Option<T>::Some */: WordOrMore;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_opt0_0(
+        &mut self,
+        _word_or_more: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_or_more = pop_item!(self, word_or_more, WordOrMore, context);
+        let count_condition_suffix_opt0_0_built = CountConditionSuffixOpt0Builder::default()
+            .word_or_more(Box::new(word_or_more))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::CountConditionSuffixOpt0(Some(Box::new(count_condition_suffix_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// CountConditionSuffixOpt0 /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_opt0_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::CountConditionSuffixOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 21:
+    ///
+    /// CountConditionSuffixOpt /* Option<T>::Some */: WordOrMore;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_opt_0(
+        &mut self,
+        _word_or_more: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_or_more = pop_item!(self, word_or_more, WordOrMore, context);
+        let count_condition_suffix_opt_0_built = CountConditionSuffixOptBuilder::default()
+            .word_or_more(Box::new(word_or_more))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::CountConditionSuffixOpt(Some(Box::new(count_condition_suffix_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// CountConditionSuffixOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn count_condition_suffix_opt_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::CountConditionSuffixOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
+    ///
+    /// LeaderBody: NameList NameSuffix No StatsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_body_0(
+        &mut self,
+        _name_list: &ParseTreeStackEntry<'t>,
+        _name_suffix: &ParseTreeStackEntry<'t>,
+        _no: &ParseTreeStackEntry<'t>,
+        _stats_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let stats_stmt = pop_item!(self, stats_stmt, StatsStmt, context);
+        let no = pop_item!(self, no, No, context);
+        let name_suffix = pop_item!(self, name_suffix, NameSuffix, context);
+        let name_list = pop_item!(self, name_list, NameList, context);
+        let leader_body_0_built = LeaderBodyNameListNameSuffixNoStatsStmtBuilder::default()
+            .name_list(Box::new(name_list))
+            .name_suffix(Box::new(name_suffix))
+            .no(Box::new(no))
+            .stats_stmt(Box::new(stats_stmt))
+            .build()
+            .into_diagnostic()?;
+        let leader_body_0_built = LeaderBody::NameListNameSuffixNoStatsStmt(leader_body_0_built);
+        // Calling user action here
+        self.user_grammar.leader_body(&leader_body_0_built)?;
+        self.push(ASTType::LeaderBody(leader_body_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// LeaderBody: PlainBody;
+    ///
+    #[parol_runtime::function_name::named]
+    fn leader_body_1(
+        &mut self,
+        _plain_body: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let plain_body = pop_item!(self, plain_body, PlainBody, context);
+        let leader_body_1_built = LeaderBodyPlainBodyBuilder::default()
+            .plain_body(Box::new(plain_body))
+            .build()
+            .into_diagnostic()?;
+        let leader_body_1_built = LeaderBody::PlainBody(leader_body_1_built);
+        // Calling user action here
+        self.user_grammar.leader_body(&leader_body_1_built)?;
+        self.push(ASTType::LeaderBody(leader_body_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
+    ///
+    /// PlainBody: StatsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn plain_body_0(
+        &mut self,
+        _stats_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let stats_stmt = pop_item!(self, stats_stmt, StatsStmt, context);
+        let plain_body_0_built = PlainBodyStatsStmtBuilder::default()
+            .stats_stmt(Box::new(stats_stmt))
+            .build()
+            .into_diagnostic()?;
+        let plain_body_0_built = PlainBody::StatsStmt(plain_body_0_built);
+        // Calling user action here
+        self.user_grammar.plain_body(&plain_body_0_built)?;
+        self.push(ASTType::PlainBody(plain_body_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 26:
+    ///
+    /// PlainBody: DamageReductionStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn plain_body_1(
+        &mut self,
+        _damage_reduction_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let damage_reduction_stmt =
+            pop_item!(self, damage_reduction_stmt, DamageReductionStmt, context);
+        let plain_body_1_built = PlainBodyDamageReductionStmtBuilder::default()
+            .damage_reduction_stmt(Box::new(damage_reduction_stmt))
+            .build()
+            .into_diagnostic()?;
+        let plain_body_1_built = PlainBody::DamageReductionStmt(plain_body_1_built);
+        // Calling user action here
+        self.user_grammar.plain_body(&plain_body_1_built)?;
+        self.push(ASTType::PlainBody(plain_body_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 27:
+    ///
+    /// PlainBody: TimeExtensionStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn plain_body_2(
+        &mut self,
+        _time_extension_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let time_extension_stmt = pop_item!(self, time_extension_stmt, TimeExtensionStmt, context);
+        let plain_body_2_built = PlainBodyTimeExtensionStmtBuilder::default()
+            .time_extension_stmt(Box::new(time_extension_stmt))
+            .build()
+            .into_diagnostic()?;
+        let plain_body_2_built = PlainBody::TimeExtensionStmt(plain_body_2_built);
+        // Calling user action here
+        self.user_grammar.plain_body(&plain_body_2_built)?;
+        self.push(ASTType::PlainBody(plain_body_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 28:
+    ///
+    /// StatsStmt: Stats StatsParticle Number WordTimes;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_stmt(
+        &mut self,
+        _stats: &ParseTreeStackEntry<'t>,
+        _stats_particle: &ParseTreeStackEntry<'t>,
+        _number: &ParseTreeStackEntry<'t>,
+        _word_times: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_times = pop_item!(self, word_times, WordTimes, context);
+        let number = pop_item!(self, number, Number, context);
+        let stats_particle = pop_item!(self, stats_particle, StatsParticle, context);
+        let stats = pop_item!(self, stats, Stats, context);
+        let stats_stmt_built = StatsStmtBuilder::default()
+            .stats(Box::new(stats))
+            .stats_particle(Box::new(stats_particle))
+            .number(Box::new(number))
+            .word_times(Box::new(word_times))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.stats_stmt(&stats_stmt_built)?;
+        self.push(ASTType::StatsStmt(stats_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 29:
+    ///
+    /// Stats: Stat StatsList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_0(
+        &mut self,
+        _stat: &ParseTreeStackEntry<'t>,
+        _stats_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let stats_list = pop_and_reverse_item!(self, stats_list, StatsList, context);
+        let stat = pop_item!(self, stat, Stat, context);
+        let stats_0_built = StatsStatStatsListBuilder::default()
+            .stat(Box::new(stat))
+            .stats_list(stats_list)
+            .build()
+            .into_diagnostic()?;
+        let stats_0_built = Stats::StatStatsList(stats_0_built);
+        // Calling user action here
+        self.user_grammar.stats(&stats_0_built)?;
+        self.push(ASTType::Stats(stats_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 30:
+    ///
+    /// StatsList /* Vec<T>::Push */: And Stat StatsList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_list_0(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _stat: &ParseTreeStackEntry<'t>,
+        _stats_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut stats_list = pop_item!(self, stats_list, StatsList, context);
+        let stat = pop_item!(self, stat, Stat, context);
+        let and = pop_item!(self, and, And, context);
+        let stats_list_0_built = StatsListBuilder::default()
+            .stat(Box::new(stat))
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        stats_list.push(stats_list_0_built);
+        self.push(ASTType::StatsList(stats_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 31:
+    ///
+    /// StatsList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let stats_list_1_built = Vec::new();
+        self.push(ASTType::StatsList(stats_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 32:
+    ///
+    /// Stats: WordAllParameters;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_1(
+        &mut self,
+        _word_all_parameters: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_all_parameters = pop_item!(self, word_all_parameters, WordAllParameters, context);
+        let stats_1_built = StatsWordAllParametersBuilder::default()
+            .word_all_parameters(Box::new(word_all_parameters))
+            .build()
+            .into_diagnostic()?;
+        let stats_1_built = Stats::WordAllParameters(stats_1_built);
+        // Calling user action here
+        self.user_grammar.stats(&stats_1_built)?;
+        self.push(ASTType::Stats(stats_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 33:
+    ///
+    /// Stat: WordHp;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stat_0(
+        &mut self,
+        _word_hp: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_hp = pop_item!(self, word_hp, WordHp, context);
+        let stat_0_built = StatWordHpBuilder::default()
+            .word_hp(Box::new(word_hp))
+            .build()
+            .into_diagnostic()?;
+        let stat_0_built = Stat::WordHp(stat_0_built);
+        // Calling user action here
+        self.user_grammar.stat(&stat_0_built)?;
+        self.push(ASTType::Stat(stat_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 34:
+    ///
+    /// Stat: WordAttack;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stat_1(
+        &mut self,
+        _word_attack: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attack = pop_item!(self, word_attack, WordAttack, context);
+        let stat_1_built = StatWordAttackBuilder::default()
+            .word_attack(Box::new(word_attack))
+            .build()
+            .into_diagnostic()?;
+        let stat_1_built = Stat::WordAttack(stat_1_built);
+        // Calling user action here
+        self.user_grammar.stat(&stat_1_built)?;
+        self.push(ASTType::Stat(stat_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 35:
+    ///
+    /// Stat: WordRecovery;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stat_2(
+        &mut self,
+        _word_recovery: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_recovery = pop_item!(self, word_recovery, WordRecovery, context);
+        let stat_2_built = StatWordRecoveryBuilder::default()
+            .word_recovery(Box::new(word_recovery))
+            .build()
+            .into_diagnostic()?;
+        let stat_2_built = Stat::WordRecovery(stat_2_built);
+        // Calling user action here
+        self.user_grammar.stat(&stat_2_built)?;
+        self.push(ASTType::Stat(stat_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 36:
+    ///
+    /// StatsParticle: Ga;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_particle_0(
+        &mut self,
+        _ga: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ga = pop_item!(self, ga, Ga, context);
+        let stats_particle_0_built = StatsParticleGaBuilder::default()
+            .ga(Box::new(ga))
+            .build()
+            .into_diagnostic()?;
+        let stats_particle_0_built = StatsParticle::Ga(stats_particle_0_built);
+        // Calling user action here
+        self.user_grammar.stats_particle(&stats_particle_0_built)?;
+        self.push(ASTType::StatsParticle(stats_particle_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 37:
+    ///
+    /// StatsParticle: Ha;
+    ///
+    #[parol_runtime::function_name::named]
+    fn stats_particle_1(
+        &mut self,
+        _ha: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ha = pop_item!(self, ha, Ha, context);
+        let stats_particle_1_built = StatsParticleHaBuilder::default()
+            .ha(Box::new(ha))
+            .build()
+            .into_diagnostic()?;
+        let stats_particle_1_built = StatsParticle::Ha(stats_particle_1_built);
+        // Calling user action here
+        self.user_grammar.stats_particle(&stats_particle_1_built)?;
+        self.push(ASTType::StatsParticle(stats_particle_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
+    ///
+    /// DamageReductionStmt: DamageReductionStmtOpt /* Option */ WordDamage Wo Number Percent WordReduce;
+    ///
+    #[parol_runtime::function_name::named]
+    fn damage_reduction_stmt(
+        &mut self,
+        _damage_reduction_stmt_opt: &ParseTreeStackEntry<'t>,
+        _word_damage: &ParseTreeStackEntry<'t>,
+        _wo: &ParseTreeStackEntry<'t>,
+        _number: &ParseTreeStackEntry<'t>,
+        _percent: &ParseTreeStackEntry<'t>,
+        _word_reduce: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_reduce = pop_item!(self, word_reduce, WordReduce, context);
+        let percent = pop_item!(self, percent, Percent, context);
+        let number = pop_item!(self, number, Number, context);
+        let wo = pop_item!(self, wo, Wo, context);
+        let word_damage = pop_item!(self, word_damage, WordDamage, context);
+        let damage_reduction_stmt_opt = pop_item!(
+            self,
+            damage_reduction_stmt_opt,
+            DamageReductionStmtOpt,
+            context
+        );
+        let damage_reduction_stmt_built = DamageReductionStmtBuilder::default()
+            .damage_reduction_stmt_opt(damage_reduction_stmt_opt)
+            .word_damage(Box::new(word_damage))
+            .wo(Box::new(wo))
+            .number(Box::new(number))
+            .percent(Box::new(percent))
+            .word_reduce(Box::new(word_reduce))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .damage_reduction_stmt(&damage_reduction_stmt_built)?;
+        self.push(
+            ASTType::DamageReductionStmt(damage_reduction_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 39:
+    ///
+    /// DamageReductionStmtOpt /* Option<T>::Some */: WordReceive;
+    ///
+    #[parol_runtime::function_name::named]
+    fn damage_reduction_stmt_opt_0(
+        &mut self,
+        _word_receive: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_receive = pop_item!(self, word_receive, WordReceive, context);
+        let damage_reduction_stmt_opt_0_built = DamageReductionStmtOptBuilder::default()
+            .word_receive(Box::new(word_receive))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::DamageReductionStmtOpt(Some(Box::new(damage_reduction_stmt_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
+    ///
+    /// DamageReductionStmtOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn damage_reduction_stmt_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::DamageReductionStmtOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// TimeExtensionStmt: WordMoveTime TimeParticle Number WordSecond WordExtend;
+    ///
+    #[parol_runtime::function_name::named]
+    fn time_extension_stmt(
+        &mut self,
+        _word_move_time: &ParseTreeStackEntry<'t>,
+        _time_particle: &ParseTreeStackEntry<'t>,
+        _number: &ParseTreeStackEntry<'t>,
+        _word_second: &ParseTreeStackEntry<'t>,
+        _word_extend: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_extend = pop_item!(self, word_extend, WordExtend, context);
+        let word_second = pop_item!(self, word_second, WordSecond, context);
+        let number = pop_item!(self, number, Number, context);
+        let time_particle = pop_item!(self, time_particle, TimeParticle, context);
+        let word_move_time = pop_item!(self, word_move_time, WordMoveTime, context);
+        let time_extension_stmt_built = TimeExtensionStmtBuilder::default()
+            .word_move_time(Box::new(word_move_time))
+            .time_particle(Box::new(time_particle))
+            .number(Box::new(number))
+            .word_second(Box::new(word_second))
+            .word_extend(Box::new(word_extend))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .time_extension_stmt(&time_extension_stmt_built)?;
+        self.push(
+            ASTType::TimeExtensionStmt(time_extension_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
+    /// TimeParticle: Wo;
+    ///
+    #[parol_runtime::function_name::named]
+    fn time_particle_0(
+        &mut self,
+        _wo: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wo = pop_item!(self, wo, Wo, context);
+        let time_particle_0_built = TimeParticleWoBuilder::default()
+            .wo(Box::new(wo))
+            .build()
+            .into_diagnostic()?;
+        let time_particle_0_built = TimeParticle::Wo(time_particle_0_built);
+        // Calling user action here
+        self.user_grammar.time_particle(&time_particle_0_built)?;
+        self.push(ASTType::TimeParticle(time_particle_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 43:
+    ///
+    /// TimeParticle: Ga;
+    ///
+    #[parol_runtime::function_name::named]
+    fn time_particle_1(
+        &mut self,
+        _ga: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ga = pop_item!(self, ga, Ga, context);
+        let time_particle_1_built = TimeParticleGaBuilder::default()
+            .ga(Box::new(ga))
+            .build()
+            .into_diagnostic()?;
+        let time_particle_1_built = TimeParticle::Ga(time_particle_1_built);
+        // Calling user action here
+        self.user_grammar.time_particle(&time_particle_1_built)?;
+        self.push(ASTType::TimeParticle(time_particle_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
+    ///
+    /// NameList: Name NameListList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list(
+        &mut self,
+        _name: &ParseTreeStackEntry<'t>,
+        _name_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name_list_list = pop_and_reverse_item!(self, name_list_list, NameListList, context);
+        let name = pop_item!(self, name, Name, context);
+        let name_list_built = NameListBuilder::default()
+            .name(Box::new(name))
+            .name_list_list(name_list_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.name_list(&name_list_built)?;
+        self.push(ASTType::NameList(name_list_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 45:
+    ///
+    /// NameListList /* Vec<T>::Push */: NameListOpt /* Option */ Name NameListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_list_0(
+        &mut self,
+        _name_list_opt: &ParseTreeStackEntry<'t>,
+        _name: &ParseTreeStackEntry<'t>,
+        _name_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut name_list_list = pop_item!(self, name_list_list, NameListList, context);
+        let name = pop_item!(self, name, Name, context);
+        let name_list_opt = pop_item!(self, name_list_opt, NameListOpt, context);
+        let name_list_list_0_built = NameListListBuilder::default()
+            .name(Box::new(name))
+            .name_list_opt(name_list_opt)
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        name_list_list.push(name_list_list_0_built);
+        self.push(ASTType::NameListList(name_list_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 46:
+    ///
+    /// NameListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name_list_list_1_built = Vec::new();
+        self.push(ASTType::NameListList(name_list_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// NameListOpt /* Option<T>::Some */: NameSep;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_opt_0(
+        &mut self,
+        _name_sep: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name_sep = pop_item!(self, name_sep, NameSep, context);
+        let name_list_opt_0_built = NameListOptBuilder::default()
+            .name_sep(Box::new(name_sep))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::NameListOpt(Some(Box::new(name_list_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// NameListOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_list_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::NameListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
+    ///
+    /// NameSep: And;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_sep_0(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let name_sep_0_built = NameSepAndBuilder::default()
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        let name_sep_0_built = NameSep::And(name_sep_0_built);
+        // Calling user action here
+        self.user_grammar.name_sep(&name_sep_0_built)?;
+        self.push(ASTType::NameSep(name_sep_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 50:
+    ///
+    /// NameSep: Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_sep_1(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let name_sep_1_built = NameSepCammaBuilder::default()
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        let name_sep_1_built = NameSep::Camma(name_sep_1_built);
+        // Calling user action here
+        self.user_grammar.name_sep(&name_sep_1_built)?;
+        self.push(ASTType::NameSep(name_sep_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 51:
+    ///
+    /// Name: Color;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_0(
+        &mut self,
+        _color: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let color = pop_item!(self, color, Color, context);
+        let name_0_built = NameColorBuilder::default()
+            .color(Box::new(color))
+            .build()
+            .into_diagnostic()?;
+        let name_0_built = Name::Color(name_0_built);
+        // Calling user action here
+        self.user_grammar.name(&name_0_built)?;
+        self.push(ASTType::Name(name_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 52:
+    ///
+    /// Name: NonColoredDrop;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_1(
+        &mut self,
+        _non_colored_drop: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let non_colored_drop = pop_item!(self, non_colored_drop, NonColoredDrop, context);
+        let name_1_built = NameNonColoredDropBuilder::default()
+            .non_colored_drop(Box::new(non_colored_drop))
+            .build()
+            .into_diagnostic()?;
+        let name_1_built = Name::NonColoredDrop(name_1_built);
+        // Calling user action here
+        self.user_grammar.name(&name_1_built)?;
+        self.push(ASTType::Name(name_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
+    ///
+    /// Name: MonsterType;
+    ///
+    #[parol_runtime::function_name::named]
+    fn name_2(
+        &mut self,
+        _monster_type: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let monster_type = pop_item!(self, monster_type, MonsterType, context);
+        let name_2_built = NameMonsterTypeBuilder::default()
+            .monster_type(Box::new(monster_type))
+            .build()
+            .into_diagnostic()?;
+        let name_2_built = Name::MonsterType(name_2_built);
+        // Calling user action here
+        self.user_grammar.name(&name_2_built)?;
+        self.push(ASTType::Name(name_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 54:
+    ///
+    /// Color: Fire;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_0(
+        &mut self,
+        _fire: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let fire = pop_item!(self, fire, Fire, context);
+        let color_0_built = ColorFireBuilder::default()
+            .fire(Box::new(fire))
+            .build()
+            .into_diagnostic()?;
+        let color_0_built = Color::Fire(color_0_built);
+        // Calling user action here
+        self.user_grammar.color(&color_0_built)?;
+        self.push(ASTType::Color(color_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 55:
+    ///
+    /// Color: Water;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_1(
+        &mut self,
+        _water: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let water = pop_item!(self, water, Water, context);
+        let color_1_built = ColorWaterBuilder::default()
+            .water(Box::new(water))
+            .build()
+            .into_diagnostic()?;
+        let color_1_built = Color::Water(color_1_built);
+        // Calling user action here
+        self.user_grammar.color(&color_1_built)?;
+        self.push(ASTType::Color(color_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
+    ///
+    /// Color: Wood;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_2(
+        &mut self,
+        _wood: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wood = pop_item!(self, wood, Wood, context);
+        let color_2_built = ColorWoodBuilder::default()
+            .wood(Box::new(wood))
+            .build()
+            .into_diagnostic()?;
+        let color_2_built = Color::Wood(color_2_built);
+        // Calling user action here
+        self.user_grammar.color(&color_2_built)?;
+        self.push(ASTType::Color(color_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 57:
+    ///
+    /// Color: Lightning;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_3(
+        &mut self,
+        _lightning: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lightning = pop_item!(self, lightning, Lightning, context);
+        let color_3_built = ColorLightningBuilder::default()
+            .lightning(Box::new(lightning))
+            .build()
+            .into_diagnostic()?;
+        let color_3_built = Color::Lightning(color_3_built);
+        // Calling user action here
+        self.user_grammar.color(&color_3_built)?;
+        self.push(ASTType::Color(color_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 58:
+    ///
+    /// Color: Dark;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_4(
+        &mut self,
+        _dark: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dark = pop_item!(self, dark, Dark, context);
+        let color_4_built = ColorDarkBuilder::default()
+            .dark(Box::new(dark))
+            .build()
+            .into_diagnostic()?;
+        let color_4_built = Color::Dark(color_4_built);
+        // Calling user action here
+        self.user_grammar.color(&color_4_built)?;
+        self.push(ASTType::Color(color_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
+    ///
+    /// NonColoredDrop: Recovery;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_0(
+        &mut self,
+        _recovery: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let recovery = pop_item!(self, recovery, Recovery, context);
+        let non_colored_drop_0_built = NonColoredDropRecoveryBuilder::default()
+            .recovery(Box::new(recovery))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_0_built = NonColoredDrop::Recovery(non_colored_drop_0_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_0_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 60:
+    ///
+    /// NonColoredDrop: Disturb;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_1(
+        &mut self,
+        _disturb: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let disturb = pop_item!(self, disturb, Disturb, context);
+        let non_colored_drop_1_built = NonColoredDropDisturbBuilder::default()
+            .disturb(Box::new(disturb))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_1_built = NonColoredDrop::Disturb(non_colored_drop_1_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_1_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 61:
+    ///
+    /// NonColoredDrop: Bomb;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_2(
+        &mut self,
+        _bomb: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let bomb = pop_item!(self, bomb, Bomb, context);
+        let non_colored_drop_2_built = NonColoredDropBombBuilder::default()
+            .bomb(Box::new(bomb))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_2_built = NonColoredDrop::Bomb(non_colored_drop_2_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_2_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 62:
+    ///
+    /// NonColoredDrop: DeadlyPoison;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_3(
+        &mut self,
+        _deadly_poison: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deadly_poison = pop_item!(self, deadly_poison, DeadlyPoison, context);
+        let non_colored_drop_3_built = NonColoredDropDeadlyPoisonBuilder::default()
+            .deadly_poison(Box::new(deadly_poison))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_3_built = NonColoredDrop::DeadlyPoison(non_colored_drop_3_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_3_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 63:
+    ///
+    /// NonColoredDrop: Poison;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_4(
+        &mut self,
+        _poison: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let poison = pop_item!(self, poison, Poison, context);
+        let non_colored_drop_4_built = NonColoredDropPoisonBuilder::default()
+            .poison(Box::new(poison))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_4_built = NonColoredDrop::Poison(non_colored_drop_4_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_4_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 64:
+    ///
+    /// MonsterType: Dragon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_0(
+        &mut self,
+        _dragon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dragon = pop_item!(self, dragon, Dragon, context);
+        let monster_type_0_built = MonsterTypeDragonBuilder::default()
+            .dragon(Box::new(dragon))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_0_built = MonsterType::Dragon(monster_type_0_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_0_built)?;
+        self.push(ASTType::MonsterType(monster_type_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
+    ///
+    /// MonsterType: God;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_1(
+        &mut self,
+        _god: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let god = pop_item!(self, god, God, context);
+        let monster_type_1_built = MonsterTypeGodBuilder::default()
+            .god(Box::new(god))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_1_built = MonsterType::God(monster_type_1_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_1_built)?;
+        self.push(ASTType::MonsterType(monster_type_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 66:
+    ///
+    /// MonsterType: Devil;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_2(
+        &mut self,
+        _devil: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let devil = pop_item!(self, devil, Devil, context);
+        let monster_type_2_built = MonsterTypeDevilBuilder::default()
+            .devil(Box::new(devil))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_2_built = MonsterType::Devil(monster_type_2_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_2_built)?;
+        self.push(ASTType::MonsterType(monster_type_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 67:
+    ///
+    /// MonsterType: Machine;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_3(
+        &mut self,
+        _machine: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let machine = pop_item!(self, machine, Machine, context);
+        let monster_type_3_built = MonsterTypeMachineBuilder::default()
+            .machine(Box::new(machine))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_3_built = MonsterType::Machine(monster_type_3_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_3_built)?;
+        self.push(ASTType::MonsterType(monster_type_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 68:
+    ///
+    /// MonsterType: Balanced;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_4(
+        &mut self,
+        _balanced: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let balanced = pop_item!(self, balanced, Balanced, context);
+        let monster_type_4_built = MonsterTypeBalancedBuilder::default()
+            .balanced(Box::new(balanced))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_4_built = MonsterType::Balanced(monster_type_4_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_4_built)?;
+        self.push(ASTType::MonsterType(monster_type_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 69:
+    ///
+    /// MonsterType: Attacker;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_5(
+        &mut self,
+        _attacker: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attacker = pop_item!(self, attacker, Attacker, context);
+        let monster_type_5_built = MonsterTypeAttackerBuilder::default()
+            .attacker(Box::new(attacker))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_5_built = MonsterType::Attacker(monster_type_5_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_5_built)?;
+        self.push(ASTType::MonsterType(monster_type_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 70:
+    ///
+    /// MonsterType: Physical;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_6(
+        &mut self,
+        _physical: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let physical = pop_item!(self, physical, Physical, context);
+        let monster_type_6_built = MonsterTypePhysicalBuilder::default()
+            .physical(Box::new(physical))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_6_built = MonsterType::Physical(monster_type_6_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_6_built)?;
+        self.push(ASTType::MonsterType(monster_type_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 71:
+    ///
+    /// MonsterType: Evolve;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_7(
+        &mut self,
+        _evolve: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let evolve = pop_item!(self, evolve, Evolve, context);
+        let monster_type_7_built = MonsterTypeEvolveBuilder::default()
+            .evolve(Box::new(evolve))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_7_built = MonsterType::Evolve(monster_type_7_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_7_built)?;
+        self.push(ASTType::MonsterType(monster_type_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 72:
+    ///
+    /// MonsterType: Awaken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_8(
+        &mut self,
+        _awaken: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let awaken = pop_item!(self, awaken, Awaken, context);
+        let monster_type_8_built = MonsterTypeAwakenBuilder::default()
+            .awaken(Box::new(awaken))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_8_built = MonsterType::Awaken(monster_type_8_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_8_built)?;
+        self.push(ASTType::MonsterType(monster_type_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 73:
+    ///
+    /// MonsterType: Enhance;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_9(
+        &mut self,
+        _enhance: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhance = pop_item!(self, enhance, Enhance, context);
+        let monster_type_9_built = MonsterTypeEnhanceBuilder::default()
+            .enhance(Box::new(enhance))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_9_built = MonsterType::Enhance(monster_type_9_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_9_built)?;
+        self.push(ASTType::MonsterType(monster_type_9_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 74:
+    ///
+    /// MonsterType: Vendor;
+    ///
+    #[parol_runtime::function_name::named]
+    fn monster_type_10(
+        &mut self,
+        _vendor: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let vendor = pop_item!(self, vendor, Vendor, context);
+        let monster_type_10_built = MonsterTypeVendorBuilder::default()
+            .vendor(Box::new(vendor))
+            .build()
+            .into_diagnostic()?;
+        let monster_type_10_built = MonsterType::Vendor(monster_type_10_built);
+        // Calling user action here
+        self.user_grammar.monster_type(&monster_type_10_built)?;
+        self.push(ASTType::MonsterType(monster_type_10_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 75:
+    ///
+    /// Size: Number Multi Number;
+    ///
+    #[parol_runtime::function_name::named]
+    fn size(
+        &mut self,
+        _number: &ParseTreeStackEntry<'t>,
+        _multi: &ParseTreeStackEntry<'t>,
+        _number0: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number0 = pop_item!(self, number0, Number, context);
+        let multi = pop_item!(self, multi, Multi, context);
+        let number = pop_item!(self, number, Number, context);
+        let size_built = SizeBuilder::default()
+            .number(Box::new(number))
+            .multi(Box::new(multi))
+            .number0(Box::new(number0))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.size(&size_built)?;
+        self.push(ASTType::Size(size_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 76:
+    ///
+    /// WordAttribute: '属性';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_attribute(
+        &mut self,
+        word_attribute: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attribute = word_attribute.token(parse_tree)?.clone();
+        let word_attribute_built = WordAttributeBuilder::default()
+            .word_attribute(word_attribute)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_attribute(&word_attribute_built)?;
+        self.push(ASTType::WordAttribute(word_attribute_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 77:
+    ///
+    /// WordType: 'タイプ';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_type(
+        &mut self,
+        word_type: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_type = word_type.token(parse_tree)?.clone();
+        let word_type_built = WordTypeBuilder::default()
+            .word_type(word_type)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_type(&word_type_built)?;
+        self.push(ASTType::WordType(word_type_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 78:
+    ///
+    /// WordSimultaneous: '同時攻撃';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_simultaneous(
+        &mut self,
+        word_simultaneous: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_simultaneous = word_simultaneous.token(parse_tree)?.clone();
+        let word_simultaneous_built = WordSimultaneousBuilder::default()
+            .word_simultaneous(word_simultaneous)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .word_simultaneous(&word_simultaneous_built)?;
+        self.push(ASTType::WordSimultaneous(word_simultaneous_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 79:
+    ///
+    /// WordCombo: 'コンボ';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_combo(
+        &mut self,
+        word_combo: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_combo = word_combo.token(parse_tree)?.clone();
+        let word_combo_built = WordComboBuilder::default()
+            .word_combo(word_combo)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_combo(&word_combo_built)?;
+        self.push(ASTType::WordCombo(word_combo_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 80:
+    ///
+    /// WordColors: '色';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_colors(
+        &mut self,
+        word_colors: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_colors = word_colors.token(parse_tree)?.clone();
+        let word_colors_built = WordColorsBuilder::default()
+            .word_colors(word_colors)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_colors(&word_colors_built)?;
+        self.push(ASTType::WordColors(word_colors_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 81:
+    ///
+    /// WordOrMore: '以上';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_or_more(
+        &mut self,
+        word_or_more: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_or_more = word_or_more.token(parse_tree)?.clone();
+        let word_or_more_built = WordOrMoreBuilder::default()
+            .word_or_more(word_or_more)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_or_more(&word_or_more_built)?;
+        self.push(ASTType::WordOrMore(word_or_more_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 82:
+    ///
+    /// WordHp: 'HP';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_hp(
+        &mut self,
+        word_hp: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_hp = word_hp.token(parse_tree)?.clone();
+        let word_hp_built = WordHpBuilder::default()
+            .word_hp(word_hp)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_hp(&word_hp_built)?;
+        self.push(ASTType::WordHp(word_hp_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 83:
+    ///
+    /// WordAttack: '攻撃力';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_attack(
+        &mut self,
+        word_attack: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attack = word_attack.token(parse_tree)?.clone();
+        let word_attack_built = WordAttackBuilder::default()
+            .word_attack(word_attack)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_attack(&word_attack_built)?;
+        self.push(ASTType::WordAttack(word_attack_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 84:
+    ///
+    /// WordRecovery: '回復力';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_recovery(
+        &mut self,
+        word_recovery: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_recovery = word_recovery.token(parse_tree)?.clone();
+        let word_recovery_built = WordRecoveryBuilder::default()
+            .word_recovery(word_recovery)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_recovery(&word_recovery_built)?;
+        self.push(ASTType::WordRecovery(word_recovery_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 85:
+    ///
+    /// WordAllParameters: '全パラメータ';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_all_parameters(
+        &mut self,
+        word_all_parameters: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_all_parameters = word_all_parameters.token(parse_tree)?.clone();
+        let word_all_parameters_built = WordAllParametersBuilder::default()
+            .word_all_parameters(word_all_parameters)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .word_all_parameters(&word_all_parameters_built)?;
+        self.push(
+            ASTType::WordAllParameters(word_all_parameters_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 86:
+    ///
+    /// WordTimes: '倍';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_times(
+        &mut self,
+        word_times: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_times = word_times.token(parse_tree)?.clone();
+        let word_times_built = WordTimesBuilder::default()
+            .word_times(word_times)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_times(&word_times_built)?;
+        self.push(ASTType::WordTimes(word_times_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 87:
+    ///
+    /// WordReceive: '受ける';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_receive(
+        &mut self,
+        word_receive: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_receive = word_receive.token(parse_tree)?.clone();
+        let word_receive_built = WordReceiveBuilder::default()
+            .word_receive(word_receive)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_receive(&word_receive_built)?;
+        self.push(ASTType::WordReceive(word_receive_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 88:
+    ///
+    /// WordDamage: 'ダメージ';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_damage(
+        &mut self,
+        word_damage: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_damage = word_damage.token(parse_tree)?.clone();
+        let word_damage_built = WordDamageBuilder::default()
+            .word_damage(word_damage)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_damage(&word_damage_built)?;
+        self.push(ASTType::WordDamage(word_damage_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 89:
+    ///
+    /// WordReduce: '軽減';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_reduce(
+        &mut self,
+        word_reduce: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_reduce = word_reduce.token(parse_tree)?.clone();
+        let word_reduce_built = WordReduceBuilder::default()
+            .word_reduce(word_reduce)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_reduce(&word_reduce_built)?;
+        self.push(ASTType::WordReduce(word_reduce_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 90:
+    ///
+    /// WordMoveTime: 'ドロップ操作時間';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_move_time(
+        &mut self,
+        word_move_time: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_move_time = word_move_time.token(parse_tree)?.clone();
+        let word_move_time_built = WordMoveTimeBuilder::default()
+            .word_move_time(word_move_time)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_move_time(&word_move_time_built)?;
+        self.push(ASTType::WordMoveTime(word_move_time_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 91:
+    ///
+    /// WordSecond: '秒';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_second(
+        &mut self,
+        word_second: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_second = word_second.token(parse_tree)?.clone();
+        let word_second_built = WordSecondBuilder::default()
+            .word_second(word_second)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_second(&word_second_built)?;
+        self.push(ASTType::WordSecond(word_second_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 92:
+    ///
+    /// WordExtend: '延長';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_extend(
+        &mut self,
+        word_extend: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_extend = word_extend.token(parse_tree)?.clone();
+        let word_extend_built = WordExtendBuilder::default()
+            .word_extend(word_extend)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_extend(&word_extend_built)?;
+        self.push(ASTType::WordExtend(word_extend_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 93:
+    ///
+    /// WordMass: 'マス';
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_mass(
+        &mut self,
+        word_mass: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_mass = word_mass.token(parse_tree)?.clone();
+        let word_mass_built = WordMassBuilder::default()
+            .word_mass(word_mass)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_mass(&word_mass_built)?;
+        self.push(ASTType::WordMass(word_mass_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 94:
+    ///
+    /// Fire: '火';
+    ///
+    #[parol_runtime::function_name::named]
+    fn fire(
+        &mut self,
+        fire: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let fire = fire.token(parse_tree)?.clone();
+        let fire_built = FireBuilder::default()
+            .fire(fire)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.fire(&fire_built)?;
+        self.push(ASTType::Fire(fire_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 95:
+    ///
+    /// Water: '水';
+    ///
+    #[parol_runtime::function_name::named]
+    fn water(
+        &mut self,
+        water: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let water = water.token(parse_tree)?.clone();
+        let water_built = WaterBuilder::default()
+            .water(water)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.water(&water_built)?;
+        self.push(ASTType::Water(water_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 96:
+    ///
+    /// Wood: '木';
+    ///
+    #[parol_runtime::function_name::named]
+    fn wood(
+        &mut self,
+        wood: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wood = wood.token(parse_tree)?.clone();
+        let wood_built = WoodBuilder::default()
+            .wood(wood)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.wood(&wood_built)?;
+        self.push(ASTType::Wood(wood_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 97:
+    ///
+    /// Lightning: '光';
+    ///
+    #[parol_runtime::function_name::named]
+    fn lightning(
+        &mut self,
+        lightning: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lightning = lightning.token(parse_tree)?.clone();
+        let lightning_built = LightningBuilder::default()
+            .lightning(lightning)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.lightning(&lightning_built)?;
+        self.push(ASTType::Lightning(lightning_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 98:
+    ///
+    /// Dark: '闇';
+    ///
+    #[parol_runtime::function_name::named]
+    fn dark(
+        &mut self,
+        dark: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dark = dark.token(parse_tree)?.clone();
+        let dark_built = DarkBuilder::default()
+            .dark(dark)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.dark(&dark_built)?;
+        self.push(ASTType::Dark(dark_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 99:
+    ///
+    /// Recovery: '回復';
+    ///
+    #[parol_runtime::function_name::named]
+    fn recovery(
+        &mut self,
+        recovery: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let recovery = recovery.token(parse_tree)?.clone();
+        let recovery_built = RecoveryBuilder::default()
+            .recovery(recovery)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.recovery(&recovery_built)?;
+        self.push(ASTType::Recovery(recovery_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 100:
+    ///
+    /// Disturb: 'お邪魔';
+    ///
+    #[parol_runtime::function_name::named]
+    fn disturb(
+        &mut self,
+        disturb: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let disturb = disturb.token(parse_tree)?.clone();
+        let disturb_built = DisturbBuilder::default()
+            .disturb(disturb)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.disturb(&disturb_built)?;
+        self.push(ASTType::Disturb(disturb_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 101:
+    ///
+    /// Bomb: '爆弾';
+    ///
+    #[parol_runtime::function_name::named]
+    fn bomb(
+        &mut self,
+        bomb: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let bomb = bomb.token(parse_tree)?.clone();
+        let bomb_built = BombBuilder::default()
+            .bomb(bomb)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.bomb(&bomb_built)?;
+        self.push(ASTType::Bomb(bomb_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 102:
+    ///
+    /// DeadlyPoison: '猛毒';
+    ///
+    #[parol_runtime::function_name::named]
+    fn deadly_poison(
+        &mut self,
+        deadly_poison: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deadly_poison = deadly_poison.token(parse_tree)?.clone();
+        let deadly_poison_built = DeadlyPoisonBuilder::default()
+            .deadly_poison(deadly_poison)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.deadly_poison(&deadly_poison_built)?;
+        self.push(ASTType::DeadlyPoison(deadly_poison_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 103:
+    ///
+    /// Poison: '毒';
+    ///
+    #[parol_runtime::function_name::named]
+    fn poison(
+        &mut self,
+        poison: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let poison = poison.token(parse_tree)?.clone();
+        let poison_built = PoisonBuilder::default()
+            .poison(poison)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.poison(&poison_built)?;
+        self.push(ASTType::Poison(poison_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 104:
+    ///
+    /// Dragon: 'ドラゴン';
+    ///
+    #[parol_runtime::function_name::named]
+    fn dragon(
+        &mut self,
+        dragon: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dragon = dragon.token(parse_tree)?.clone();
+        let dragon_built = DragonBuilder::default()
+            .dragon(dragon)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.dragon(&dragon_built)?;
+        self.push(ASTType::Dragon(dragon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 105:
+    ///
+    /// God: '神';
+    ///
+    #[parol_runtime::function_name::named]
+    fn god(
+        &mut self,
+        god: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let god = god.token(parse_tree)?.clone();
+        let god_built = GodBuilder::default().god(god).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.god(&god_built)?;
+        self.push(ASTType::God(god_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 106:
+    ///
+    /// Devil: '悪魔';
+    ///
+    #[parol_runtime::function_name::named]
+    fn devil(
+        &mut self,
+        devil: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let devil = devil.token(parse_tree)?.clone();
+        let devil_built = DevilBuilder::default()
+            .devil(devil)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.devil(&devil_built)?;
+        self.push(ASTType::Devil(devil_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
+    ///
+    /// Machine: 'マシン';
+    ///
+    #[parol_runtime::function_name::named]
+    fn machine(
+        &mut self,
+        machine: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let machine = machine.token(parse_tree)?.clone();
+        let machine_built = MachineBuilder::default()
+            .machine(machine)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.machine(&machine_built)?;
+        self.push(ASTType::Machine(machine_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 108:
+    ///
+    /// Balanced: 'バランス';
+    ///
+    #[parol_runtime::function_name::named]
+    fn balanced(
+        &mut self,
+        balanced: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let balanced = balanced.token(parse_tree)?.clone();
+        let balanced_built = BalancedBuilder::default()
+            .balanced(balanced)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.balanced(&balanced_built)?;
+        self.push(ASTType::Balanced(balanced_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 109:
+    ///
+    /// Attacker: '攻撃';
+    ///
+    #[parol_runtime::function_name::named]
+    fn attacker(
+        &mut self,
+        attacker: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attacker = attacker.token(parse_tree)?.clone();
+        let attacker_built = AttackerBuilder::default()
+            .attacker(attacker)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.attacker(&attacker_built)?;
+        self.push(ASTType::Attacker(attacker_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 110:
+    ///
+    /// Physical: '体力';
+    ///
+    #[parol_runtime::function_name::named]
+    fn physical(
+        &mut self,
+        physical: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let physical = physical.token(parse_tree)?.clone();
+        let physical_built = PhysicalBuilder::default()
+            .physical(physical)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.physical(&physical_built)?;
+        self.push(ASTType::Physical(physical_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 111:
+    ///
+    /// Evolve: '進化用';
+    ///
+    #[parol_runtime::function_name::named]
+    fn evolve(
+        &mut self,
+        evolve: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let evolve = evolve.token(parse_tree)?.clone();
+        let evolve_built = EvolveBuilder::default()
+            .evolve(evolve)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.evolve(&evolve_built)?;
+        self.push(ASTType::Evolve(evolve_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 112:
+    ///
+    /// Awaken: '能力覚醒用';
+    ///
+    #[parol_runtime::function_name::named]
+    fn awaken(
+        &mut self,
+        awaken: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let awaken = awaken.token(parse_tree)?.clone();
+        let awaken_built = AwakenBuilder::default()
+            .awaken(awaken)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.awaken(&awaken_built)?;
+        self.push(ASTType::Awaken(awaken_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 113:
+    ///
+    /// Enhance: '強化合成用';
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhance(
+        &mut self,
+        enhance: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhance = enhance.token(parse_tree)?.clone();
+        let enhance_built = EnhanceBuilder::default()
+            .enhance(enhance)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.enhance(&enhance_built)?;
+        self.push(ASTType::Enhance(enhance_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 114:
+    ///
+    /// Vendor: '売却用';
+    ///
+    #[parol_runtime::function_name::named]
+    fn vendor(
+        &mut self,
+        vendor: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let vendor = vendor.token(parse_tree)?.clone();
+        let vendor_built = VendorBuilder::default()
+            .vendor(vendor)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.vendor(&vendor_built)?;
+        self.push(ASTType::Vendor(vendor_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 115:
+    ///
+    /// Wo: 'を';
+    ///
+    #[parol_runtime::function_name::named]
+    fn wo(
+        &mut self,
+        wo: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wo = wo.token(parse_tree)?.clone();
+        let wo_built = WoBuilder::default().wo(wo).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.wo(&wo_built)?;
+        self.push(ASTType::Wo(wo_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 116:
+    ///
+    /// No: 'の';
+    ///
+    #[parol_runtime::function_name::named]
+    fn no(
+        &mut self,
+        no: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let no = no.token(parse_tree)?.clone();
+        let no_built = NoBuilder::default().no(no).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.no(&no_built)?;
+        self.push(ASTType::No(no_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 117:
+    ///
+    /// De: 'で';
+    ///
+    #[parol_runtime::function_name::named]
+    fn de(
+        &mut self,
+        de: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let de = de.token(parse_tree)?.clone();
+        let de_built = DeBuilder::default().de(de).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.de(&de_built)?;
+        self.push(ASTType::De(de_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 118:
+    ///
+    /// And: 'と';
+    ///
+    #[parol_runtime::function_name::named]
+    fn and(
+        &mut self,
+        and: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = and.token(parse_tree)?.clone();
+        let and_built = AndBuilder::default().and(and).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.and(&and_built)?;
+        self.push(ASTType::And(and_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 119:
+    ///
+    /// Ga: 'が';
+    ///
+    #[parol_runtime::function_name::named]
+    fn ga(
+        &mut self,
+        ga: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ga = ga.token(parse_tree)?.clone();
+        let ga_built = GaBuilder::default().ga(ga).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.ga(&ga_built)?;
+        self.push(ASTType::Ga(ga_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 120:
+    ///
+    /// Ha: 'は';
+    ///
+    #[parol_runtime::function_name::named]
+    fn ha(
+        &mut self,
+        ha: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ha = ha.token(parse_tree)?.clone();
+        let ha_built = HaBuilder::default().ha(ha).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.ha(&ha_built)?;
+        self.push(ASTType::Ha(ha_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 121:
+    ///
+    /// LBracket: '【';
+    ///
+    #[parol_runtime::function_name::named]
+    fn l_bracket(
+        &mut self,
+        l_bracket: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_bracket = l_bracket.token(parse_tree)?.clone();
+        let l_bracket_built = LBracketBuilder::default()
+            .l_bracket(l_bracket)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.l_bracket(&l_bracket_built)?;
+        self.push(ASTType::LBracket(l_bracket_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 122:
+    ///
+    /// RBracket: '】';
+    ///
+    #[parol_runtime::function_name::named]
+    fn r_bracket(
+        &mut self,
+        r_bracket: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_bracket = r_bracket.token(parse_tree)?.clone();
+        let r_bracket_built = RBracketBuilder::default()
+            .r_bracket(r_bracket)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.r_bracket(&r_bracket_built)?;
+        self.push(ASTType::RBracket(r_bracket_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 123:
+    ///
+    /// Camma: '、';
+    ///
+    #[parol_runtime::function_name::named]
+    fn camma(
+        &mut self,
+        camma: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = camma.token(parse_tree)?.clone();
+        let camma_built = CammaBuilder::default()
+            .camma(camma)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.camma(&camma_built)?;
+        self.push(ASTType::Camma(camma_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 124:
+    ///
+    /// Period: '。';
+    ///
+    #[parol_runtime::function_name::named]
+    fn period(
+        &mut self,
+        period: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = period.token(parse_tree)?.clone();
+        let period_built = PeriodBuilder::default()
+            .period(period)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.period(&period_built)?;
+        self.push(ASTType::Period(period_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 125:
+    ///
+    /// Multi: '×';
+    ///
+    #[parol_runtime::function_name::named]
+    fn multi(
+        &mut self,
+        multi: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let multi = multi.token(parse_tree)?.clone();
+        let multi_built = MultiBuilder::default()
+            .multi(multi)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.multi(&multi_built)?;
+        self.push(ASTType::Multi(multi_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 126:
+    ///
+    /// Percent: '%';
+    ///
+    #[parol_runtime::function_name::named]
+    fn percent(
+        &mut self,
+        percent: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent = percent.token(parse_tree)?.clone();
+        let percent_built = PercentBuilder::default()
+            .percent(percent)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.percent(&percent_built)?;
+        self.push(ASTType::Percent(percent_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 127:
+    ///
+    /// Number: "[0-9]+(\.[0-9]+)?";
+    ///
+    #[parol_runtime::function_name::named]
+    fn number(
+        &mut self,
+        number: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = number.token(parse_tree)?.clone();
+        let number_built = NumberBuilder::default()
+            .number(number)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.number(&number_built)?;
+        self.push(ASTType::Number(number_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for LeaderSkillGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item LeaderSkillGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeStackEntry<'t>],
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.leader_skill_lines(&children[0], &children[1], &children[2], parse_tree),
+            1 => self.leader_skill_lines_list_0(&children[0], &children[1], parse_tree),
+            2 => self.leader_skill_lines_list_1(parse_tree),
+            3 => self.leader_skill_lines_opt_0(&children[0], parse_tree),
+            4 => self.leader_skill_lines_opt_1(parse_tree),
+            5 => self.board_size_prefix(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            6 => self.leader_sentence(&children[0], &children[1], &children[2], parse_tree),
+            7 => self.leader_sentence_list_0(&children[0], &children[1], &children[2], parse_tree),
+            8 => self.leader_sentence_list_1(parse_tree),
+            9 => self.leader_clause_0(&children[0], &children[1], parse_tree),
+            10 => self.leader_clause_1(&children[0], &children[1], &children[2], parse_tree),
+            11 => self.leader_clause_2(&children[0], parse_tree),
+            12 => self.name_list_clause_0(&children[0], &children[1], &children[2], parse_tree),
+            13 => self.name_list_clause_1(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            14 => self.name_suffix_0(&children[0], parse_tree),
+            15 => self.name_suffix_1(&children[0], parse_tree),
+            16 => self.count_condition(&children[0], &children[1], parse_tree),
+            17 => self.count_condition_suffix_0(&children[0], &children[1], parse_tree),
+            18 => {
+                self.count_condition_suffix_1(&children[0], &children[1], &children[2], parse_tree)
+            }
+            19 => self.count_condition_suffix_opt0_0(&children[0], parse_tree),
+            20 => self.count_condition_suffix_opt0_1(parse_tree),
+            21 => self.count_condition_suffix_opt_0(&children[0], parse_tree),
+            22 => self.count_condition_suffix_opt_1(parse_tree),
+            23 => self.leader_body_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            24 => self.leader_body_1(&children[0], parse_tree),
+            25 => self.plain_body_0(&children[0], parse_tree),
+            26 => self.plain_body_1(&children[0], parse_tree),
+            27 => self.plain_body_2(&children[0], parse_tree),
+            28 => self.stats_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            29 => self.stats_0(&children[0], &children[1], parse_tree),
+            30 => self.stats_list_0(&children[0], &children[1], &children[2], parse_tree),
+            31 => self.stats_list_1(parse_tree),
+            32 => self.stats_1(&children[0], parse_tree),
+            33 => self.stat_0(&children[0], parse_tree),
+            34 => self.stat_1(&children[0], parse_tree),
+            35 => self.stat_2(&children[0], parse_tree),
+            36 => self.stats_particle_0(&children[0], parse_tree),
+            37 => self.stats_particle_1(&children[0], parse_tree),
+            38 => self.damage_reduction_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                parse_tree,
+            ),
+            39 => self.damage_reduction_stmt_opt_0(&children[0], parse_tree),
+            40 => self.damage_reduction_stmt_opt_1(parse_tree),
+            41 => self.time_extension_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            42 => self.time_particle_0(&children[0], parse_tree),
+            43 => self.time_particle_1(&children[0], parse_tree),
+            44 => self.name_list(&children[0], &children[1], parse_tree),
+            45 => self.name_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            46 => self.name_list_list_1(parse_tree),
+            47 => self.name_list_opt_0(&children[0], parse_tree),
+            48 => self.name_list_opt_1(parse_tree),
+            49 => self.name_sep_0(&children[0], parse_tree),
+            50 => self.name_sep_1(&children[0], parse_tree),
+            51 => self.name_0(&children[0], parse_tree),
+            52 => self.name_1(&children[0], parse_tree),
+            53 => self.name_2(&children[0], parse_tree),
+            54 => self.color_0(&children[0], parse_tree),
+            55 => self.color_1(&children[0], parse_tree),
+            56 => self.color_2(&children[0], parse_tree),
+            57 => self.color_3(&children[0], parse_tree),
+            58 => self.color_4(&children[0], parse_tree),
+            59 => self.non_colored_drop_0(&children[0], parse_tree),
+            60 => self.non_colored_drop_1(&children[0], parse_tree),
+            61 => self.non_colored_drop_2(&children[0], parse_tree),
+            62 => self.non_colored_drop_3(&children[0], parse_tree),
+            63 => self.non_colored_drop_4(&children[0], parse_tree),
+            64 => self.monster_type_0(&children[0], parse_tree),
+            65 => self.monster_type_1(&children[0], parse_tree),
+            66 => self.monster_type_2(&children[0], parse_tree),
+            67 => self.monster_type_3(&children[0], parse_tree),
+            68 => self.monster_type_4(&children[0], parse_tree),
+            69 => self.monster_type_5(&children[0], parse_tree),
+            70 => self.monster_type_6(&children[0], parse_tree),
+            71 => self.monster_type_7(&children[0], parse_tree),
+            72 => self.monster_type_8(&children[0], parse_tree),
+            73 => self.monster_type_9(&children[0], parse_tree),
+            74 => self.monster_type_10(&children[0], parse_tree),
+            75 => self.size(&children[0], &children[1], &children[2], parse_tree),
+            76 => self.word_attribute(&children[0], parse_tree),
+            77 => self.word_type(&children[0], parse_tree),
+            78 => self.word_simultaneous(&children[0], parse_tree),
+            79 => self.word_combo(&children[0], parse_tree),
+            80 => self.word_colors(&children[0], parse_tree),
+            81 => self.word_or_more(&children[0], parse_tree),
+            82 => self.word_hp(&children[0], parse_tree),
+            83 => self.word_attack(&children[0], parse_tree),
+            84 => self.word_recovery(&children[0], parse_tree),
+            85 => self.word_all_parameters(&children[0], parse_tree),
+            86 => self.word_times(&children[0], parse_tree),
+            87 => self.word_receive(&children[0], parse_tree),
+            88 => self.word_damage(&children[0], parse_tree),
+            89 => self.word_reduce(&children[0], parse_tree),
+            90 => self.word_move_time(&children[0], parse_tree),
+            91 => self.word_second(&children[0], parse_tree),
+            92 => self.word_extend(&children[0], parse_tree),
+            93 => self.word_mass(&children[0], parse_tree),
+            94 => self.fire(&children[0], parse_tree),
+            95 => self.water(&children[0], parse_tree),
+            96 => self.wood(&children[0], parse_tree),
+            97 => self.lightning(&children[0], parse_tree),
+            98 => self.dark(&children[0], parse_tree),
+            99 => self.recovery(&children[0], parse_tree),
+            100 => self.disturb(&children[0], parse_tree),
+            101 => self.bomb(&children[0], parse_tree),
+            102 => self.deadly_poison(&children[0], parse_tree),
+            103 => self.poison(&children[0], parse_tree),
+            104 => self.dragon(&children[0], parse_tree),
+            105 => self.god(&children[0], parse_tree),
+            106 => self.devil(&children[0], parse_tree),
+            107 => self.machine(&children[0], parse_tree),
+            108 => self.balanced(&children[0], parse_tree),
+            109 => self.attacker(&children[0], parse_tree),
+            110 => self.physical(&children[0], parse_tree),
+            111 => self.evolve(&children[0], parse_tree),
+            112 => self.awaken(&children[0], parse_tree),
+            113 => self.enhance(&children[0], parse_tree),
+            114 => self.vendor(&children[0], parse_tree),
+            115 => self.wo(&children[0], parse_tree),
+            116 => self.no(&children[0], parse_tree),
+            117 => self.de(&children[0], parse_tree),
+            118 => self.and(&children[0], parse_tree),
+            119 => self.ga(&children[0], parse_tree),
+            120 => self.ha(&children[0], parse_tree),
+            121 => self.l_bracket(&children[0], parse_tree),
+            122 => self.r_bracket(&children[0], parse_tree),
+            123 => self.camma(&children[0], parse_tree),
+            124 => self.period(&children[0], parse_tree),
+            125 => self.multi(&children[0], parse_tree),
+            126 => self.percent(&children[0], parse_tree),
+            127 => self.number(&children[0], parse_tree),
+            _ => Err(miette!("Unhandled production number: {}", prod_num)),
+        }
+    }
+}
@@ -0,0 +1,1778 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::{TokenStream, Tokenizer};
+use parol_runtime::miette::Result;
+use parol_runtime::once_cell::sync::Lazy;
+#[allow(unused_imports)]
+use parol_runtime::parser::{
+    DFATransition, LLKParser, LookaheadDFA, ParseTreeType, ParseType, Production,
+};
+use std::cell::RefCell;
+use std::path::Path;
+
+use crate::leader_skill_grammar::LeaderSkillGrammar;
+use crate::leader_skill_grammar_trait::LeaderSkillGrammarAuto;
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+
+pub const TERMINALS: &[&str; 58] = &[
+    /*  0 */ UNMATCHABLE_TOKEN,
+    /*  1 */ UNMATCHABLE_TOKEN,
+    /*  2 */ UNMATCHABLE_TOKEN,
+    /*  3 */ UNMATCHABLE_TOKEN,
+    /*  4 */ UNMATCHABLE_TOKEN,
+    /*  5 */ r###"属性"###,
+    /*  6 */ r###"タイプ"###,
+    /*  7 */ r###"同時攻撃"###,
+    /*  8 */ r###"コンボ"###,
+    /*  9 */ r###"色"###,
+    /* 10 */ r###"以上"###,
+    /* 11 */ r###"HP"###,
+    /* 12 */ r###"攻撃力"###,
+    /* 13 */ r###"回復力"###,
+    /* 14 */ r###"全パラメータ"###,
+    /* 15 */ r###"倍"###,
+    /* 16 */ r###"受ける"###,
+    /* 17 */ r###"ダメージ"###,
+    /* 18 */ r###"軽減"###,
+    /* 19 */ r###"ドロップ操作時間"###,
+    /* 20 */ r###"秒"###,
+    /* 21 */ r###"延長"###,
+    /* 22 */ r###"マス"###,
+    /* 23 */ r###"火"###,
+    /* 24 */ r###"水"###,
+    /* 25 */ r###"木"###,
+    /* 26 */ r###"光"###,
+    /* 27 */ r###"闇"###,
+    /* 28 */ r###"回復"###,
+    /* 29 */ r###"お邪魔"###,
+    /* 30 */ r###"爆弾"###,
+    /* 31 */ r###"猛毒"###,
+    /* 32 */ r###"毒"###,
+    /* 33 */ r###"ドラゴン"###,
+    /* 34 */ r###"神"###,
+    /* 35 */ r###"悪魔"###,
+    /* 36 */ r###"マシン"###,
+    /* 37 */ r###"バランス"###,
+    /* 38 */ r###"攻撃"###,
+    /* 39 */ r###"体力"###,
+    /* 40 */ r###"進化用"###,
+    /* 41 */ r###"能力覚醒用"###,
+    /* 42 */ r###"強化合成用"###,
+    /* 43 */ r###"売却用"###,
+    /* 44 */ r###"を"###,
+    /* 45 */ r###"の"###,
+    /* 46 */ r###"で"###,
+    /* 47 */ r###"と"###,
+    /* 48 */ r###"が"###,
+    /* 49 */ r###"は"###,
+    /* 50 */ r###"【"###,
+    /* 51 */ r###"】"###,
+    /* 52 */ r###"、"###,
+    /* 53 */ r###"。"###,
+    /* 54 */ r###"×"###,
+    /* 55 */ r###"%"###,
+    /* 56 */ r###"[0-9]+(\.[0-9]+)?"###,
+    /* 57 */ ERROR_TOKEN,
+];
+
+pub const TERMINAL_NAMES: &[&str; 58] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "WordAttribute",
+    /*  6 */ "WordType",
+    /*  7 */ "WordSimultaneous",
+    /*  8 */ "WordCombo",
+    /*  9 */ "WordColors",
+    /* 10 */ "WordOrMore",
+    /* 11 */ "WordHp",
+    /* 12 */ "WordAttack",
+    /* 13 */ "WordRecovery",
+    /* 14 */ "WordAllParameters",
+    /* 15 */ "WordTimes",
+    /* 16 */ "WordReceive",
+    /* 17 */ "WordDamage",
+    /* 18 */ "WordReduce",
+    /* 19 */ "WordMoveTime",
+    /* 20 */ "WordSecond",
+    /* 21 */ "WordExtend",
+    /* 22 */ "WordMass",
+    /* 23 */ "Fire",
+    /* 24 */ "Water",
+    /* 25 */ "Wood",
+    /* 26 */ "Lightning",
+    /* 27 */ "Dark",
+    /* 28 */ "Recovery",
+    /* 29 */ "Disturb",
+    /* 30 */ "Bomb",
+    /* 31 */ "DeadlyPoison",
+    /* 32 */ "Poison",
+    /* 33 */ "Dragon",
+    /* 34 */ "God",
+    /* 35 */ "Devil",
+    /* 36 */ "Machine",
+    /* 37 */ "Balanced",
+    /* 38 */ "Attacker",
+    /* 39 */ "Physical",
+    /* 40 */ "Evolve",
+    /* 41 */ "Awaken",
+    /* 42 */ "Enhance",
+    /* 43 */ "Vendor",
+    /* 44 */ "Wo",
+    /* 45 */ "No",
+    /* 46 */ "De",
+    /* 47 */ "And",
+    /* 48 */ "Ga",
+    /* 49 */ "Ha",
+    /* 50 */ "LBracket",
+    /* 51 */ "RBracket",
+    /* 52 */ "Camma",
+    /* 53 */ "Period",
+    /* 54 */ "Multi",
+    /* 55 */ "Percent",
+    /* 56 */ "Number",
+    /* 57 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[usize; 52]) = (
+    &[
+        /*  0 */ UNMATCHABLE_TOKEN,
+        /*  1 */ NEW_LINE_TOKEN,
+        /*  2 */ WHITESPACE_TOKEN,
+        /*  3 */ UNMATCHABLE_TOKEN,
+        /*  4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[
+        5,  /* WordAttribute */
+        6,  /* WordType */
+        7,  /* WordSimultaneous */
+        8,  /* WordCombo */
+        9,  /* WordColors */
+        10, /* WordOrMore */
+        11, /* WordHp */
+        12, /* WordAttack */
+        13, /* WordRecovery */
+        14, /* WordAllParameters */
+        15, /* WordTimes */
+        16, /* WordReceive */
+        17, /* WordDamage */
+        18, /* WordReduce */
+        19, /* WordMoveTime */
+        20, /* WordSecond */
+        21, /* WordExtend */
+        22, /* WordMass */
+        23, /* Fire */
+        24, /* Water */
+        25, /* Wood */
+        26, /* Lightning */
+        27, /* Dark */
+        28, /* Recovery */
+        29, /* Disturb */
+        30, /* Bomb */
+        31, /* DeadlyPoison */
+        32, /* Poison */
+        33, /* Dragon */
+        34, /* God */
+        35, /* Devil */
+        36, /* Machine */
+        37, /* Balanced */
+        38, /* Attacker */
+        39, /* Physical */
+        40, /* Evolve */
+        41, /* Awaken */
+        42, /* Enhance */
+        43, /* Vendor */
+        44, /* Wo */
+        45, /* No */
+        46, /* De */
+        47, /* And */
+        48, /* Ga */
+        49, /* Ha */
+        50, /* LBracket */
+        51, /* RBracket */
+        52, /* Camma */
+        53, /* Period */
+        54, /* Multi */
+        55, /* Percent */
+        56, /* Number */
+    ],
+);
+
+const MAX_K: usize = 1;
+
+pub const NON_TERMINALS: &[&str; 85] = &[
+    /*  0 */ "And",
+    /*  1 */ "Attacker",
+    /*  2 */ "Awaken",
+    /*  3 */ "Balanced",
+    /*  4 */ "BoardSizePrefix",
+    /*  5 */ "Bomb",
+    /*  6 */ "Camma",
+    /*  7 */ "Color",
+    /*  8 */ "CountCondition",
+    /*  9 */ "CountConditionSuffix",
+    /* 10 */ "CountConditionSuffixOpt",
+    /* 11 */ "CountConditionSuffixOpt0",
+    /* 12 */ "DamageReductionStmt",
+    /* 13 */ "DamageReductionStmtOpt",
+    /* 14 */ "Dark",
+    /* 15 */ "De",
+    /* 16 */ "DeadlyPoison",
+    /* 17 */ "Devil",
+    /* 18 */ "Disturb",
+    /* 19 */ "Dragon",
+    /* 20 */ "Enhance",
+    /* 21 */ "Evolve",
+    /* 22 */ "Fire",
+    /* 23 */ "Ga",
+    /* 24 */ "God",
+    /* 25 */ "Ha",
+    /* 26 */ "LBracket",
+    /* 27 */ "LeaderBody",
+    /* 28 */ "LeaderClause",
+    /* 29 */ "LeaderSentence",
+    /* 30 */ "LeaderSentenceList",
+    /* 31 */ "LeaderSkillLines",
+    /* 32 */ "LeaderSkillLinesList",
+    /* 33 */ "LeaderSkillLinesOpt",
+    /* 34 */ "Lightning",
+    /* 35 */ "Machine",
+    /* 36 */ "MonsterType",
+    /* 37 */ "Multi",
+    /* 38 */ "Name",
+    /* 39 */ "NameList",
+    /* 40 */ "NameListClause",
+    /* 41 */ "NameListList",
+    /* 42 */ "NameListOpt",
+    /* 43 */ "NameSep",
+    /* 44 */ "NameSuffix",
+    /* 45 */ "No",
+    /* 46 */ "NonColoredDrop",
+    /* 47 */ "Number",
+    /* 48 */ "Percent",
+    /* 49 */ "Period",
+    /* 50 */ "Physical",
+    /* 51 */ "PlainBody",
+    /* 52 */ "Poison",
+    /* 53 */ "RBracket",
+    /* 54 */ "Recovery",
+    /* 55 */ "Size",
+    /* 56 */ "Stat",
+    /* 57 */ "Stats",
+    /* 58 */ "StatsList",
+    /* 59 */ "StatsParticle",
+    /* 60 */ "StatsStmt",
+    /* 61 */ "TimeExtensionStmt",
+    /* 62 */ "TimeParticle",
+    /* 63 */ "Vendor",
+    /* 64 */ "Water",
+    /* 65 */ "Wo",
+    /* 66 */ "Wood",
+    /* 67 */ "WordAllParameters",
+    /* 68 */ "WordAttack",
+    /* 69 */ "WordAttribute",
+    /* 70 */ "WordColors",
+    /* 71 */ "WordCombo",
+    /* 72 */ "WordDamage",
+    /* 73 */ "WordExtend",
+    /* 74 */ "WordHp",
+    /* 75 */ "WordMass",
+    /* 76 */ "WordMoveTime",
+    /* 77 */ "WordOrMore",
+    /* 78 */ "WordReceive",
+    /* 79 */ "WordRecovery",
+    /* 80 */ "WordReduce",
+    /* 81 */ "WordSecond",
+    /* 82 */ "WordSimultaneous",
+    /* 83 */ "WordTimes",
+    /* 84 */ "WordType",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 85] = &[
+    /* 0 - "And" */
+    LookaheadDFA {
+        states: &[Some(118)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "Attacker" */
+    LookaheadDFA {
+        states: &[Some(109)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "Awaken" */
+    LookaheadDFA {
+        states: &[Some(112)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Balanced" */
+    LookaheadDFA {
+        states: &[Some(108)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "BoardSizePrefix" */
+    LookaheadDFA {
+        states: &[Some(5)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "Bomb" */
+    LookaheadDFA {
+        states: &[Some(101)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "Camma" */
+    LookaheadDFA {
+        states: &[Some(123)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "Color" */
+    LookaheadDFA {
+        states: &[None, Some(54), Some(55), Some(56), Some(57), Some(58)],
+        transitions: &[
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 2),
+            DFATransition(0, 25, 3),
+            DFATransition(0, 26, 4),
+            DFATransition(0, 27, 5),
+        ],
+        k: 1,
+    },
+    /* 8 - "CountCondition" */
+    LookaheadDFA {
+        states: &[Some(16)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "CountConditionSuffix" */
+    LookaheadDFA {
+        states: &[None, Some(17), Some(18)],
+        transitions: &[DFATransition(0, 8, 1), DFATransition(0, 9, 2)],
+        k: 1,
+    },
+    /* 10 - "CountConditionSuffixOpt" */
+    LookaheadDFA {
+        states: &[None, Some(21), Some(22)],
+        transitions: &[DFATransition(0, 10, 1), DFATransition(0, 46, 2)],
+        k: 1,
+    },
+    /* 11 - "CountConditionSuffixOpt0" */
+    LookaheadDFA {
+        states: &[None, Some(19), Some(20)],
+        transitions: &[DFATransition(0, 7, 2), DFATransition(0, 10, 1)],
+        k: 1,
+    },
+    /* 12 - "DamageReductionStmt" */
+    LookaheadDFA {
+        states: &[Some(38)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 13 - "DamageReductionStmtOpt" */
+    LookaheadDFA {
+        states: &[None, Some(39), Some(40)],
+        transitions: &[DFATransition(0, 16, 1), DFATransition(0, 17, 2)],
+        k: 1,
+    },
+    /* 14 - "Dark" */
+    LookaheadDFA {
+        states: &[Some(98)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 15 - "De" */
+    LookaheadDFA {
+        states: &[Some(117)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 16 - "DeadlyPoison" */
+    LookaheadDFA {
+        states: &[Some(102)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 17 - "Devil" */
+    LookaheadDFA {
+        states: &[Some(106)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 18 - "Disturb" */
+    LookaheadDFA {
+        states: &[Some(100)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 19 - "Dragon" */
+    LookaheadDFA {
+        states: &[Some(104)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 20 - "Enhance" */
+    LookaheadDFA {
+        states: &[Some(113)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 21 - "Evolve" */
+    LookaheadDFA {
+        states: &[Some(111)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "Fire" */
+    LookaheadDFA {
+        states: &[Some(94)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 23 - "Ga" */
+    LookaheadDFA {
+        states: &[Some(119)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 24 - "God" */
+    LookaheadDFA {
+        states: &[Some(105)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 25 - "Ha" */
+    LookaheadDFA {
+        states: &[Some(120)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 26 - "LBracket" */
+    LookaheadDFA {
+        states: &[Some(121)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 27 - "LeaderBody" */
+    LookaheadDFA {
+        states: &[None, Some(23), Some(24)],
+        transitions: &[
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 16, 2),
+            DFATransition(0, 17, 2),
+            DFATransition(0, 19, 2),
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 25, 1),
+            DFATransition(0, 26, 1),
+            DFATransition(0, 27, 1),
+            DFATransition(0, 28, 1),
+            DFATransition(0, 29, 1),
+            DFATransition(0, 30, 1),
+            DFATransition(0, 31, 1),
+            DFATransition(0, 32, 1),
+            DFATransition(0, 33, 1),
+            DFATransition(0, 34, 1),
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 37, 1),
+            DFATransition(0, 38, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 41, 1),
+            DFATransition(0, 42, 1),
+            DFATransition(0, 43, 1),
+        ],
+        k: 1,
+    },
+    /* 28 - "LeaderClause" */
+    LookaheadDFA {
+        states: &[None, Some(9), Some(10), Some(11)],
+        transitions: &[
+            DFATransition(0, 11, 3),
+            DFATransition(0, 12, 3),
+            DFATransition(0, 13, 3),
+            DFATransition(0, 14, 3),
+            DFATransition(0, 16, 3),
+            DFATransition(0, 17, 3),
+            DFATransition(0, 19, 3),
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 25, 1),
+            DFATransition(0, 26, 1),
+            DFATransition(0, 27, 1),
+            DFATransition(0, 28, 1),
+            DFATransition(0, 29, 1),
+            DFATransition(0, 30, 1),
+            DFATransition(0, 31, 1),
+            DFATransition(0, 32, 1),
+            DFATransition(0, 33, 1),
+            DFATransition(0, 34, 1),
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 37, 1),
+            DFATransition(0, 38, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 41, 1),
+            DFATransition(0, 42, 1),
+            DFATransition(0, 43, 1),
+            DFATransition(0, 56, 2),
+        ],
+        k: 1,
+    },
+    /* 29 - "LeaderSentence" */
+    LookaheadDFA {
+        states: &[Some(6)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 30 - "LeaderSentenceList" */
+    LookaheadDFA {
+        states: &[None, Some(7), Some(8)],
+        transitions: &[DFATransition(0, 52, 1), DFATransition(0, 53, 2)],
+        k: 1,
+    },
+    /* 31 - "LeaderSkillLines" */
+    LookaheadDFA {
+        states: &[Some(0)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 32 - "LeaderSkillLinesList" */
+    LookaheadDFA {
+        states: &[None, Some(1), Some(2)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 11, 1),
+            DFATransition(0, 12, 1),
+            DFATransition(0, 13, 1),
+            DFATransition(0, 14, 1),
+            DFATransition(0, 16, 1),
+            DFATransition(0, 17, 1),
+            DFATransition(0, 19, 1),
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 25, 1),
+            DFATransition(0, 26, 1),
+            DFATransition(0, 27, 1),
+            DFATransition(0, 28, 1),
+            DFATransition(0, 29, 1),
+            DFATransition(0, 30, 1),
+            DFATransition(0, 31, 1),
+            DFATransition(0, 32, 1),
+            DFATransition(0, 33, 1),
+            DFATransition(0, 34, 1),
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 37, 1),
+            DFATransition(0, 38, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 41, 1),
+            DFATransition(0, 42, 1),
+            DFATransition(0, 43, 1),
+            DFATransition(0, 56, 1),
+        ],
+        k: 1,
+    },
+    /* 33 - "LeaderSkillLinesOpt" */
+    LookaheadDFA {
+        states: &[None, Some(3), Some(4)],
+        transitions: &[
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 16, 2),
+            DFATransition(0, 17, 2),
+            DFATransition(0, 19, 2),
+            DFATransition(0, 23, 2),
+            DFATransition(0, 24, 2),
+            DFATransition(0, 25, 2),
+            DFATransition(0, 26, 2),
+            DFATransition(0, 27, 2),
+            DFATransition(0, 28, 2),
+            DFATransition(0, 29, 2),
+            DFATransition(0, 30, 2),
+            DFATransition(0, 31, 2),
+            DFATransition(0, 32, 2),
+            DFATransition(0, 33, 2),
+            DFATransition(0, 34, 2),
+            DFATransition(0, 35, 2),
+            DFATransition(0, 36, 2),
+            DFATransition(0, 37, 2),
+            DFATransition(0, 38, 2),
+            DFATransition(0, 39, 2),
+            DFATransition(0, 40, 2),
+            DFATransition(0, 41, 2),
+            DFATransition(0, 42, 2),
+            DFATransition(0, 43, 2),
+            DFATransition(0, 50, 1),
+            DFATransition(0, 56, 2),
+        ],
+        k: 1,
+    },
+    /* 34 - "Lightning" */
+    LookaheadDFA {
+        states: &[Some(97)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 35 - "Machine" */
+    LookaheadDFA {
+        states: &[Some(107)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 36 - "MonsterType" */
+    LookaheadDFA {
+        states: &[
+            None,
+            Some(64),
+            Some(65),
+            Some(66),
+            Some(67),
+            Some(68),
+            Some(69),
+            Some(70),
+            Some(71),
+            Some(72),
+            Some(73),
+            Some(74),
+        ],
+        transitions: &[
+            DFATransition(0, 33, 1),
+            DFATransition(0, 34, 2),
+            DFATransition(0, 35, 3),
+            DFATransition(0, 36, 4),
+            DFATransition(0, 37, 5),
+            DFATransition(0, 38, 6),
+            DFATransition(0, 39, 7),
+            DFATransition(0, 40, 8),
+            DFATransition(0, 41, 9),
+            DFATransition(0, 42, 10),
+            DFATransition(0, 43, 11),
+        ],
+        k: 1,
+    },
+    /* 37 - "Multi" */
+    LookaheadDFA {
+        states: &[Some(125)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 38 - "Name" */
+    LookaheadDFA {
+        states: &[None, Some(51), Some(52), Some(53)],
+        transitions: &[
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 25, 1),
+            DFATransition(0, 26, 1),
+            DFATransition(0, 27, 1),
+            DFATransition(0, 28, 2),
+            DFATransition(0, 29, 2),
+            DFATransition(0, 30, 2),
+            DFATransition(0, 31, 2),
+            DFATransition(0, 32, 2),
+            DFATransition(0, 33, 3),
+            DFATransition(0, 34, 3),
+            DFATransition(0, 35, 3),
+            DFATransition(0, 36, 3),
+            DFATransition(0, 37, 3),
+            DFATransition(0, 38, 3),
+            DFATransition(0, 39, 3),
+            DFATransition(0, 40, 3),
+            DFATransition(0, 41, 3),
+            DFATransition(0, 42, 3),
+            DFATransition(0, 43, 3),
+        ],
+        k: 1,
+    },
+    /* 39 - "NameList" */
+    LookaheadDFA {
+        states: &[Some(44)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 40 - "NameListClause" */
+    LookaheadDFA {
+        states: &[None, Some(12), Some(13)],
+        transitions: &[
+            DFATransition(0, 5, 1),
+            DFATransition(0, 6, 1),
+            DFATransition(0, 45, 2),
+        ],
+        k: 1,
+    },
+    /* 41 - "NameListList" */
+    LookaheadDFA {
+        states: &[None, Some(45), Some(46)],
+        transitions: &[
+            DFATransition(0, 5, 2),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 23, 1),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 25, 1),
+            DFATransition(0, 26, 1),
+            DFATransition(0, 27, 1),
+            DFATransition(0, 28, 1),
+            DFATransition(0, 29, 1),
+            DFATransition(0, 30, 1),
+            DFATransition(0, 31, 1),
+            DFATransition(0, 32, 1),
+            DFATransition(0, 33, 1),
+            DFATransition(0, 34, 1),
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 37, 1),
+            DFATransition(0, 38, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 41, 1),
+            DFATransition(0, 42, 1),
+            DFATransition(0, 43, 1),
+            DFATransition(0, 45, 2),
+            DFATransition(0, 47, 1),
+            DFATransition(0, 52, 1),
+        ],
+        k: 1,
+    },
+    /* 42 - "NameListOpt" */
+    LookaheadDFA {
+        states: &[None, Some(47), Some(48)],
+        transitions: &[
+            DFATransition(0, 23, 2),
+            DFATransition(0, 24, 2),
+            DFATransition(0, 25, 2),
+            DFATransition(0, 26, 2),
+            DFATransition(0, 27, 2),
+            DFATransition(0, 28, 2),
+            DFATransition(0, 29, 2),
+            DFATransition(0, 30, 2),
+            DFATransition(0, 31, 2),
+            DFATransition(0, 32, 2),
+            DFATransition(0, 33, 2),
+            DFATransition(0, 34, 2),
+            DFATransition(0, 35, 2),
+            DFATransition(0, 36, 2),
+            DFATransition(0, 37, 2),
+            DFATransition(0, 38, 2),
+            DFATransition(0, 39, 2),
+            DFATransition(0, 40, 2),
+            DFATransition(0, 41, 2),
+            DFATransition(0, 42, 2),
+            DFATransition(0, 43, 2),
+            DFATransition(0, 47, 1),
+            DFATransition(0, 52, 1),
+        ],
+        k: 1,
+    },
+    /* 43 - "NameSep" */
+    LookaheadDFA {
+        states: &[None, Some(49), Some(50)],
+        transitions: &[DFATransition(0, 47, 1), DFATransition(0, 52, 2)],
+        k: 1,
+    },
+    /* 44 - "NameSuffix" */
+    LookaheadDFA {
+        states: &[None, Some(14), Some(15)],
+        transitions: &[DFATransition(0, 5, 1), DFATransition(0, 6, 2)],
+        k: 1,
+    },
+    /* 45 - "No" */
+    LookaheadDFA {
+        states: &[Some(116)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 46 - "NonColoredDrop" */
+    LookaheadDFA {
+        states: &[None, Some(59), Some(60), Some(61), Some(62), Some(63)],
+        transitions: &[
+            DFATransition(0, 28, 1),
+            DFATransition(0, 29, 2),
+            DFATransition(0, 30, 3),
+            DFATransition(0, 31, 4),
+            DFATransition(0, 32, 5),
+        ],
+        k: 1,
+    },
+    /* 47 - "Number" */
+    LookaheadDFA {
+        states: &[Some(127)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 48 - "Percent" */
+    LookaheadDFA {
+        states: &[Some(126)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 49 - "Period" */
+    LookaheadDFA {
+        states: &[Some(124)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 50 - "Physical" */
+    LookaheadDFA {
+        states: &[Some(110)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 51 - "PlainBody" */
+    LookaheadDFA {
+        states: &[None, Some(25), Some(26), Some(27)],
+        transitions: &[
+            DFATransition(0, 11, 1),
+            DFATransition(0, 12, 1),
+            DFATransition(0, 13, 1),
+            DFATransition(0, 14, 1),
+            DFATransition(0, 16, 2),
+            DFATransition(0, 17, 2),
+            DFATransition(0, 19, 3),
+        ],
+        k: 1,
+    },
+    /* 52 - "Poison" */
+    LookaheadDFA {
+        states: &[Some(103)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 53 - "RBracket" */
+    LookaheadDFA {
+        states: &[Some(122)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 54 - "Recovery" */
+    LookaheadDFA {
+        states: &[Some(99)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 55 - "Size" */
+    LookaheadDFA {
+        states: &[Some(75)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 56 - "Stat" */
+    LookaheadDFA {
+        states: &[None, Some(33), Some(34), Some(35)],
+        transitions: &[
+            DFATransition(0, 11, 1),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 3),
+        ],
+        k: 1,
+    },
+    /* 57 - "Stats" */
+    LookaheadDFA {
+        states: &[None, Some(29), Some(32)],
+        transitions: &[
+            DFATransition(0, 11, 1),
+            DFATransition(0, 12, 1),
+            DFATransition(0, 13, 1),
+            DFATransition(0, 14, 2),
+        ],
+        k: 1,
+    },
+    /* 58 - "StatsList" */
+    LookaheadDFA {
+        states: &[None, Some(30), Some(31)],
+        transitions: &[
+            DFATransition(0, 47, 1),
+            DFATransition(0, 48, 2),
+            DFATransition(0, 49, 2),
+        ],
+        k: 1,
+    },
+    /* 59 - "StatsParticle" */
+    LookaheadDFA {
+        states: &[None, Some(36), Some(37)],
+        transitions: &[DFATransition(0, 48, 1), DFATransition(0, 49, 2)],
+        k: 1,
+    },
+    /* 60 - "StatsStmt" */
+    LookaheadDFA {
+        states: &[Some(28)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 61 - "TimeExtensionStmt" */
+    LookaheadDFA {
+        states: &[Some(41)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 62 - "TimeParticle" */
+    LookaheadDFA {
+        states: &[None, Some(42), Some(43)],
+        transitions: &[DFATransition(0, 44, 1), DFATransition(0, 48, 2)],
+        k: 1,
+    },
+    /* 63 - "Vendor" */
+    LookaheadDFA {
+        states: &[Some(114)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 64 - "Water" */
+    LookaheadDFA {
+        states: &[Some(95)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 65 - "Wo" */
+    LookaheadDFA {
+        states: &[Some(115)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 66 - "Wood" */
+    LookaheadDFA {
+        states: &[Some(96)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 67 - "WordAllParameters" */
+    LookaheadDFA {
+        states: &[Some(85)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 68 - "WordAttack" */
+    LookaheadDFA {
+        states: &[Some(83)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 69 - "WordAttribute" */
+    LookaheadDFA {
+        states: &[Some(76)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 70 - "WordColors" */
+    LookaheadDFA {
+        states: &[Some(80)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 71 - "WordCombo" */
+    LookaheadDFA {
+        states: &[Some(79)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 72 - "WordDamage" */
+    LookaheadDFA {
+        states: &[Some(88)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 73 - "WordExtend" */
+    LookaheadDFA {
+        states: &[Some(92)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 74 - "WordHp" */
+    LookaheadDFA {
+        states: &[Some(82)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 75 - "WordMass" */
+    LookaheadDFA {
+        states: &[Some(93)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 76 - "WordMoveTime" */
+    LookaheadDFA {
+        states: &[Some(90)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 77 - "WordOrMore" */
+    LookaheadDFA {
+        states: &[Some(81)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 78 - "WordReceive" */
+    LookaheadDFA {
+        states: &[Some(87)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 79 - "WordRecovery" */
+    LookaheadDFA {
+        states: &[Some(84)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 80 - "WordReduce" */
+    LookaheadDFA {
+        states: &[Some(89)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 81 - "WordSecond" */
+    LookaheadDFA {
+        states: &[Some(91)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 82 - "WordSimultaneous" */
+    LookaheadDFA {
+        states: &[Some(78)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 83 - "WordTimes" */
+    LookaheadDFA {
+        states: &[Some(86)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 84 - "WordType" */
+    LookaheadDFA {
+        states: &[Some(77)],
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 128] = &[
+    // 0 - LeaderSkillLines: LeaderSkillLinesOpt /* Option */ LeaderSentence LeaderSkillLinesList /* Vec */;
+    Production {
+        lhs: 31,
+        production: &[ParseType::N(32), ParseType::N(29), ParseType::N(33)],
+    },
+    // 1 - LeaderSkillLinesList: LeaderSentence LeaderSkillLinesList;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(32), ParseType::N(29)],
+    },
+    // 2 - LeaderSkillLinesList: ;
+    Production {
+        lhs: 32,
+        production: &[],
+    },
+    // 3 - LeaderSkillLinesOpt: BoardSizePrefix;
+    Production {
+        lhs: 33,
+        production: &[ParseType::N(4)],
+    },
+    // 4 - LeaderSkillLinesOpt: ;
+    Production {
+        lhs: 33,
+        production: &[],
+    },
+    // 5 - BoardSizePrefix: LBracket Size WordMass RBracket;
+    Production {
+        lhs: 4,
+        production: &[
+            ParseType::N(53),
+            ParseType::N(75),
+            ParseType::N(55),
+            ParseType::N(26),
+        ],
+    },
+    // 6 - LeaderSentence: LeaderClause LeaderSentenceList /* Vec */ Period;
+    Production {
+        lhs: 29,
+        production: &[ParseType::N(49), ParseType::N(30), ParseType::N(28)],
+    },
+    // 7 - LeaderSentenceList: Camma LeaderClause LeaderSentenceList;
+    Production {
+        lhs: 30,
+        production: &[ParseType::N(30), ParseType::N(28), ParseType::N(6)],
+    },
+    // 8 - LeaderSentenceList: ;
+    Production {
+        lhs: 30,
+        production: &[],
+    },
+    // 9 - LeaderClause: NameList NameListClause;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(40), ParseType::N(39)],
+    },
+    // 10 - LeaderClause: CountCondition De LeaderBody;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(27), ParseType::N(15), ParseType::N(8)],
+    },
+    // 11 - LeaderClause: PlainBody;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(51)],
+    },
+    // 12 - NameListClause: NameSuffix No StatsStmt;
+    Production {
+        lhs: 40,
+        production: &[ParseType::N(60), ParseType::N(45), ParseType::N(44)],
+    },
+    // 13 - NameListClause: No WordSimultaneous De LeaderBody;
+    Production {
+        lhs: 40,
+        production: &[
+            ParseType::N(27),
+            ParseType::N(15),
+            ParseType::N(82),
+            ParseType::N(45),
+        ],
+    },
+    // 14 - NameSuffix: WordAttribute;
+    Production {
+        lhs: 44,
+        production: &[ParseType::N(69)],
+    },
+    // 15 - NameSuffix: WordType;
+    Production {
+        lhs: 44,
+        production: &[ParseType::N(84)],
+    },
+    // 16 - CountCondition: Number CountConditionSuffix;
+    Production {
+        lhs: 8,
+        production: &[ParseType::N(9), ParseType::N(47)],
+    },
+    // 17 - CountConditionSuffix: WordCombo CountConditionSuffixOpt /* Option */;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(10), ParseType::N(71)],
+    },
+    // 18 - CountConditionSuffix: WordColors CountConditionSuffixOpt0 /* Option */ WordSimultaneous;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(82), ParseType::N(11), ParseType::N(70)],
+    },
+    // 19 - CountConditionSuffixOpt0: WordOrMore;
+    Production {
+        lhs: 11,
+        production: &[ParseType::N(77)],
+    },
+    // 20 - CountConditionSuffixOpt0: ;
+    Production {
+        lhs: 11,
+        production: &[],
+    },
+    // 21 - CountConditionSuffixOpt: WordOrMore;
+    Production {
+        lhs: 10,
+        production: &[ParseType::N(77)],
+    },
+    // 22 - CountConditionSuffixOpt: ;
+    Production {
+        lhs: 10,
+        production: &[],
+    },
+    // 23 - LeaderBody: NameList NameSuffix No StatsStmt;
+    Production {
+        lhs: 27,
+        production: &[
+            ParseType::N(60),
+            ParseType::N(45),
+            ParseType::N(44),
+            ParseType::N(39),
+        ],
+    },
+    // 24 - LeaderBody: PlainBody;
+    Production {
+        lhs: 27,
+        production: &[ParseType::N(51)],
+    },
+    // 25 - PlainBody: StatsStmt;
+    Production {
+        lhs: 51,
+        production: &[ParseType::N(60)],
+    },
+    // 26 - PlainBody: DamageReductionStmt;
+    Production {
+        lhs: 51,
+        production: &[ParseType::N(12)],
+    },
+    // 27 - PlainBody: TimeExtensionStmt;
+    Production {
+        lhs: 51,
+        production: &[ParseType::N(61)],
+    },
+    // 28 - StatsStmt: Stats StatsParticle Number WordTimes;
+    Production {
+        lhs: 60,
+        production: &[
+            ParseType::N(83),
+            ParseType::N(47),
+            ParseType::N(59),
+            ParseType::N(57),
+        ],
+    },
+    // 29 - Stats: Stat StatsList /* Vec */;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(58), ParseType::N(56)],
+    },
+    // 30 - StatsList: And Stat StatsList;
+    Production {
+        lhs: 58,
+        production: &[ParseType::N(58), ParseType::N(56), ParseType::N(0)],
+    },
+    // 31 - StatsList: ;
+    Production {
+        lhs: 58,
+        production: &[],
+    },
+    // 32 - Stats: WordAllParameters;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(67)],
+    },
+    // 33 - Stat: WordHp;
+    Production {
+        lhs: 56,
+        production: &[ParseType::N(74)],
+    },
+    // 34 - Stat: WordAttack;
+    Production {
+        lhs: 56,
+        production: &[ParseType::N(68)],
+    },
+    // 35 - Stat: WordRecovery;
+    Production {
+        lhs: 56,
+        production: &[ParseType::N(79)],
+    },
+    // 36 - StatsParticle: Ga;
+    Production {
+        lhs: 59,
+        production: &[ParseType::N(23)],
+    },
+    // 37 - StatsParticle: Ha;
+    Production {
+        lhs: 59,
+        production: &[ParseType::N(25)],
+    },
+    // 38 - DamageReductionStmt: DamageReductionStmtOpt /* Option */ WordDamage Wo Number Percent WordReduce;
+    Production {
+        lhs: 12,
+        production: &[
+            ParseType::N(80),
+            ParseType::N(48),
+            ParseType::N(47),
+            ParseType::N(65),
+            ParseType::N(72),
+            ParseType::N(13),
+        ],
+    },
+    // 39 - DamageReductionStmtOpt: WordReceive;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(78)],
+    },
+    // 40 - DamageReductionStmtOpt: ;
+    Production {
+        lhs: 13,
+        production: &[],
+    },
+    // 41 - TimeExtensionStmt: WordMoveTime TimeParticle Number WordSecond WordExtend;
+    Production {
+        lhs: 61,
+        production: &[
+            ParseType::N(73),
+            ParseType::N(81),
+            ParseType::N(47),
+            ParseType::N(62),
+            ParseType::N(76),
+        ],
+    },
+    // 42 - TimeParticle: Wo;
+    Production {
+        lhs: 62,
+        production: &[ParseType::N(65)],
+    },
+    // 43 - TimeParticle: Ga;
+    Production {
+        lhs: 62,
+        production: &[ParseType::N(23)],
+    },
+    // 44 - NameList: Name NameListList /* Vec */;
+    Production {
+        lhs: 39,
+        production: &[ParseType::N(41), ParseType::N(38)],
+    },
+    // 45 - NameListList: NameListOpt /* Option */ Name NameListList;
+    Production {
+        lhs: 41,
+        production: &[ParseType::N(41), ParseType::N(38), ParseType::N(42)],
+    },
+    // 46 - NameListList: ;
+    Production {
+        lhs: 41,
+        production: &[],
+    },
+    // 47 - NameListOpt: NameSep;
+    Production {
+        lhs: 42,
+        production: &[ParseType::N(43)],
+    },
+    // 48 - NameListOpt: ;
+    Production {
+        lhs: 42,
+        production: &[],
+    },
+    // 49 - NameSep: And;
+    Production {
+        lhs: 43,
+        production: &[ParseType::N(0)],
+    },
+    // 50 - NameSep: Camma;
+    Production {
+        lhs: 43,
+        production: &[ParseType::N(6)],
+    },
+    // 51 - Name: Color;
+    Production {
+        lhs: 38,
+        production: &[ParseType::N(7)],
+    },
+    // 52 - Name: NonColoredDrop;
+    Production {
+        lhs: 38,
+        production: &[ParseType::N(46)],
+    },
+    // 53 - Name: MonsterType;
+    Production {
+        lhs: 38,
+        production: &[ParseType::N(36)],
+    },
+    // 54 - Color: Fire;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(22)],
+    },
+    // 55 - Color: Water;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(64)],
+    },
+    // 56 - Color: Wood;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(66)],
+    },
+    // 57 - Color: Lightning;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(34)],
+    },
+    // 58 - Color: Dark;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(14)],
+    },
+    // 59 - NonColoredDrop: Recovery;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(54)],
+    },
+    // 60 - NonColoredDrop: Disturb;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(18)],
+    },
+    // 61 - NonColoredDrop: Bomb;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(5)],
+    },
+    // 62 - NonColoredDrop: DeadlyPoison;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(16)],
+    },
+    // 63 - NonColoredDrop: Poison;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(52)],
+    },
+    // 64 - MonsterType: Dragon;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(19)],
+    },
+    // 65 - MonsterType: God;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(24)],
+    },
+    // 66 - MonsterType: Devil;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(17)],
+    },
+    // 67 - MonsterType: Machine;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(35)],
+    },
+    // 68 - MonsterType: Balanced;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(3)],
+    },
+    // 69 - MonsterType: Attacker;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(1)],
+    },
+    // 70 - MonsterType: Physical;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(50)],
+    },
+    // 71 - MonsterType: Evolve;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(21)],
+    },
+    // 72 - MonsterType: Awaken;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(2)],
+    },
+    // 73 - MonsterType: Enhance;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(20)],
+    },
+    // 74 - MonsterType: Vendor;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(63)],
+    },
+    // 75 - Size: Number Multi Number;
+    Production {
+        lhs: 55,
+        production: &[ParseType::N(47), ParseType::N(37), ParseType::N(47)],
+    },
+    // 76 - WordAttribute: '属性';
+    Production {
+        lhs: 69,
+        production: &[ParseType::T(5)],
+    },
+    // 77 - WordType: 'タイプ';
+    Production {
+        lhs: 84,
+        production: &[ParseType::T(6)],
+    },
+    // 78 - WordSimultaneous: '同時攻撃';
+    Production {
+        lhs: 82,
+        production: &[ParseType::T(7)],
+    },
+    // 79 - WordCombo: 'コンボ';
+    Production {
+        lhs: 71,
+        production: &[ParseType::T(8)],
+    },
+    // 80 - WordColors: '色';
+    Production {
+        lhs: 70,
+        production: &[ParseType::T(9)],
+    },
+    // 81 - WordOrMore: '以上';
+    Production {
+        lhs: 77,
+        production: &[ParseType::T(10)],
+    },
+    // 82 - WordHp: 'HP';
+    Production {
+        lhs: 74,
+        production: &[ParseType::T(11)],
+    },
+    // 83 - WordAttack: '攻撃力';
+    Production {
+        lhs: 68,
+        production: &[ParseType::T(12)],
+    },
+    // 84 - WordRecovery: '回復力';
+    Production {
+        lhs: 79,
+        production: &[ParseType::T(13)],
+    },
+    // 85 - WordAllParameters: '全パラメータ';
+    Production {
+        lhs: 67,
+        production: &[ParseType::T(14)],
+    },
+    // 86 - WordTimes: '倍';
+    Production {
+        lhs: 83,
+        production: &[ParseType::T(15)],
+    },
+    // 87 - WordReceive: '受ける';
+    Production {
+        lhs: 78,
+        production: &[ParseType::T(16)],
+    },
+    // 88 - WordDamage: 'ダメージ';
+    Production {
+        lhs: 72,
+        production: &[ParseType::T(17)],
+    },
+    // 89 - WordReduce: '軽減';
+    Production {
+        lhs: 80,
+        production: &[ParseType::T(18)],
+    },
+    // 90 - WordMoveTime: 'ドロップ操作時間';
+    Production {
+        lhs: 76,
+        production: &[ParseType::T(19)],
+    },
+    // 91 - WordSecond: '秒';
+    Production {
+        lhs: 81,
+        production: &[ParseType::T(20)],
+    },
+    // 92 - WordExtend: '延長';
+    Production {
+        lhs: 73,
+        production: &[ParseType::T(21)],
+    },
+    // 93 - WordMass: 'マス';
+    Production {
+        lhs: 75,
+        production: &[ParseType::T(22)],
+    },
+    // 94 - Fire: '火';
+    Production {
+        lhs: 22,
+        production: &[ParseType::T(23)],
+    },
+    // 95 - Water: '水';
+    Production {
+        lhs: 64,
+        production: &[ParseType::T(24)],
+    },
+    // 96 - Wood: '木';
+    Production {
+        lhs: 66,
+        production: &[ParseType::T(25)],
+    },
+    // 97 - Lightning: '光';
+    Production {
+        lhs: 34,
+        production: &[ParseType::T(26)],
+    },
+    // 98 - Dark: '闇';
+    Production {
+        lhs: 14,
+        production: &[ParseType::T(27)],
+    },
+    // 99 - Recovery: '回復';
+    Production {
+        lhs: 54,
+        production: &[ParseType::T(28)],
+    },
+    // 100 - Disturb: 'お邪魔';
+    Production {
+        lhs: 18,
+        production: &[ParseType::T(29)],
+    },
+    // 101 - Bomb: '爆弾';
+    Production {
+        lhs: 5,
+        production: &[ParseType::T(30)],
+    },
+    // 102 - DeadlyPoison: '猛毒';
+    Production {
+        lhs: 16,
+        production: &[ParseType::T(31)],
+    },
+    // 103 - Poison: '毒';
+    Production {
+        lhs: 52,
+        production: &[ParseType::T(32)],
+    },
+    // 104 - Dragon: 'ドラゴン';
+    Production {
+        lhs: 19,
+        production: &[ParseType::T(33)],
+    },
+    // 105 - God: '神';
+    Production {
+        lhs: 24,
+        production: &[ParseType::T(34)],
+    },
+    // 106 - Devil: '悪魔';
+    Production {
+        lhs: 17,
+        production: &[ParseType::T(35)],
+    },
+    // 107 - Machine: 'マシン';
+    Production {
+        lhs: 35,
+        production: &[ParseType::T(36)],
+    },
+    // 108 - Balanced: 'バランス';
+    Production {
+        lhs: 3,
+        production: &[ParseType::T(37)],
+    },
+    // 109 - Attacker: '攻撃';
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(38)],
+    },
+    // 110 - Physical: '体力';
+    Production {
+        lhs: 50,
+        production: &[ParseType::T(39)],
+    },
+    // 111 - Evolve: '進化用';
+    Production {
+        lhs: 21,
+        production: &[ParseType::T(40)],
+    },
+    // 112 - Awaken: '能力覚醒用';
+    Production {
+        lhs: 2,
+        production: &[ParseType::T(41)],
+    },
+    // 113 - Enhance: '強化合成用';
+    Production {
+        lhs: 20,
+        production: &[ParseType::T(42)],
+    },
+    // 114 - Vendor: '売却用';
+    Production {
+        lhs: 63,
+        production: &[ParseType::T(43)],
+    },
+    // 115 - Wo: 'を';
+    Production {
+        lhs: 65,
+        production: &[ParseType::T(44)],
+    },
+    // 116 - No: 'の';
+    Production {
+        lhs: 45,
+        production: &[ParseType::T(45)],
+    },
+    // 117 - De: 'で';
+    Production {
+        lhs: 15,
+        production: &[ParseType::T(46)],
+    },
+    // 118 - And: 'と';
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(47)],
+    },
+    // 119 - Ga: 'が';
+    Production {
+        lhs: 23,
+        production: &[ParseType::T(48)],
+    },
+    // 120 - Ha: 'は';
+    Production {
+        lhs: 25,
+        production: &[ParseType::T(49)],
+    },
+    // 121 - LBracket: '【';
+    Production {
+        lhs: 26,
+        production: &[ParseType::T(50)],
+    },
+    // 122 - RBracket: '】';
+    Production {
+        lhs: 53,
+        production: &[ParseType::T(51)],
+    },
+    // 123 - Camma: '、';
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(52)],
+    },
+    // 124 - Period: '。';
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(53)],
+    },
+    // 125 - Multi: '×';
+    Production {
+        lhs: 37,
+        production: &[ParseType::T(54)],
+    },
+    // 126 - Percent: '%';
+    Production {
+        lhs: 48,
+        production: &[ParseType::T(55)],
+    },
+    // 127 - Number: "[0-9]+(\.[0-9]+)?";
+    Production {
+        lhs: 47,
+        production: &[ParseType::T(56)],
+    },
+];
+
+static TOKENIZERS: Lazy<Vec<(&'static str, Tokenizer)>> = Lazy::new(|| {
+    vec![(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+    )]
+});
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut LeaderSkillGrammar<'t>,
+) -> Result<Tree<ParseTreeType<'t>>>
+where
+    T: AsRef<Path>,
+{
+    let mut llk_parser = LLKParser::new(
+        31,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let token_stream =
+        RefCell::new(TokenStream::new(input, file_name, &TOKENIZERS, MAX_K).unwrap());
+    // Initialize wrapper
+    let mut user_actions = LeaderSkillGrammarAuto::new(user_actions);
+    let result = llk_parser.parse(token_stream, &mut user_actions);
+    match result {
+        Ok(()) => Ok(llk_parser.parse_tree),
+        Err(e) => Err(e),
+    }
+}
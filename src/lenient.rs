@@ -0,0 +1,117 @@
+//! 寛容モードのパース
+//!
+//! 本文全体をパースできない場合は`。`で区切った文毎にパースし、
+//! パースできた文のスキルはそのまま、できなかった文は`SkillEffect::Unrecognized`として返す。
+//!
+//! ```text
+//! 火ドロップを木ドロップに変化。よく分からない効果。1ターンの間、落ちコンなし。
+//! ```
+//!
+//! は`ChangeDropAToB`、`Unrecognized`(`よく分からない効果`)、`DropsNotFalling`の3スキルになる。
+//!
+//! 文をパースできない場合は、読点(`、`)で区切った先頭の節から読めるところまでをパースし、
+//! 残りを再度パースする。`ロックを解除し、よく分からない効果。`は`DropUnLock`と`Unrecognized`になる。
+
+use std::ops::Range;
+
+use super::skill::*;
+use super::skill_grammar::SkillGrammar;
+
+const PERIOD: char = '。';
+const COMMA: char = '、';
+
+/// 寛容モードでパースする。失敗した文は`SkillEffect::Unrecognized`になる
+pub fn parse_lenient(input: &str) -> Vec<Skill> {
+    if let Ok(skills) = SkillGrammar::parse_skills(input) {
+        return skills;
+    }
+
+    sentence_spans(input)
+        .into_iter()
+        .flat_map(|span| parse_sentence(input, span))
+        .collect()
+}
+
+/// 1文をパースする。失敗した場合は、最も長くパースできる先頭の節までをスキルとし、残りを再度パースする
+fn parse_sentence(input: &str, span: Range<usize>) -> Vec<Skill> {
+    let sentence = &input[span.clone()];
+    if let Ok(skills) = SkillGrammar::parse_skills(sentence) {
+        return skills;
+    }
+
+    for (idx, _) in sentence.rmatch_indices(COMMA) {
+        if let Some(skills) = parse_clause(&sentence[..idx]) {
+            let rest = trim_span(input, span.start + idx + COMMA.len_utf8()..span.end);
+
+            return match rest.is_empty() {
+                true => skills,
+                false => skills
+                    .into_iter()
+                    .chain(parse_sentence(input, rest))
+                    .collect(),
+            };
+        }
+    }
+
+    vec![unrecognized(input, span)]
+}
+
+/// 読点より前の節をパースする
+/// * `火を水に変化し`は`火を水に変化。`
+/// * `ロックを解除し`は後続を省略した`ロックを解除し、。`
+fn parse_clause(clause: &str) -> Option<Vec<Skill>> {
+    let candidates = [
+        Some(format!("{clause}{PERIOD}")),
+        clause
+            .strip_suffix('し')
+            .map(|clause| format!("{clause}{PERIOD}")),
+        Some(format!("{clause}{COMMA}{PERIOD}")),
+    ];
+
+    candidates
+        .into_iter()
+        .flatten()
+        .find_map(|candidate| SkillGrammar::parse_skills(&candidate).ok())
+}
+
+/// 文の範囲。句点までを1文とし、前後の空白は含めない
+fn sentence_spans(input: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    for (idx, c) in input.char_indices() {
+        if c == PERIOD {
+            spans.push(start..idx + c.len_utf8());
+            start = idx + c.len_utf8();
+        }
+    }
+    spans.push(start..input.len());
+
+    spans
+        .into_iter()
+        .map(|span| trim_span(input, span))
+        .filter(|span| !span.is_empty())
+        .collect()
+}
+
+fn trim_span(input: &str, span: Range<usize>) -> Range<usize> {
+    let text = &input[span.clone()];
+    let start = span.start + (text.len() - text.trim_start().len());
+    let end = span.end - (text.len() - text.trim_end().len());
+
+    start..end.max(start)
+}
+
+/// 句点を除いた本文と位置
+fn unrecognized(input: &str, span: Range<usize>) -> Skill {
+    let text = input[span.clone()].trim_end_matches(PERIOD);
+    let span = span.start..span.start + text.len();
+
+    Skill {
+        effect: SkillEffect::Unrecognized {
+            text: text.to_owned(),
+            span,
+        },
+        ..Default::default()
+    }
+}
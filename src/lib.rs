@@ -1,7 +1,51 @@
+#![allow(clippy::needless_arbitrary_self_type, clippy::match_like_matches_macro)]
+
 pub mod skill_grammar;
+#[allow(clippy::too_many_arguments)]
 mod skill_grammar_trait;
 pub mod skill_parser;
 
+pub mod skill_en_grammar;
+#[allow(clippy::too_many_arguments)]
+mod skill_en_grammar_trait;
+pub mod skill_en_parser;
+
+pub mod leader_skill_grammar;
+#[allow(clippy::too_many_arguments)]
+mod leader_skill_grammar_trait;
+pub mod leader_skill_parser;
+
+pub mod enemy_skill_grammar;
+#[allow(clippy::too_many_arguments)]
+mod enemy_skill_grammar_trait;
+pub mod enemy_skill_parser;
+
+pub mod active_skill;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod board;
+pub mod category;
+pub mod coverage;
+pub mod diff;
+pub mod drop_set;
+pub mod enemy_skill;
+pub mod english;
+pub mod guarantee;
+pub mod japanese;
+#[cfg(feature = "schema")]
+pub mod json_schema;
+pub mod leader_skill;
+pub mod lenient;
+pub mod monte_carlo;
+pub mod normalize;
+pub mod notation;
+pub mod query;
 pub mod schema;
+pub mod shape;
+pub mod simulator;
 pub mod skill;
+pub mod skyfall;
 pub mod stack_item;
+pub mod svg;
+pub mod terminal;
+mod wording;
@@ -0,0 +1,162 @@
+use super::schema::*;
+use super::simulator::Simulator;
+use super::skill::*;
+
+/// ランダム要素のあるスキルを、シミュレータで繰り返し適用して評価する
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonteCarlo {
+    /// 試行回数
+    pub trials: usize,
+    /// 乱数のシード。試行毎に1ずつずらして使う
+    pub seed: u64,
+    /// 盤面サイズ
+    pub size: Size,
+}
+
+impl Default for MonteCarlo {
+    fn default() -> Self {
+        MonteCarlo {
+            trials: 1000,
+            seed: 0,
+            size: Size::standard(),
+        }
+    }
+}
+
+/// 評価結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Evaluation {
+    pub trials: usize,
+    /// スキル発動直後のドロップ毎の個数の分布
+    /// * 1: N番目の要素は、盤面にN個存在した試行回数
+    pub drop_counts: Vec<(Drop, Vec<usize>)>,
+    /// 発動直後の盤面を消化した際のコンボ数の分布。消化を打ち切った試行は含まない
+    /// * N番目の要素は、Nコンボした試行回数
+    pub combos: Vec<usize>,
+    /// 消化が終わらず打ち切った試行回数
+    pub truncated: usize,
+}
+
+impl MonteCarlo {
+    pub fn new(trials: usize, seed: u64) -> Self {
+        MonteCarlo {
+            trials,
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// 試行毎にランダムな盤面からスキルを適用し、結果を集計する
+    /// シミュレータで適用できないスキル(形が分からない指定型生成)があればエラーを返す
+    pub fn evaluate(self: &Self, skills: &[Skill]) -> miette::Result<Evaluation> {
+        let cells = self.size.width() * self.size.height();
+
+        let mut evaluation = Evaluation {
+            trials: self.trials,
+            drop_counts: Drop::all()
+                .into_iter()
+                .map(|drop| (drop, vec![0; cells + 1]))
+                .collect(),
+            combos: vec![],
+            truncated: 0,
+        };
+
+        for trial in 0..self.trials {
+            let seed = self.seed.wrapping_add(trial as u64);
+            let mut simulator = Simulator::with_random_board(self.size.clone(), seed);
+
+            simulator.apply_skills(skills)?;
+
+            evaluation
+                .drop_counts
+                .iter_mut()
+                .for_each(|(drop, histogram)| {
+                    let qty = simulator.board.count(drop);
+
+                    if histogram.len() <= qty {
+                        histogram.resize(qty + 1, 0);
+                    }
+                    histogram[qty] += 1;
+                });
+
+            let report = simulator.cascade();
+
+            if report.truncated {
+                evaluation.truncated += 1;
+                continue;
+            }
+
+            let total = report.total();
+            if evaluation.combos.len() <= total {
+                evaluation.combos.resize(total + 1, 0);
+            }
+            evaluation.combos[total] += 1;
+        }
+
+        Ok(evaluation)
+    }
+}
+
+impl Evaluation {
+    /// ドロップの個数の期待値
+    pub fn expected_drops(self: &Self, drop: &Drop) -> f64 {
+        self.histogram(drop)
+            .map_or(0.0, |histogram| Self::expected(histogram, self.trials))
+    }
+
+    /// 全ドロップの個数の期待値
+    pub fn expected_drops_all(self: &Self) -> Vec<(Drop, f64)> {
+        self.drop_counts
+            .iter()
+            .map(|(drop, histogram)| (drop.clone(), Self::expected(histogram, self.trials)))
+            .collect()
+    }
+
+    /// ドロップがN個以上存在する確率
+    pub fn probability_at_least(self: &Self, drop: &Drop, qty: usize) -> f64 {
+        self.histogram(drop).map_or(0.0, |histogram| {
+            let hits: usize = histogram.iter().skip(qty).sum();
+            Self::ratio(hits, self.trials)
+        })
+    }
+
+    /// コンボ数の期待値。消化を打ち切った試行を除いて計算する
+    pub fn expected_combos(self: &Self) -> f64 {
+        Self::expected(&self.combos, self.completed())
+    }
+
+    /// Nコンボ以上する確率。消化を打ち切った試行を除いて計算する
+    pub fn probability_combos_at_least(self: &Self, qty: usize) -> f64 {
+        let hits: usize = self.combos.iter().skip(qty).sum();
+        Self::ratio(hits, self.completed())
+    }
+
+    /// 消化が終わらず打ち切った確率
+    pub fn probability_truncated(self: &Self) -> f64 {
+        Self::ratio(self.truncated, self.trials)
+    }
+
+    fn completed(self: &Self) -> usize {
+        self.trials - self.truncated
+    }
+
+    fn histogram(self: &Self, drop: &Drop) -> Option<&Vec<usize>> {
+        self.drop_counts
+            .iter()
+            .find(|(d, _)| d == drop)
+            .map(|(_, histogram)| histogram)
+    }
+
+    fn expected(histogram: &[usize], trials: usize) -> f64 {
+        let sum: usize = histogram.iter().enumerate().map(|(qty, n)| qty * n).sum();
+        Self::ratio(sum, trials)
+    }
+
+    fn ratio(n: usize, trials: usize) -> f64 {
+        if trials == 0 {
+            0.0
+        } else {
+            n as f64 / trials as f64
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! パース前のスキル本文の正規化
+//!
+//! Wikiやゲーム内からコピーした本文は、文字単位の表記揺れで文法に一致しないことがある。
+//! 字句解析の前に以下を揃える。
+//!
+//! * 全角英数字、記号を半角へ(`５個` → `5個`、`％` → `%`、`＋` → `+`)
+//! * 数字に挟まれた`x`、`X`、`*`を`×`へ(`7x6` → `7×6`)
+//! * `,`を`、`へ、末尾の`.`を`。`へ
+//! * 空白、改行を削除
+//! * `。`の直後の`※`から始まる末尾の注記を削除。文中の`※`は残す
+//! * 文法が受け付けない言い回しを受け付ける言い回しへ(`十字形` → `十字型`など)
+//! * 末尾に`。`が無ければ付ける
+
+/// 文法が受け付けない言い回しと、対応する受け付ける言い回し
+const WORDINGS: [(&str, &str); 11] = [
+    ("おじゃま", "お邪魔"),
+    ("L字形", "L字型"),
+    ("Z字形", "Z字型"),
+    ("十字形", "十字型"),
+    ("四隅", "4隅"),
+    ("ターン間", "ターンの間"),
+    ("ロック解除", "ロックを解除"),
+    ("落ちコン無し", "落ちコンなし"),
+    ("ランダムに", "ランダムで"),
+    ("落ちやすくなります", "落ちやすくなる"),
+    ("変化します", "変化"),
+];
+
+const NOTE: char = '※';
+const PERIOD: char = '。';
+
+/// スキル本文を正規化する
+pub fn normalize(input: &str) -> String {
+    let text = strip_note(input);
+
+    let chars: Vec<char> = text
+        .chars()
+        .map(to_half_width)
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let mut result: String = chars
+        .iter()
+        .enumerate()
+        .map(|(idx, &c)| match c {
+            'x' | 'X' | '*' if is_between_digits(&chars, idx) => '×',
+            ',' => '、',
+            '.' if idx == chars.len() - 1 => PERIOD,
+            c => c,
+        })
+        .collect();
+
+    for (from, to) in WORDINGS {
+        result = result.replace(from, to);
+    }
+
+    if !result.is_empty() && !result.ends_with(PERIOD) {
+        result.push(PERIOD);
+    }
+    result
+}
+
+/// 末尾の注記を除く。注記は文の区切り(`。`)の直後から始まり、注記自体が`。`を含むこともある
+fn strip_note(input: &str) -> &str {
+    input
+        .match_indices(NOTE)
+        .map(|(idx, _)| &input[..idx])
+        .find(|text| text.trim_end().ends_with(PERIOD))
+        .unwrap_or(input)
+}
+
+/// 全角の英数字、記号(U+FF01〜U+FF5E)を半角へ
+fn to_half_width(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        c => c,
+    }
+}
+
+fn is_between_digits(chars: &[char], idx: usize) -> bool {
+    let is_digit = |i: Option<usize>| {
+        i.and_then(|i| chars.get(i))
+            .is_some_and(char::is_ascii_digit)
+    };
+
+    is_digit(idx.checked_sub(1)) && is_digit(Some(idx + 1))
+}
@@ -0,0 +1,165 @@
+//! 盤面のテキスト表記
+//!
+//! 1行が盤面の1段に対応し、1文字が1マスのドロップを表す。
+//! 段は改行か`/`で区切る。空白は無視する。
+//!
+//! | 文字 | ドロップ |
+//! |------|----------|
+//! | R | 火 |
+//! | B | 水 |
+//! | G | 木 |
+//! | L | 光 |
+//! | D | 闇 |
+//! | H | 回復 |
+//! | J | お邪魔 |
+//! | P | 毒 |
+//! | M | 猛毒 |
+//! | X | 爆弾 (`B`は水で使っているため) |
+//! | . | 空きマス |
+//!
+//! ドロップの後ろに以下の記号を付けて状態を表す。強化とロックは空きマスには付けられない。
+//!
+//! | 記号 | 状態 |
+//! |------|------|
+//! | + | 強化 |
+//! | * | ロック |
+//! | ? | 雲 |
+//! | # | テープ(操作不可) |
+//! | @ | ルーレット |
+//!
+//! ```text
+//! R+B*GLDH
+//! BGLDHR
+//! ```
+
+use std::fmt;
+
+use miette::{bail, Result};
+
+use super::board::*;
+use super::schema::*;
+
+impl Drop {
+    /// テキスト表記の1文字
+    pub fn notation(self: &Self) -> char {
+        match self {
+            Drop::Colored(Color::Fire) => 'R',
+            Drop::Colored(Color::Water) => 'B',
+            Drop::Colored(Color::Wood) => 'G',
+            Drop::Colored(Color::Lightning) => 'L',
+            Drop::Colored(Color::Dark) => 'D',
+            Drop::NonColored(NonColoredDrop::Recovery) => 'H',
+            Drop::NonColored(NonColoredDrop::Disturb) => 'J',
+            Drop::NonColored(NonColoredDrop::Poison) => 'P',
+            Drop::NonColored(NonColoredDrop::DeadlyPoison) => 'M',
+            Drop::NonColored(NonColoredDrop::Bomb) => 'X',
+        }
+    }
+
+    /// テキスト表記の1文字から変換する
+    pub fn from_notation(c: char) -> Option<Self> {
+        Drop::all().into_iter().find(|drop| drop.notation() == c)
+    }
+}
+
+impl Board {
+    /// テキスト表記から盤面を作る
+    pub fn from_notation(text: &str) -> Result<Self> {
+        // (元の行番号, 段)。空行は段にならないため、エラーには元の行番号を使う
+        let mut rows: Vec<(usize, Vec<Cell>)> = Vec::new();
+
+        for (line_no, line) in text.split(['\n', '/']).enumerate() {
+            let mut row: Vec<Cell> = Vec::new();
+
+            for c in line.chars().filter(|c| !c.is_whitespace()) {
+                match c {
+                    '.' => row.push(Cell::default()),
+                    '+' | '*' | '?' | '#' | '@' => {
+                        let cell = match row.last_mut() {
+                            Some(cell) => cell,
+                            None => bail!("row {}: marker `{c}` must follow a drop.", line_no + 1),
+                        };
+
+                        if cell.is_empty() && matches!(c, '+' | '*') {
+                            bail!("row {}: marker `{c}` cannot follow `.`.", line_no + 1);
+                        }
+
+                        match c {
+                            '+' => cell.powered_up = true,
+                            '*' => cell.locked = true,
+                            '?' => cell.cloud = true,
+                            '#' => cell.tape = true,
+                            _ => cell.roulette = true,
+                        }
+                    }
+                    _ => match Drop::from_notation(c) {
+                        Some(drop) => row.push(Cell::new(drop)),
+                        None => bail!("row {}: unknown drop `{c}`.", line_no + 1),
+                    },
+                }
+            }
+
+            if !row.is_empty() {
+                rows.push((line_no, row));
+            }
+        }
+
+        if rows.is_empty() {
+            bail!("board is empty.");
+        }
+
+        let width = rows[0].1.len();
+        if let Some((line_no, row)) = rows.iter().find(|(_, row)| row.len() != width) {
+            bail!(
+                "row {}: expected {width} cells, found {}.",
+                line_no + 1,
+                row.len()
+            );
+        }
+
+        Board::from_rows(rows.into_iter().map(|(_, row)| row).collect())
+    }
+
+    /// テキスト表記に変換する。段は改行で区切る
+    pub fn to_notation(self: &Self) -> String {
+        self.rows()
+            .into_iter()
+            .map(|row| row.iter().map(Cell::to_notation).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Cell {
+    /// テキスト表記に変換する
+    pub fn to_notation(self: &Self) -> String {
+        let mut result = self
+            .drop
+            .as_ref()
+            .map_or('.', |drop| drop.notation())
+            .to_string();
+
+        if self.powered_up {
+            result.push('+');
+        }
+        if self.locked {
+            result.push('*');
+        }
+        if self.cloud {
+            result.push('?');
+        }
+        if self.tape {
+            result.push('#');
+        }
+        if self.roulette {
+            result.push('@');
+        }
+        result
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_notation())
+    }
+}
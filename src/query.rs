@@ -0,0 +1,398 @@
+//! パース済みスキルに対する検索クエリ
+//!
+//! 1つのスキル本文をパースした`[Skill]`に対して、条件に一致するかを判定する。
+//!
+//! ```text
+//! generates(light)>=10 and effect:DropUnLock
+//! (converts_to:water or converts_to:水) and not turns>=2
+//! ```
+//!
+//! | 条件 | 一致するスキル |
+//! |------|----------------|
+//! | `converts_to:X` | 単色変換、全ドロップ変化の変換先にXを含む |
+//! | `generates(X)>=N` | ランダム生成、指定型生成で生成するXの合計がN個以上 |
+//! | `turns>=N` | 効果ターン数がNターン以上 |
+//! | `shape:NAME` | 指定型生成にNAMEの形状を含む |
+//! | `falloff(X)` | Xのドロップ目覚め |
+//! | `effect:NAME` | `SkillEffect`のバリアント名がNAME(大文字小文字は区別しない。存在しない名前はエラー) |
+//!
+//! * 比較は`>=`、`<=`、`>`、`<`、`=`。`generates(X)`の比較を省略した場合は1個以上
+//! * 指定型生成の個数は6×5の盤面で数える
+//! * ドロップ名は英語名(`fire`、`water`、`wood`、`light`、`dark`、`heal`、`jammer`、`bomb`、`poison`、`mortal_poison`)か日本語名(`火`、`回復`など)
+//! * 形状名は`col`、`row`、`l`、`z`、`cross`、`small_cross`、`square`、`perimeter`、`top`、`center`、`bottom`、`corners`、`spiderweb`、`crescent_moon`、`oblique`、`some_kind`。`cross`は十字型(5個)も含む
+//! * 条件は`and`、`or`、`not`と括弧で組み合わせる。優先順位は`not`、`and`、`or`の順
+
+use std::str::FromStr;
+
+use miette::{bail, Result};
+
+use super::schema::*;
+use super::skill::*;
+
+/// 検索クエリ
+#[derive(Clone, Debug, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    /// 変換先にドロップを含む
+    ConvertsTo(Drop),
+    /// ドロップの生成数
+    Generates(Drop, Comparison),
+    /// 効果ターン数
+    Turns(Comparison),
+    /// 指定型生成の形状名
+    Shape(String),
+    /// ドロップ目覚め
+    Falloff(Drop),
+    /// `SkillEffect`のバリアント名(`SkillEffect::NAMES`の表記)
+    Effect(String),
+}
+
+/// 数値の比較
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison(pub Op, pub usize);
+
+/// 比較演算子
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    /// `>=`
+    Ge,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `<`
+    Lt,
+    /// `=`
+    Eq,
+}
+
+impl Comparison {
+    pub fn test(self: &Self, value: usize) -> bool {
+        let Comparison(op, rhs) = self;
+        match op {
+            Op::Ge => value >= *rhs,
+            Op::Le => value <= *rhs,
+            Op::Gt => value > *rhs,
+            Op::Lt => value < *rhs,
+            Op::Eq => value == *rhs,
+        }
+    }
+}
+
+/// 形状名
+const SHAPE_NAMES: [&str; 16] = [
+    "col",
+    "row",
+    "l",
+    "z",
+    "cross",
+    "small_cross",
+    "square",
+    "perimeter",
+    "top",
+    "center",
+    "bottom",
+    "corners",
+    "spiderweb",
+    "crescent_moon",
+    "oblique",
+    "some_kind",
+];
+
+/// 形状名が`ShapeType`に一致するか。`cross`は十字型(5個)も含む
+fn is_shape(name: &str, shape: &ShapeType) -> bool {
+    match (name, shape) {
+        ("col", ShapeType::Col(..))
+        | ("row", ShapeType::Row(..))
+        | ("l", ShapeType::LShape(..))
+        | ("z", ShapeType::ZShape(..))
+        | ("cross", ShapeType::CrossShape(..) | ShapeType::SmallCrossShape(..))
+        | ("small_cross", ShapeType::SmallCrossShape(..))
+        | ("square", ShapeType::Square(..))
+        | ("perimeter", ShapeType::ShapeOfBoardPerimeter(..))
+        | ("top", ShapeType::ShapeOfBoardTop(..))
+        | ("center", ShapeType::ShapeOfBoardCenter(..))
+        | ("bottom", ShapeType::ShapeOfBoardBottom(..))
+        | ("corners", ShapeType::ShapeOfBoardCorners(..))
+        | ("spiderweb", ShapeType::ShapeOfSpiderweb(..))
+        | ("crescent_moon", ShapeType::ShapeOfCrescentMoon(..))
+        | ("oblique", ShapeType::ShapeOfOblique(..))
+        | ("some_kind", ShapeType::ShapeOfSomeKind(..)) => true,
+        _ => false,
+    }
+}
+
+impl Query {
+    /// クエリ文字列をパースする
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = QueryParser { tokens, pos: 0 };
+
+        let query = parser.or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("unexpected {token:?} in query {input:?}");
+        }
+        Ok(query)
+    }
+
+    /// 1つのスキル本文をパースしたスキルが条件に一致するか
+    pub fn matches(self: &Self, skills: &[Skill]) -> bool {
+        match self {
+            Query::And(a, b) => a.matches(skills) && b.matches(skills),
+            Query::Or(a, b) => a.matches(skills) || b.matches(skills),
+            Query::Not(a) => !a.matches(skills),
+            Query::ConvertsTo(drop) => skills.iter().any(|skill| match &skill.effect {
+                SkillEffect::ChangeDropAToB(_, to) => to.contains(drop),
+                SkillEffect::ChangeAllOfBoard(to) => to.contains(drop),
+                _ => false,
+            }),
+            Query::Generates(drop, comparison) => comparison.test(generated(skills, drop)),
+            Query::Turns(comparison) => skills
+                .iter()
+                .filter_map(|skill| skill.turns_of_apply)
+                .any(|turns| comparison.test(turns)),
+            Query::Shape(name) => skills.iter().any(|skill| match &skill.effect {
+                SkillEffect::DropShapeGen(shapes) => {
+                    shapes.iter().any(|shape| is_shape(name, shape))
+                }
+                _ => false,
+            }),
+            Query::Falloff(drop) => skills.iter().any(|skill| match &skill.effect {
+                SkillEffect::DropFalloff(drops, _) => drops.contains(drop),
+                _ => false,
+            }),
+            Query::Effect(name) => skills.iter().any(|skill| skill.effect.name() == name),
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = miette::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Query::parse(s)
+    }
+}
+
+/// ランダム生成、指定型生成で生成するドロップの合計
+fn generated(skills: &[Skill], drop: &Drop) -> usize {
+    let size = Size::standard();
+
+    skills
+        .iter()
+        .map(|skill| match &skill.effect {
+            SkillEffect::GenRandomDrop(_, to) => to
+                .iter()
+                .filter(|(d, _)| d == drop)
+                .map(|(_, qty)| qty)
+                .sum(),
+            // 形が分からない形状は数えない
+            SkillEffect::DropShapeGen(shapes) => shapes
+                .iter()
+                .flat_map(|shape| shape.mask(&size).unwrap_or_default())
+                .filter(|(_, _, d)| d == drop)
+                .count(),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// 英語名、日本語名、バリアント名からドロップを返す。大文字小文字、空白、`_`は区別しない
+fn parse_drop(name: &str) -> Result<Drop> {
+    let key = |s: &str| s.replace([' ', '_'], "").to_lowercase();
+    let name = key(name);
+
+    match Drop::all().into_iter().find(|drop| {
+        let variant = match drop {
+            Drop::Colored(color) => format!("{color:?}"),
+            Drop::NonColored(drop) => format!("{drop:?}"),
+        };
+        [drop.to_english(), drop.to_japanese(), &variant]
+            .iter()
+            .any(|s| key(s) == name)
+    }) {
+        Some(drop) => Ok(drop),
+        None => bail!("unknown drop {name:?} in query"),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Op(Op),
+    Word(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '>' | '<' => {
+                let or_equal = chars.next_if_eq(&'=').is_some();
+                Token::Op(match (c, or_equal) {
+                    ('>', true) => Op::Ge,
+                    ('>', false) => Op::Gt,
+                    ('<', true) => Op::Le,
+                    _ => Op::Lt,
+                })
+            }
+            '=' => Token::Op(Op::Eq),
+            c => {
+                let mut word = c.to_string();
+                while let Some(c) = chars.next_if(|c| !is_delimiter(*c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+        };
+        tokens.push(token);
+    }
+
+    if tokens.is_empty() {
+        bail!("empty query");
+    }
+    Ok(tokens)
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '<' | '>' | '=')
+}
+
+/// 再帰下降パーサ
+/// * or   := and ("or" and)*
+/// * and  := not ("and" not)*
+/// * not  := "not" not | "(" or ")" | 条件
+struct QueryParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl QueryParser {
+    fn next(self: &mut Self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn next_if_keyword(self: &mut Self, keyword: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(self: &mut Self, expected: Token) -> Result<()> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            token => bail!("expected {expected:?} but found {token:?} in query"),
+        }
+    }
+
+    fn word(self: &mut Self) -> Result<String> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            token => bail!("expected a word but found {token:?} in query"),
+        }
+    }
+
+    fn comparison(self: &mut Self) -> Result<Comparison> {
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            token => bail!("expected a comparison operator but found {token:?} in query"),
+        };
+        let word = self.word()?;
+
+        match word.parse() {
+            Ok(value) => Ok(Comparison(op, value)),
+            Err(_) => bail!("expected a number but found {word:?} in query"),
+        }
+    }
+
+    /// `(X)`のドロップ
+    fn drop_arg(self: &mut Self) -> Result<Drop> {
+        self.expect(Token::LParen)?;
+        let drop = parse_drop(&self.word()?)?;
+        self.expect(Token::RParen)?;
+        Ok(drop)
+    }
+
+    fn or(self: &mut Self) -> Result<Query> {
+        let mut query = self.and()?;
+        while self.next_if_keyword("or") {
+            query = Query::Or(Box::new(query), Box::new(self.and()?));
+        }
+        Ok(query)
+    }
+
+    fn and(self: &mut Self) -> Result<Query> {
+        let mut query = self.not()?;
+        while self.next_if_keyword("and") {
+            query = Query::And(Box::new(query), Box::new(self.not()?));
+        }
+        Ok(query)
+    }
+
+    fn not(self: &mut Self) -> Result<Query> {
+        if self.next_if_keyword("not") {
+            return Ok(Query::Not(Box::new(self.not()?)));
+        }
+
+        match self.next() {
+            Some(Token::LParen) => {
+                let query = self.or()?;
+                self.expect(Token::RParen)?;
+                Ok(query)
+            }
+            Some(Token::Word(word)) => self.predicate(&word),
+            token => bail!("expected a condition but found {token:?} in query"),
+        }
+    }
+
+    fn predicate(self: &mut Self, word: &str) -> Result<Query> {
+        if let Some((key, value)) = word.split_once(':') {
+            return match key.to_lowercase().as_str() {
+                "converts_to" => Ok(Query::ConvertsTo(parse_drop(value)?)),
+                "shape" => {
+                    let name = value.to_lowercase();
+                    match SHAPE_NAMES.contains(&name.as_str()) {
+                        true => Ok(Query::Shape(name)),
+                        false => bail!("unknown shape {value:?} in query"),
+                    }
+                }
+                "effect" => match SkillEffect::NAMES
+                    .iter()
+                    .find(|name| name.eq_ignore_ascii_case(value))
+                {
+                    Some(name) => Ok(Query::Effect(name.to_string())),
+                    None => bail!("unknown effect {value:?} in query"),
+                },
+                _ => bail!("unknown condition {word:?} in query"),
+            };
+        }
+
+        match word.to_lowercase().as_str() {
+            "turns" => Ok(Query::Turns(self.comparison()?)),
+            "generates" => {
+                let drop = self.drop_arg()?;
+                let comparison = match self.tokens.get(self.pos) {
+                    Some(Token::Op(_)) => self.comparison()?,
+                    _ => Comparison(Op::Ge, 1),
+                };
+                Ok(Query::Generates(drop, comparison))
+            }
+            "falloff" => Ok(Query::Falloff(self.drop_arg()?)),
+            _ => bail!("unknown condition {word:?} in query"),
+        }
+    }
+}
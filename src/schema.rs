@@ -1,3 +1,5 @@
+use super::drop_set::DropSet;
+
 /// ドロップリスト
 pub type Drops = Vec<Drop>;
 
@@ -5,6 +7,9 @@ pub type GenDropsWithQty = Vec<(Drop, usize)>;
 
 /// 何かのドロップを表す
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Drop {
     Colored(Color),
     NonColored(NonColoredDrop),
@@ -14,10 +19,17 @@ impl Drop {
     pub(super) fn default() -> Self {
         Self::Colored(Color::Fire)
     }
+
+    /// 5属性＋回復、お邪魔、爆弾、毒、猛毒
+    pub fn all() -> Drops {
+        DropSet::all().to_drops()
+    }
 }
 
 /// 何かの色を表す
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Color {
     #[default]
     Fire,
@@ -47,6 +59,8 @@ impl From<&str> for Color {
 
 /// 色を持たないドロップ
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum NonColoredDrop {
     #[default]
     Recovery,
@@ -76,6 +90,8 @@ impl From<&str> for NonColoredDrop {
 
 /// 位置を表す
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Position {
     Left,
     Right,
@@ -103,6 +119,9 @@ impl From<&str> for Position {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum GenShapeRowCol {
     Row(isize),
     Col(isize),
@@ -129,6 +148,8 @@ pub type GenPositions = Vec<GenShapeRowCol>;
 
 /// スキルの効果がどの程度及ぼすかの変化量
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum VolumeVariation {
     // ほんの少し
     LittleMore,
@@ -140,11 +161,33 @@ pub enum VolumeVariation {
     Only,
 }
 
-/// row * col
+/// 横 × 縦のマス数。`7×6`なら`Size(7, 6)`
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Size(pub usize, pub usize);
 
+impl Size {
+    /// 通常の盤面サイズ(6×5)
+    pub fn standard() -> Self {
+        Size(6, 5)
+    }
+
+    /// 横のマス数。`7×6`なら7
+    pub fn width(self: &Self) -> usize {
+        self.0
+    }
+
+    /// 縦のマス数。`7×6`なら6
+    pub fn height(self: &Self) -> usize {
+        self.1
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum BoardPosition {
     Row(isize),
     Col(isize),
@@ -0,0 +1,214 @@
+use miette::{bail, Result};
+
+use super::board::Board;
+use super::schema::*;
+use super::skill::*;
+
+/// 形状生成で埋めるマス
+/// * 0: row
+/// * 1: col
+/// * 2: 生成するドロップ
+pub type ShapeMask = Vec<(usize, usize, Drop)>;
+
+/// 形状を構成するマスの、左上からの相対位置
+type Stamp = Vec<(usize, usize)>;
+
+impl ShapeType {
+    /// 指定サイズの盤面で、形状生成が埋めるマスを返す。
+    /// ゲームではランダムな位置に生成される形状(L字型、正方形など)は、
+    /// 左上から順に重ならない位置へ配置したものを返す。
+    /// 形が分からない`ShapeOfSomeKind`はエラーを返す。
+    pub fn mask(self: &Self, size: &Size) -> Result<ShapeMask> {
+        let (width, height) = (size.width(), size.height());
+
+        let mask = match self {
+            Self::Col(idx, drop) => Board::resolve_idx(*idx, width)
+                .map(|col| (0..height).map(|row| (row, col, drop.clone())).collect())
+                .unwrap_or_default(),
+            Self::Row(idx, drop) => Board::resolve_idx(*idx, height)
+                .map(|row| (0..width).map(|col| (row, col, drop.clone())).collect())
+                .unwrap_or_default(),
+            Self::LShape(drop, qty) => {
+                let stamps = vec![
+                    vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+                    vec![(0, 2), (1, 2), (2, 2), (2, 1), (2, 0)],
+                    vec![(0, 0), (0, 1), (0, 2), (1, 0), (2, 0)],
+                    vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+                ];
+                Self::place(size, &stamps, *qty, drop)
+            }
+            Self::ZShape(drop) => Self::cells_of(size, drop, |row, col| {
+                if row == 0 || row + 1 >= height {
+                    true
+                } else {
+                    // 右上から左下へ斜めに結ぶ。高さが2以下の盤面は上端と下端のみになる
+                    let diagonal = (row * (width - 1) + (height - 1) / 2) / (height - 1);
+                    col == width - 1 - diagonal
+                }
+            }),
+            Self::SmallCrossShape(drop, qty) => {
+                let stamps = vec![vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]];
+                Self::place(size, &stamps, *qty, drop)
+            }
+            Self::CrossShape(drop) => Self::cells_of(size, drop, |row, col| {
+                row == height / 2 || col == (width - 1) / 2
+            }),
+            Self::Square(drop, side, qty) => {
+                let stamp = (0..*side)
+                    .flat_map(|row| (0..*side).map(move |col| (row, col)))
+                    .collect();
+                Self::place(size, &[stamp], *qty, drop)
+            }
+            Self::ShapeOfBoardPerimeter(drop) => Self::cells_of(size, drop, |row, col| {
+                row == 0 || col == 0 || row == height - 1 || col == width - 1
+            }),
+            Self::ShapeOfBoardTop(drop, qty) => {
+                Self::cells_of(size, drop, |row, col| row * width + col < *qty)
+            }
+            Self::ShapeOfBoardCenter(drop) => Self::cells_of(size, drop, |row, col| {
+                row != 0 && col != 0 && row != height - 1 && col != width - 1
+            }),
+            Self::ShapeOfBoardBottom(drop, qty) => Self::cells_of(size, drop, |row, col| {
+                (height - 1 - row) * width + (width - 1 - col) < *qty
+            }),
+            Self::ShapeOfBoardCorners(drop, qty) => {
+                // 角に近い順に並べたマス
+                let mut offsets: Vec<(usize, usize)> = (0..height)
+                    .flat_map(|row| (0..width).map(move |col| (row, col)))
+                    .collect();
+                offsets.sort_by_key(|(row, col)| (row + col, *row));
+
+                let corner = offsets.into_iter().take(*qty).collect::<Vec<_>>();
+
+                let mut result: ShapeMask = Vec::new();
+                corner.iter().for_each(|(row, col)| {
+                    [
+                        (*row, *col),
+                        (*row, width - 1 - col),
+                        (height - 1 - row, *col),
+                        (height - 1 - row, width - 1 - col),
+                    ]
+                    .into_iter()
+                    .for_each(|(row, col)| {
+                        if !result.iter().any(|(r, c, _)| *r == row && *c == col) {
+                            result.push((row, col, drop.clone()));
+                        }
+                    });
+                });
+                result.sort_by_key(|(row, col, _)| (*row, *col));
+                result
+            }
+            Self::ShapeOfSpiderweb(web, center) => {
+                let (center_row, center_col) = (height / 2, width.saturating_sub(1) / 2);
+
+                let mut result = Self::cells_of(size, web, |row, col| {
+                    let ring = row.min(col).min(height - 1 - row).min(width - 1 - col);
+                    ring % 2 == 0 && row != center_row && col != center_col
+                });
+                result.append(&mut Self::cells_of(size, center, |row, col| {
+                    row == center_row || col == center_col
+                }));
+                result.sort_by_key(|(row, col, _)| (*row, *col));
+                result
+            }
+            Self::ShapeOfCrescentMoon(drop) => Self::cells_of(size, drop, |row, col| {
+                let center = width / 2;
+                if row == 0 || row == height - 1 {
+                    col + 1 == center || col == center
+                } else {
+                    col + 2 == center
+                }
+            }),
+            Self::ShapeOfOblique(drop) => Self::cells_of(size, drop, |row, col| row == col),
+            Self::ShapeOfSomeKind(drop, some_kind, qty) => {
+                let stamps = match some_kind.as_str() {
+                    "7" => vec![vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 1), (3, 1)]],
+                    _ => bail!("unsupported shape {some_kind:?}の形"),
+                };
+                Self::place(size, &stamps, *qty, drop)
+            }
+        };
+        Ok(mask)
+    }
+
+    /// 条件に合うマスを全て埋める
+    fn cells_of<F>(size: &Size, drop: &Drop, fun: F) -> ShapeMask
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        (0..size.height())
+            .flat_map(|row| (0..size.width()).map(move |col| (row, col)))
+            .filter(|(row, col)| fun(*row, *col))
+            .map(|(row, col)| (row, col, drop.clone()))
+            .collect()
+    }
+
+    /// 形状を左上から順に、既に配置した形状と重ならず隣接もしない位置へ配置する。
+    /// 隣接しない位置が無い場合は、重ならない位置へ配置する。
+    fn place(size: &Size, stamps: &[Stamp], qty: usize, drop: &Drop) -> ShapeMask {
+        let mut placed: Vec<(usize, usize)> = Vec::new();
+
+        for _ in 0..qty {
+            let found = Self::find_place(size, stamps, &placed, true)
+                .or_else(|| Self::find_place(size, stamps, &placed, false));
+
+            match found {
+                Some(mut cells) => placed.append(&mut cells),
+                None => break,
+            }
+        }
+
+        placed.sort();
+        placed
+            .into_iter()
+            .map(|(row, col)| (row, col, drop.clone()))
+            .collect()
+    }
+
+    fn find_place(
+        size: &Size,
+        stamps: &[Stamp],
+        placed: &[(usize, usize)],
+        keep_apart: bool,
+    ) -> Option<Vec<(usize, usize)>> {
+        let anchors =
+            (0..size.height()).flat_map(|row| (0..size.width()).map(move |col| (row, col)));
+
+        for (row, col) in anchors {
+            for stamp in stamps {
+                let cells: Vec<(usize, usize)> =
+                    stamp.iter().map(|(r, c)| (row + r, col + c)).collect();
+
+                let in_board = cells
+                    .iter()
+                    .all(|(r, c)| *r < size.height() && *c < size.width());
+
+                let free = cells.iter().all(|cell| {
+                    placed.iter().all(|p| {
+                        let distance = p.0.abs_diff(cell.0) + p.1.abs_diff(cell.1);
+                        distance > if keep_apart { 1 } else { 0 }
+                    })
+                });
+
+                if in_board && free {
+                    return Some(cells);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl SkillEffect {
+    /// 形状生成スキルが埋めるマス。形状生成以外のスキルは空
+    pub fn shape_mask(self: &Self, size: &Size) -> Result<ShapeMask> {
+        match self {
+            Self::DropShapeGen(shapes) => Ok(shapes
+                .iter()
+                .map(|shape| shape.mask(size))
+                .collect::<Result<Vec<_>>>()?
+                .concat()),
+            _ => Ok(vec![]),
+        }
+    }
+}
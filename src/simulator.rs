@@ -0,0 +1,498 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::board::*;
+use super::schema::*;
+use super::skill::*;
+use super::skyfall::Skyfall;
+
+/// 全ドロップ変化で、変化先のドロップ毎に最低限生成される個数
+pub const MIN_DROPS_OF_BOARD_CHANGE: usize = 3;
+
+/// 落ちコンなしの補充で、消せる組み合わせを避けるために引き直す回数
+const RETRY_OF_NOT_FALLING: usize = 100;
+
+/// 盤面の消化で、消去、落下、補充を繰り返す最大の回数。
+/// 単色しか落ちてこない場合などは補充の度に消せる組み合わせができ、消化が終わらない
+pub const MAX_CASCADE_ROUNDS: usize = 100;
+
+/// 1コンボ分のドロップ
+#[derive(Clone, Debug, PartialEq)]
+pub struct Combo {
+    pub drop: Drop,
+    /// 消えたマス (row, col)
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// 盤面を消化した結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComboReport {
+    /// ドロップ毎のコンボ数
+    pub combos: Vec<(Drop, usize)>,
+    /// 落ちコンが発生した回数。最初の消去は含まない
+    pub cascades: usize,
+    /// `MAX_CASCADE_ROUNDS`回消去しても消せるドロップが残り、消化を打ち切った
+    pub truncated: bool,
+}
+
+impl ComboReport {
+    /// コンボ数の合計
+    pub fn total(self: &Self) -> usize {
+        self.combos.iter().map(|(_, qty)| qty).sum()
+    }
+
+    /// 指定したドロップのコンボ数
+    pub fn combos_of(self: &Self, drop: &Drop) -> usize {
+        self.combos
+            .iter()
+            .find(|(d, _)| d == drop)
+            .map_or(0, |(_, qty)| *qty)
+    }
+
+    fn add(self: &mut Self, drop: &Drop) {
+        match self.combos.iter_mut().find(|(d, _)| d == drop) {
+            Some((_, qty)) => *qty += 1,
+            None => self.combos.push((drop.clone(), 1)),
+        }
+    }
+}
+
+/// スキルを盤面へ適用し、盤面の消化をシミュレートする。
+/// 乱数はシードから生成するため、同じシードなら同じ結果になる。
+#[derive(Clone, Debug)]
+pub struct Simulator {
+    pub board: Board,
+    pub skyfall: Skyfall,
+    /// 落ちコンなし
+    pub drops_not_falling: bool,
+    /// ロック状態で落ちてくるドロップ
+    pub fall_lock_drops: Drops,
+    rng: StdRng,
+}
+
+impl Simulator {
+    pub fn new(board: Board, seed: u64) -> Self {
+        Simulator {
+            board,
+            skyfall: Skyfall::new(),
+            drops_not_falling: false,
+            fall_lock_drops: vec![],
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// 消せる組み合わせの無いランダムな盤面から始める
+    pub fn with_random_board(size: Size, seed: u64) -> Self {
+        let mut simulator = Self::new(Board::new(size), seed);
+
+        simulator.fill(true);
+        simulator
+    }
+
+    pub fn apply_skills(self: &mut Self, skills: &[Skill]) -> miette::Result<()> {
+        skills.iter().try_for_each(|skill| self.apply_skill(skill))
+    }
+
+    /// スキルを盤面へ適用する。ロック状態のドロップは変化しない。
+    /// 形が分からない指定型生成はエラーを返す。
+    pub fn apply_skill(self: &mut Self, skill: &Skill) -> miette::Result<()> {
+        match &skill.effect {
+            SkillEffect::ChangeDropAToB(from, to) => {
+                self.unlocked_positions()
+                    .into_iter()
+                    .filter(|(row, col)| {
+                        let drop = self.board.cell(*row, *col).drop.as_ref();
+                        drop.is_some_and(|drop| from.contains(drop))
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .for_each(|(row, col)| {
+                        // 変換先が無い場合は変換しない
+                        if let Some(drop) = to.choose(&mut self.rng) {
+                            self.board.cell_mut(row, col).replace(drop.clone());
+                        }
+                    });
+            }
+            SkillEffect::ChangeAllOfBoard(drops) => self.change_all_of_board(drops),
+            SkillEffect::GenRandomDrop(exc, gen_list) => {
+                // 除外するドロップ以外のマスへ優先して生成し、足りない場合は除外するドロップのマスへ生成する
+                let (mut candidates, mut fallback): (Vec<_>, Vec<_>) = self
+                    .unlocked_positions()
+                    .into_iter()
+                    .partition(|(row, col)| {
+                        let drop = self.board.cell(*row, *col).drop.as_ref();
+                        drop.is_none_or(|drop| !exc.contains(drop))
+                    });
+                candidates.shuffle(&mut self.rng);
+                fallback.shuffle(&mut self.rng);
+
+                // 後ろから取り出すため、優先するマスを後ろに置く
+                fallback.append(&mut candidates);
+                let mut candidates = fallback;
+
+                gen_list.iter().for_each(|(drop, qty)| {
+                    let at = candidates.len().saturating_sub(*qty);
+
+                    candidates.split_off(at).into_iter().for_each(|(row, col)| {
+                        self.board.cell_mut(row, col).replace(drop.clone());
+                    });
+                });
+            }
+            SkillEffect::DropShapeGen(_) => {
+                let mask = skill.effect.shape_mask(&self.board.size)?;
+
+                for (row, col, drop) in mask {
+                    let cell = self.board.get_mut(row, col)?;
+                    if !cell.locked {
+                        cell.replace(drop);
+                    }
+                }
+            }
+            SkillEffect::DropUnLock => self
+                .board
+                .cells_mut()
+                .iter_mut()
+                .for_each(|cell| cell.locked = false),
+            SkillEffect::DropLock(drops) => self.board.cells_mut().iter_mut().for_each(|cell| {
+                if cell.drop.as_ref().is_some_and(|drop| drops.contains(drop)) {
+                    cell.locked = true;
+                }
+            }),
+            SkillEffect::DropRefresh => {
+                self.board
+                    .cells_mut()
+                    .iter_mut()
+                    .for_each(|cell| cell.clear());
+                self.fill(true);
+            }
+            SkillEffect::DropPowerUp(drops) => self.board.cells_mut().iter_mut().for_each(|cell| {
+                if cell.drop.as_ref().is_some_and(|drop| drops.contains(drop)) {
+                    cell.powered_up = true;
+                }
+            }),
+            SkillEffect::DropFalloff(_, _) | SkillEffect::PowerupDropFalloff(_) => {
+                self.skyfall.apply(skill)
+            }
+            SkillEffect::FallLockDrop(drops) => self.fall_lock_drops = drops.to_vec(),
+            SkillEffect::DropsNotFalling => self.drops_not_falling = true,
+            SkillEffect::GenCloud(position, size) => self.gen_cloud(position, size),
+            SkillEffect::GenTeap(position) => self.gen_tape(position),
+            SkillEffect::GenRoulette(qty) => self.gen_roulette(*qty),
+            SkillEffect::ChangeBoardSize(size) => {
+                self.board = Board::new(size.clone());
+                self.fill(true);
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// 盤面上の消せるドロップを全て探す
+    pub fn find_combos(self: &Self) -> Vec<Combo> {
+        let (width, height) = (self.board.width(), self.board.height());
+        let mut matched = vec![false; width * height];
+
+        // 縦横に3つ以上並んでいるマスに印をつける
+        for (row, col) in self.board.positions() {
+            if let Some(drop) = self.matchable(row, col) {
+                let same = |r: usize, c: usize| self.matchable(r, c) == Some(drop);
+
+                if col + 2 < width && same(row, col + 1) && same(row, col + 2) {
+                    (0..3).for_each(|i| matched[row * width + col + i] = true);
+                }
+                if row + 2 < height && same(row + 1, col) && same(row + 2, col) {
+                    (0..3).for_each(|i| matched[(row + i) * width + col] = true);
+                }
+            }
+        }
+
+        // 隣接している同じドロップは1コンボとして扱う
+        let mut visited = vec![false; width * height];
+        let mut combos: Vec<Combo> = Vec::new();
+
+        for (row, col) in self.board.positions() {
+            if !matched[row * width + col] || visited[row * width + col] {
+                continue;
+            }
+
+            let drop = self.matchable(row, col).unwrap().clone();
+            let mut cells: Vec<(usize, usize)> = Vec::new();
+            let mut stack = vec![(row, col)];
+            visited[row * width + col] = true;
+
+            while let Some((r, c)) = stack.pop() {
+                cells.push((r, c));
+
+                let mut neighbors = vec![];
+                if r > 0 {
+                    neighbors.push((r - 1, c));
+                }
+                if r + 1 < height {
+                    neighbors.push((r + 1, c));
+                }
+                if c > 0 {
+                    neighbors.push((r, c - 1));
+                }
+                if c + 1 < width {
+                    neighbors.push((r, c + 1));
+                }
+
+                neighbors.into_iter().for_each(|(nr, nc)| {
+                    let idx = nr * width + nc;
+                    if matched[idx] && !visited[idx] && self.matchable(nr, nc) == Some(&drop) {
+                        visited[idx] = true;
+                        stack.push((nr, nc));
+                    }
+                });
+            }
+
+            cells.sort();
+            combos.push(Combo { drop, cells });
+        }
+
+        combos
+    }
+
+    /// 消せるドロップが無くなるまで、消去、落下、補充を繰り返す。
+    /// `MAX_CASCADE_ROUNDS`回で終わらない場合は打ち切り、`ComboReport::truncated`を立てる
+    pub fn cascade(self: &mut Self) -> ComboReport {
+        let mut report = ComboReport::default();
+        let mut rounds = 0;
+
+        loop {
+            let combos = self.find_combos();
+
+            if combos.is_empty() {
+                break;
+            }
+
+            if rounds == MAX_CASCADE_ROUNDS {
+                report.truncated = true;
+                break;
+            }
+
+            combos.iter().for_each(|combo| {
+                report.add(&combo.drop);
+
+                combo
+                    .cells
+                    .iter()
+                    .for_each(|(row, col)| self.board.cell_mut(*row, *col).clear());
+            });
+
+            self.fall();
+            self.fill(self.drops_not_falling);
+            rounds += 1;
+        }
+
+        report.cascades = rounds.max(1) - 1;
+        report
+    }
+
+    /// 空きマスへ上のドロップを落とす。雲、テープ、ルーレットはマスに残る
+    pub fn fall(self: &mut Self) {
+        for col in 0..self.board.width() {
+            let stacked: Vec<Cell> = (0..self.board.height())
+                .rev()
+                .map(|row| self.board.cell(row, col).clone())
+                .filter(|cell| !cell.is_empty())
+                .collect();
+
+            for row in (0..self.board.height()).rev() {
+                let from = stacked.get(self.board.height() - 1 - row);
+                let cell = self.board.cell_mut(row, col);
+
+                match from {
+                    Some(from) => {
+                        cell.drop = from.drop.clone();
+                        cell.locked = from.locked;
+                        cell.powered_up = from.powered_up;
+                    }
+                    None => cell.clear(),
+                }
+            }
+        }
+    }
+
+    /// 空きマスを落ちてくるドロップで埋める。
+    /// * avoid_match: 消せる組み合わせができないように埋める。埋められないマスは空きマスのまま残す
+    ///
+    /// 落ちてくるドロップが無い場合も、空きマスのまま残す。
+    pub fn fill(self: &mut Self, avoid_match: bool) {
+        for (row, col) in self.board.positions() {
+            if !self.board.cell(row, col).is_empty() {
+                continue;
+            }
+
+            let tries = if avoid_match { RETRY_OF_NOT_FALLING } else { 1 };
+
+            for _ in 0..tries {
+                let Some(drop) = self.next_drop() else {
+                    return;
+                };
+                let locked = self.fall_lock_drops.contains(&drop);
+                let powered_up = self.rng.gen_bool(self.skyfall.powerup_rate());
+
+                let cell = self.board.cell_mut(row, col);
+                cell.drop = Some(drop);
+                cell.locked = locked;
+                cell.powered_up = powered_up;
+
+                if !avoid_match || !self.makes_match(row, col) {
+                    break;
+                }
+                self.board.cell_mut(row, col).clear();
+            }
+        }
+    }
+
+    /// 次に落ちてくるドロップ。落ちてくるドロップが無い場合は`None`
+    fn next_drop(self: &mut Self) -> Option<Drop> {
+        let distribution = self.skyfall.distribution();
+        let mut point: f64 = self.rng.gen();
+
+        for (drop, probability) in distribution.iter() {
+            if point < *probability {
+                return Some(drop.clone());
+            }
+            point -= probability;
+        }
+
+        distribution.last().map(|(drop, _)| drop.clone())
+    }
+
+    fn change_all_of_board(self: &mut Self, drops: &Drops) {
+        if drops.is_empty() {
+            return;
+        }
+
+        let mut positions = self.unlocked_positions();
+        positions.shuffle(&mut self.rng);
+
+        let guaranteed = MIN_DROPS_OF_BOARD_CHANGE.min(positions.len() / drops.len());
+
+        let mut new_drops: Drops = drops
+            .iter()
+            .flat_map(|drop| vec![drop.clone(); guaranteed])
+            .collect();
+
+        // 残りはランダム
+        let rest = positions.len().saturating_sub(new_drops.len());
+        new_drops.extend((0..rest).filter_map(|_| drops.choose(&mut self.rng).cloned()));
+
+        positions
+            .into_iter()
+            .zip(new_drops)
+            .for_each(|((row, col), drop)| self.board.cell_mut(row, col).replace(drop));
+    }
+
+    fn gen_cloud(self: &mut Self, position: &BoardPosition, size: &Size) {
+        let (width, height) = (self.board.width(), self.board.height());
+        let cloud_width = size.width().min(width);
+        let cloud_height = size.height().min(height);
+
+        let top = match position {
+            BoardPosition::Row(idx) => Board::resolve_idx(*idx, height)
+                .map(|row| row.min(height - cloud_height))
+                .unwrap_or(0),
+            _ => self.rng.gen_range(0..=height - cloud_height),
+        };
+        let left = match position {
+            BoardPosition::Col(idx) => Board::resolve_idx(*idx, width)
+                .map(|col| col.min(width - cloud_width))
+                .unwrap_or(0),
+            _ => self.rng.gen_range(0..=width - cloud_width),
+        };
+
+        for row in top..top + cloud_height {
+            for col in left..left + cloud_width {
+                self.board.cell_mut(row, col).cloud = true;
+            }
+        }
+    }
+
+    fn gen_tape(self: &mut Self, position: &BoardPosition) {
+        let (width, height) = (self.board.width(), self.board.height());
+
+        let cells: Vec<(usize, usize)> = match position {
+            BoardPosition::Row(idx) => Board::resolve_idx(*idx, height)
+                .map(|row| (0..width).map(|col| (row, col)).collect())
+                .unwrap_or_default(),
+            BoardPosition::Col(idx) => Board::resolve_idx(*idx, width)
+                .map(|col| (0..height).map(|row| (row, col)).collect())
+                .unwrap_or_default(),
+            // 列の無い盤面ではテープを貼る列を選べない
+            BoardPosition::Random if width == 0 => return,
+            BoardPosition::Random => {
+                let col = self.rng.gen_range(0..width);
+                (0..height).map(|row| (row, col)).collect()
+            }
+        };
+
+        cells
+            .into_iter()
+            .for_each(|(row, col)| self.board.cell_mut(row, col).tape = true);
+    }
+
+    /// ランダムなマスをルーレットにする。
+    /// ルーレットのドロップは切り替わり続けるため、落ちてくるドロップのいずれかを置く
+    fn gen_roulette(self: &mut Self, qty: usize) {
+        let mut positions: Vec<(usize, usize)> = self
+            .board
+            .positions()
+            .into_iter()
+            .filter(|(row, col)| !self.board.cell(*row, *col).roulette)
+            .collect();
+        positions.shuffle(&mut self.rng);
+
+        for (row, col) in positions.into_iter().take(qty) {
+            let drop = self.next_drop();
+            let cell = self.board.cell_mut(row, col);
+
+            cell.roulette = true;
+            match drop {
+                Some(drop) => cell.replace(drop),
+                None => cell.clear(),
+            }
+        }
+    }
+
+    fn unlocked_positions(self: &Self) -> Vec<(usize, usize)> {
+        self.board
+            .positions()
+            .into_iter()
+            .filter(|(row, col)| !self.board.cell(*row, *col).locked)
+            .collect()
+    }
+
+    /// 消去対象になるドロップ。爆弾は消せないため除外する
+    fn matchable(self: &Self, row: usize, col: usize) -> Option<&Drop> {
+        self.board
+            .cell(row, col)
+            .drop
+            .as_ref()
+            .filter(|drop| **drop != Drop::NonColored(NonColoredDrop::Bomb))
+    }
+
+    /// 指定したマスを含んで3つ以上並んでいるか
+    fn makes_match(self: &Self, row: usize, col: usize) -> bool {
+        let drop = match self.matchable(row, col) {
+            Some(drop) => drop,
+            None => return false,
+        };
+        let same = |r: isize, c: isize| {
+            r >= 0
+                && c >= 0
+                && (r as usize) < self.board.height()
+                && (c as usize) < self.board.width()
+                && self.matchable(r as usize, c as usize) == Some(drop)
+        };
+        let (row, col) = (row as isize, col as isize);
+
+        (-2..=0).any(|offset| {
+            (0..3).all(|i| same(row, col + offset + i))
+                || (0..3).all(|i| same(row + offset + i, col))
+        })
+    }
+}
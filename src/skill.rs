@@ -1,6 +1,14 @@
+use super::drop_set::DropSet;
 use super::schema::*;
 
+/// 敵の属性変化でターン数の記載が無い場合に設定されるターン数
+pub const PERMANENT_TURNS: usize = 999;
+
+/// スキル1つ分
+/// `serde`フィーチャーでのJSONの形は`book/src/json.md`を参照
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Skill {
     /// スキルの効果
     pub effect: SkillEffect,
@@ -11,19 +19,23 @@ pub struct Skill {
 }
 
 /// スキル効果
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum SkillEffect {
+    #[default]
     Other,
     /// N色のドロップを(単色|ランダムでN色)に変換する。
-    /// 0: from
+    /// 0: from 順序を持たない
     /// 1: to
-    ChangeDropAToB(Drops, Drops),
+    ChangeDropAToB(DropSet, Drops),
     /// 全ドロップを変化
     ChangeAllOfBoard(Drops),
     /// ランダム生成
-    /// * 0: FromOtherDrops これに指定されているドロップ以外から生成する
+    /// * 0: FromOtherDrops これに指定されているドロップ以外から生成する。順序を持たない
     /// * 1: To 生成するドロップの種類と個数
-    GenRandomDrop(Drops, GenDropsWithQty),
+    GenRandomDrop(DropSet, GenDropsWithQty),
     DropShapeGen(Vec<ShapeType>),
     // ロック解除
     DropUnLock,
@@ -60,17 +72,79 @@ pub enum SkillEffect {
     PenetrationDamageNullification,
     /// 敵の属性変更
     EnemyAttributeChange(Color),
+    /// 寛容モードでパースできなかった文
+    /// * text: 元のスキル本文
+    /// * span: 入力中の位置(バイト単位)
+    Unrecognized {
+        text: String,
+        span: std::ops::Range<usize>,
+    },
 }
 
-impl Default for SkillEffect {
-    fn default() -> Self {
-        Self::Other
+impl SkillEffect {
+    /// バリアント名の一覧
+    pub const NAMES: [&'static str; 23] = [
+        "Other",
+        "ChangeDropAToB",
+        "ChangeAllOfBoard",
+        "GenRandomDrop",
+        "DropShapeGen",
+        "DropUnLock",
+        "DropLock",
+        "DropRefresh",
+        "DropPowerUp",
+        "DropFalloff",
+        "PowerupDropFalloff",
+        "FallLockDrop",
+        "FallNailDropEasierToFalloff",
+        "DropsNotFalling",
+        "GenRoulette",
+        "GenCloud",
+        "GenTeap",
+        "ChangeBoardSize",
+        "NullificationDamageAbsorption",
+        "NullificationAttributeAbsorption",
+        "PenetrationDamageNullification",
+        "EnemyAttributeChange",
+        "Unrecognized",
+    ];
+
+    /// バリアント名。`DropShapeGen([...])`なら`DropShapeGen`
+    pub fn name(self: &Self) -> &'static str {
+        match self {
+            Self::Other => "Other",
+            Self::ChangeDropAToB(..) => "ChangeDropAToB",
+            Self::ChangeAllOfBoard(..) => "ChangeAllOfBoard",
+            Self::GenRandomDrop(..) => "GenRandomDrop",
+            Self::DropShapeGen(..) => "DropShapeGen",
+            Self::DropUnLock => "DropUnLock",
+            Self::DropLock(..) => "DropLock",
+            Self::DropRefresh => "DropRefresh",
+            Self::DropPowerUp(..) => "DropPowerUp",
+            Self::DropFalloff(..) => "DropFalloff",
+            Self::PowerupDropFalloff(..) => "PowerupDropFalloff",
+            Self::FallLockDrop(..) => "FallLockDrop",
+            Self::FallNailDropEasierToFalloff(..) => "FallNailDropEasierToFalloff",
+            Self::DropsNotFalling => "DropsNotFalling",
+            Self::GenRoulette(..) => "GenRoulette",
+            Self::GenCloud(..) => "GenCloud",
+            Self::GenTeap(..) => "GenTeap",
+            Self::ChangeBoardSize(..) => "ChangeBoardSize",
+            Self::NullificationDamageAbsorption => "NullificationDamageAbsorption",
+            Self::NullificationAttributeAbsorption => "NullificationAttributeAbsorption",
+            Self::PenetrationDamageNullification => "PenetrationDamageNullification",
+            Self::EnemyAttributeChange(..) => "EnemyAttributeChange",
+            Self::Unrecognized { .. } => "Unrecognized",
+        }
     }
 }
 
 /// スキルの副次効果
 /// 制約、条件による効果追加、スキル進化など
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum SubEffect {
     /// バトル(以後|以前)の場合
     /// * pos_int 6  バトル6以降
@@ -88,6 +162,8 @@ pub enum SubEffect {
 
 /// 副次効果に付加される属性
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum SubEffectAttribute {
     /// 条件を満たしている場合にのみ使用可能
     Available,
@@ -104,6 +180,9 @@ pub type ShapeGenIdx = isize;
 
 /// 形状生成の種類
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum ShapeType {
     /// 縦に生成
     Col(ShapeGenIdx, Drop),
@@ -151,36 +230,54 @@ pub enum ShapeType {
 }
 
 impl ShapeType {
-    /// 生成個数が必要ないバリアント用
-    pub(super) fn set_drop(self: Self, drop: Drop) -> Self {
+    /// バリアント名。`Col(1, ...)`なら`Col`
+    pub fn name(self: &Self) -> &'static str {
         match self {
+            Self::Col(..) => "Col",
+            Self::Row(..) => "Row",
+            Self::LShape(..) => "LShape",
+            Self::ZShape(..) => "ZShape",
+            Self::SmallCrossShape(..) => "SmallCrossShape",
+            Self::CrossShape(..) => "CrossShape",
+            Self::Square(..) => "Square",
+            Self::ShapeOfBoardPerimeter(..) => "ShapeOfBoardPerimeter",
+            Self::ShapeOfBoardTop(..) => "ShapeOfBoardTop",
+            Self::ShapeOfBoardCenter(..) => "ShapeOfBoardCenter",
+            Self::ShapeOfBoardBottom(..) => "ShapeOfBoardBottom",
+            Self::ShapeOfBoardCorners(..) => "ShapeOfBoardCorners",
+            Self::ShapeOfSpiderweb(..) => "ShapeOfSpiderweb",
+            Self::ShapeOfCrescentMoon(..) => "ShapeOfCrescentMoon",
+            Self::ShapeOfOblique(..) => "ShapeOfOblique",
+            Self::ShapeOfSomeKind(..) => "ShapeOfSomeKind",
+        }
+    }
+
+    /// 生成個数が必要ないバリアント用
+    /// 生成個数が必要な形状(`L字型に光を生成`等)はエラー
+    pub(super) fn set_drop(self: Self, drop: Drop) -> miette::Result<Self> {
+        Ok(match self {
             Self::ZShape(_) => Self::ZShape(drop),
             Self::CrossShape(_) => Self::CrossShape(drop),
             Self::ShapeOfBoardPerimeter(_) => Self::ShapeOfBoardPerimeter(drop),
             Self::ShapeOfBoardCenter(_) => Self::ShapeOfBoardCenter(drop),
             Self::ShapeOfCrescentMoon(_) => Self::ShapeOfCrescentMoon(drop),
             Self::ShapeOfOblique(_) => Self::ShapeOfOblique(drop),
-            _ => panic!(
-                "from ShapeType::set_drop(). This Variant require qty {:?}",
-                self
-            ),
-        }
+            _ => miette::bail!("{:?} requires the number of shapes to generate", self),
+        })
     }
 
     /// 生成個数が指定されているバリアント用
-    pub(super) fn set_with_qty(self: Self, drop: Drop, qty: usize) -> Self {
-        match self {
+    /// 生成個数を持たない形状はエラー
+    pub(super) fn set_with_qty(self: Self, drop: Drop, qty: usize) -> miette::Result<Self> {
+        Ok(match self {
             Self::LShape(_, _) => Self::LShape(drop, qty),
             Self::ShapeOfBoardCorners(_, _) => Self::ShapeOfBoardCorners(drop, qty),
             // パース時点では盤面最大生成が指定されているので型を変更する
             Self::CrossShape(_) => Self::SmallCrossShape(drop, qty),
             Self::ShapeOfBoardTop(_, _) => Self::ShapeOfBoardTop(drop, qty),
             Self::ShapeOfBoardBottom(_, _) => Self::ShapeOfBoardBottom(drop, qty),
-            _ => panic!(
-                "from ShapeType::set_with_qty(). This Variant can't have qty {:?}",
-                self
-            ),
-        }
+            _ => miette::bail!("{:?} can't have the number of shapes to generate", self),
+        })
     }
 
     /// 正方形用
@@ -253,6 +350,9 @@ impl From<&str> for ShapeType {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum PowerupDropFalloffKind {
     /// N%
     Num(usize),
@@ -0,0 +1,689 @@
+use std::marker::PhantomData;
+use std::ops::Neg;
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::skill::*;
+use super::skill_en_grammar_trait as ast;
+use super::skill_en_grammar_trait::SkillEnGrammarTrait;
+use super::skill_grammar::SkillGrammar;
+use super::stack_item::*;
+
+/// 北米版のスキル本文のセマンティックアクション
+/// 日本版(`SkillGrammar`)と同じ`Skill`を生成する
+#[derive(Debug, Default, PartialEq)]
+pub struct SkillEnGrammar<'t> {
+    pub skill_list: Vec<Skill>,
+    stack: Vec<StackItem>,
+    pd: PhantomData<&'t str>,
+}
+
+impl SkillEnGrammar<'_> {
+    pub fn new() -> Self {
+        SkillEnGrammar {
+            skill_list: Vec::new(),
+            stack: Vec::new(),
+            pd: PhantomData,
+        }
+    }
+
+    /// 末尾を取り出す。スタックが空の場合は、本文に必要な語が足りないのでエラー
+    fn pop(self: &mut Self) -> miette::Result<StackItem> {
+        self.stack.pop().ok_or_else(missing_item)
+    }
+
+    /// 末尾からN個取り出す。取り出した順序は積んだ順序と同じ
+    fn pop_n(self: &mut Self, n: usize) -> miette::Result<Vec<StackItem>> {
+        let at = self.stack.len().checked_sub(n).ok_or_else(missing_item)?;
+        Ok(self.stack.split_off(at))
+    }
+
+    fn push(self: &mut Self, item: StackItem) {
+        self.stack.push(item);
+    }
+
+    fn push_effect(self: &mut Self, effect: SkillEffect) {
+        self.push(StackItem::ApplyInTurnsSkill(effect));
+    }
+
+    /// 1色だけのドロップを取り出す。形状生成は1色ずつなので、複数色はエラーにする
+    fn pop_single_drop(self: &mut Self) -> miette::Result<Drop> {
+        match &self.pop()?.drops()?[..] {
+            [drop] => Ok(drop.clone()),
+            drops => miette::bail!("expected a single orb type but found {drops:?}"),
+        }
+    }
+
+    /// 盤面の位置。`at random`以外は`RowColPosition`で積まれている
+    fn pop_board_position(
+        self: &mut Self,
+        arg: &ast::BoardPosition,
+    ) -> miette::Result<BoardPosition> {
+        match arg {
+            ast::BoardPosition::AtWordRandom(_) => Ok(BoardPosition::Random),
+            ast::BoardPosition::InTheRowColPosition(_) => self.pop_row_col_position(),
+        }
+    }
+
+    /// `RowColPosition`で積まれた縦、横の位置
+    fn pop_row_col_position(self: &mut Self) -> miette::Result<BoardPosition> {
+        let position = self.pop()?.gen_positions()?.into_iter().next();
+
+        match position.ok_or_else(missing_item)? {
+            GenShapeRowCol::Row(idx) => Ok(BoardPosition::Row(idx)),
+            GenShapeRowCol::Col(idx) => Ok(BoardPosition::Col(idx)),
+        }
+    }
+
+    /// 生成元。ランダム生成以外は形状生成として扱う
+    fn build_gen_effect(
+        self: &mut Self,
+        source: &ast::GenSource,
+        to: GenDropsWithQty,
+    ) -> miette::Result<SkillEffect> {
+        // 盤面の上下、四隅への生成は形状生成なので1色だけ
+        let single = || match &to[..] {
+            [(drop, qty)] => Ok((drop.clone(), *qty)),
+            to => miette::bail!("expected a single orb type but found {to:?}"),
+        };
+
+        let effect = match source {
+            ast::GenSource::AtGenSourceAt(at) => match &*at.gen_source_at {
+                ast::GenSourceAt::WordRandom(_) => {
+                    let to = &mut to.to_owned();
+                    let exc = SkillGrammar::build_gen_random_drop_exc_from(&mut vec![], to);
+
+                    SkillEffect::GenRandomDrop(exc, to.to_owned())
+                }
+                ast::GenSourceAt::TheTopOrBottomOfTheWordBoard(board) => {
+                    match &*board.top_or_bottom {
+                        ast::TopOrBottom::WordTop(_) => {
+                            let (drop, qty) = single()?;
+                            SkillEffect::DropShapeGen(vec![ShapeType::ShapeOfBoardTop(drop, qty)])
+                        }
+                        ast::TopOrBottom::WordBottom(_) => {
+                            let (drop, qty) = single()?;
+                            SkillEffect::DropShapeGen(vec![ShapeType::ShapeOfBoardBottom(
+                                drop, qty,
+                            )])
+                        }
+                    }
+                }
+            },
+            ast::GenSource::FromNonDrops(_) => {
+                let to = &mut to.to_owned();
+                let exc =
+                    SkillGrammar::build_gen_random_drop_exc_from(&mut self.pop()?.drops()?, to);
+
+                SkillEffect::GenRandomDrop(exc, to.to_owned())
+            }
+            ast::GenSource::InWordEachWordCornerOfTheWordBoard(_) => {
+                let (drop, qty) = single()?;
+                SkillEffect::DropShapeGen(vec![ShapeType::ShapeOfBoardCorners(drop, qty)])
+            }
+        };
+        Ok(effect)
+    }
+
+    /// 1文分のスキルを追加する
+    fn push_sentence_skill(self: &mut Self, effect: SkillEffect, turns: Option<usize>) {
+        let turns_of_apply = match (turns, &effect) {
+            (Some(turns), _) => Some(turns),
+            // 日本版と同じく、ターン数の指定が無い敵の属性変化は`PERMANENT_TURNS`とする
+            (None, SkillEffect::EnemyAttributeChange(_)) => Some(PERMANENT_TURNS),
+            (None, _) => None,
+        };
+
+        // 陣→ランダム生成は盤面を上書きするため、除外するドロップはなし
+        let effect = match (self.skill_list.last(), effect) {
+            (
+                Some(Skill {
+                    effect: SkillEffect::ChangeAllOfBoard(_),
+                    ..
+                }),
+                SkillEffect::GenRandomDrop(exc, to),
+            ) if exc == to.iter().map(|(drop, _)| drop).collect() => {
+                SkillEffect::GenRandomDrop(DropSet::empty(), to)
+            }
+            (_, effect) => effect,
+        };
+
+        self.skill_list.push(Skill {
+            effect,
+            turns_of_apply,
+            ..Default::default()
+        });
+    }
+
+    /// 生成元の`from non-X orbs`はドロップより後に積まれているので、先に取り出す
+    fn pop_gen_source_drops(
+        self: &mut Self,
+        source: &ast::GenSource,
+    ) -> miette::Result<Option<StackItem>> {
+        match source {
+            ast::GenSource::FromNonDrops(_) => Ok(Some(self.pop()?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'t> SkillEnGrammarTrait<'t> for SkillEnGrammar<'t> {
+    /// ターン数は`For N turns, `と` for N turns`のどちらでも記載される
+    fn sentence(&mut self, arg: &ast::Sentence<'t>) -> miette::Result<()> {
+        let suffix_turns = match arg.sentence_opt0 {
+            Some(_) => Some(self.pop()?.pos_int()?),
+            None => None,
+        };
+
+        // `voids attribute and damage absorption`のように1文で複数の効果を積む場合がある
+        let mut effects = vec![];
+        while self
+            .stack
+            .last()
+            .is_some_and(|item| item.is_apply_in_turns_skill())
+        {
+            effects.push(self.pop()?.apply_in_turns_skill()?);
+        }
+        effects.reverse();
+
+        let prefix_turns = match arg.sentence_opt {
+            Some(_) => Some(self.pop()?.pos_int()?),
+            None => None,
+        };
+
+        for effect in effects {
+            self.push_sentence_skill(effect, prefix_turns.or(suffix_turns));
+        }
+        Ok(())
+    }
+
+    fn change_all_of_board_stmt(
+        &mut self,
+        _arg: &ast::ChangeAllOfBoardStmt<'t>,
+    ) -> miette::Result<()> {
+        let drops = self.pop()?.drops()?;
+        self.push_effect(SkillEffect::ChangeAllOfBoard(drops));
+        Ok(())
+    }
+
+    fn enemy_attribute_change_stmt(
+        &mut self,
+        _arg: &ast::EnemyAttributeChangeStmt<'t>,
+    ) -> miette::Result<()> {
+        let color = self.pop()?.color()?;
+        self.push_effect(SkillEffect::EnemyAttributeChange(color));
+        Ok(())
+    }
+
+    fn change_drop_stmt(&mut self, _arg: &ast::ChangeDropStmt<'t>) -> miette::Result<()> {
+        let to = self.pop()?.drops()?;
+        let from = self.pop()?.drops()?;
+
+        self.push_effect(SkillEffect::ChangeDropAToB(from.into(), to));
+        Ok(())
+    }
+
+    fn shape_row_col_stmt(&mut self, _arg: &ast::ShapeRowColStmt<'t>) -> miette::Result<()> {
+        let mut shapes = vec![];
+        while self.stack.last().is_some_and(|item| item.is_shape_type()) {
+            shapes.push(self.pop()?.shape_type()?);
+        }
+        shapes.reverse();
+
+        self.push_effect(SkillEffect::DropShapeGen(shapes));
+        Ok(())
+    }
+
+    /// 同じドロップに変化する縦、横の生成位置をまとめる
+    fn row_col_block(&mut self, arg: &ast::RowColBlock<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+
+        let positions = self
+            .pop_n(arg.row_col_block_list.len() + 1)?
+            .into_iter()
+            .map(|item| item.gen_positions())
+            .collect::<miette::Result<Vec<_>>>()?
+            .concat();
+
+        for position in positions {
+            let shape = match position {
+                GenShapeRowCol::Row(idx) => ShapeType::Row(idx, drop.clone()),
+                GenShapeRowCol::Col(idx) => ShapeType::Col(idx, drop.clone()),
+            };
+            self.push(StackItem::DropShapeGenShapeType(shape));
+        }
+        Ok(())
+    }
+
+    fn row_col_position(&mut self, arg: &ast::RowColPosition<'t>) -> miette::Result<()> {
+        let position = match arg {
+            ast::RowColPosition::WordLeftmostWordColumn(_) => GenShapeRowCol::Col(1),
+            ast::RowColPosition::WordRightmostWordColumn(_) => GenShapeRowCol::Col(-1),
+            ast::RowColPosition::WordTopWordRow(_) => GenShapeRowCol::Row(1),
+            ast::RowColPosition::WordBottomWordRow(_) => GenShapeRowCol::Row(-1),
+            ast::RowColPosition::OrdinalRowOrColumnFromTheDirection(ordinal) => {
+                let idx = self.pop()?.pos_int()? as isize;
+
+                match &*ordinal.direction {
+                    ast::Direction::WordLeft(_) => GenShapeRowCol::Col(idx),
+                    ast::Direction::WordRight(_) => GenShapeRowCol::Col(idx.neg()),
+                    ast::Direction::WordTop(_) => GenShapeRowCol::Row(idx),
+                    ast::Direction::WordBottom(_) => GenShapeRowCol::Row(idx.neg()),
+                }
+            }
+        };
+
+        self.push(StackItem::GenPositions(vec![position]));
+        Ok(())
+    }
+
+    fn shape_of_board_perimeter_stmt(
+        &mut self,
+        _arg: &ast::ShapeOfBoardPerimeterStmt<'t>,
+    ) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![
+            ShapeType::ShapeOfBoardPerimeter(drop),
+        ]));
+        Ok(())
+    }
+
+    fn shape_of_board_center_stmt(
+        &mut self,
+        _arg: &ast::ShapeOfBoardCenterStmt<'t>,
+    ) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![
+            ShapeType::ShapeOfBoardCenter(drop),
+        ]));
+        Ok(())
+    }
+
+    fn change_board_size_stmt(
+        &mut self,
+        _arg: &ast::ChangeBoardSizeStmt<'t>,
+    ) -> miette::Result<()> {
+        let size = self.pop()?.size()?;
+        self.push_effect(SkillEffect::ChangeBoardSize(size));
+        Ok(())
+    }
+
+    /// 生成数が異なるランダム生成
+    /// 1つ目の生成数は`CreateTarget`で積まれている
+    fn gen_random_drop_stmt(&mut self, arg: &ast::GenRandomDropStmt<'t>) -> miette::Result<()> {
+        let source = self.pop_gen_source_drops(&arg.gen_source)?;
+
+        let rest: GenDropsWithQty = self
+            .pop_n(arg.gen_random_drop_stmt_list.len())?
+            .into_iter()
+            .map(|item| item.gen_drop_with_qty())
+            .collect::<miette::Result<Vec<_>>>()?
+            .concat();
+
+        let drop = self.pop()?.drop()?;
+        let qty = self.pop()?.pos_int()?;
+
+        let mut to = vec![(drop, qty)];
+        to.extend(rest);
+
+        if let Some(source) = source {
+            self.push(source);
+        }
+        let effect = self.build_gen_effect(&arg.gen_source, to)?;
+        self.push_effect(effect);
+        Ok(())
+    }
+
+    fn qty_drop(&mut self, _arg: &ast::QtyDrop<'t>) -> miette::Result<()> {
+        let drop = self.pop()?.drop()?;
+        let qty = self.pop()?.pos_int()?;
+
+        self.push(StackItem::GenDropsWithQty(vec![(drop, qty)]));
+        Ok(())
+    }
+
+    /// 生成数が同じランダム生成
+    fn gen_each_stmt(&mut self, arg: &ast::GenEachStmt<'t>) -> miette::Result<()> {
+        let source = self.pop_gen_source_drops(&arg.gen_source)?;
+        let drops = self.pop()?.drops()?;
+        let qty = self.pop()?.pos_int()?;
+
+        if let Some(source) = source {
+            self.push(source);
+        }
+        let to = SkillGrammar::build_gen_drop_and_qty_list(drops, qty);
+        let effect = self.build_gen_effect(&arg.gen_source, to)?;
+        self.push_effect(effect);
+        Ok(())
+    }
+
+    fn non_drops(&mut self, arg: &ast::NonDrops<'t>) -> miette::Result<()> {
+        let drops = self
+            .pop_n(arg.non_drops_list.len() + 1)?
+            .into_iter()
+            .map(|item| item.drop())
+            .collect::<miette::Result<_>>()?;
+
+        self.push(StackItem::Drops(drops));
+        Ok(())
+    }
+
+    fn l_shape_stmt(&mut self, _arg: &ast::LShapeStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        let qty = self.pop()?.pos_int()?;
+
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::LShape(
+            drop, qty,
+        )]));
+        Ok(())
+    }
+
+    fn small_cross_stmt(&mut self, _arg: &ast::SmallCrossStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        let qty = self.pop()?.pos_int()?;
+
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::SmallCrossShape(
+            drop, qty,
+        )]));
+        Ok(())
+    }
+
+    fn square_stmt(&mut self, _arg: &ast::SquareStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        let size = self.pop()?.size()?;
+        let qty = self.pop()?.pos_int()?;
+
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::Square(
+            drop,
+            size.width(),
+            qty,
+        )]));
+        Ok(())
+    }
+
+    fn some_kind_stmt(&mut self, _arg: &ast::SomeKindStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        let kind = self.pop()?.pos_int()?;
+        let qty = self.pop()?.pos_int()?;
+
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::ShapeOfSomeKind(
+            drop,
+            kind.to_string(),
+            qty,
+        )]));
+        Ok(())
+    }
+
+    fn gen_roulette_stmt(&mut self, _arg: &ast::GenRouletteStmt<'t>) -> miette::Result<()> {
+        let qty = self.pop()?.pos_int()?;
+        self.push_effect(SkillEffect::GenRoulette(qty));
+        Ok(())
+    }
+
+    fn z_shape_stmt(&mut self, _arg: &ast::ZShapeStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::ZShape(drop)]));
+        Ok(())
+    }
+
+    fn cross_shape_stmt(&mut self, _arg: &ast::CrossShapeStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::CrossShape(drop)]));
+        Ok(())
+    }
+
+    fn spiderweb_stmt(&mut self, _arg: &ast::SpiderwebStmt<'t>) -> miette::Result<()> {
+        let center = self.pop_single_drop()?;
+        let web = self.pop_single_drop()?;
+
+        self.push_effect(SkillEffect::DropShapeGen(vec![
+            ShapeType::ShapeOfSpiderweb(web, center),
+        ]));
+        Ok(())
+    }
+
+    fn crescent_moon_stmt(&mut self, _arg: &ast::CrescentMoonStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![
+            ShapeType::ShapeOfCrescentMoon(drop),
+        ]));
+        Ok(())
+    }
+
+    fn oblique_stmt(&mut self, _arg: &ast::ObliqueStmt<'t>) -> miette::Result<()> {
+        let drop = self.pop_single_drop()?;
+        self.push_effect(SkillEffect::DropShapeGen(vec![ShapeType::ShapeOfOblique(
+            drop,
+        )]));
+        Ok(())
+    }
+
+    fn gen_cloud_stmt(&mut self, arg: &ast::GenCloudStmt<'t>) -> miette::Result<()> {
+        let position = self.pop_board_position(&arg.board_position)?;
+        let size = self.pop()?.size()?;
+
+        self.push_effect(SkillEffect::GenCloud(position, size));
+        Ok(())
+    }
+
+    fn unlock_stmt(&mut self, _arg: &ast::UnlockStmt<'t>) -> miette::Result<()> {
+        self.push_effect(SkillEffect::DropUnLock);
+        Ok(())
+    }
+
+    fn lock_stmt(&mut self, arg: &ast::LockStmt<'t>) -> miette::Result<()> {
+        let drops = match &*arg.lock_target {
+            ast::LockTarget::WordAllWordOrbs(_) => DropSet::all().to_drops(),
+            ast::LockTarget::Drops(_) => self.pop()?.drops()?,
+        };
+
+        self.push_effect(SkillEffect::DropLock(drops));
+        Ok(())
+    }
+
+    fn replace_stmt(&mut self, _arg: &ast::ReplaceStmt<'t>) -> miette::Result<()> {
+        self.push_effect(SkillEffect::DropRefresh);
+        Ok(())
+    }
+
+    fn enhance_stmt(&mut self, arg: &ast::EnhanceStmt<'t>) -> miette::Result<()> {
+        let drops = match &*arg.enhance_target {
+            ast::EnhanceTarget::WordAllWordOrbs(_) => {
+                DropSet::five_attributes_and_recovery().to_drops()
+            }
+            ast::EnhanceTarget::Drops(_) => self.pop()?.drops()?,
+        };
+
+        self.push_effect(SkillEffect::DropPowerUp(drops));
+        Ok(())
+    }
+
+    fn orbs_stmt(&mut self, arg: &ast::OrbsStmt<'t>) -> miette::Result<()> {
+        let effect = match &*arg.orbs_stmt_suffix {
+            ast::OrbsStmtSuffix::MoreLikely(_) => {
+                let volume = self.pop()?.volume_variation()?;
+                SkillEffect::DropFalloff(self.pop()?.drops()?, volume)
+            }
+            ast::OrbsStmtSuffix::WordAppearWordLocked(_) => {
+                SkillEffect::FallLockDrop(self.pop()?.drops()?)
+            }
+        };
+
+        self.push_effect(effect);
+        Ok(())
+    }
+
+    fn all_orbs_locked_stmt(&mut self, _arg: &ast::AllOrbsLockedStmt<'t>) -> miette::Result<()> {
+        self.push_effect(SkillEffect::FallLockDrop(DropSet::all().to_drops()));
+        Ok(())
+    }
+
+    fn only_stmt(&mut self, arg: &ast::OnlyStmt<'t>) -> miette::Result<()> {
+        let effect = match &*arg.only_target {
+            ast::OnlyTarget::Drops(_) => {
+                SkillEffect::DropFalloff(self.pop()?.drops()?, VolumeVariation::Only)
+            }
+            ast::OnlyTarget::WordEnhancedWordOrbs(_) => SkillEffect::PowerupDropFalloff(
+                PowerupDropFalloffKind::VolumeVariation(VolumeVariation::Only),
+            ),
+            ast::OnlyTarget::WordNailWordOrbs(_) => {
+                SkillEffect::FallNailDropEasierToFalloff(VolumeVariation::Only)
+            }
+        };
+
+        self.push_effect(effect);
+        Ok(())
+    }
+
+    fn enhanced_orbs_stmt(&mut self, arg: &ast::EnhancedOrbsStmt<'t>) -> miette::Result<()> {
+        let kind = match &*arg.enhanced_orbs_suffix {
+            ast::EnhancedOrbsSuffix::WordHaveAPosIntPercentWordChanceToWordAppear(_) => {
+                PowerupDropFalloffKind::Num(self.pop()?.pos_int()?)
+            }
+            ast::EnhancedOrbsSuffix::MoreLikely(_) => {
+                PowerupDropFalloffKind::VolumeVariation(self.pop()?.volume_variation()?)
+            }
+        };
+
+        self.push_effect(SkillEffect::PowerupDropFalloff(kind));
+        Ok(())
+    }
+
+    fn nail_orbs_stmt(&mut self, _arg: &ast::NailOrbsStmt<'t>) -> miette::Result<()> {
+        let volume = self.pop()?.volume_variation()?;
+        self.push_effect(SkillEffect::FallNailDropEasierToFalloff(volume));
+        Ok(())
+    }
+
+    fn more_likely(&mut self, arg: &ast::MoreLikely<'t>) -> miette::Result<()> {
+        let volume = match arg.more_likely_opt.as_ref().map(|opt| &*opt.volume) {
+            Some(ast::Volume::WordVerySlightly(_)) => VolumeVariation::LittleMore,
+            Some(ast::Volume::WordSlightly(_)) => VolumeVariation::Little,
+            None => VolumeVariation::Normal,
+        };
+
+        self.push(StackItem::VolumeVariation(volume));
+        Ok(())
+    }
+
+    fn no_skyfall_stmt(&mut self, _arg: &ast::NoSkyfallStmt<'t>) -> miette::Result<()> {
+        self.push_effect(SkillEffect::DropsNotFalling);
+        Ok(())
+    }
+
+    /// `the board`は日本版の`盤面`と同じく`BoardPosition::Random`とする
+    fn tape_stmt(&mut self, arg: &ast::TapeStmt<'t>) -> miette::Result<()> {
+        let position = match &*arg.tape_target {
+            ast::TapeTarget::RowColPosition(_) => self.pop_row_col_position()?,
+            ast::TapeTarget::WordBoard(_) => BoardPosition::Random,
+        };
+
+        self.push_effect(SkillEffect::GenTeap(position));
+        Ok(())
+    }
+
+    /// 効果は記載順に積む。`attribute and damage absorption`は属性吸収、ダメージ吸収の順
+    fn void_target(&mut self, arg: &ast::VoidTarget<'t>) -> miette::Result<()> {
+        match arg {
+            ast::VoidTarget::WordAttributeVoidTargetOptWordAbsorption(target) => {
+                self.push_effect(SkillEffect::NullificationAttributeAbsorption);
+
+                if target.void_target_opt.is_some() {
+                    self.push_effect(SkillEffect::NullificationDamageAbsorption);
+                }
+            }
+            ast::VoidTarget::WordDamageDamageTarget(target) => {
+                let effect = match &*target.damage_target {
+                    ast::DamageTarget::WordAbsorption(_) => {
+                        SkillEffect::NullificationDamageAbsorption
+                    }
+                    ast::DamageTarget::WordVoid(_) => SkillEffect::PenetrationDamageNullification,
+                };
+
+                self.push_effect(effect);
+            }
+        }
+        Ok(())
+    }
+
+    fn pierce_stmt(&mut self, _arg: &ast::PierceStmt<'t>) -> miette::Result<()> {
+        self.push_effect(SkillEffect::PenetrationDamageNullification);
+        Ok(())
+    }
+
+    fn drops(&mut self, arg: &ast::Drops<'t>) -> miette::Result<()> {
+        let drops = self
+            .pop_n(arg.drops_list.len() + 1)?
+            .into_iter()
+            .map(|item| item.drop())
+            .collect::<miette::Result<_>>()?;
+
+        self.push(StackItem::Drops(drops));
+        Ok(())
+    }
+
+    fn drop_name(&mut self, arg: &ast::DropName<'t>) -> miette::Result<()> {
+        // 色ならドロップに変換、色なしドロップはそのまま
+        if let ast::DropName::Color(_) = arg {
+            let color = self.pop()?.color()?;
+            self.push(StackItem::Drop(Drop::Colored(color)));
+        }
+        Ok(())
+    }
+
+    fn color(&mut self, arg: &ast::Color<'t>) -> miette::Result<()> {
+        let color = match arg {
+            ast::Color::Fire(_) => Color::Fire,
+            ast::Color::Water(_) => Color::Water,
+            ast::Color::Wood(_) => Color::Wood,
+            ast::Color::Lightning(_) => Color::Lightning,
+            ast::Color::Dark(_) => Color::Dark,
+        };
+
+        self.push(StackItem::Color(color));
+        Ok(())
+    }
+
+    fn non_colored_drop(&mut self, arg: &ast::NonColoredDrop<'t>) -> miette::Result<()> {
+        let drop = match arg {
+            ast::NonColoredDrop::Recovery(_) => NonColoredDrop::Recovery,
+            ast::NonColoredDrop::Disturb(_) => NonColoredDrop::Disturb,
+            ast::NonColoredDrop::Bomb(_) => NonColoredDrop::Bomb,
+            ast::NonColoredDrop::DeadlyPoison(_) => NonColoredDrop::DeadlyPoison,
+            ast::NonColoredDrop::Poison(_) => NonColoredDrop::Poison,
+        };
+
+        self.push(StackItem::Drop(Drop::NonColored(drop)));
+        Ok(())
+    }
+
+    /// 7x6
+    fn size(&mut self, _arg: &ast::Size<'t>) -> miette::Result<()> {
+        let height = self.pop()?.pos_int()?;
+        let width = self.pop()?.pos_int()?;
+
+        self.push(StackItem::Size(Size(width, height)));
+        Ok(())
+    }
+
+    /// 2nd
+    fn ordinal(&mut self, arg: &ast::Ordinal<'t>) -> miette::Result<()> {
+        let text = arg.ordinal.text();
+        let num = text[..text.len() - 2]
+            .parse::<usize>()
+            .map_err(|e| miette::miette!("invalid ordinal {text:?}: {e}"))?;
+
+        self.push(StackItem::PosInt(num));
+        Ok(())
+    }
+
+    fn pos_int(&mut self, arg: &ast::PosInt<'t>) -> miette::Result<()> {
+        let text = arg.pos_int.text();
+        let num = text
+            .parse::<usize>()
+            .map_err(|e| miette::miette!("invalid number {text:?}: {e}"))?;
+
+        self.push(StackItem::PosInt(num));
+        Ok(())
+    }
+}
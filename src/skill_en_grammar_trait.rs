@@ -0,0 +1,11381 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use derive_builder::Builder;
+#[allow(unused_imports)]
+use parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::Token;
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::miette::{bail, miette, IntoDiagnostic, Result};
+use parol_runtime::parser::{ParseTreeStackEntry, ParseTreeType, UserActionsTrait};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait SkillEnGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'SkillEnLines'
+    fn skill_en_lines(&mut self, _arg: &SkillEnLines<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Sentence'
+    fn sentence(&mut self, _arg: &Sentence<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TurnsPrefix'
+    fn turns_prefix(&mut self, _arg: &TurnsPrefix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TurnsSuffix'
+    fn turns_suffix(&mut self, _arg: &TurnsSuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Effect'
+    fn effect(&mut self, _arg: &Effect<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeStmt'
+    fn change_stmt(&mut self, _arg: &ChangeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeTarget'
+    fn change_target(&mut self, _arg: &ChangeTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeAllTarget'
+    fn change_all_target(&mut self, _arg: &ChangeAllTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeAllOfBoardStmt'
+    fn change_all_of_board_stmt(&mut self, _arg: &ChangeAllOfBoardStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnemyAttributeChangeStmt'
+    fn enemy_attribute_change_stmt(&mut self, _arg: &EnemyAttributeChangeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeDropStmt'
+    fn change_drop_stmt(&mut self, _arg: &ChangeDropStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeTheTarget'
+    fn change_the_target(&mut self, _arg: &ChangeTheTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ShapeRowColStmt'
+    fn shape_row_col_stmt(&mut self, _arg: &ShapeRowColStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RowColBlock'
+    fn row_col_block(&mut self, _arg: &RowColBlock<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RowColSep'
+    fn row_col_sep(&mut self, _arg: &RowColSep<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RowColPosition'
+    fn row_col_position(&mut self, _arg: &RowColPosition<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RowOrColumn'
+    fn row_or_column(&mut self, _arg: &RowOrColumn<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Direction'
+    fn direction(&mut self, _arg: &Direction<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ShapeOfBoardPerimeterStmt'
+    fn shape_of_board_perimeter_stmt(
+        &mut self,
+        _arg: &ShapeOfBoardPerimeterStmt<'t>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ShapeOfBoardCenterStmt'
+    fn shape_of_board_center_stmt(&mut self, _arg: &ShapeOfBoardCenterStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ChangeBoardSizeStmt'
+    fn change_board_size_stmt(&mut self, _arg: &ChangeBoardSizeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CreateStmt'
+    fn create_stmt(&mut self, _arg: &CreateStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CreateTarget'
+    fn create_target(&mut self, _arg: &CreateTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CreateQtyTarget'
+    fn create_qty_target(&mut self, _arg: &CreateQtyTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenRandomDropStmt'
+    fn gen_random_drop_stmt(&mut self, _arg: &GenRandomDropStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'QtyDropName'
+    fn qty_drop_name(&mut self, _arg: &QtyDropName<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'QtyDrop'
+    fn qty_drop(&mut self, _arg: &QtyDrop<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'QtySep'
+    fn qty_sep(&mut self, _arg: &QtySep<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenEachStmt'
+    fn gen_each_stmt(&mut self, _arg: &GenEachStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenSource'
+    fn gen_source(&mut self, _arg: &GenSource<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenSourceAt'
+    fn gen_source_at(&mut self, _arg: &GenSourceAt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TopOrBottom'
+    fn top_or_bottom(&mut self, _arg: &TopOrBottom<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NonDrops'
+    fn non_drops(&mut self, _arg: &NonDrops<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NonDrop'
+    fn non_drop(&mut self, _arg: &NonDrop<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LShapeStmt'
+    fn l_shape_stmt(&mut self, _arg: &LShapeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SmallCrossStmt'
+    fn small_cross_stmt(&mut self, _arg: &SmallCrossStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SquareStmt'
+    fn square_stmt(&mut self, _arg: &SquareStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SomeKindStmt'
+    fn some_kind_stmt(&mut self, _arg: &SomeKindStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenRouletteStmt'
+    fn gen_roulette_stmt(&mut self, _arg: &GenRouletteStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CreateATarget'
+    fn create_a_target(&mut self, _arg: &CreateATarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ZShapeStmt'
+    fn z_shape_stmt(&mut self, _arg: &ZShapeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CrossShapeStmt'
+    fn cross_shape_stmt(&mut self, _arg: &CrossShapeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SpiderwebStmt'
+    fn spiderweb_stmt(&mut self, _arg: &SpiderwebStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CrescentMoonStmt'
+    fn crescent_moon_stmt(&mut self, _arg: &CrescentMoonStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ObliqueStmt'
+    fn oblique_stmt(&mut self, _arg: &ObliqueStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'GenCloudStmt'
+    fn gen_cloud_stmt(&mut self, _arg: &GenCloudStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'UnlockStmt'
+    fn unlock_stmt(&mut self, _arg: &UnlockStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LockStmt'
+    fn lock_stmt(&mut self, _arg: &LockStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'LockTarget'
+    fn lock_target(&mut self, _arg: &LockTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ReplaceStmt'
+    fn replace_stmt(&mut self, _arg: &ReplaceStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnhanceStmt'
+    fn enhance_stmt(&mut self, _arg: &EnhanceStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnhanceTarget'
+    fn enhance_target(&mut self, _arg: &EnhanceTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OrbsStmt'
+    fn orbs_stmt(&mut self, _arg: &OrbsStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OrbsStmtSuffix'
+    fn orbs_stmt_suffix(&mut self, _arg: &OrbsStmtSuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AllOrbsLockedStmt'
+    fn all_orbs_locked_stmt(&mut self, _arg: &AllOrbsLockedStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OnlyStmt'
+    fn only_stmt(&mut self, _arg: &OnlyStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OnlyTarget'
+    fn only_target(&mut self, _arg: &OnlyTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnhancedOrbsStmt'
+    fn enhanced_orbs_stmt(&mut self, _arg: &EnhancedOrbsStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EnhancedOrbsSuffix'
+    fn enhanced_orbs_suffix(&mut self, _arg: &EnhancedOrbsSuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NailOrbsStmt'
+    fn nail_orbs_stmt(&mut self, _arg: &NailOrbsStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'MoreLikely'
+    fn more_likely(&mut self, _arg: &MoreLikely<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Volume'
+    fn volume(&mut self, _arg: &Volume<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NoSkyfallStmt'
+    fn no_skyfall_stmt(&mut self, _arg: &NoSkyfallStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TapeStmt'
+    fn tape_stmt(&mut self, _arg: &TapeStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TapeTarget'
+    fn tape_target(&mut self, _arg: &TapeTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'BoardPosition'
+    fn board_position(&mut self, _arg: &BoardPosition<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'VoidStmt'
+    fn void_stmt(&mut self, _arg: &VoidStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'VoidSep'
+    fn void_sep(&mut self, _arg: &VoidSep<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'VoidTarget'
+    fn void_target(&mut self, _arg: &VoidTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DamageTarget'
+    fn damage_target(&mut self, _arg: &DamageTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PierceStmt'
+    fn pierce_stmt(&mut self, _arg: &PierceStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Drops'
+    fn drops(&mut self, _arg: &Drops<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DropSep'
+    fn drop_sep(&mut self, _arg: &DropSep<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DropName'
+    fn drop_name(&mut self, _arg: &DropName<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Color'
+    fn color(&mut self, _arg: &Color<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'NonColoredDrop'
+    fn non_colored_drop(&mut self, _arg: &NonColoredDrop<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Size'
+    fn size(&mut self, _arg: &Size<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Fire'
+    fn fire(&mut self, _arg: &Fire<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Water'
+    fn water(&mut self, _arg: &Water<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Wood'
+    fn wood(&mut self, _arg: &Wood<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Lightning'
+    fn lightning(&mut self, _arg: &Lightning<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Dark'
+    fn dark(&mut self, _arg: &Dark<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Recovery'
+    fn recovery(&mut self, _arg: &Recovery<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Disturb'
+    fn disturb(&mut self, _arg: &Disturb<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Bomb'
+    fn bomb(&mut self, _arg: &Bomb<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeadlyPoison'
+    fn deadly_poison(&mut self, _arg: &DeadlyPoison<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Poison'
+    fn poison(&mut self, _arg: &Poison<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordFor'
+    fn word_for(&mut self, _arg: &WordFor<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordTurn'
+    fn word_turn(&mut self, _arg: &WordTurn<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordChange'
+    fn word_change(&mut self, _arg: &WordChange<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCreate'
+    fn word_create(&mut self, _arg: &WordCreate<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordUnlock'
+    fn word_unlock(&mut self, _arg: &WordUnlock<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLock'
+    fn word_lock(&mut self, _arg: &WordLock<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordReplace'
+    fn word_replace(&mut self, _arg: &WordReplace<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordEnhance'
+    fn word_enhance(&mut self, _arg: &WordEnhance<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordEnhanced'
+    fn word_enhanced(&mut self, _arg: &WordEnhanced<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAll'
+    fn word_all(&mut self, _arg: &WordAll<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordOnly'
+    fn word_only(&mut self, _arg: &WordOnly<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordNail'
+    fn word_nail(&mut self, _arg: &WordNail<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordNo'
+    fn word_no(&mut self, _arg: &WordNo<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordOrbs'
+    fn word_orbs(&mut self, _arg: &WordOrbs<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordVoid'
+    fn word_void(&mut self, _arg: &WordVoid<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordPierce'
+    fn word_pierce(&mut self, _arg: &WordPierce<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordThrough'
+    fn word_through(&mut self, _arg: &WordThrough<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordEnemies'
+    fn word_enemies(&mut self, _arg: &WordEnemies<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAttribute'
+    fn word_attribute(&mut self, _arg: &WordAttribute<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRandom'
+    fn word_random(&mut self, _arg: &WordRandom<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLeftmost'
+    fn word_leftmost(&mut self, _arg: &WordLeftmost<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRightmost'
+    fn word_rightmost(&mut self, _arg: &WordRightmost<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLeft'
+    fn word_left(&mut self, _arg: &WordLeft<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRight'
+    fn word_right(&mut self, _arg: &WordRight<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordTop'
+    fn word_top(&mut self, _arg: &WordTop<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordBottom'
+    fn word_bottom(&mut self, _arg: &WordBottom<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordColumn'
+    fn word_column(&mut self, _arg: &WordColumn<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRow'
+    fn word_row(&mut self, _arg: &WordRow<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordOuter'
+    fn word_outer(&mut self, _arg: &WordOuter<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordEdges'
+    fn word_edges(&mut self, _arg: &WordEdges<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCenter'
+    fn word_center(&mut self, _arg: &WordCenter<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordBoard'
+    fn word_board(&mut self, _arg: &WordBoard<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordEach'
+    fn word_each(&mut self, _arg: &WordEach<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCorner'
+    fn word_corner(&mut self, _arg: &WordCorner<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordNon'
+    fn word_non(&mut self, _arg: &WordNon<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLShape'
+    fn word_l_shape(&mut self, _arg: &WordLShape<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordZShape'
+    fn word_z_shape(&mut self, _arg: &WordZShape<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCross'
+    fn word_cross(&mut self, _arg: &WordCross<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLarge'
+    fn word_large(&mut self, _arg: &WordLarge<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAcross'
+    fn word_across(&mut self, _arg: &WordAcross<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSquare'
+    fn word_square(&mut self, _arg: &WordSquare<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordShape'
+    fn word_shape(&mut self, _arg: &WordShape<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordRoulette'
+    fn word_roulette(&mut self, _arg: &WordRoulette<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSpiderweb'
+    fn word_spiderweb(&mut self, _arg: &WordSpiderweb<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordWith'
+    fn word_with(&mut self, _arg: &WordWith<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordIts'
+    fn word_its(&mut self, _arg: &WordIts<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCrescentMoon'
+    fn word_crescent_moon(&mut self, _arg: &WordCrescentMoon<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordDiagonalLine'
+    fn word_diagonal_line(&mut self, _arg: &WordDiagonalLine<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCloud'
+    fn word_cloud(&mut self, _arg: &WordCloud<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAppear'
+    fn word_appear(&mut self, _arg: &WordAppear<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLocked'
+    fn word_locked(&mut self, _arg: &WordLocked<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordWill'
+    fn word_will(&mut self, _arg: &WordWill<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordHave'
+    fn word_have(&mut self, _arg: &WordHave<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordChance'
+    fn word_chance(&mut self, _arg: &WordChance<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordMore'
+    fn word_more(&mut self, _arg: &WordMore<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordLikely'
+    fn word_likely(&mut self, _arg: &WordLikely<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordVerySlightly'
+    fn word_very_slightly(&mut self, _arg: &WordVerySlightly<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSlightly'
+    fn word_slightly(&mut self, _arg: &WordSlightly<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordSkyfall'
+    fn word_skyfall(&mut self, _arg: &WordSkyfall<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordCombos'
+    fn word_combos(&mut self, _arg: &WordCombos<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordBecomes'
+    fn word_becomes(&mut self, _arg: &WordBecomes<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordUnmovable'
+    fn word_unmovable(&mut self, _arg: &WordUnmovable<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordDamage'
+    fn word_damage(&mut self, _arg: &WordDamage<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WordAbsorption'
+    fn word_absorption(&mut self, _arg: &WordAbsorption<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'A'
+    fn a(&mut self, _arg: &A<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'And'
+    fn and(&mut self, _arg: &And<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Are'
+    fn are(&mut self, _arg: &Are<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'At'
+    fn at(&mut self, _arg: &At<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'From'
+    fn from(&mut self, _arg: &From<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'In'
+    fn r#in(&mut self, _arg: &In<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Of'
+    fn of(&mut self, _arg: &Of<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'The'
+    fn the(&mut self, _arg: &The<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'To'
+    fn to(&mut self, _arg: &To<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Camma'
+    fn camma(&mut self, _arg: &Camma<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Semicolon'
+    fn semicolon(&mut self, _arg: &Semicolon<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Period'
+    fn period(&mut self, _arg: &Period<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Percent'
+    fn percent(&mut self, _arg: &Percent<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Multi'
+    fn multi(&mut self, _arg: &Multi<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Ordinal'
+    fn ordinal(&mut self, _arg: &Ordinal<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PosInt'
+    fn pos_int(&mut self, _arg: &PosInt<'t>) -> Result<()> {
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 12
+///
+/// Effect: ChangeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectChangeStmt<'t> {
+    pub change_stmt: Box<ChangeStmt<'t>>,
+}
+
+///
+/// Type derived for production 13
+///
+/// Effect: CreateStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectCreateStmt<'t> {
+    pub create_stmt: Box<CreateStmt<'t>>,
+}
+
+///
+/// Type derived for production 14
+///
+/// Effect: UnlockStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectUnlockStmt<'t> {
+    pub unlock_stmt: Box<UnlockStmt<'t>>,
+}
+
+///
+/// Type derived for production 15
+///
+/// Effect: LockStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectLockStmt<'t> {
+    pub lock_stmt: Box<LockStmt<'t>>,
+}
+
+///
+/// Type derived for production 16
+///
+/// Effect: ReplaceStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectReplaceStmt<'t> {
+    pub replace_stmt: Box<ReplaceStmt<'t>>,
+}
+
+///
+/// Type derived for production 17
+///
+/// Effect: EnhanceStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectEnhanceStmt<'t> {
+    pub enhance_stmt: Box<EnhanceStmt<'t>>,
+}
+
+///
+/// Type derived for production 18
+///
+/// Effect: OrbsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectOrbsStmt<'t> {
+    pub orbs_stmt: Box<OrbsStmt<'t>>,
+}
+
+///
+/// Type derived for production 19
+///
+/// Effect: AllOrbsLockedStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectAllOrbsLockedStmt<'t> {
+    pub all_orbs_locked_stmt: Box<AllOrbsLockedStmt<'t>>,
+}
+
+///
+/// Type derived for production 20
+///
+/// Effect: OnlyStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectOnlyStmt<'t> {
+    pub only_stmt: Box<OnlyStmt<'t>>,
+}
+
+///
+/// Type derived for production 21
+///
+/// Effect: EnhancedOrbsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectEnhancedOrbsStmt<'t> {
+    pub enhanced_orbs_stmt: Box<EnhancedOrbsStmt<'t>>,
+}
+
+///
+/// Type derived for production 22
+///
+/// Effect: NailOrbsStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectNailOrbsStmt<'t> {
+    pub nail_orbs_stmt: Box<NailOrbsStmt<'t>>,
+}
+
+///
+/// Type derived for production 23
+///
+/// Effect: NoSkyfallStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectNoSkyfallStmt<'t> {
+    pub no_skyfall_stmt: Box<NoSkyfallStmt<'t>>,
+}
+
+///
+/// Type derived for production 24
+///
+/// Effect: TapeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectTapeStmt<'t> {
+    pub tape_stmt: Box<TapeStmt<'t>>,
+}
+
+///
+/// Type derived for production 25
+///
+/// Effect: VoidStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectVoidStmt<'t> {
+    pub void_stmt: Box<VoidStmt<'t>>,
+}
+
+///
+/// Type derived for production 26
+///
+/// Effect: PierceStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EffectPierceStmt<'t> {
+    pub pierce_stmt: Box<PierceStmt<'t>>,
+}
+
+///
+/// Type derived for production 28
+///
+/// ChangeTarget: WordAll ChangeAllTarget;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTargetWordAllChangeAllTarget<'t> {
+    pub word_all: Box<WordAll<'t>>,
+    pub change_all_target: Box<ChangeAllTarget<'t>>,
+}
+
+///
+/// Type derived for production 29
+///
+/// ChangeTarget: ChangeDropStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTargetChangeDropStmt<'t> {
+    pub change_drop_stmt: Box<ChangeDropStmt<'t>>,
+}
+
+///
+/// Type derived for production 30
+///
+/// ChangeTarget: The ChangeTheTarget;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTargetTheChangeTheTarget<'t> {
+    pub the: Box<The<'t>>,
+    pub change_the_target: Box<ChangeTheTarget<'t>>,
+}
+
+///
+/// Type derived for production 31
+///
+/// ChangeAllTarget: ChangeAllOfBoardStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeAllTargetChangeAllOfBoardStmt<'t> {
+    pub change_all_of_board_stmt: Box<ChangeAllOfBoardStmt<'t>>,
+}
+
+///
+/// Type derived for production 32
+///
+/// ChangeAllTarget: EnemyAttributeChangeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeAllTargetEnemyAttributeChangeStmt<'t> {
+    pub enemy_attribute_change_stmt: Box<EnemyAttributeChangeStmt<'t>>,
+}
+
+///
+/// Type derived for production 38
+///
+/// ChangeTheTarget: ShapeRowColStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTheTargetShapeRowColStmt<'t> {
+    pub shape_row_col_stmt: Box<ShapeRowColStmt<'t>>,
+}
+
+///
+/// Type derived for production 39
+///
+/// ChangeTheTarget: ShapeOfBoardPerimeterStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTheTargetShapeOfBoardPerimeterStmt<'t> {
+    pub shape_of_board_perimeter_stmt: Box<ShapeOfBoardPerimeterStmt<'t>>,
+}
+
+///
+/// Type derived for production 40
+///
+/// ChangeTheTarget: ShapeOfBoardCenterStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTheTargetShapeOfBoardCenterStmt<'t> {
+    pub shape_of_board_center_stmt: Box<ShapeOfBoardCenterStmt<'t>>,
+}
+
+///
+/// Type derived for production 41
+///
+/// ChangeTheTarget: ChangeBoardSizeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeTheTargetChangeBoardSizeStmt<'t> {
+    pub change_board_size_stmt: Box<ChangeBoardSizeStmt<'t>>,
+}
+
+///
+/// Type derived for production 48
+///
+/// RowColSep: Camma;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColSepCamma<'t> {
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for production 49
+///
+/// RowColSep: And;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColSepAnd<'t> {
+    pub and: Box<And<'t>>,
+}
+
+///
+/// Type derived for production 50
+///
+/// RowColPosition: WordLeftmost WordColumn;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColPositionWordLeftmostWordColumn<'t> {
+    pub word_leftmost: Box<WordLeftmost<'t>>,
+    pub word_column: Box<WordColumn<'t>>,
+}
+
+///
+/// Type derived for production 51
+///
+/// RowColPosition: WordRightmost WordColumn;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColPositionWordRightmostWordColumn<'t> {
+    pub word_rightmost: Box<WordRightmost<'t>>,
+    pub word_column: Box<WordColumn<'t>>,
+}
+
+///
+/// Type derived for production 52
+///
+/// RowColPosition: WordTop WordRow;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColPositionWordTopWordRow<'t> {
+    pub word_top: Box<WordTop<'t>>,
+    pub word_row: Box<WordRow<'t>>,
+}
+
+///
+/// Type derived for production 53
+///
+/// RowColPosition: WordBottom WordRow;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColPositionWordBottomWordRow<'t> {
+    pub word_bottom: Box<WordBottom<'t>>,
+    pub word_row: Box<WordRow<'t>>,
+}
+
+///
+/// Type derived for production 54
+///
+/// RowColPosition: Ordinal RowOrColumn From The Direction;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColPositionOrdinalRowOrColumnFromTheDirection<'t> {
+    pub ordinal: Box<Ordinal<'t>>,
+    pub row_or_column: Box<RowOrColumn<'t>>,
+    pub from: Box<From<'t>>,
+    pub the: Box<The<'t>>,
+    pub direction: Box<Direction<'t>>,
+}
+
+///
+/// Type derived for production 55
+///
+/// RowOrColumn: WordColumn;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowOrColumnWordColumn<'t> {
+    pub word_column: Box<WordColumn<'t>>,
+}
+
+///
+/// Type derived for production 56
+///
+/// RowOrColumn: WordRow;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowOrColumnWordRow<'t> {
+    pub word_row: Box<WordRow<'t>>,
+}
+
+///
+/// Type derived for production 57
+///
+/// Direction: WordLeft;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DirectionWordLeft<'t> {
+    pub word_left: Box<WordLeft<'t>>,
+}
+
+///
+/// Type derived for production 58
+///
+/// Direction: WordRight;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DirectionWordRight<'t> {
+    pub word_right: Box<WordRight<'t>>,
+}
+
+///
+/// Type derived for production 59
+///
+/// Direction: WordTop;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DirectionWordTop<'t> {
+    pub word_top: Box<WordTop<'t>>,
+}
+
+///
+/// Type derived for production 60
+///
+/// Direction: WordBottom;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DirectionWordBottom<'t> {
+    pub word_bottom: Box<WordBottom<'t>>,
+}
+
+///
+/// Type derived for production 65
+///
+/// CreateTarget: PosInt CreateQtyTarget;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateTargetPosIntCreateQtyTarget<'t> {
+    pub pos_int: Box<PosInt<'t>>,
+    pub create_qty_target: Box<CreateQtyTarget<'t>>,
+}
+
+///
+/// Type derived for production 66
+///
+/// CreateTarget: A CreateATarget;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateTargetACreateATarget<'t> {
+    pub a: Box<A<'t>>,
+    pub create_a_target: Box<CreateATarget<'t>>,
+}
+
+///
+/// Type derived for production 67
+///
+/// CreateQtyTarget: GenRandomDropStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetGenRandomDropStmt<'t> {
+    pub gen_random_drop_stmt: Box<GenRandomDropStmt<'t>>,
+}
+
+///
+/// Type derived for production 68
+///
+/// CreateQtyTarget: GenEachStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetGenEachStmt<'t> {
+    pub gen_each_stmt: Box<GenEachStmt<'t>>,
+}
+
+///
+/// Type derived for production 69
+///
+/// CreateQtyTarget: LShapeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetLShapeStmt<'t> {
+    pub l_shape_stmt: Box<LShapeStmt<'t>>,
+}
+
+///
+/// Type derived for production 70
+///
+/// CreateQtyTarget: SmallCrossStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetSmallCrossStmt<'t> {
+    pub small_cross_stmt: Box<SmallCrossStmt<'t>>,
+}
+
+///
+/// Type derived for production 71
+///
+/// CreateQtyTarget: SquareStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetSquareStmt<'t> {
+    pub square_stmt: Box<SquareStmt<'t>>,
+}
+
+///
+/// Type derived for production 72
+///
+/// CreateQtyTarget: SomeKindStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetSomeKindStmt<'t> {
+    pub some_kind_stmt: Box<SomeKindStmt<'t>>,
+}
+
+///
+/// Type derived for production 73
+///
+/// CreateQtyTarget: GenRouletteStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateQtyTargetGenRouletteStmt<'t> {
+    pub gen_roulette_stmt: Box<GenRouletteStmt<'t>>,
+}
+
+///
+/// Type derived for production 79
+///
+/// QtySep: Camma;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct QtySepCamma<'t> {
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for production 80
+///
+/// QtySep: And;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct QtySepAnd<'t> {
+    pub and: Box<And<'t>>,
+}
+
+///
+/// Type derived for production 82
+///
+/// GenSource: At GenSourceAt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenSourceAtGenSourceAt<'t> {
+    pub at: Box<At<'t>>,
+    pub gen_source_at: Box<GenSourceAt<'t>>,
+}
+
+///
+/// Type derived for production 83
+///
+/// GenSource: From NonDrops;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenSourceFromNonDrops<'t> {
+    pub from: Box<From<'t>>,
+    pub non_drops: Box<NonDrops<'t>>,
+}
+
+///
+/// Type derived for production 84
+///
+/// GenSource: In WordEach WordCorner Of The WordBoard;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenSourceInWordEachWordCornerOfTheWordBoard<'t> {
+    pub r#in: Box<In<'t>>,
+    pub word_each: Box<WordEach<'t>>,
+    pub word_corner: Box<WordCorner<'t>>,
+    pub of: Box<Of<'t>>,
+    pub the: Box<The<'t>>,
+    pub word_board: Box<WordBoard<'t>>,
+}
+
+///
+/// Type derived for production 85
+///
+/// GenSourceAt: WordRandom;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenSourceAtWordRandom<'t> {
+    pub word_random: Box<WordRandom<'t>>,
+}
+
+///
+/// Type derived for production 86
+///
+/// GenSourceAt: The TopOrBottom Of The WordBoard;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenSourceAtTheTopOrBottomOfTheWordBoard<'t> {
+    pub the: Box<The<'t>>,
+    pub top_or_bottom: Box<TopOrBottom<'t>>,
+    pub of: Box<Of<'t>>,
+    pub the0: Box<The<'t>>,
+    pub word_board: Box<WordBoard<'t>>,
+}
+
+///
+/// Type derived for production 87
+///
+/// TopOrBottom: WordTop;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TopOrBottomWordTop<'t> {
+    pub word_top: Box<WordTop<'t>>,
+}
+
+///
+/// Type derived for production 88
+///
+/// TopOrBottom: WordBottom;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TopOrBottomWordBottom<'t> {
+    pub word_bottom: Box<WordBottom<'t>>,
+}
+
+///
+/// Type derived for production 98
+///
+/// CreateATarget: ZShapeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetZShapeStmt<'t> {
+    pub z_shape_stmt: Box<ZShapeStmt<'t>>,
+}
+
+///
+/// Type derived for production 99
+///
+/// CreateATarget: CrossShapeStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetCrossShapeStmt<'t> {
+    pub cross_shape_stmt: Box<CrossShapeStmt<'t>>,
+}
+
+///
+/// Type derived for production 100
+///
+/// CreateATarget: SpiderwebStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetSpiderwebStmt<'t> {
+    pub spiderweb_stmt: Box<SpiderwebStmt<'t>>,
+}
+
+///
+/// Type derived for production 101
+///
+/// CreateATarget: CrescentMoonStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetCrescentMoonStmt<'t> {
+    pub crescent_moon_stmt: Box<CrescentMoonStmt<'t>>,
+}
+
+///
+/// Type derived for production 102
+///
+/// CreateATarget: ObliqueStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetObliqueStmt<'t> {
+    pub oblique_stmt: Box<ObliqueStmt<'t>>,
+}
+
+///
+/// Type derived for production 103
+///
+/// CreateATarget: GenCloudStmt;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateATargetGenCloudStmt<'t> {
+    pub gen_cloud_stmt: Box<GenCloudStmt<'t>>,
+}
+
+///
+/// Type derived for production 112
+///
+/// LockTarget: WordAll WordOrbs;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LockTargetWordAllWordOrbs<'t> {
+    pub word_all: Box<WordAll<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for production 113
+///
+/// LockTarget: Drops;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LockTargetDrops<'t> {
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for production 116
+///
+/// EnhanceTarget: WordAll WordOrbs;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhanceTargetWordAllWordOrbs<'t> {
+    pub word_all: Box<WordAll<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for production 117
+///
+/// EnhanceTarget: Drops;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhanceTargetDrops<'t> {
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for production 119
+///
+/// OrbsStmtSuffix: MoreLikely;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OrbsStmtSuffixMoreLikely<'t> {
+    pub more_likely: Box<MoreLikely<'t>>,
+}
+
+///
+/// Type derived for production 120
+///
+/// OrbsStmtSuffix: WordAppear WordLocked;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OrbsStmtSuffixWordAppearWordLocked<'t> {
+    pub word_appear: Box<WordAppear<'t>>,
+    pub word_locked: Box<WordLocked<'t>>,
+}
+
+///
+/// Type derived for production 123
+///
+/// OnlyTarget: Drops;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OnlyTargetDrops<'t> {
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for production 124
+///
+/// OnlyTarget: WordEnhanced WordOrbs;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OnlyTargetWordEnhancedWordOrbs<'t> {
+    pub word_enhanced: Box<WordEnhanced<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for production 125
+///
+/// OnlyTarget: WordNail WordOrbs;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OnlyTargetWordNailWordOrbs<'t> {
+    pub word_nail: Box<WordNail<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for production 127
+///
+/// EnhancedOrbsSuffix: WordHave A PosInt Percent WordChance To WordAppear;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhancedOrbsSuffixWordHaveAPosIntPercentWordChanceToWordAppear<'t> {
+    pub word_have: Box<WordHave<'t>>,
+    pub a: Box<A<'t>>,
+    pub pos_int: Box<PosInt<'t>>,
+    pub percent: Box<Percent<'t>>,
+    pub word_chance: Box<WordChance<'t>>,
+    pub to: Box<To<'t>>,
+    pub word_appear: Box<WordAppear<'t>>,
+}
+
+///
+/// Type derived for production 128
+///
+/// EnhancedOrbsSuffix: MoreLikely;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhancedOrbsSuffixMoreLikely<'t> {
+    pub more_likely: Box<MoreLikely<'t>>,
+}
+
+///
+/// Type derived for production 133
+///
+/// Volume: WordVerySlightly;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VolumeWordVerySlightly<'t> {
+    pub word_very_slightly: Box<WordVerySlightly<'t>>,
+}
+
+///
+/// Type derived for production 134
+///
+/// Volume: WordSlightly;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VolumeWordSlightly<'t> {
+    pub word_slightly: Box<WordSlightly<'t>>,
+}
+
+///
+/// Type derived for production 137
+///
+/// TapeTarget: RowColPosition;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TapeTargetRowColPosition<'t> {
+    pub row_col_position: Box<RowColPosition<'t>>,
+}
+
+///
+/// Type derived for production 138
+///
+/// TapeTarget: WordBoard;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TapeTargetWordBoard<'t> {
+    pub word_board: Box<WordBoard<'t>>,
+}
+
+///
+/// Type derived for production 139
+///
+/// BoardPosition: At WordRandom;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct BoardPositionAtWordRandom<'t> {
+    pub at: Box<At<'t>>,
+    pub word_random: Box<WordRandom<'t>>,
+}
+
+///
+/// Type derived for production 140
+///
+/// BoardPosition: In The RowColPosition;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct BoardPositionInTheRowColPosition<'t> {
+    pub r#in: Box<In<'t>>,
+    pub the: Box<The<'t>>,
+    pub row_col_position: Box<RowColPosition<'t>>,
+}
+
+///
+/// Type derived for production 144
+///
+/// VoidSep: Camma;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidSepCamma<'t> {
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for production 145
+///
+/// VoidSep: And;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidSepAnd<'t> {
+    pub and: Box<And<'t>>,
+}
+
+///
+/// Type derived for production 146
+///
+/// VoidTarget: WordAttribute VoidTargetOpt /* Option */ WordAbsorption;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidTargetWordAttributeVoidTargetOptWordAbsorption<'t> {
+    pub word_attribute: Box<WordAttribute<'t>>,
+    pub void_target_opt: Option<Box<VoidTargetOpt<'t>>>,
+    pub word_absorption: Box<WordAbsorption<'t>>,
+}
+
+///
+/// Type derived for production 147
+///
+/// VoidTarget: WordDamage DamageTarget;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidTargetWordDamageDamageTarget<'t> {
+    pub word_damage: Box<WordDamage<'t>>,
+    pub damage_target: Box<DamageTarget<'t>>,
+}
+
+///
+/// Type derived for production 150
+///
+/// DamageTarget: WordAbsorption;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DamageTargetWordAbsorption<'t> {
+    pub word_absorption: Box<WordAbsorption<'t>>,
+}
+
+///
+/// Type derived for production 151
+///
+/// DamageTarget: WordVoid;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DamageTargetWordVoid<'t> {
+    pub word_void: Box<WordVoid<'t>>,
+}
+
+///
+/// Type derived for production 156
+///
+/// DropSep: Camma;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DropSepCamma<'t> {
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for production 157
+///
+/// DropSep: And;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DropSepAnd<'t> {
+    pub and: Box<And<'t>>,
+}
+
+///
+/// Type derived for production 158
+///
+/// DropName: Color;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DropNameColor<'t> {
+    pub color: Box<Color<'t>>,
+}
+
+///
+/// Type derived for production 159
+///
+/// DropName: NonColoredDrop;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DropNameNonColoredDrop<'t> {
+    pub non_colored_drop: Box<NonColoredDrop<'t>>,
+}
+
+///
+/// Type derived for production 160
+///
+/// Color: Fire;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorFire<'t> {
+    pub fire: Box<Fire<'t>>,
+}
+
+///
+/// Type derived for production 161
+///
+/// Color: Water;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorWater<'t> {
+    pub water: Box<Water<'t>>,
+}
+
+///
+/// Type derived for production 162
+///
+/// Color: Wood;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorWood<'t> {
+    pub wood: Box<Wood<'t>>,
+}
+
+///
+/// Type derived for production 163
+///
+/// Color: Lightning;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorLightning<'t> {
+    pub lightning: Box<Lightning<'t>>,
+}
+
+///
+/// Type derived for production 164
+///
+/// Color: Dark;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ColorDark<'t> {
+    pub dark: Box<Dark<'t>>,
+}
+
+///
+/// Type derived for production 165
+///
+/// NonColoredDrop: Recovery;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropRecovery<'t> {
+    pub recovery: Box<Recovery<'t>>,
+}
+
+///
+/// Type derived for production 166
+///
+/// NonColoredDrop: Disturb;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropDisturb<'t> {
+    pub disturb: Box<Disturb<'t>>,
+}
+
+///
+/// Type derived for production 167
+///
+/// NonColoredDrop: Bomb;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropBomb<'t> {
+    pub bomb: Box<Bomb<'t>>,
+}
+
+///
+/// Type derived for production 168
+///
+/// NonColoredDrop: DeadlyPoison;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropDeadlyPoison<'t> {
+    pub deadly_poison: Box<DeadlyPoison<'t>>,
+}
+
+///
+/// Type derived for production 169
+///
+/// NonColoredDrop: Poison;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonColoredDropPoison<'t> {
+    pub poison: Box<Poison<'t>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal A
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct A<'t> {
+    pub a: Token<'t>, /* a\b */
+}
+
+///
+/// Type derived for non-terminal AllOrbsLockedStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct AllOrbsLockedStmt<'t> {
+    pub word_all: Box<WordAll<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub word_appear: Box<WordAppear<'t>>,
+    pub word_locked: Box<WordLocked<'t>>,
+}
+
+///
+/// Type derived for non-terminal And
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct And<'t> {
+    pub and: Token<'t>, /* and\b */
+}
+
+///
+/// Type derived for non-terminal Are
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Are<'t> {
+    pub are: Token<'t>, /* are\b */
+}
+
+///
+/// Type derived for non-terminal At
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct At<'t> {
+    pub at: Token<'t>, /* at\b */
+}
+
+///
+/// Type derived for non-terminal BoardPosition
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum BoardPosition<'t> {
+    AtWordRandom(BoardPositionAtWordRandom<'t>),
+    InTheRowColPosition(BoardPositionInTheRowColPosition<'t>),
+}
+
+///
+/// Type derived for non-terminal Bomb
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Bomb<'t> {
+    pub bomb: Token<'t>, /* Bomb\b */
+}
+
+///
+/// Type derived for non-terminal Camma
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Camma<'t> {
+    pub camma: Token<'t>, /* , */
+}
+
+///
+/// Type derived for non-terminal ChangeAllOfBoardStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeAllOfBoardStmt<'t> {
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub to: Box<To<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal ChangeAllTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ChangeAllTarget<'t> {
+    ChangeAllOfBoardStmt(ChangeAllTargetChangeAllOfBoardStmt<'t>),
+    EnemyAttributeChangeStmt(ChangeAllTargetEnemyAttributeChangeStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal ChangeBoardSizeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeBoardSizeStmt<'t> {
+    pub word_board: Box<WordBoard<'t>>,
+    pub to: Box<To<'t>>,
+    pub size: Box<Size<'t>>,
+}
+
+///
+/// Type derived for non-terminal ChangeDropStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeDropStmt<'t> {
+    pub drops: Box<Drops<'t>>,
+    pub to: Box<To<'t>>,
+    pub drops0: Box<Drops<'t>>,
+    pub change_drop_stmt_opt: Option<Box<ChangeDropStmtOpt<'t>>>,
+}
+
+///
+/// Type derived for non-terminal ChangeDropStmtOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeDropStmtOpt<'t> {
+    pub at: Box<At<'t>>,
+    pub word_random: Box<WordRandom<'t>>,
+}
+
+///
+/// Type derived for non-terminal ChangeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ChangeStmt<'t> {
+    pub word_change: Box<WordChange<'t>>,
+    pub change_target: Box<ChangeTarget<'t>>,
+}
+
+///
+/// Type derived for non-terminal ChangeTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ChangeTarget<'t> {
+    WordAllChangeAllTarget(ChangeTargetWordAllChangeAllTarget<'t>),
+    ChangeDropStmt(ChangeTargetChangeDropStmt<'t>),
+    TheChangeTheTarget(ChangeTargetTheChangeTheTarget<'t>),
+}
+
+///
+/// Type derived for non-terminal ChangeTheTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ChangeTheTarget<'t> {
+    ShapeRowColStmt(ChangeTheTargetShapeRowColStmt<'t>),
+    ShapeOfBoardPerimeterStmt(ChangeTheTargetShapeOfBoardPerimeterStmt<'t>),
+    ShapeOfBoardCenterStmt(ChangeTheTargetShapeOfBoardCenterStmt<'t>),
+    ChangeBoardSizeStmt(ChangeTheTargetChangeBoardSizeStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal Color
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Color<'t> {
+    Fire(ColorFire<'t>),
+    Water(ColorWater<'t>),
+    Wood(ColorWood<'t>),
+    Lightning(ColorLightning<'t>),
+    Dark(ColorDark<'t>),
+}
+
+///
+/// Type derived for non-terminal CreateATarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum CreateATarget<'t> {
+    ZShapeStmt(CreateATargetZShapeStmt<'t>),
+    CrossShapeStmt(CreateATargetCrossShapeStmt<'t>),
+    SpiderwebStmt(CreateATargetSpiderwebStmt<'t>),
+    CrescentMoonStmt(CreateATargetCrescentMoonStmt<'t>),
+    ObliqueStmt(CreateATargetObliqueStmt<'t>),
+    GenCloudStmt(CreateATargetGenCloudStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal CreateQtyTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum CreateQtyTarget<'t> {
+    GenRandomDropStmt(CreateQtyTargetGenRandomDropStmt<'t>),
+    GenEachStmt(CreateQtyTargetGenEachStmt<'t>),
+    LShapeStmt(CreateQtyTargetLShapeStmt<'t>),
+    SmallCrossStmt(CreateQtyTargetSmallCrossStmt<'t>),
+    SquareStmt(CreateQtyTargetSquareStmt<'t>),
+    SomeKindStmt(CreateQtyTargetSomeKindStmt<'t>),
+    GenRouletteStmt(CreateQtyTargetGenRouletteStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal CreateStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CreateStmt<'t> {
+    pub word_create: Box<WordCreate<'t>>,
+    pub create_target: Box<CreateTarget<'t>>,
+}
+
+///
+/// Type derived for non-terminal CreateTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum CreateTarget<'t> {
+    PosIntCreateQtyTarget(CreateTargetPosIntCreateQtyTarget<'t>),
+    ACreateATarget(CreateTargetACreateATarget<'t>),
+}
+
+///
+/// Type derived for non-terminal CrescentMoonStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CrescentMoonStmt<'t> {
+    pub word_crescent_moon: Box<WordCrescentMoon<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal CrossShapeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct CrossShapeStmt<'t> {
+    pub word_large: Box<WordLarge<'t>>,
+    pub word_cross: Box<WordCross<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+    pub word_across: Box<WordAcross<'t>>,
+    pub the: Box<The<'t>>,
+    pub word_board: Box<WordBoard<'t>>,
+}
+
+///
+/// Type derived for non-terminal DamageTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DamageTarget<'t> {
+    WordAbsorption(DamageTargetWordAbsorption<'t>),
+    WordVoid(DamageTargetWordVoid<'t>),
+}
+
+///
+/// Type derived for non-terminal Dark
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Dark<'t> {
+    pub dark: Token<'t>, /* Dark\b */
+}
+
+///
+/// Type derived for non-terminal DeadlyPoison
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DeadlyPoison<'t> {
+    pub deadly_poison: Token<'t>, /* Mortal Poison\b */
+}
+
+///
+/// Type derived for non-terminal Direction
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Direction<'t> {
+    WordLeft(DirectionWordLeft<'t>),
+    WordRight(DirectionWordRight<'t>),
+    WordTop(DirectionWordTop<'t>),
+    WordBottom(DirectionWordBottom<'t>),
+}
+
+///
+/// Type derived for non-terminal Disturb
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Disturb<'t> {
+    pub disturb: Token<'t>, /* Jammer\b */
+}
+
+///
+/// Type derived for non-terminal DropName
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DropName<'t> {
+    Color(DropNameColor<'t>),
+    NonColoredDrop(DropNameNonColoredDrop<'t>),
+}
+
+///
+/// Type derived for non-terminal DropSep
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DropSep<'t> {
+    Camma(DropSepCamma<'t>),
+    And(DropSepAnd<'t>),
+}
+
+///
+/// Type derived for non-terminal Drops
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Drops<'t> {
+    pub drop_name: Box<DropName<'t>>,
+    pub drops_list: Vec<DropsList<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for non-terminal DropsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct DropsList<'t> {
+    pub drop_sep: Box<DropSep<'t>>,
+    pub drop_name: Box<DropName<'t>>,
+}
+
+///
+/// Type derived for non-terminal Effect
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Effect<'t> {
+    ChangeStmt(EffectChangeStmt<'t>),
+    CreateStmt(EffectCreateStmt<'t>),
+    UnlockStmt(EffectUnlockStmt<'t>),
+    LockStmt(EffectLockStmt<'t>),
+    ReplaceStmt(EffectReplaceStmt<'t>),
+    EnhanceStmt(EffectEnhanceStmt<'t>),
+    OrbsStmt(EffectOrbsStmt<'t>),
+    AllOrbsLockedStmt(EffectAllOrbsLockedStmt<'t>),
+    OnlyStmt(EffectOnlyStmt<'t>),
+    EnhancedOrbsStmt(EffectEnhancedOrbsStmt<'t>),
+    NailOrbsStmt(EffectNailOrbsStmt<'t>),
+    NoSkyfallStmt(EffectNoSkyfallStmt<'t>),
+    TapeStmt(EffectTapeStmt<'t>),
+    VoidStmt(EffectVoidStmt<'t>),
+    PierceStmt(EffectPierceStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal EnemyAttributeChangeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnemyAttributeChangeStmt<'t> {
+    pub word_enemies: Box<WordEnemies<'t>>,
+    pub to: Box<To<'t>>,
+    pub color: Box<Color<'t>>,
+    pub word_attribute: Box<WordAttribute<'t>>,
+}
+
+///
+/// Type derived for non-terminal EnhanceStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhanceStmt<'t> {
+    pub word_enhance: Box<WordEnhance<'t>>,
+    pub enhance_target: Box<EnhanceTarget<'t>>,
+}
+
+///
+/// Type derived for non-terminal EnhanceTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EnhanceTarget<'t> {
+    WordAllWordOrbs(EnhanceTargetWordAllWordOrbs<'t>),
+    Drops(EnhanceTargetDrops<'t>),
+}
+
+///
+/// Type derived for non-terminal EnhancedOrbsStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct EnhancedOrbsStmt<'t> {
+    pub word_enhanced: Box<WordEnhanced<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub enhanced_orbs_suffix: Box<EnhancedOrbsSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal EnhancedOrbsSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EnhancedOrbsSuffix<'t> {
+    WordHaveAPosIntPercentWordChanceToWordAppear(
+        EnhancedOrbsSuffixWordHaveAPosIntPercentWordChanceToWordAppear<'t>,
+    ),
+    MoreLikely(EnhancedOrbsSuffixMoreLikely<'t>),
+}
+
+///
+/// Type derived for non-terminal Fire
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Fire<'t> {
+    pub fire: Token<'t>, /* Fire\b */
+}
+
+///
+/// Type derived for non-terminal From
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct From<'t> {
+    pub from: Token<'t>, /* from\b */
+}
+
+///
+/// Type derived for non-terminal GenCloudStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenCloudStmt<'t> {
+    pub size: Box<Size<'t>>,
+    pub word_cloud: Box<WordCloud<'t>>,
+    pub board_position: Box<BoardPosition<'t>>,
+}
+
+///
+/// Type derived for non-terminal GenEachStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenEachStmt<'t> {
+    pub word_each: Box<WordEach<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+    pub gen_source: Box<GenSource<'t>>,
+}
+
+///
+/// Type derived for non-terminal GenRandomDropStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenRandomDropStmt<'t> {
+    pub qty_drop_name: Box<QtyDropName<'t>>,
+    pub gen_random_drop_stmt_list: Vec<GenRandomDropStmtList<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub gen_source: Box<GenSource<'t>>,
+}
+
+///
+/// Type derived for non-terminal GenRandomDropStmtList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenRandomDropStmtList<'t> {
+    pub qty_sep: Box<QtySep<'t>>,
+    pub qty_drop: Box<QtyDrop<'t>>,
+}
+
+///
+/// Type derived for non-terminal GenRouletteStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct GenRouletteStmt<'t> {
+    pub word_roulette: Box<WordRoulette<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub at: Box<At<'t>>,
+    pub word_random: Box<WordRandom<'t>>,
+}
+
+///
+/// Type derived for non-terminal GenSource
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum GenSource<'t> {
+    AtGenSourceAt(GenSourceAtGenSourceAt<'t>),
+    FromNonDrops(GenSourceFromNonDrops<'t>),
+    InWordEachWordCornerOfTheWordBoard(GenSourceInWordEachWordCornerOfTheWordBoard<'t>),
+}
+
+///
+/// Type derived for non-terminal GenSourceAt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum GenSourceAt<'t> {
+    WordRandom(GenSourceAtWordRandom<'t>),
+    TheTopOrBottomOfTheWordBoard(GenSourceAtTheTopOrBottomOfTheWordBoard<'t>),
+}
+
+///
+/// Type derived for non-terminal In
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct In<'t> {
+    pub r#in: Token<'t>, /* in\b */
+}
+
+///
+/// Type derived for non-terminal LShapeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LShapeStmt<'t> {
+    pub word_l_shape: Box<WordLShape<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal Lightning
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Lightning<'t> {
+    pub lightning: Token<'t>, /* Light\b */
+}
+
+///
+/// Type derived for non-terminal LockStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct LockStmt<'t> {
+    pub word_lock: Box<WordLock<'t>>,
+    pub lock_target: Box<LockTarget<'t>>,
+}
+
+///
+/// Type derived for non-terminal LockTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum LockTarget<'t> {
+    WordAllWordOrbs(LockTargetWordAllWordOrbs<'t>),
+    Drops(LockTargetDrops<'t>),
+}
+
+///
+/// Type derived for non-terminal MoreLikely
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MoreLikely<'t> {
+    pub are: Box<Are<'t>>,
+    pub more_likely_opt: Option<Box<MoreLikelyOpt<'t>>>,
+    pub word_more: Box<WordMore<'t>>,
+    pub word_likely: Box<WordLikely<'t>>,
+    pub to: Box<To<'t>>,
+    pub word_appear: Box<WordAppear<'t>>,
+}
+
+///
+/// Type derived for non-terminal MoreLikelyOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct MoreLikelyOpt<'t> {
+    pub volume: Box<Volume<'t>>,
+}
+
+///
+/// Type derived for non-terminal Multi
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Multi<'t> {
+    pub multi: Token<'t>, /* x */
+}
+
+///
+/// Type derived for non-terminal NailOrbsStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NailOrbsStmt<'t> {
+    pub word_nail: Box<WordNail<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+    pub more_likely: Box<MoreLikely<'t>>,
+}
+
+///
+/// Type derived for non-terminal NoSkyfallStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NoSkyfallStmt<'t> {
+    pub word_no: Box<WordNo<'t>>,
+    pub word_skyfall: Box<WordSkyfall<'t>>,
+    pub word_combos: Box<WordCombos<'t>>,
+}
+
+///
+/// Type derived for non-terminal NonColoredDrop
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum NonColoredDrop<'t> {
+    Recovery(NonColoredDropRecovery<'t>),
+    Disturb(NonColoredDropDisturb<'t>),
+    Bomb(NonColoredDropBomb<'t>),
+    DeadlyPoison(NonColoredDropDeadlyPoison<'t>),
+    Poison(NonColoredDropPoison<'t>),
+}
+
+///
+/// Type derived for non-terminal NonDrop
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonDrop<'t> {
+    pub word_non: Box<WordNon<'t>>,
+    pub drop_name: Box<DropName<'t>>,
+}
+
+///
+/// Type derived for non-terminal NonDrops
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonDrops<'t> {
+    pub non_drop: Box<NonDrop<'t>>,
+    pub non_drops_list: Vec<NonDropsList<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for non-terminal NonDropsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct NonDropsList<'t> {
+    pub drop_sep: Box<DropSep<'t>>,
+    pub non_drop: Box<NonDrop<'t>>,
+}
+
+///
+/// Type derived for non-terminal ObliqueStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ObliqueStmt<'t> {
+    pub word_diagonal_line: Box<WordDiagonalLine<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal Of
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Of<'t> {
+    pub of: Token<'t>, /* of\b */
+}
+
+///
+/// Type derived for non-terminal OnlyStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OnlyStmt<'t> {
+    pub word_only: Box<WordOnly<'t>>,
+    pub only_target: Box<OnlyTarget<'t>>,
+    pub word_will: Box<WordWill<'t>>,
+    pub word_appear: Box<WordAppear<'t>>,
+}
+
+///
+/// Type derived for non-terminal OnlyTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum OnlyTarget<'t> {
+    Drops(OnlyTargetDrops<'t>),
+    WordEnhancedWordOrbs(OnlyTargetWordEnhancedWordOrbs<'t>),
+    WordNailWordOrbs(OnlyTargetWordNailWordOrbs<'t>),
+}
+
+///
+/// Type derived for non-terminal OrbsStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct OrbsStmt<'t> {
+    pub drops: Box<Drops<'t>>,
+    pub orbs_stmt_suffix: Box<OrbsStmtSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal OrbsStmtSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum OrbsStmtSuffix<'t> {
+    MoreLikely(OrbsStmtSuffixMoreLikely<'t>),
+    WordAppearWordLocked(OrbsStmtSuffixWordAppearWordLocked<'t>),
+}
+
+///
+/// Type derived for non-terminal Ordinal
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Ordinal<'t> {
+    pub ordinal: Token<'t>, /* [1-9][0-9]*(st|nd|rd|th)\b */
+}
+
+///
+/// Type derived for non-terminal Percent
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Percent<'t> {
+    pub percent: Token<'t>, /* % */
+}
+
+///
+/// Type derived for non-terminal Period
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Period<'t> {
+    pub period: Token<'t>, /* . */
+}
+
+///
+/// Type derived for non-terminal PierceStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct PierceStmt<'t> {
+    pub word_pierce: Box<WordPierce<'t>>,
+    pub word_through: Box<WordThrough<'t>>,
+    pub word_damage: Box<WordDamage<'t>>,
+    pub word_void: Box<WordVoid<'t>>,
+}
+
+///
+/// Type derived for non-terminal Poison
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Poison<'t> {
+    pub poison: Token<'t>, /* Poison\b */
+}
+
+///
+/// Type derived for non-terminal PosInt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct PosInt<'t> {
+    pub pos_int: Token<'t>, /* [1-9][0-9]* */
+}
+
+///
+/// Type derived for non-terminal QtyDrop
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct QtyDrop<'t> {
+    pub pos_int: Box<PosInt<'t>>,
+    pub drop_name: Box<DropName<'t>>,
+}
+
+///
+/// Type derived for non-terminal QtyDropName
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct QtyDropName<'t> {
+    pub drop_name: Box<DropName<'t>>,
+}
+
+///
+/// Type derived for non-terminal QtySep
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum QtySep<'t> {
+    Camma(QtySepCamma<'t>),
+    And(QtySepAnd<'t>),
+}
+
+///
+/// Type derived for non-terminal Recovery
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Recovery<'t> {
+    pub recovery: Token<'t>, /* Heal\b */
+}
+
+///
+/// Type derived for non-terminal ReplaceStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ReplaceStmt<'t> {
+    pub word_replace: Box<WordReplace<'t>>,
+    pub word_all: Box<WordAll<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for non-terminal RowColBlock
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColBlock<'t> {
+    pub row_col_position: Box<RowColPosition<'t>>,
+    pub row_col_block_list: Vec<RowColBlockList<'t>>,
+    pub to: Box<To<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal RowColBlockList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct RowColBlockList<'t> {
+    pub row_col_sep: Box<RowColSep<'t>>,
+    pub the: Box<The<'t>>,
+    pub row_col_position: Box<RowColPosition<'t>>,
+}
+
+///
+/// Type derived for non-terminal RowColPosition
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum RowColPosition<'t> {
+    WordLeftmostWordColumn(RowColPositionWordLeftmostWordColumn<'t>),
+    WordRightmostWordColumn(RowColPositionWordRightmostWordColumn<'t>),
+    WordTopWordRow(RowColPositionWordTopWordRow<'t>),
+    WordBottomWordRow(RowColPositionWordBottomWordRow<'t>),
+    OrdinalRowOrColumnFromTheDirection(RowColPositionOrdinalRowOrColumnFromTheDirection<'t>),
+}
+
+///
+/// Type derived for non-terminal RowColSep
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum RowColSep<'t> {
+    Camma(RowColSepCamma<'t>),
+    And(RowColSepAnd<'t>),
+}
+
+///
+/// Type derived for non-terminal RowOrColumn
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum RowOrColumn<'t> {
+    WordColumn(RowOrColumnWordColumn<'t>),
+    WordRow(RowOrColumnWordRow<'t>),
+}
+
+///
+/// Type derived for non-terminal Semicolon
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Semicolon<'t> {
+    pub semicolon: Token<'t>, /* ; */
+}
+
+///
+/// Type derived for non-terminal Sentence
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Sentence<'t> {
+    pub sentence_opt: Option<Box<SentenceOpt<'t>>>,
+    pub effect: Box<Effect<'t>>,
+    pub sentence_opt0: Option<Box<SentenceOpt0<'t>>>,
+}
+
+///
+/// Type derived for non-terminal SentenceOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SentenceOpt<'t> {
+    pub turns_prefix: Box<TurnsPrefix<'t>>,
+}
+
+///
+/// Type derived for non-terminal SentenceOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SentenceOpt0<'t> {
+    pub turns_suffix: Box<TurnsSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal ShapeOfBoardCenterStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ShapeOfBoardCenterStmt<'t> {
+    pub word_center: Box<WordCenter<'t>>,
+    pub of: Box<Of<'t>>,
+    pub the: Box<The<'t>>,
+    pub word_board: Box<WordBoard<'t>>,
+    pub to: Box<To<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal ShapeOfBoardPerimeterStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ShapeOfBoardPerimeterStmt<'t> {
+    pub word_outer: Box<WordOuter<'t>>,
+    pub word_edges: Box<WordEdges<'t>>,
+    pub of: Box<Of<'t>>,
+    pub the: Box<The<'t>>,
+    pub word_board: Box<WordBoard<'t>>,
+    pub to: Box<To<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal ShapeRowColStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ShapeRowColStmt<'t> {
+    pub row_col_block: Box<RowColBlock<'t>>,
+    pub shape_row_col_stmt_list: Vec<ShapeRowColStmtList<'t>>,
+}
+
+///
+/// Type derived for non-terminal ShapeRowColStmtList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ShapeRowColStmtList<'t> {
+    pub camma: Box<Camma<'t>>,
+    pub and: Box<And<'t>>,
+    pub the: Box<The<'t>>,
+    pub row_col_block: Box<RowColBlock<'t>>,
+}
+
+///
+/// Type derived for non-terminal Size
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Size<'t> {
+    pub pos_int: Box<PosInt<'t>>,
+    pub multi: Box<Multi<'t>>,
+    pub pos_int0: Box<PosInt<'t>>,
+}
+
+///
+/// Type derived for non-terminal SkillEnLines
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillEnLines<'t> {
+    pub sentence: Box<Sentence<'t>>,
+    pub skill_en_lines_list: Vec<SkillEnLinesList<'t>>,
+    pub skill_en_lines_opt: Option<Box<SkillEnLinesOpt<'t>>>,
+}
+
+///
+/// Type derived for non-terminal SkillEnLinesList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillEnLinesList<'t> {
+    pub semicolon: Box<Semicolon<'t>>,
+    pub sentence: Box<Sentence<'t>>,
+}
+
+///
+/// Type derived for non-terminal SkillEnLinesOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SkillEnLinesOpt<'t> {
+    pub period: Box<Period<'t>>,
+}
+
+///
+/// Type derived for non-terminal SmallCrossStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SmallCrossStmt<'t> {
+    pub word_cross: Box<WordCross<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal SomeKindStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SomeKindStmt<'t> {
+    pub pos_int: Box<PosInt<'t>>,
+    pub word_shape: Box<WordShape<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal SpiderwebStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SpiderwebStmt<'t> {
+    pub word_spiderweb: Box<WordSpiderweb<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+    pub word_with: Box<WordWith<'t>>,
+    pub drops0: Box<Drops<'t>>,
+    pub at: Box<At<'t>>,
+    pub word_its: Box<WordIts<'t>>,
+    pub word_center: Box<WordCenter<'t>>,
+}
+
+///
+/// Type derived for non-terminal SquareStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct SquareStmt<'t> {
+    pub size: Box<Size<'t>>,
+    pub word_square: Box<WordSquare<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+///
+/// Type derived for non-terminal TapeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TapeStmt<'t> {
+    pub the: Box<The<'t>>,
+    pub tape_target: Box<TapeTarget<'t>>,
+    pub word_becomes: Box<WordBecomes<'t>>,
+    pub word_unmovable: Box<WordUnmovable<'t>>,
+}
+
+///
+/// Type derived for non-terminal TapeTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TapeTarget<'t> {
+    RowColPosition(TapeTargetRowColPosition<'t>),
+    WordBoard(TapeTargetWordBoard<'t>),
+}
+
+///
+/// Type derived for non-terminal The
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct The<'t> {
+    pub the: Token<'t>, /* [Tt]he\b */
+}
+
+///
+/// Type derived for non-terminal To
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct To<'t> {
+    pub to: Token<'t>, /* to\b */
+}
+
+///
+/// Type derived for non-terminal TopOrBottom
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TopOrBottom<'t> {
+    WordTop(TopOrBottomWordTop<'t>),
+    WordBottom(TopOrBottomWordBottom<'t>),
+}
+
+///
+/// Type derived for non-terminal TurnsPrefix
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TurnsPrefix<'t> {
+    pub word_for: Box<WordFor<'t>>,
+    pub pos_int: Box<PosInt<'t>>,
+    pub word_turn: Box<WordTurn<'t>>,
+    pub camma: Box<Camma<'t>>,
+}
+
+///
+/// Type derived for non-terminal TurnsSuffix
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct TurnsSuffix<'t> {
+    pub word_for: Box<WordFor<'t>>,
+    pub pos_int: Box<PosInt<'t>>,
+    pub word_turn: Box<WordTurn<'t>>,
+}
+
+///
+/// Type derived for non-terminal UnlockStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct UnlockStmt<'t> {
+    pub word_unlock: Box<WordUnlock<'t>>,
+    pub word_all: Box<WordAll<'t>>,
+    pub word_orbs: Box<WordOrbs<'t>>,
+}
+
+///
+/// Type derived for non-terminal VoidSep
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum VoidSep<'t> {
+    Camma(VoidSepCamma<'t>),
+    And(VoidSepAnd<'t>),
+}
+
+///
+/// Type derived for non-terminal VoidStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidStmt<'t> {
+    pub word_void: Box<WordVoid<'t>>,
+    pub void_target: Box<VoidTarget<'t>>,
+    pub void_stmt_list: Vec<VoidStmtList<'t>>,
+}
+
+///
+/// Type derived for non-terminal VoidStmtList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidStmtList<'t> {
+    pub void_sep: Box<VoidSep<'t>>,
+    pub void_target: Box<VoidTarget<'t>>,
+}
+
+///
+/// Type derived for non-terminal VoidTarget
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum VoidTarget<'t> {
+    WordAttributeVoidTargetOptWordAbsorption(
+        VoidTargetWordAttributeVoidTargetOptWordAbsorption<'t>,
+    ),
+    WordDamageDamageTarget(VoidTargetWordDamageDamageTarget<'t>),
+}
+
+///
+/// Type derived for non-terminal VoidTargetOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct VoidTargetOpt<'t> {
+    pub and: Box<And<'t>>,
+    pub word_damage: Box<WordDamage<'t>>,
+}
+
+///
+/// Type derived for non-terminal Volume
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Volume<'t> {
+    WordVerySlightly(VolumeWordVerySlightly<'t>),
+    WordSlightly(VolumeWordSlightly<'t>),
+}
+
+///
+/// Type derived for non-terminal Water
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Water<'t> {
+    pub water: Token<'t>, /* Water\b */
+}
+
+///
+/// Type derived for non-terminal Wood
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct Wood<'t> {
+    pub wood: Token<'t>, /* Wood\b */
+}
+
+///
+/// Type derived for non-terminal WordAbsorption
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAbsorption<'t> {
+    pub word_absorption: Token<'t>, /* absorption\b */
+}
+
+///
+/// Type derived for non-terminal WordAcross
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAcross<'t> {
+    pub word_across: Token<'t>, /* across\b */
+}
+
+///
+/// Type derived for non-terminal WordAll
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAll<'t> {
+    pub word_all: Token<'t>, /* [Aa]ll\b */
+}
+
+///
+/// Type derived for non-terminal WordAppear
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAppear<'t> {
+    pub word_appear: Token<'t>, /* appear\b */
+}
+
+///
+/// Type derived for non-terminal WordAttribute
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordAttribute<'t> {
+    pub word_attribute: Token<'t>, /* attribute\b */
+}
+
+///
+/// Type derived for non-terminal WordBecomes
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordBecomes<'t> {
+    pub word_becomes: Token<'t>, /* becomes\b */
+}
+
+///
+/// Type derived for non-terminal WordBoard
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordBoard<'t> {
+    pub word_board: Token<'t>, /* board\b */
+}
+
+///
+/// Type derived for non-terminal WordBottom
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordBottom<'t> {
+    pub word_bottom: Token<'t>, /* bottom\b */
+}
+
+///
+/// Type derived for non-terminal WordCenter
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCenter<'t> {
+    pub word_center: Token<'t>, /* center\b */
+}
+
+///
+/// Type derived for non-terminal WordChance
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordChance<'t> {
+    pub word_chance: Token<'t>, /* chance\b */
+}
+
+///
+/// Type derived for non-terminal WordChange
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordChange<'t> {
+    pub word_change: Token<'t>, /* [Cc]hange\b */
+}
+
+///
+/// Type derived for non-terminal WordCloud
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCloud<'t> {
+    pub word_cloud: Token<'t>, /* cloud\b */
+}
+
+///
+/// Type derived for non-terminal WordColumn
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordColumn<'t> {
+    pub word_column: Token<'t>, /* column\b */
+}
+
+///
+/// Type derived for non-terminal WordCombos
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCombos<'t> {
+    pub word_combos: Token<'t>, /* combos\b */
+}
+
+///
+/// Type derived for non-terminal WordCorner
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCorner<'t> {
+    pub word_corner: Token<'t>, /* corner\b */
+}
+
+///
+/// Type derived for non-terminal WordCreate
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCreate<'t> {
+    pub word_create: Token<'t>, /* [Cc]reate\b */
+}
+
+///
+/// Type derived for non-terminal WordCrescentMoon
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCrescentMoon<'t> {
+    pub word_crescent_moon: Token<'t>, /* crescent moon\b */
+}
+
+///
+/// Type derived for non-terminal WordCross
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordCross<'t> {
+    pub word_cross: Token<'t>, /* cross(es)?\b */
+}
+
+///
+/// Type derived for non-terminal WordDamage
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordDamage<'t> {
+    pub word_damage: Token<'t>, /* damage\b */
+}
+
+///
+/// Type derived for non-terminal WordDiagonalLine
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordDiagonalLine<'t> {
+    pub word_diagonal_line: Token<'t>, /* diagonal line\b */
+}
+
+///
+/// Type derived for non-terminal WordEach
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordEach<'t> {
+    pub word_each: Token<'t>, /* each\b */
+}
+
+///
+/// Type derived for non-terminal WordEdges
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordEdges<'t> {
+    pub word_edges: Token<'t>, /* edges\b */
+}
+
+///
+/// Type derived for non-terminal WordEnemies
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordEnemies<'t> {
+    pub word_enemies: Token<'t>, /* enemies\b */
+}
+
+///
+/// Type derived for non-terminal WordEnhance
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordEnhance<'t> {
+    pub word_enhance: Token<'t>, /* [Ee]nhance\b */
+}
+
+///
+/// Type derived for non-terminal WordEnhanced
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordEnhanced<'t> {
+    pub word_enhanced: Token<'t>, /* [Ee]nhanced\b */
+}
+
+///
+/// Type derived for non-terminal WordFor
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordFor<'t> {
+    pub word_for: Token<'t>, /* [Ff]or\b */
+}
+
+///
+/// Type derived for non-terminal WordHave
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordHave<'t> {
+    pub word_have: Token<'t>, /* have\b */
+}
+
+///
+/// Type derived for non-terminal WordIts
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordIts<'t> {
+    pub word_its: Token<'t>, /* its\b */
+}
+
+///
+/// Type derived for non-terminal WordLShape
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLShape<'t> {
+    pub word_l_shape: Token<'t>, /* L shapes?\b */
+}
+
+///
+/// Type derived for non-terminal WordLarge
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLarge<'t> {
+    pub word_large: Token<'t>, /* large\b */
+}
+
+///
+/// Type derived for non-terminal WordLeft
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLeft<'t> {
+    pub word_left: Token<'t>, /* left\b */
+}
+
+///
+/// Type derived for non-terminal WordLeftmost
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLeftmost<'t> {
+    pub word_leftmost: Token<'t>, /* leftmost\b */
+}
+
+///
+/// Type derived for non-terminal WordLikely
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLikely<'t> {
+    pub word_likely: Token<'t>, /* likely\b */
+}
+
+///
+/// Type derived for non-terminal WordLock
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLock<'t> {
+    pub word_lock: Token<'t>, /* [Ll]ock\b */
+}
+
+///
+/// Type derived for non-terminal WordLocked
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordLocked<'t> {
+    pub word_locked: Token<'t>, /* locked\b */
+}
+
+///
+/// Type derived for non-terminal WordMore
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordMore<'t> {
+    pub word_more: Token<'t>, /* more\b */
+}
+
+///
+/// Type derived for non-terminal WordNail
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordNail<'t> {
+    pub word_nail: Token<'t>, /* [Nn]ail\b */
+}
+
+///
+/// Type derived for non-terminal WordNo
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordNo<'t> {
+    pub word_no: Token<'t>, /* [Nn]o\b */
+}
+
+///
+/// Type derived for non-terminal WordNon
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordNon<'t> {
+    pub word_non: Token<'t>, /* non- */
+}
+
+///
+/// Type derived for non-terminal WordOnly
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordOnly<'t> {
+    pub word_only: Token<'t>, /* [Oo]nly\b */
+}
+
+///
+/// Type derived for non-terminal WordOrbs
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordOrbs<'t> {
+    pub word_orbs: Token<'t>, /* [Oo]rbs?\b */
+}
+
+///
+/// Type derived for non-terminal WordOuter
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordOuter<'t> {
+    pub word_outer: Token<'t>, /* outer\b */
+}
+
+///
+/// Type derived for non-terminal WordPierce
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordPierce<'t> {
+    pub word_pierce: Token<'t>, /* [Pp]ierces?\b */
+}
+
+///
+/// Type derived for non-terminal WordRandom
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRandom<'t> {
+    pub word_random: Token<'t>, /* random\b */
+}
+
+///
+/// Type derived for non-terminal WordReplace
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordReplace<'t> {
+    pub word_replace: Token<'t>, /* [Rr]eplace\b */
+}
+
+///
+/// Type derived for non-terminal WordRight
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRight<'t> {
+    pub word_right: Token<'t>, /* right\b */
+}
+
+///
+/// Type derived for non-terminal WordRightmost
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRightmost<'t> {
+    pub word_rightmost: Token<'t>, /* rightmost\b */
+}
+
+///
+/// Type derived for non-terminal WordRoulette
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRoulette<'t> {
+    pub word_roulette: Token<'t>, /* roulette\b */
+}
+
+///
+/// Type derived for non-terminal WordRow
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordRow<'t> {
+    pub word_row: Token<'t>, /* row\b */
+}
+
+///
+/// Type derived for non-terminal WordShape
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordShape<'t> {
+    pub word_shape: Token<'t>, /* shapes?\b */
+}
+
+///
+/// Type derived for non-terminal WordSkyfall
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSkyfall<'t> {
+    pub word_skyfall: Token<'t>, /* skyfall\b */
+}
+
+///
+/// Type derived for non-terminal WordSlightly
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSlightly<'t> {
+    pub word_slightly: Token<'t>, /* slightly\b */
+}
+
+///
+/// Type derived for non-terminal WordSpiderweb
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSpiderweb<'t> {
+    pub word_spiderweb: Token<'t>, /* spiderweb\b */
+}
+
+///
+/// Type derived for non-terminal WordSquare
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordSquare<'t> {
+    pub word_square: Token<'t>, /* squares?\b */
+}
+
+///
+/// Type derived for non-terminal WordThrough
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordThrough<'t> {
+    pub word_through: Token<'t>, /* through\b */
+}
+
+///
+/// Type derived for non-terminal WordTop
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordTop<'t> {
+    pub word_top: Token<'t>, /* top\b */
+}
+
+///
+/// Type derived for non-terminal WordTurn
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordTurn<'t> {
+    pub word_turn: Token<'t>, /* turns?\b */
+}
+
+///
+/// Type derived for non-terminal WordUnlock
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordUnlock<'t> {
+    pub word_unlock: Token<'t>, /* [Uu]nlock\b */
+}
+
+///
+/// Type derived for non-terminal WordUnmovable
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordUnmovable<'t> {
+    pub word_unmovable: Token<'t>, /* unmovable\b */
+}
+
+///
+/// Type derived for non-terminal WordVerySlightly
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordVerySlightly<'t> {
+    pub word_very_slightly: Token<'t>, /* very slightly\b */
+}
+
+///
+/// Type derived for non-terminal WordVoid
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordVoid<'t> {
+    pub word_void: Token<'t>, /* [Vv]oids?\b */
+}
+
+///
+/// Type derived for non-terminal WordWill
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordWill<'t> {
+    pub word_will: Token<'t>, /* will\b */
+}
+
+///
+/// Type derived for non-terminal WordWith
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordWith<'t> {
+    pub word_with: Token<'t>, /* with\b */
+}
+
+///
+/// Type derived for non-terminal WordZShape
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct WordZShape<'t> {
+    pub word_z_shape: Token<'t>, /* Z shape\b */
+}
+
+///
+/// Type derived for non-terminal ZShapeStmt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "derive_builder")]
+pub struct ZShapeStmt<'t> {
+    pub word_z_shape: Box<WordZShape<'t>>,
+    pub of: Box<Of<'t>>,
+    pub drops: Box<Drops<'t>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    A(A<'t>),
+    AllOrbsLockedStmt(AllOrbsLockedStmt<'t>),
+    And(And<'t>),
+    Are(Are<'t>),
+    At(At<'t>),
+    BoardPosition(BoardPosition<'t>),
+    Bomb(Bomb<'t>),
+    Camma(Camma<'t>),
+    ChangeAllOfBoardStmt(ChangeAllOfBoardStmt<'t>),
+    ChangeAllTarget(ChangeAllTarget<'t>),
+    ChangeBoardSizeStmt(ChangeBoardSizeStmt<'t>),
+    ChangeDropStmt(ChangeDropStmt<'t>),
+    ChangeDropStmtOpt(Option<Box<ChangeDropStmtOpt<'t>>>),
+    ChangeStmt(ChangeStmt<'t>),
+    ChangeTarget(ChangeTarget<'t>),
+    ChangeTheTarget(ChangeTheTarget<'t>),
+    Color(Color<'t>),
+    CreateATarget(CreateATarget<'t>),
+    CreateQtyTarget(CreateQtyTarget<'t>),
+    CreateStmt(CreateStmt<'t>),
+    CreateTarget(CreateTarget<'t>),
+    CrescentMoonStmt(CrescentMoonStmt<'t>),
+    CrossShapeStmt(CrossShapeStmt<'t>),
+    DamageTarget(DamageTarget<'t>),
+    Dark(Dark<'t>),
+    DeadlyPoison(DeadlyPoison<'t>),
+    Direction(Direction<'t>),
+    Disturb(Disturb<'t>),
+    DropName(DropName<'t>),
+    DropSep(DropSep<'t>),
+    Drops(Drops<'t>),
+    DropsList(Vec<DropsList<'t>>),
+    Effect(Effect<'t>),
+    EnemyAttributeChangeStmt(EnemyAttributeChangeStmt<'t>),
+    EnhanceStmt(EnhanceStmt<'t>),
+    EnhanceTarget(EnhanceTarget<'t>),
+    EnhancedOrbsStmt(EnhancedOrbsStmt<'t>),
+    EnhancedOrbsSuffix(EnhancedOrbsSuffix<'t>),
+    Fire(Fire<'t>),
+    From(From<'t>),
+    GenCloudStmt(GenCloudStmt<'t>),
+    GenEachStmt(GenEachStmt<'t>),
+    GenRandomDropStmt(GenRandomDropStmt<'t>),
+    GenRandomDropStmtList(Vec<GenRandomDropStmtList<'t>>),
+    GenRouletteStmt(GenRouletteStmt<'t>),
+    GenSource(GenSource<'t>),
+    GenSourceAt(GenSourceAt<'t>),
+    In(In<'t>),
+    LShapeStmt(LShapeStmt<'t>),
+    Lightning(Lightning<'t>),
+    LockStmt(LockStmt<'t>),
+    LockTarget(LockTarget<'t>),
+    MoreLikely(MoreLikely<'t>),
+    MoreLikelyOpt(Option<Box<MoreLikelyOpt<'t>>>),
+    Multi(Multi<'t>),
+    NailOrbsStmt(NailOrbsStmt<'t>),
+    NoSkyfallStmt(NoSkyfallStmt<'t>),
+    NonColoredDrop(NonColoredDrop<'t>),
+    NonDrop(NonDrop<'t>),
+    NonDrops(NonDrops<'t>),
+    NonDropsList(Vec<NonDropsList<'t>>),
+    ObliqueStmt(ObliqueStmt<'t>),
+    Of(Of<'t>),
+    OnlyStmt(OnlyStmt<'t>),
+    OnlyTarget(OnlyTarget<'t>),
+    OrbsStmt(OrbsStmt<'t>),
+    OrbsStmtSuffix(OrbsStmtSuffix<'t>),
+    Ordinal(Ordinal<'t>),
+    Percent(Percent<'t>),
+    Period(Period<'t>),
+    PierceStmt(PierceStmt<'t>),
+    Poison(Poison<'t>),
+    PosInt(PosInt<'t>),
+    QtyDrop(QtyDrop<'t>),
+    QtyDropName(QtyDropName<'t>),
+    QtySep(QtySep<'t>),
+    Recovery(Recovery<'t>),
+    ReplaceStmt(ReplaceStmt<'t>),
+    RowColBlock(RowColBlock<'t>),
+    RowColBlockList(Vec<RowColBlockList<'t>>),
+    RowColPosition(RowColPosition<'t>),
+    RowColSep(RowColSep<'t>),
+    RowOrColumn(RowOrColumn<'t>),
+    Semicolon(Semicolon<'t>),
+    Sentence(Sentence<'t>),
+    SentenceOpt(Option<Box<SentenceOpt<'t>>>),
+    SentenceOpt0(Option<Box<SentenceOpt0<'t>>>),
+    ShapeOfBoardCenterStmt(ShapeOfBoardCenterStmt<'t>),
+    ShapeOfBoardPerimeterStmt(ShapeOfBoardPerimeterStmt<'t>),
+    ShapeRowColStmt(ShapeRowColStmt<'t>),
+    ShapeRowColStmtList(Vec<ShapeRowColStmtList<'t>>),
+    Size(Size<'t>),
+    SkillEnLines(SkillEnLines<'t>),
+    SkillEnLinesList(Vec<SkillEnLinesList<'t>>),
+    SkillEnLinesOpt(Option<Box<SkillEnLinesOpt<'t>>>),
+    SmallCrossStmt(SmallCrossStmt<'t>),
+    SomeKindStmt(SomeKindStmt<'t>),
+    SpiderwebStmt(SpiderwebStmt<'t>),
+    SquareStmt(SquareStmt<'t>),
+    TapeStmt(TapeStmt<'t>),
+    TapeTarget(TapeTarget<'t>),
+    The(The<'t>),
+    To(To<'t>),
+    TopOrBottom(TopOrBottom<'t>),
+    TurnsPrefix(TurnsPrefix<'t>),
+    TurnsSuffix(TurnsSuffix<'t>),
+    UnlockStmt(UnlockStmt<'t>),
+    VoidSep(VoidSep<'t>),
+    VoidStmt(VoidStmt<'t>),
+    VoidStmtList(Vec<VoidStmtList<'t>>),
+    VoidTarget(VoidTarget<'t>),
+    VoidTargetOpt(Option<Box<VoidTargetOpt<'t>>>),
+    Volume(Volume<'t>),
+    Water(Water<'t>),
+    Wood(Wood<'t>),
+    WordAbsorption(WordAbsorption<'t>),
+    WordAcross(WordAcross<'t>),
+    WordAll(WordAll<'t>),
+    WordAppear(WordAppear<'t>),
+    WordAttribute(WordAttribute<'t>),
+    WordBecomes(WordBecomes<'t>),
+    WordBoard(WordBoard<'t>),
+    WordBottom(WordBottom<'t>),
+    WordCenter(WordCenter<'t>),
+    WordChance(WordChance<'t>),
+    WordChange(WordChange<'t>),
+    WordCloud(WordCloud<'t>),
+    WordColumn(WordColumn<'t>),
+    WordCombos(WordCombos<'t>),
+    WordCorner(WordCorner<'t>),
+    WordCreate(WordCreate<'t>),
+    WordCrescentMoon(WordCrescentMoon<'t>),
+    WordCross(WordCross<'t>),
+    WordDamage(WordDamage<'t>),
+    WordDiagonalLine(WordDiagonalLine<'t>),
+    WordEach(WordEach<'t>),
+    WordEdges(WordEdges<'t>),
+    WordEnemies(WordEnemies<'t>),
+    WordEnhance(WordEnhance<'t>),
+    WordEnhanced(WordEnhanced<'t>),
+    WordFor(WordFor<'t>),
+    WordHave(WordHave<'t>),
+    WordIts(WordIts<'t>),
+    WordLShape(WordLShape<'t>),
+    WordLarge(WordLarge<'t>),
+    WordLeft(WordLeft<'t>),
+    WordLeftmost(WordLeftmost<'t>),
+    WordLikely(WordLikely<'t>),
+    WordLock(WordLock<'t>),
+    WordLocked(WordLocked<'t>),
+    WordMore(WordMore<'t>),
+    WordNail(WordNail<'t>),
+    WordNo(WordNo<'t>),
+    WordNon(WordNon<'t>),
+    WordOnly(WordOnly<'t>),
+    WordOrbs(WordOrbs<'t>),
+    WordOuter(WordOuter<'t>),
+    WordPierce(WordPierce<'t>),
+    WordRandom(WordRandom<'t>),
+    WordReplace(WordReplace<'t>),
+    WordRight(WordRight<'t>),
+    WordRightmost(WordRightmost<'t>),
+    WordRoulette(WordRoulette<'t>),
+    WordRow(WordRow<'t>),
+    WordShape(WordShape<'t>),
+    WordSkyfall(WordSkyfall<'t>),
+    WordSlightly(WordSlightly<'t>),
+    WordSpiderweb(WordSpiderweb<'t>),
+    WordSquare(WordSquare<'t>),
+    WordThrough(WordThrough<'t>),
+    WordTop(WordTop<'t>),
+    WordTurn(WordTurn<'t>),
+    WordUnlock(WordUnlock<'t>),
+    WordUnmovable(WordUnmovable<'t>),
+    WordVerySlightly(WordVerySlightly<'t>),
+    WordVoid(WordVoid<'t>),
+    WordWill(WordWill<'t>),
+    WordWith(WordWith<'t>),
+    WordZShape(WordZShape<'t>),
+    ZShapeStmt(ZShapeStmt<'t>),
+}
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct SkillEnGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn SkillEnGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `SkillEnGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> SkillEnGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn SkillEnGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {}: {:?}", context, item);
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        if !self.item_stack.is_empty() {
+            let item = self.item_stack.pop();
+            if let Some(ref item) = item {
+                trace!("pop     {}: {:?}", context, item);
+            }
+            item
+        } else {
+            None
+        }
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {:?}", s))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// SkillEnLines: Sentence SkillEnLinesList /* Vec */ SkillEnLinesOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_en_lines(
+        &mut self,
+        _sentence: &ParseTreeStackEntry<'t>,
+        _skill_en_lines_list: &ParseTreeStackEntry<'t>,
+        _skill_en_lines_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let skill_en_lines_opt = pop_item!(self, skill_en_lines_opt, SkillEnLinesOpt, context);
+        let skill_en_lines_list =
+            pop_and_reverse_item!(self, skill_en_lines_list, SkillEnLinesList, context);
+        let sentence = pop_item!(self, sentence, Sentence, context);
+        let skill_en_lines_built = SkillEnLinesBuilder::default()
+            .sentence(Box::new(sentence))
+            .skill_en_lines_list(skill_en_lines_list)
+            .skill_en_lines_opt(skill_en_lines_opt)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.skill_en_lines(&skill_en_lines_built)?;
+        self.push(ASTType::SkillEnLines(skill_en_lines_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// SkillEnLinesList /* Vec<T>::Push */: Semicolon Sentence SkillEnLinesList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_en_lines_list_0(
+        &mut self,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _sentence: &ParseTreeStackEntry<'t>,
+        _skill_en_lines_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut skill_en_lines_list =
+            pop_item!(self, skill_en_lines_list, SkillEnLinesList, context);
+        let sentence = pop_item!(self, sentence, Sentence, context);
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let skill_en_lines_list_0_built = SkillEnLinesListBuilder::default()
+            .sentence(Box::new(sentence))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        skill_en_lines_list.push(skill_en_lines_list_0_built);
+        self.push(ASTType::SkillEnLinesList(skill_en_lines_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// SkillEnLinesList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_en_lines_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let skill_en_lines_list_1_built = Vec::new();
+        self.push(
+            ASTType::SkillEnLinesList(skill_en_lines_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// SkillEnLinesOpt /* Option<T>::Some */: Period;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_en_lines_opt_0(
+        &mut self,
+        _period: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = pop_item!(self, period, Period, context);
+        let skill_en_lines_opt_0_built = SkillEnLinesOptBuilder::default()
+            .period(Box::new(period))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SkillEnLinesOpt(Some(Box::new(skill_en_lines_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// SkillEnLinesOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn skill_en_lines_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SkillEnLinesOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// Sentence: SentenceOpt /* Option */ Effect SentenceOpt0 /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn sentence(
+        &mut self,
+        _sentence_opt: &ParseTreeStackEntry<'t>,
+        _effect: &ParseTreeStackEntry<'t>,
+        _sentence_opt0: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let sentence_opt0 = pop_item!(self, sentence_opt0, SentenceOpt0, context);
+        let effect = pop_item!(self, effect, Effect, context);
+        let sentence_opt = pop_item!(self, sentence_opt, SentenceOpt, context);
+        let sentence_built = SentenceBuilder::default()
+            .sentence_opt(sentence_opt)
+            .effect(Box::new(effect))
+            .sentence_opt0(sentence_opt0)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.sentence(&sentence_built)?;
+        self.push(ASTType::Sentence(sentence_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// SentenceOpt0 /* Option<T>::Some */: TurnsSuffix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn sentence_opt0_0(
+        &mut self,
+        _turns_suffix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let turns_suffix = pop_item!(self, turns_suffix, TurnsSuffix, context);
+        let sentence_opt0_0_built = SentenceOpt0Builder::default()
+            .turns_suffix(Box::new(turns_suffix))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SentenceOpt0(Some(Box::new(sentence_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// SentenceOpt0 /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn sentence_opt0_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SentenceOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// SentenceOpt /* Option<T>::Some */: TurnsPrefix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn sentence_opt_0(
+        &mut self,
+        _turns_prefix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let turns_prefix = pop_item!(self, turns_prefix, TurnsPrefix, context);
+        let sentence_opt_0_built = SentenceOptBuilder::default()
+            .turns_prefix(Box::new(turns_prefix))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SentenceOpt(Some(Box::new(sentence_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// SentenceOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn sentence_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SentenceOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 10:
+    ///
+    /// TurnsPrefix: WordFor PosInt WordTurn Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn turns_prefix(
+        &mut self,
+        _word_for: &ParseTreeStackEntry<'t>,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _word_turn: &ParseTreeStackEntry<'t>,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let word_turn = pop_item!(self, word_turn, WordTurn, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let word_for = pop_item!(self, word_for, WordFor, context);
+        let turns_prefix_built = TurnsPrefixBuilder::default()
+            .word_for(Box::new(word_for))
+            .pos_int(Box::new(pos_int))
+            .word_turn(Box::new(word_turn))
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.turns_prefix(&turns_prefix_built)?;
+        self.push(ASTType::TurnsPrefix(turns_prefix_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
+    /// TurnsSuffix: WordFor PosInt WordTurn;
+    ///
+    #[parol_runtime::function_name::named]
+    fn turns_suffix(
+        &mut self,
+        _word_for: &ParseTreeStackEntry<'t>,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _word_turn: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_turn = pop_item!(self, word_turn, WordTurn, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let word_for = pop_item!(self, word_for, WordFor, context);
+        let turns_suffix_built = TurnsSuffixBuilder::default()
+            .word_for(Box::new(word_for))
+            .pos_int(Box::new(pos_int))
+            .word_turn(Box::new(word_turn))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.turns_suffix(&turns_suffix_built)?;
+        self.push(ASTType::TurnsSuffix(turns_suffix_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
+    /// Effect: ChangeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_0(
+        &mut self,
+        _change_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_stmt = pop_item!(self, change_stmt, ChangeStmt, context);
+        let effect_0_built = EffectChangeStmtBuilder::default()
+            .change_stmt(Box::new(change_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_0_built = Effect::ChangeStmt(effect_0_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_0_built)?;
+        self.push(ASTType::Effect(effect_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// Effect: CreateStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_1(
+        &mut self,
+        _create_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let create_stmt = pop_item!(self, create_stmt, CreateStmt, context);
+        let effect_1_built = EffectCreateStmtBuilder::default()
+            .create_stmt(Box::new(create_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_1_built = Effect::CreateStmt(effect_1_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_1_built)?;
+        self.push(ASTType::Effect(effect_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// Effect: UnlockStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_2(
+        &mut self,
+        _unlock_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let unlock_stmt = pop_item!(self, unlock_stmt, UnlockStmt, context);
+        let effect_2_built = EffectUnlockStmtBuilder::default()
+            .unlock_stmt(Box::new(unlock_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_2_built = Effect::UnlockStmt(effect_2_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_2_built)?;
+        self.push(ASTType::Effect(effect_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// Effect: LockStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_3(
+        &mut self,
+        _lock_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lock_stmt = pop_item!(self, lock_stmt, LockStmt, context);
+        let effect_3_built = EffectLockStmtBuilder::default()
+            .lock_stmt(Box::new(lock_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_3_built = Effect::LockStmt(effect_3_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_3_built)?;
+        self.push(ASTType::Effect(effect_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 16:
+    ///
+    /// Effect: ReplaceStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_4(
+        &mut self,
+        _replace_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let replace_stmt = pop_item!(self, replace_stmt, ReplaceStmt, context);
+        let effect_4_built = EffectReplaceStmtBuilder::default()
+            .replace_stmt(Box::new(replace_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_4_built = Effect::ReplaceStmt(effect_4_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_4_built)?;
+        self.push(ASTType::Effect(effect_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 17:
+    ///
+    /// Effect: EnhanceStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_5(
+        &mut self,
+        _enhance_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhance_stmt = pop_item!(self, enhance_stmt, EnhanceStmt, context);
+        let effect_5_built = EffectEnhanceStmtBuilder::default()
+            .enhance_stmt(Box::new(enhance_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_5_built = Effect::EnhanceStmt(effect_5_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_5_built)?;
+        self.push(ASTType::Effect(effect_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 18:
+    ///
+    /// Effect: OrbsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_6(
+        &mut self,
+        _orbs_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let orbs_stmt = pop_item!(self, orbs_stmt, OrbsStmt, context);
+        let effect_6_built = EffectOrbsStmtBuilder::default()
+            .orbs_stmt(Box::new(orbs_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_6_built = Effect::OrbsStmt(effect_6_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_6_built)?;
+        self.push(ASTType::Effect(effect_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 19:
+    ///
+    /// Effect: AllOrbsLockedStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_7(
+        &mut self,
+        _all_orbs_locked_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let all_orbs_locked_stmt =
+            pop_item!(self, all_orbs_locked_stmt, AllOrbsLockedStmt, context);
+        let effect_7_built = EffectAllOrbsLockedStmtBuilder::default()
+            .all_orbs_locked_stmt(Box::new(all_orbs_locked_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_7_built = Effect::AllOrbsLockedStmt(effect_7_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_7_built)?;
+        self.push(ASTType::Effect(effect_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// Effect: OnlyStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_8(
+        &mut self,
+        _only_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let only_stmt = pop_item!(self, only_stmt, OnlyStmt, context);
+        let effect_8_built = EffectOnlyStmtBuilder::default()
+            .only_stmt(Box::new(only_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_8_built = Effect::OnlyStmt(effect_8_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_8_built)?;
+        self.push(ASTType::Effect(effect_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 21:
+    ///
+    /// Effect: EnhancedOrbsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_9(
+        &mut self,
+        _enhanced_orbs_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhanced_orbs_stmt = pop_item!(self, enhanced_orbs_stmt, EnhancedOrbsStmt, context);
+        let effect_9_built = EffectEnhancedOrbsStmtBuilder::default()
+            .enhanced_orbs_stmt(Box::new(enhanced_orbs_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_9_built = Effect::EnhancedOrbsStmt(effect_9_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_9_built)?;
+        self.push(ASTType::Effect(effect_9_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// Effect: NailOrbsStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_10(
+        &mut self,
+        _nail_orbs_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let nail_orbs_stmt = pop_item!(self, nail_orbs_stmt, NailOrbsStmt, context);
+        let effect_10_built = EffectNailOrbsStmtBuilder::default()
+            .nail_orbs_stmt(Box::new(nail_orbs_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_10_built = Effect::NailOrbsStmt(effect_10_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_10_built)?;
+        self.push(ASTType::Effect(effect_10_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
+    ///
+    /// Effect: NoSkyfallStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_11(
+        &mut self,
+        _no_skyfall_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let no_skyfall_stmt = pop_item!(self, no_skyfall_stmt, NoSkyfallStmt, context);
+        let effect_11_built = EffectNoSkyfallStmtBuilder::default()
+            .no_skyfall_stmt(Box::new(no_skyfall_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_11_built = Effect::NoSkyfallStmt(effect_11_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_11_built)?;
+        self.push(ASTType::Effect(effect_11_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// Effect: TapeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_12(
+        &mut self,
+        _tape_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tape_stmt = pop_item!(self, tape_stmt, TapeStmt, context);
+        let effect_12_built = EffectTapeStmtBuilder::default()
+            .tape_stmt(Box::new(tape_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_12_built = Effect::TapeStmt(effect_12_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_12_built)?;
+        self.push(ASTType::Effect(effect_12_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
+    ///
+    /// Effect: VoidStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_13(
+        &mut self,
+        _void_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let void_stmt = pop_item!(self, void_stmt, VoidStmt, context);
+        let effect_13_built = EffectVoidStmtBuilder::default()
+            .void_stmt(Box::new(void_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_13_built = Effect::VoidStmt(effect_13_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_13_built)?;
+        self.push(ASTType::Effect(effect_13_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 26:
+    ///
+    /// Effect: PierceStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn effect_14(
+        &mut self,
+        _pierce_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let pierce_stmt = pop_item!(self, pierce_stmt, PierceStmt, context);
+        let effect_14_built = EffectPierceStmtBuilder::default()
+            .pierce_stmt(Box::new(pierce_stmt))
+            .build()
+            .into_diagnostic()?;
+        let effect_14_built = Effect::PierceStmt(effect_14_built);
+        // Calling user action here
+        self.user_grammar.effect(&effect_14_built)?;
+        self.push(ASTType::Effect(effect_14_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 27:
+    ///
+    /// ChangeStmt: WordChange ChangeTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_stmt(
+        &mut self,
+        _word_change: &ParseTreeStackEntry<'t>,
+        _change_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_target = pop_item!(self, change_target, ChangeTarget, context);
+        let word_change = pop_item!(self, word_change, WordChange, context);
+        let change_stmt_built = ChangeStmtBuilder::default()
+            .word_change(Box::new(word_change))
+            .change_target(Box::new(change_target))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.change_stmt(&change_stmt_built)?;
+        self.push(ASTType::ChangeStmt(change_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 28:
+    ///
+    /// ChangeTarget: WordAll ChangeAllTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_target_0(
+        &mut self,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _change_all_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_all_target = pop_item!(self, change_all_target, ChangeAllTarget, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let change_target_0_built = ChangeTargetWordAllChangeAllTargetBuilder::default()
+            .word_all(Box::new(word_all))
+            .change_all_target(Box::new(change_all_target))
+            .build()
+            .into_diagnostic()?;
+        let change_target_0_built = ChangeTarget::WordAllChangeAllTarget(change_target_0_built);
+        // Calling user action here
+        self.user_grammar.change_target(&change_target_0_built)?;
+        self.push(ASTType::ChangeTarget(change_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 29:
+    ///
+    /// ChangeTarget: ChangeDropStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_target_1(
+        &mut self,
+        _change_drop_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_drop_stmt = pop_item!(self, change_drop_stmt, ChangeDropStmt, context);
+        let change_target_1_built = ChangeTargetChangeDropStmtBuilder::default()
+            .change_drop_stmt(Box::new(change_drop_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_target_1_built = ChangeTarget::ChangeDropStmt(change_target_1_built);
+        // Calling user action here
+        self.user_grammar.change_target(&change_target_1_built)?;
+        self.push(ASTType::ChangeTarget(change_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 30:
+    ///
+    /// ChangeTarget: The ChangeTheTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_target_2(
+        &mut self,
+        _the: &ParseTreeStackEntry<'t>,
+        _change_the_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_the_target = pop_item!(self, change_the_target, ChangeTheTarget, context);
+        let the = pop_item!(self, the, The, context);
+        let change_target_2_built = ChangeTargetTheChangeTheTargetBuilder::default()
+            .the(Box::new(the))
+            .change_the_target(Box::new(change_the_target))
+            .build()
+            .into_diagnostic()?;
+        let change_target_2_built = ChangeTarget::TheChangeTheTarget(change_target_2_built);
+        // Calling user action here
+        self.user_grammar.change_target(&change_target_2_built)?;
+        self.push(ASTType::ChangeTarget(change_target_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 31:
+    ///
+    /// ChangeAllTarget: ChangeAllOfBoardStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_all_target_0(
+        &mut self,
+        _change_all_of_board_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_all_of_board_stmt = pop_item!(
+            self,
+            change_all_of_board_stmt,
+            ChangeAllOfBoardStmt,
+            context
+        );
+        let change_all_target_0_built = ChangeAllTargetChangeAllOfBoardStmtBuilder::default()
+            .change_all_of_board_stmt(Box::new(change_all_of_board_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_all_target_0_built =
+            ChangeAllTarget::ChangeAllOfBoardStmt(change_all_target_0_built);
+        // Calling user action here
+        self.user_grammar
+            .change_all_target(&change_all_target_0_built)?;
+        self.push(ASTType::ChangeAllTarget(change_all_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 32:
+    ///
+    /// ChangeAllTarget: EnemyAttributeChangeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_all_target_1(
+        &mut self,
+        _enemy_attribute_change_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enemy_attribute_change_stmt = pop_item!(
+            self,
+            enemy_attribute_change_stmt,
+            EnemyAttributeChangeStmt,
+            context
+        );
+        let change_all_target_1_built = ChangeAllTargetEnemyAttributeChangeStmtBuilder::default()
+            .enemy_attribute_change_stmt(Box::new(enemy_attribute_change_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_all_target_1_built =
+            ChangeAllTarget::EnemyAttributeChangeStmt(change_all_target_1_built);
+        // Calling user action here
+        self.user_grammar
+            .change_all_target(&change_all_target_1_built)?;
+        self.push(ASTType::ChangeAllTarget(change_all_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 33:
+    ///
+    /// ChangeAllOfBoardStmt: WordOrbs To Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_all_of_board_stmt(
+        &mut self,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let to = pop_item!(self, to, To, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let change_all_of_board_stmt_built = ChangeAllOfBoardStmtBuilder::default()
+            .word_orbs(Box::new(word_orbs))
+            .to(Box::new(to))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .change_all_of_board_stmt(&change_all_of_board_stmt_built)?;
+        self.push(
+            ASTType::ChangeAllOfBoardStmt(change_all_of_board_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 34:
+    ///
+    /// EnemyAttributeChangeStmt: WordEnemies To Color WordAttribute;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enemy_attribute_change_stmt(
+        &mut self,
+        _word_enemies: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _color: &ParseTreeStackEntry<'t>,
+        _word_attribute: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attribute = pop_item!(self, word_attribute, WordAttribute, context);
+        let color = pop_item!(self, color, Color, context);
+        let to = pop_item!(self, to, To, context);
+        let word_enemies = pop_item!(self, word_enemies, WordEnemies, context);
+        let enemy_attribute_change_stmt_built = EnemyAttributeChangeStmtBuilder::default()
+            .word_enemies(Box::new(word_enemies))
+            .to(Box::new(to))
+            .color(Box::new(color))
+            .word_attribute(Box::new(word_attribute))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .enemy_attribute_change_stmt(&enemy_attribute_change_stmt_built)?;
+        self.push(
+            ASTType::EnemyAttributeChangeStmt(enemy_attribute_change_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 35:
+    ///
+    /// ChangeDropStmt: Drops To Drops ChangeDropStmtOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_drop_stmt(
+        &mut self,
+        _drops: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _drops0: &ParseTreeStackEntry<'t>,
+        _change_drop_stmt_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_drop_stmt_opt =
+            pop_item!(self, change_drop_stmt_opt, ChangeDropStmtOpt, context);
+        let drops0 = pop_item!(self, drops0, Drops, context);
+        let to = pop_item!(self, to, To, context);
+        let drops = pop_item!(self, drops, Drops, context);
+        let change_drop_stmt_built = ChangeDropStmtBuilder::default()
+            .drops(Box::new(drops))
+            .to(Box::new(to))
+            .drops0(Box::new(drops0))
+            .change_drop_stmt_opt(change_drop_stmt_opt)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .change_drop_stmt(&change_drop_stmt_built)?;
+        self.push(ASTType::ChangeDropStmt(change_drop_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 36:
+    ///
+    /// ChangeDropStmtOpt /* Option<T>::Some */: At WordRandom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_drop_stmt_opt_0(
+        &mut self,
+        _at: &ParseTreeStackEntry<'t>,
+        _word_random: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_random = pop_item!(self, word_random, WordRandom, context);
+        let at = pop_item!(self, at, At, context);
+        let change_drop_stmt_opt_0_built = ChangeDropStmtOptBuilder::default()
+            .at(Box::new(at))
+            .word_random(Box::new(word_random))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::ChangeDropStmtOpt(Some(Box::new(change_drop_stmt_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 37:
+    ///
+    /// ChangeDropStmtOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_drop_stmt_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ChangeDropStmtOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
+    ///
+    /// ChangeTheTarget: ShapeRowColStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_the_target_0(
+        &mut self,
+        _shape_row_col_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let shape_row_col_stmt = pop_item!(self, shape_row_col_stmt, ShapeRowColStmt, context);
+        let change_the_target_0_built = ChangeTheTargetShapeRowColStmtBuilder::default()
+            .shape_row_col_stmt(Box::new(shape_row_col_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_the_target_0_built = ChangeTheTarget::ShapeRowColStmt(change_the_target_0_built);
+        // Calling user action here
+        self.user_grammar
+            .change_the_target(&change_the_target_0_built)?;
+        self.push(ASTType::ChangeTheTarget(change_the_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 39:
+    ///
+    /// ChangeTheTarget: ShapeOfBoardPerimeterStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_the_target_1(
+        &mut self,
+        _shape_of_board_perimeter_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let shape_of_board_perimeter_stmt = pop_item!(
+            self,
+            shape_of_board_perimeter_stmt,
+            ShapeOfBoardPerimeterStmt,
+            context
+        );
+        let change_the_target_1_built = ChangeTheTargetShapeOfBoardPerimeterStmtBuilder::default()
+            .shape_of_board_perimeter_stmt(Box::new(shape_of_board_perimeter_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_the_target_1_built =
+            ChangeTheTarget::ShapeOfBoardPerimeterStmt(change_the_target_1_built);
+        // Calling user action here
+        self.user_grammar
+            .change_the_target(&change_the_target_1_built)?;
+        self.push(ASTType::ChangeTheTarget(change_the_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
+    ///
+    /// ChangeTheTarget: ShapeOfBoardCenterStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_the_target_2(
+        &mut self,
+        _shape_of_board_center_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let shape_of_board_center_stmt = pop_item!(
+            self,
+            shape_of_board_center_stmt,
+            ShapeOfBoardCenterStmt,
+            context
+        );
+        let change_the_target_2_built = ChangeTheTargetShapeOfBoardCenterStmtBuilder::default()
+            .shape_of_board_center_stmt(Box::new(shape_of_board_center_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_the_target_2_built =
+            ChangeTheTarget::ShapeOfBoardCenterStmt(change_the_target_2_built);
+        // Calling user action here
+        self.user_grammar
+            .change_the_target(&change_the_target_2_built)?;
+        self.push(ASTType::ChangeTheTarget(change_the_target_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// ChangeTheTarget: ChangeBoardSizeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_the_target_3(
+        &mut self,
+        _change_board_size_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let change_board_size_stmt =
+            pop_item!(self, change_board_size_stmt, ChangeBoardSizeStmt, context);
+        let change_the_target_3_built = ChangeTheTargetChangeBoardSizeStmtBuilder::default()
+            .change_board_size_stmt(Box::new(change_board_size_stmt))
+            .build()
+            .into_diagnostic()?;
+        let change_the_target_3_built =
+            ChangeTheTarget::ChangeBoardSizeStmt(change_the_target_3_built);
+        // Calling user action here
+        self.user_grammar
+            .change_the_target(&change_the_target_3_built)?;
+        self.push(ASTType::ChangeTheTarget(change_the_target_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
+    /// ShapeRowColStmt: RowColBlock ShapeRowColStmtList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn shape_row_col_stmt(
+        &mut self,
+        _row_col_block: &ParseTreeStackEntry<'t>,
+        _shape_row_col_stmt_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let shape_row_col_stmt_list =
+            pop_and_reverse_item!(self, shape_row_col_stmt_list, ShapeRowColStmtList, context);
+        let row_col_block = pop_item!(self, row_col_block, RowColBlock, context);
+        let shape_row_col_stmt_built = ShapeRowColStmtBuilder::default()
+            .row_col_block(Box::new(row_col_block))
+            .shape_row_col_stmt_list(shape_row_col_stmt_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .shape_row_col_stmt(&shape_row_col_stmt_built)?;
+        self.push(ASTType::ShapeRowColStmt(shape_row_col_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 43:
+    ///
+    /// ShapeRowColStmtList /* Vec<T>::Push */: Camma And The RowColBlock ShapeRowColStmtList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn shape_row_col_stmt_list_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _and: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _row_col_block: &ParseTreeStackEntry<'t>,
+        _shape_row_col_stmt_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut shape_row_col_stmt_list =
+            pop_item!(self, shape_row_col_stmt_list, ShapeRowColStmtList, context);
+        let row_col_block = pop_item!(self, row_col_block, RowColBlock, context);
+        let the = pop_item!(self, the, The, context);
+        let and = pop_item!(self, and, And, context);
+        let camma = pop_item!(self, camma, Camma, context);
+        let shape_row_col_stmt_list_0_built = ShapeRowColStmtListBuilder::default()
+            .row_col_block(Box::new(row_col_block))
+            .the(Box::new(the))
+            .and(Box::new(and))
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        shape_row_col_stmt_list.push(shape_row_col_stmt_list_0_built);
+        self.push(
+            ASTType::ShapeRowColStmtList(shape_row_col_stmt_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
+    ///
+    /// ShapeRowColStmtList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn shape_row_col_stmt_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let shape_row_col_stmt_list_1_built = Vec::new();
+        self.push(
+            ASTType::ShapeRowColStmtList(shape_row_col_stmt_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 45:
+    ///
+    /// RowColBlock: RowColPosition RowColBlockList /* Vec */ To Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_block(
+        &mut self,
+        _row_col_position: &ParseTreeStackEntry<'t>,
+        _row_col_block_list: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let to = pop_item!(self, to, To, context);
+        let row_col_block_list =
+            pop_and_reverse_item!(self, row_col_block_list, RowColBlockList, context);
+        let row_col_position = pop_item!(self, row_col_position, RowColPosition, context);
+        let row_col_block_built = RowColBlockBuilder::default()
+            .row_col_position(Box::new(row_col_position))
+            .row_col_block_list(row_col_block_list)
+            .to(Box::new(to))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.row_col_block(&row_col_block_built)?;
+        self.push(ASTType::RowColBlock(row_col_block_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 46:
+    ///
+    /// RowColBlockList /* Vec<T>::Push */: RowColSep The RowColPosition RowColBlockList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_block_list_0(
+        &mut self,
+        _row_col_sep: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _row_col_position: &ParseTreeStackEntry<'t>,
+        _row_col_block_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut row_col_block_list = pop_item!(self, row_col_block_list, RowColBlockList, context);
+        let row_col_position = pop_item!(self, row_col_position, RowColPosition, context);
+        let the = pop_item!(self, the, The, context);
+        let row_col_sep = pop_item!(self, row_col_sep, RowColSep, context);
+        let row_col_block_list_0_built = RowColBlockListBuilder::default()
+            .row_col_position(Box::new(row_col_position))
+            .the(Box::new(the))
+            .row_col_sep(Box::new(row_col_sep))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        row_col_block_list.push(row_col_block_list_0_built);
+        self.push(ASTType::RowColBlockList(row_col_block_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// RowColBlockList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_block_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let row_col_block_list_1_built = Vec::new();
+        self.push(
+            ASTType::RowColBlockList(row_col_block_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// RowColSep: Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_sep_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let row_col_sep_0_built = RowColSepCammaBuilder::default()
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        let row_col_sep_0_built = RowColSep::Camma(row_col_sep_0_built);
+        // Calling user action here
+        self.user_grammar.row_col_sep(&row_col_sep_0_built)?;
+        self.push(ASTType::RowColSep(row_col_sep_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
+    ///
+    /// RowColSep: And;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_sep_1(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let row_col_sep_1_built = RowColSepAndBuilder::default()
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        let row_col_sep_1_built = RowColSep::And(row_col_sep_1_built);
+        // Calling user action here
+        self.user_grammar.row_col_sep(&row_col_sep_1_built)?;
+        self.push(ASTType::RowColSep(row_col_sep_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 50:
+    ///
+    /// RowColPosition: WordLeftmost WordColumn;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_position_0(
+        &mut self,
+        _word_leftmost: &ParseTreeStackEntry<'t>,
+        _word_column: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_column = pop_item!(self, word_column, WordColumn, context);
+        let word_leftmost = pop_item!(self, word_leftmost, WordLeftmost, context);
+        let row_col_position_0_built = RowColPositionWordLeftmostWordColumnBuilder::default()
+            .word_leftmost(Box::new(word_leftmost))
+            .word_column(Box::new(word_column))
+            .build()
+            .into_diagnostic()?;
+        let row_col_position_0_built =
+            RowColPosition::WordLeftmostWordColumn(row_col_position_0_built);
+        // Calling user action here
+        self.user_grammar
+            .row_col_position(&row_col_position_0_built)?;
+        self.push(ASTType::RowColPosition(row_col_position_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 51:
+    ///
+    /// RowColPosition: WordRightmost WordColumn;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_position_1(
+        &mut self,
+        _word_rightmost: &ParseTreeStackEntry<'t>,
+        _word_column: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_column = pop_item!(self, word_column, WordColumn, context);
+        let word_rightmost = pop_item!(self, word_rightmost, WordRightmost, context);
+        let row_col_position_1_built = RowColPositionWordRightmostWordColumnBuilder::default()
+            .word_rightmost(Box::new(word_rightmost))
+            .word_column(Box::new(word_column))
+            .build()
+            .into_diagnostic()?;
+        let row_col_position_1_built =
+            RowColPosition::WordRightmostWordColumn(row_col_position_1_built);
+        // Calling user action here
+        self.user_grammar
+            .row_col_position(&row_col_position_1_built)?;
+        self.push(ASTType::RowColPosition(row_col_position_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 52:
+    ///
+    /// RowColPosition: WordTop WordRow;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_position_2(
+        &mut self,
+        _word_top: &ParseTreeStackEntry<'t>,
+        _word_row: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_row = pop_item!(self, word_row, WordRow, context);
+        let word_top = pop_item!(self, word_top, WordTop, context);
+        let row_col_position_2_built = RowColPositionWordTopWordRowBuilder::default()
+            .word_top(Box::new(word_top))
+            .word_row(Box::new(word_row))
+            .build()
+            .into_diagnostic()?;
+        let row_col_position_2_built = RowColPosition::WordTopWordRow(row_col_position_2_built);
+        // Calling user action here
+        self.user_grammar
+            .row_col_position(&row_col_position_2_built)?;
+        self.push(ASTType::RowColPosition(row_col_position_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
+    ///
+    /// RowColPosition: WordBottom WordRow;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_position_3(
+        &mut self,
+        _word_bottom: &ParseTreeStackEntry<'t>,
+        _word_row: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_row = pop_item!(self, word_row, WordRow, context);
+        let word_bottom = pop_item!(self, word_bottom, WordBottom, context);
+        let row_col_position_3_built = RowColPositionWordBottomWordRowBuilder::default()
+            .word_bottom(Box::new(word_bottom))
+            .word_row(Box::new(word_row))
+            .build()
+            .into_diagnostic()?;
+        let row_col_position_3_built = RowColPosition::WordBottomWordRow(row_col_position_3_built);
+        // Calling user action here
+        self.user_grammar
+            .row_col_position(&row_col_position_3_built)?;
+        self.push(ASTType::RowColPosition(row_col_position_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 54:
+    ///
+    /// RowColPosition: Ordinal RowOrColumn From The Direction;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_col_position_4(
+        &mut self,
+        _ordinal: &ParseTreeStackEntry<'t>,
+        _row_or_column: &ParseTreeStackEntry<'t>,
+        _from: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _direction: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let direction = pop_item!(self, direction, Direction, context);
+        let the = pop_item!(self, the, The, context);
+        let from = pop_item!(self, from, From, context);
+        let row_or_column = pop_item!(self, row_or_column, RowOrColumn, context);
+        let ordinal = pop_item!(self, ordinal, Ordinal, context);
+        let row_col_position_4_built =
+            RowColPositionOrdinalRowOrColumnFromTheDirectionBuilder::default()
+                .ordinal(Box::new(ordinal))
+                .row_or_column(Box::new(row_or_column))
+                .from(Box::new(from))
+                .the(Box::new(the))
+                .direction(Box::new(direction))
+                .build()
+                .into_diagnostic()?;
+        let row_col_position_4_built =
+            RowColPosition::OrdinalRowOrColumnFromTheDirection(row_col_position_4_built);
+        // Calling user action here
+        self.user_grammar
+            .row_col_position(&row_col_position_4_built)?;
+        self.push(ASTType::RowColPosition(row_col_position_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 55:
+    ///
+    /// RowOrColumn: WordColumn;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_or_column_0(
+        &mut self,
+        _word_column: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_column = pop_item!(self, word_column, WordColumn, context);
+        let row_or_column_0_built = RowOrColumnWordColumnBuilder::default()
+            .word_column(Box::new(word_column))
+            .build()
+            .into_diagnostic()?;
+        let row_or_column_0_built = RowOrColumn::WordColumn(row_or_column_0_built);
+        // Calling user action here
+        self.user_grammar.row_or_column(&row_or_column_0_built)?;
+        self.push(ASTType::RowOrColumn(row_or_column_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
+    ///
+    /// RowOrColumn: WordRow;
+    ///
+    #[parol_runtime::function_name::named]
+    fn row_or_column_1(
+        &mut self,
+        _word_row: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_row = pop_item!(self, word_row, WordRow, context);
+        let row_or_column_1_built = RowOrColumnWordRowBuilder::default()
+            .word_row(Box::new(word_row))
+            .build()
+            .into_diagnostic()?;
+        let row_or_column_1_built = RowOrColumn::WordRow(row_or_column_1_built);
+        // Calling user action here
+        self.user_grammar.row_or_column(&row_or_column_1_built)?;
+        self.push(ASTType::RowOrColumn(row_or_column_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 57:
+    ///
+    /// Direction: WordLeft;
+    ///
+    #[parol_runtime::function_name::named]
+    fn direction_0(
+        &mut self,
+        _word_left: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_left = pop_item!(self, word_left, WordLeft, context);
+        let direction_0_built = DirectionWordLeftBuilder::default()
+            .word_left(Box::new(word_left))
+            .build()
+            .into_diagnostic()?;
+        let direction_0_built = Direction::WordLeft(direction_0_built);
+        // Calling user action here
+        self.user_grammar.direction(&direction_0_built)?;
+        self.push(ASTType::Direction(direction_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 58:
+    ///
+    /// Direction: WordRight;
+    ///
+    #[parol_runtime::function_name::named]
+    fn direction_1(
+        &mut self,
+        _word_right: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_right = pop_item!(self, word_right, WordRight, context);
+        let direction_1_built = DirectionWordRightBuilder::default()
+            .word_right(Box::new(word_right))
+            .build()
+            .into_diagnostic()?;
+        let direction_1_built = Direction::WordRight(direction_1_built);
+        // Calling user action here
+        self.user_grammar.direction(&direction_1_built)?;
+        self.push(ASTType::Direction(direction_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
+    ///
+    /// Direction: WordTop;
+    ///
+    #[parol_runtime::function_name::named]
+    fn direction_2(
+        &mut self,
+        _word_top: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_top = pop_item!(self, word_top, WordTop, context);
+        let direction_2_built = DirectionWordTopBuilder::default()
+            .word_top(Box::new(word_top))
+            .build()
+            .into_diagnostic()?;
+        let direction_2_built = Direction::WordTop(direction_2_built);
+        // Calling user action here
+        self.user_grammar.direction(&direction_2_built)?;
+        self.push(ASTType::Direction(direction_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 60:
+    ///
+    /// Direction: WordBottom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn direction_3(
+        &mut self,
+        _word_bottom: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_bottom = pop_item!(self, word_bottom, WordBottom, context);
+        let direction_3_built = DirectionWordBottomBuilder::default()
+            .word_bottom(Box::new(word_bottom))
+            .build()
+            .into_diagnostic()?;
+        let direction_3_built = Direction::WordBottom(direction_3_built);
+        // Calling user action here
+        self.user_grammar.direction(&direction_3_built)?;
+        self.push(ASTType::Direction(direction_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 61:
+    ///
+    /// ShapeOfBoardPerimeterStmt: WordOuter WordEdges Of The WordBoard To Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn shape_of_board_perimeter_stmt(
+        &mut self,
+        _word_outer: &ParseTreeStackEntry<'t>,
+        _word_edges: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let to = pop_item!(self, to, To, context);
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let the = pop_item!(self, the, The, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_edges = pop_item!(self, word_edges, WordEdges, context);
+        let word_outer = pop_item!(self, word_outer, WordOuter, context);
+        let shape_of_board_perimeter_stmt_built = ShapeOfBoardPerimeterStmtBuilder::default()
+            .word_outer(Box::new(word_outer))
+            .word_edges(Box::new(word_edges))
+            .of(Box::new(of))
+            .the(Box::new(the))
+            .word_board(Box::new(word_board))
+            .to(Box::new(to))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .shape_of_board_perimeter_stmt(&shape_of_board_perimeter_stmt_built)?;
+        self.push(
+            ASTType::ShapeOfBoardPerimeterStmt(shape_of_board_perimeter_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 62:
+    ///
+    /// ShapeOfBoardCenterStmt: WordCenter Of The WordBoard To Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn shape_of_board_center_stmt(
+        &mut self,
+        _word_center: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let to = pop_item!(self, to, To, context);
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let the = pop_item!(self, the, The, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_center = pop_item!(self, word_center, WordCenter, context);
+        let shape_of_board_center_stmt_built = ShapeOfBoardCenterStmtBuilder::default()
+            .word_center(Box::new(word_center))
+            .of(Box::new(of))
+            .the(Box::new(the))
+            .word_board(Box::new(word_board))
+            .to(Box::new(to))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .shape_of_board_center_stmt(&shape_of_board_center_stmt_built)?;
+        self.push(
+            ASTType::ShapeOfBoardCenterStmt(shape_of_board_center_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 63:
+    ///
+    /// ChangeBoardSizeStmt: WordBoard To Size;
+    ///
+    #[parol_runtime::function_name::named]
+    fn change_board_size_stmt(
+        &mut self,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _size: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let size = pop_item!(self, size, Size, context);
+        let to = pop_item!(self, to, To, context);
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let change_board_size_stmt_built = ChangeBoardSizeStmtBuilder::default()
+            .word_board(Box::new(word_board))
+            .to(Box::new(to))
+            .size(Box::new(size))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .change_board_size_stmt(&change_board_size_stmt_built)?;
+        self.push(
+            ASTType::ChangeBoardSizeStmt(change_board_size_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 64:
+    ///
+    /// CreateStmt: WordCreate CreateTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_stmt(
+        &mut self,
+        _word_create: &ParseTreeStackEntry<'t>,
+        _create_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let create_target = pop_item!(self, create_target, CreateTarget, context);
+        let word_create = pop_item!(self, word_create, WordCreate, context);
+        let create_stmt_built = CreateStmtBuilder::default()
+            .word_create(Box::new(word_create))
+            .create_target(Box::new(create_target))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.create_stmt(&create_stmt_built)?;
+        self.push(ASTType::CreateStmt(create_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
+    ///
+    /// CreateTarget: PosInt CreateQtyTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_target_0(
+        &mut self,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _create_qty_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let create_qty_target = pop_item!(self, create_qty_target, CreateQtyTarget, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let create_target_0_built = CreateTargetPosIntCreateQtyTargetBuilder::default()
+            .pos_int(Box::new(pos_int))
+            .create_qty_target(Box::new(create_qty_target))
+            .build()
+            .into_diagnostic()?;
+        let create_target_0_built = CreateTarget::PosIntCreateQtyTarget(create_target_0_built);
+        // Calling user action here
+        self.user_grammar.create_target(&create_target_0_built)?;
+        self.push(ASTType::CreateTarget(create_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 66:
+    ///
+    /// CreateTarget: A CreateATarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_target_1(
+        &mut self,
+        _a: &ParseTreeStackEntry<'t>,
+        _create_a_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let create_a_target = pop_item!(self, create_a_target, CreateATarget, context);
+        let a = pop_item!(self, a, A, context);
+        let create_target_1_built = CreateTargetACreateATargetBuilder::default()
+            .a(Box::new(a))
+            .create_a_target(Box::new(create_a_target))
+            .build()
+            .into_diagnostic()?;
+        let create_target_1_built = CreateTarget::ACreateATarget(create_target_1_built);
+        // Calling user action here
+        self.user_grammar.create_target(&create_target_1_built)?;
+        self.push(ASTType::CreateTarget(create_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 67:
+    ///
+    /// CreateQtyTarget: GenRandomDropStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_0(
+        &mut self,
+        _gen_random_drop_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_random_drop_stmt =
+            pop_item!(self, gen_random_drop_stmt, GenRandomDropStmt, context);
+        let create_qty_target_0_built = CreateQtyTargetGenRandomDropStmtBuilder::default()
+            .gen_random_drop_stmt(Box::new(gen_random_drop_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_0_built =
+            CreateQtyTarget::GenRandomDropStmt(create_qty_target_0_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_0_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 68:
+    ///
+    /// CreateQtyTarget: GenEachStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_1(
+        &mut self,
+        _gen_each_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_each_stmt = pop_item!(self, gen_each_stmt, GenEachStmt, context);
+        let create_qty_target_1_built = CreateQtyTargetGenEachStmtBuilder::default()
+            .gen_each_stmt(Box::new(gen_each_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_1_built = CreateQtyTarget::GenEachStmt(create_qty_target_1_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_1_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 69:
+    ///
+    /// CreateQtyTarget: LShapeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_2(
+        &mut self,
+        _l_shape_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_shape_stmt = pop_item!(self, l_shape_stmt, LShapeStmt, context);
+        let create_qty_target_2_built = CreateQtyTargetLShapeStmtBuilder::default()
+            .l_shape_stmt(Box::new(l_shape_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_2_built = CreateQtyTarget::LShapeStmt(create_qty_target_2_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_2_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 70:
+    ///
+    /// CreateQtyTarget: SmallCrossStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_3(
+        &mut self,
+        _small_cross_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let small_cross_stmt = pop_item!(self, small_cross_stmt, SmallCrossStmt, context);
+        let create_qty_target_3_built = CreateQtyTargetSmallCrossStmtBuilder::default()
+            .small_cross_stmt(Box::new(small_cross_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_3_built = CreateQtyTarget::SmallCrossStmt(create_qty_target_3_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_3_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 71:
+    ///
+    /// CreateQtyTarget: SquareStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_4(
+        &mut self,
+        _square_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let square_stmt = pop_item!(self, square_stmt, SquareStmt, context);
+        let create_qty_target_4_built = CreateQtyTargetSquareStmtBuilder::default()
+            .square_stmt(Box::new(square_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_4_built = CreateQtyTarget::SquareStmt(create_qty_target_4_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_4_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 72:
+    ///
+    /// CreateQtyTarget: SomeKindStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_5(
+        &mut self,
+        _some_kind_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let some_kind_stmt = pop_item!(self, some_kind_stmt, SomeKindStmt, context);
+        let create_qty_target_5_built = CreateQtyTargetSomeKindStmtBuilder::default()
+            .some_kind_stmt(Box::new(some_kind_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_5_built = CreateQtyTarget::SomeKindStmt(create_qty_target_5_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_5_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 73:
+    ///
+    /// CreateQtyTarget: GenRouletteStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_qty_target_6(
+        &mut self,
+        _gen_roulette_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_roulette_stmt = pop_item!(self, gen_roulette_stmt, GenRouletteStmt, context);
+        let create_qty_target_6_built = CreateQtyTargetGenRouletteStmtBuilder::default()
+            .gen_roulette_stmt(Box::new(gen_roulette_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_qty_target_6_built = CreateQtyTarget::GenRouletteStmt(create_qty_target_6_built);
+        // Calling user action here
+        self.user_grammar
+            .create_qty_target(&create_qty_target_6_built)?;
+        self.push(ASTType::CreateQtyTarget(create_qty_target_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 74:
+    ///
+    /// GenRandomDropStmt: QtyDropName GenRandomDropStmtList /* Vec */ WordOrbs GenSource;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_random_drop_stmt(
+        &mut self,
+        _qty_drop_name: &ParseTreeStackEntry<'t>,
+        _gen_random_drop_stmt_list: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _gen_source: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_source = pop_item!(self, gen_source, GenSource, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let gen_random_drop_stmt_list = pop_and_reverse_item!(
+            self,
+            gen_random_drop_stmt_list,
+            GenRandomDropStmtList,
+            context
+        );
+        let qty_drop_name = pop_item!(self, qty_drop_name, QtyDropName, context);
+        let gen_random_drop_stmt_built = GenRandomDropStmtBuilder::default()
+            .qty_drop_name(Box::new(qty_drop_name))
+            .gen_random_drop_stmt_list(gen_random_drop_stmt_list)
+            .word_orbs(Box::new(word_orbs))
+            .gen_source(Box::new(gen_source))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .gen_random_drop_stmt(&gen_random_drop_stmt_built)?;
+        self.push(
+            ASTType::GenRandomDropStmt(gen_random_drop_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 75:
+    ///
+    /// GenRandomDropStmtList /* Vec<T>::Push */: QtySep QtyDrop GenRandomDropStmtList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_random_drop_stmt_list_0(
+        &mut self,
+        _qty_sep: &ParseTreeStackEntry<'t>,
+        _qty_drop: &ParseTreeStackEntry<'t>,
+        _gen_random_drop_stmt_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut gen_random_drop_stmt_list = pop_item!(
+            self,
+            gen_random_drop_stmt_list,
+            GenRandomDropStmtList,
+            context
+        );
+        let qty_drop = pop_item!(self, qty_drop, QtyDrop, context);
+        let qty_sep = pop_item!(self, qty_sep, QtySep, context);
+        let gen_random_drop_stmt_list_0_built = GenRandomDropStmtListBuilder::default()
+            .qty_drop(Box::new(qty_drop))
+            .qty_sep(Box::new(qty_sep))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        gen_random_drop_stmt_list.push(gen_random_drop_stmt_list_0_built);
+        self.push(
+            ASTType::GenRandomDropStmtList(gen_random_drop_stmt_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 76:
+    ///
+    /// GenRandomDropStmtList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_random_drop_stmt_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_random_drop_stmt_list_1_built = Vec::new();
+        self.push(
+            ASTType::GenRandomDropStmtList(gen_random_drop_stmt_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 77:
+    ///
+    /// QtyDropName: DropName;
+    ///
+    #[parol_runtime::function_name::named]
+    fn qty_drop_name(
+        &mut self,
+        _drop_name: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drop_name = pop_item!(self, drop_name, DropName, context);
+        let qty_drop_name_built = QtyDropNameBuilder::default()
+            .drop_name(Box::new(drop_name))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.qty_drop_name(&qty_drop_name_built)?;
+        self.push(ASTType::QtyDropName(qty_drop_name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 78:
+    ///
+    /// QtyDrop: PosInt DropName;
+    ///
+    #[parol_runtime::function_name::named]
+    fn qty_drop(
+        &mut self,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _drop_name: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drop_name = pop_item!(self, drop_name, DropName, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let qty_drop_built = QtyDropBuilder::default()
+            .pos_int(Box::new(pos_int))
+            .drop_name(Box::new(drop_name))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.qty_drop(&qty_drop_built)?;
+        self.push(ASTType::QtyDrop(qty_drop_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 79:
+    ///
+    /// QtySep: Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn qty_sep_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let qty_sep_0_built = QtySepCammaBuilder::default()
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        let qty_sep_0_built = QtySep::Camma(qty_sep_0_built);
+        // Calling user action here
+        self.user_grammar.qty_sep(&qty_sep_0_built)?;
+        self.push(ASTType::QtySep(qty_sep_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 80:
+    ///
+    /// QtySep: And;
+    ///
+    #[parol_runtime::function_name::named]
+    fn qty_sep_1(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let qty_sep_1_built = QtySepAndBuilder::default()
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        let qty_sep_1_built = QtySep::And(qty_sep_1_built);
+        // Calling user action here
+        self.user_grammar.qty_sep(&qty_sep_1_built)?;
+        self.push(ASTType::QtySep(qty_sep_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 81:
+    ///
+    /// GenEachStmt: WordEach Of Drops GenSource;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_each_stmt(
+        &mut self,
+        _word_each: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _gen_source: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_source = pop_item!(self, gen_source, GenSource, context);
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_each = pop_item!(self, word_each, WordEach, context);
+        let gen_each_stmt_built = GenEachStmtBuilder::default()
+            .word_each(Box::new(word_each))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .gen_source(Box::new(gen_source))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.gen_each_stmt(&gen_each_stmt_built)?;
+        self.push(ASTType::GenEachStmt(gen_each_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 82:
+    ///
+    /// GenSource: At GenSourceAt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_source_0(
+        &mut self,
+        _at: &ParseTreeStackEntry<'t>,
+        _gen_source_at: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_source_at = pop_item!(self, gen_source_at, GenSourceAt, context);
+        let at = pop_item!(self, at, At, context);
+        let gen_source_0_built = GenSourceAtGenSourceAtBuilder::default()
+            .at(Box::new(at))
+            .gen_source_at(Box::new(gen_source_at))
+            .build()
+            .into_diagnostic()?;
+        let gen_source_0_built = GenSource::AtGenSourceAt(gen_source_0_built);
+        // Calling user action here
+        self.user_grammar.gen_source(&gen_source_0_built)?;
+        self.push(ASTType::GenSource(gen_source_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 83:
+    ///
+    /// GenSource: From NonDrops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_source_1(
+        &mut self,
+        _from: &ParseTreeStackEntry<'t>,
+        _non_drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let non_drops = pop_item!(self, non_drops, NonDrops, context);
+        let from = pop_item!(self, from, From, context);
+        let gen_source_1_built = GenSourceFromNonDropsBuilder::default()
+            .from(Box::new(from))
+            .non_drops(Box::new(non_drops))
+            .build()
+            .into_diagnostic()?;
+        let gen_source_1_built = GenSource::FromNonDrops(gen_source_1_built);
+        // Calling user action here
+        self.user_grammar.gen_source(&gen_source_1_built)?;
+        self.push(ASTType::GenSource(gen_source_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 84:
+    ///
+    /// GenSource: In WordEach WordCorner Of The WordBoard;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_source_2(
+        &mut self,
+        _in: &ParseTreeStackEntry<'t>,
+        _word_each: &ParseTreeStackEntry<'t>,
+        _word_corner: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let the = pop_item!(self, the, The, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_corner = pop_item!(self, word_corner, WordCorner, context);
+        let word_each = pop_item!(self, word_each, WordEach, context);
+        let r#in = pop_item!(self, r#in, In, context);
+        let gen_source_2_built = GenSourceInWordEachWordCornerOfTheWordBoardBuilder::default()
+            .r#in(Box::new(r#in))
+            .word_each(Box::new(word_each))
+            .word_corner(Box::new(word_corner))
+            .of(Box::new(of))
+            .the(Box::new(the))
+            .word_board(Box::new(word_board))
+            .build()
+            .into_diagnostic()?;
+        let gen_source_2_built = GenSource::InWordEachWordCornerOfTheWordBoard(gen_source_2_built);
+        // Calling user action here
+        self.user_grammar.gen_source(&gen_source_2_built)?;
+        self.push(ASTType::GenSource(gen_source_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 85:
+    ///
+    /// GenSourceAt: WordRandom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_source_at_0(
+        &mut self,
+        _word_random: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_random = pop_item!(self, word_random, WordRandom, context);
+        let gen_source_at_0_built = GenSourceAtWordRandomBuilder::default()
+            .word_random(Box::new(word_random))
+            .build()
+            .into_diagnostic()?;
+        let gen_source_at_0_built = GenSourceAt::WordRandom(gen_source_at_0_built);
+        // Calling user action here
+        self.user_grammar.gen_source_at(&gen_source_at_0_built)?;
+        self.push(ASTType::GenSourceAt(gen_source_at_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 86:
+    ///
+    /// GenSourceAt: The TopOrBottom Of The WordBoard;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_source_at_1(
+        &mut self,
+        _the: &ParseTreeStackEntry<'t>,
+        _top_or_bottom: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _the0: &ParseTreeStackEntry<'t>,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let the0 = pop_item!(self, the0, The, context);
+        let of = pop_item!(self, of, Of, context);
+        let top_or_bottom = pop_item!(self, top_or_bottom, TopOrBottom, context);
+        let the = pop_item!(self, the, The, context);
+        let gen_source_at_1_built = GenSourceAtTheTopOrBottomOfTheWordBoardBuilder::default()
+            .the(Box::new(the))
+            .top_or_bottom(Box::new(top_or_bottom))
+            .of(Box::new(of))
+            .the0(Box::new(the0))
+            .word_board(Box::new(word_board))
+            .build()
+            .into_diagnostic()?;
+        let gen_source_at_1_built =
+            GenSourceAt::TheTopOrBottomOfTheWordBoard(gen_source_at_1_built);
+        // Calling user action here
+        self.user_grammar.gen_source_at(&gen_source_at_1_built)?;
+        self.push(ASTType::GenSourceAt(gen_source_at_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 87:
+    ///
+    /// TopOrBottom: WordTop;
+    ///
+    #[parol_runtime::function_name::named]
+    fn top_or_bottom_0(
+        &mut self,
+        _word_top: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_top = pop_item!(self, word_top, WordTop, context);
+        let top_or_bottom_0_built = TopOrBottomWordTopBuilder::default()
+            .word_top(Box::new(word_top))
+            .build()
+            .into_diagnostic()?;
+        let top_or_bottom_0_built = TopOrBottom::WordTop(top_or_bottom_0_built);
+        // Calling user action here
+        self.user_grammar.top_or_bottom(&top_or_bottom_0_built)?;
+        self.push(ASTType::TopOrBottom(top_or_bottom_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 88:
+    ///
+    /// TopOrBottom: WordBottom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn top_or_bottom_1(
+        &mut self,
+        _word_bottom: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_bottom = pop_item!(self, word_bottom, WordBottom, context);
+        let top_or_bottom_1_built = TopOrBottomWordBottomBuilder::default()
+            .word_bottom(Box::new(word_bottom))
+            .build()
+            .into_diagnostic()?;
+        let top_or_bottom_1_built = TopOrBottom::WordBottom(top_or_bottom_1_built);
+        // Calling user action here
+        self.user_grammar.top_or_bottom(&top_or_bottom_1_built)?;
+        self.push(ASTType::TopOrBottom(top_or_bottom_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 89:
+    ///
+    /// NonDrops: NonDrop NonDropsList /* Vec */ WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_drops(
+        &mut self,
+        _non_drop: &ParseTreeStackEntry<'t>,
+        _non_drops_list: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let non_drops_list = pop_and_reverse_item!(self, non_drops_list, NonDropsList, context);
+        let non_drop = pop_item!(self, non_drop, NonDrop, context);
+        let non_drops_built = NonDropsBuilder::default()
+            .non_drop(Box::new(non_drop))
+            .non_drops_list(non_drops_list)
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.non_drops(&non_drops_built)?;
+        self.push(ASTType::NonDrops(non_drops_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 90:
+    ///
+    /// NonDropsList /* Vec<T>::Push */: DropSep NonDrop NonDropsList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_drops_list_0(
+        &mut self,
+        _drop_sep: &ParseTreeStackEntry<'t>,
+        _non_drop: &ParseTreeStackEntry<'t>,
+        _non_drops_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut non_drops_list = pop_item!(self, non_drops_list, NonDropsList, context);
+        let non_drop = pop_item!(self, non_drop, NonDrop, context);
+        let drop_sep = pop_item!(self, drop_sep, DropSep, context);
+        let non_drops_list_0_built = NonDropsListBuilder::default()
+            .non_drop(Box::new(non_drop))
+            .drop_sep(Box::new(drop_sep))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        non_drops_list.push(non_drops_list_0_built);
+        self.push(ASTType::NonDropsList(non_drops_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 91:
+    ///
+    /// NonDropsList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_drops_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let non_drops_list_1_built = Vec::new();
+        self.push(ASTType::NonDropsList(non_drops_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 92:
+    ///
+    /// NonDrop: WordNon DropName;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_drop(
+        &mut self,
+        _word_non: &ParseTreeStackEntry<'t>,
+        _drop_name: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drop_name = pop_item!(self, drop_name, DropName, context);
+        let word_non = pop_item!(self, word_non, WordNon, context);
+        let non_drop_built = NonDropBuilder::default()
+            .word_non(Box::new(word_non))
+            .drop_name(Box::new(drop_name))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.non_drop(&non_drop_built)?;
+        self.push(ASTType::NonDrop(non_drop_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 93:
+    ///
+    /// LShapeStmt: WordLShape Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn l_shape_stmt(
+        &mut self,
+        _word_l_shape: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_l_shape = pop_item!(self, word_l_shape, WordLShape, context);
+        let l_shape_stmt_built = LShapeStmtBuilder::default()
+            .word_l_shape(Box::new(word_l_shape))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.l_shape_stmt(&l_shape_stmt_built)?;
+        self.push(ASTType::LShapeStmt(l_shape_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 94:
+    ///
+    /// SmallCrossStmt: WordCross Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn small_cross_stmt(
+        &mut self,
+        _word_cross: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_cross = pop_item!(self, word_cross, WordCross, context);
+        let small_cross_stmt_built = SmallCrossStmtBuilder::default()
+            .word_cross(Box::new(word_cross))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .small_cross_stmt(&small_cross_stmt_built)?;
+        self.push(ASTType::SmallCrossStmt(small_cross_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 95:
+    ///
+    /// SquareStmt: Size WordSquare Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn square_stmt(
+        &mut self,
+        _size: &ParseTreeStackEntry<'t>,
+        _word_square: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_square = pop_item!(self, word_square, WordSquare, context);
+        let size = pop_item!(self, size, Size, context);
+        let square_stmt_built = SquareStmtBuilder::default()
+            .size(Box::new(size))
+            .word_square(Box::new(word_square))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.square_stmt(&square_stmt_built)?;
+        self.push(ASTType::SquareStmt(square_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 96:
+    ///
+    /// SomeKindStmt: PosInt WordShape Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn some_kind_stmt(
+        &mut self,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _word_shape: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_shape = pop_item!(self, word_shape, WordShape, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let some_kind_stmt_built = SomeKindStmtBuilder::default()
+            .pos_int(Box::new(pos_int))
+            .word_shape(Box::new(word_shape))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.some_kind_stmt(&some_kind_stmt_built)?;
+        self.push(ASTType::SomeKindStmt(some_kind_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 97:
+    ///
+    /// GenRouletteStmt: WordRoulette WordOrbs At WordRandom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_roulette_stmt(
+        &mut self,
+        _word_roulette: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _at: &ParseTreeStackEntry<'t>,
+        _word_random: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_random = pop_item!(self, word_random, WordRandom, context);
+        let at = pop_item!(self, at, At, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_roulette = pop_item!(self, word_roulette, WordRoulette, context);
+        let gen_roulette_stmt_built = GenRouletteStmtBuilder::default()
+            .word_roulette(Box::new(word_roulette))
+            .word_orbs(Box::new(word_orbs))
+            .at(Box::new(at))
+            .word_random(Box::new(word_random))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .gen_roulette_stmt(&gen_roulette_stmt_built)?;
+        self.push(ASTType::GenRouletteStmt(gen_roulette_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 98:
+    ///
+    /// CreateATarget: ZShapeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_0(
+        &mut self,
+        _z_shape_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let z_shape_stmt = pop_item!(self, z_shape_stmt, ZShapeStmt, context);
+        let create_a_target_0_built = CreateATargetZShapeStmtBuilder::default()
+            .z_shape_stmt(Box::new(z_shape_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_0_built = CreateATarget::ZShapeStmt(create_a_target_0_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_0_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 99:
+    ///
+    /// CreateATarget: CrossShapeStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_1(
+        &mut self,
+        _cross_shape_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cross_shape_stmt = pop_item!(self, cross_shape_stmt, CrossShapeStmt, context);
+        let create_a_target_1_built = CreateATargetCrossShapeStmtBuilder::default()
+            .cross_shape_stmt(Box::new(cross_shape_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_1_built = CreateATarget::CrossShapeStmt(create_a_target_1_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_1_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 100:
+    ///
+    /// CreateATarget: SpiderwebStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_2(
+        &mut self,
+        _spiderweb_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let spiderweb_stmt = pop_item!(self, spiderweb_stmt, SpiderwebStmt, context);
+        let create_a_target_2_built = CreateATargetSpiderwebStmtBuilder::default()
+            .spiderweb_stmt(Box::new(spiderweb_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_2_built = CreateATarget::SpiderwebStmt(create_a_target_2_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_2_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 101:
+    ///
+    /// CreateATarget: CrescentMoonStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_3(
+        &mut self,
+        _crescent_moon_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let crescent_moon_stmt = pop_item!(self, crescent_moon_stmt, CrescentMoonStmt, context);
+        let create_a_target_3_built = CreateATargetCrescentMoonStmtBuilder::default()
+            .crescent_moon_stmt(Box::new(crescent_moon_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_3_built = CreateATarget::CrescentMoonStmt(create_a_target_3_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_3_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 102:
+    ///
+    /// CreateATarget: ObliqueStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_4(
+        &mut self,
+        _oblique_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let oblique_stmt = pop_item!(self, oblique_stmt, ObliqueStmt, context);
+        let create_a_target_4_built = CreateATargetObliqueStmtBuilder::default()
+            .oblique_stmt(Box::new(oblique_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_4_built = CreateATarget::ObliqueStmt(create_a_target_4_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_4_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 103:
+    ///
+    /// CreateATarget: GenCloudStmt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn create_a_target_5(
+        &mut self,
+        _gen_cloud_stmt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let gen_cloud_stmt = pop_item!(self, gen_cloud_stmt, GenCloudStmt, context);
+        let create_a_target_5_built = CreateATargetGenCloudStmtBuilder::default()
+            .gen_cloud_stmt(Box::new(gen_cloud_stmt))
+            .build()
+            .into_diagnostic()?;
+        let create_a_target_5_built = CreateATarget::GenCloudStmt(create_a_target_5_built);
+        // Calling user action here
+        self.user_grammar
+            .create_a_target(&create_a_target_5_built)?;
+        self.push(ASTType::CreateATarget(create_a_target_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 104:
+    ///
+    /// ZShapeStmt: WordZShape Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn z_shape_stmt(
+        &mut self,
+        _word_z_shape: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_z_shape = pop_item!(self, word_z_shape, WordZShape, context);
+        let z_shape_stmt_built = ZShapeStmtBuilder::default()
+            .word_z_shape(Box::new(word_z_shape))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.z_shape_stmt(&z_shape_stmt_built)?;
+        self.push(ASTType::ZShapeStmt(z_shape_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 105:
+    ///
+    /// CrossShapeStmt: WordLarge WordCross Of Drops WordAcross The WordBoard;
+    ///
+    #[parol_runtime::function_name::named]
+    fn cross_shape_stmt(
+        &mut self,
+        _word_large: &ParseTreeStackEntry<'t>,
+        _word_cross: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _word_across: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let the = pop_item!(self, the, The, context);
+        let word_across = pop_item!(self, word_across, WordAcross, context);
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_cross = pop_item!(self, word_cross, WordCross, context);
+        let word_large = pop_item!(self, word_large, WordLarge, context);
+        let cross_shape_stmt_built = CrossShapeStmtBuilder::default()
+            .word_large(Box::new(word_large))
+            .word_cross(Box::new(word_cross))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .word_across(Box::new(word_across))
+            .the(Box::new(the))
+            .word_board(Box::new(word_board))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .cross_shape_stmt(&cross_shape_stmt_built)?;
+        self.push(ASTType::CrossShapeStmt(cross_shape_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 106:
+    ///
+    /// SpiderwebStmt: WordSpiderweb Of Drops WordWith Drops At WordIts WordCenter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn spiderweb_stmt(
+        &mut self,
+        _word_spiderweb: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _word_with: &ParseTreeStackEntry<'t>,
+        _drops0: &ParseTreeStackEntry<'t>,
+        _at: &ParseTreeStackEntry<'t>,
+        _word_its: &ParseTreeStackEntry<'t>,
+        _word_center: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_center = pop_item!(self, word_center, WordCenter, context);
+        let word_its = pop_item!(self, word_its, WordIts, context);
+        let at = pop_item!(self, at, At, context);
+        let drops0 = pop_item!(self, drops0, Drops, context);
+        let word_with = pop_item!(self, word_with, WordWith, context);
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_spiderweb = pop_item!(self, word_spiderweb, WordSpiderweb, context);
+        let spiderweb_stmt_built = SpiderwebStmtBuilder::default()
+            .word_spiderweb(Box::new(word_spiderweb))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .word_with(Box::new(word_with))
+            .drops0(Box::new(drops0))
+            .at(Box::new(at))
+            .word_its(Box::new(word_its))
+            .word_center(Box::new(word_center))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.spiderweb_stmt(&spiderweb_stmt_built)?;
+        self.push(ASTType::SpiderwebStmt(spiderweb_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
+    ///
+    /// CrescentMoonStmt: WordCrescentMoon Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn crescent_moon_stmt(
+        &mut self,
+        _word_crescent_moon: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_crescent_moon = pop_item!(self, word_crescent_moon, WordCrescentMoon, context);
+        let crescent_moon_stmt_built = CrescentMoonStmtBuilder::default()
+            .word_crescent_moon(Box::new(word_crescent_moon))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .crescent_moon_stmt(&crescent_moon_stmt_built)?;
+        self.push(ASTType::CrescentMoonStmt(crescent_moon_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 108:
+    ///
+    /// ObliqueStmt: WordDiagonalLine Of Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn oblique_stmt(
+        &mut self,
+        _word_diagonal_line: &ParseTreeStackEntry<'t>,
+        _of: &ParseTreeStackEntry<'t>,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let of = pop_item!(self, of, Of, context);
+        let word_diagonal_line = pop_item!(self, word_diagonal_line, WordDiagonalLine, context);
+        let oblique_stmt_built = ObliqueStmtBuilder::default()
+            .word_diagonal_line(Box::new(word_diagonal_line))
+            .of(Box::new(of))
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.oblique_stmt(&oblique_stmt_built)?;
+        self.push(ASTType::ObliqueStmt(oblique_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 109:
+    ///
+    /// GenCloudStmt: Size WordCloud BoardPosition;
+    ///
+    #[parol_runtime::function_name::named]
+    fn gen_cloud_stmt(
+        &mut self,
+        _size: &ParseTreeStackEntry<'t>,
+        _word_cloud: &ParseTreeStackEntry<'t>,
+        _board_position: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let board_position = pop_item!(self, board_position, BoardPosition, context);
+        let word_cloud = pop_item!(self, word_cloud, WordCloud, context);
+        let size = pop_item!(self, size, Size, context);
+        let gen_cloud_stmt_built = GenCloudStmtBuilder::default()
+            .size(Box::new(size))
+            .word_cloud(Box::new(word_cloud))
+            .board_position(Box::new(board_position))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.gen_cloud_stmt(&gen_cloud_stmt_built)?;
+        self.push(ASTType::GenCloudStmt(gen_cloud_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 110:
+    ///
+    /// UnlockStmt: WordUnlock WordAll WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn unlock_stmt(
+        &mut self,
+        _word_unlock: &ParseTreeStackEntry<'t>,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let word_unlock = pop_item!(self, word_unlock, WordUnlock, context);
+        let unlock_stmt_built = UnlockStmtBuilder::default()
+            .word_unlock(Box::new(word_unlock))
+            .word_all(Box::new(word_all))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.unlock_stmt(&unlock_stmt_built)?;
+        self.push(ASTType::UnlockStmt(unlock_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 111:
+    ///
+    /// LockStmt: WordLock LockTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn lock_stmt(
+        &mut self,
+        _word_lock: &ParseTreeStackEntry<'t>,
+        _lock_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lock_target = pop_item!(self, lock_target, LockTarget, context);
+        let word_lock = pop_item!(self, word_lock, WordLock, context);
+        let lock_stmt_built = LockStmtBuilder::default()
+            .word_lock(Box::new(word_lock))
+            .lock_target(Box::new(lock_target))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.lock_stmt(&lock_stmt_built)?;
+        self.push(ASTType::LockStmt(lock_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 112:
+    ///
+    /// LockTarget: WordAll WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn lock_target_0(
+        &mut self,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let lock_target_0_built = LockTargetWordAllWordOrbsBuilder::default()
+            .word_all(Box::new(word_all))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        let lock_target_0_built = LockTarget::WordAllWordOrbs(lock_target_0_built);
+        // Calling user action here
+        self.user_grammar.lock_target(&lock_target_0_built)?;
+        self.push(ASTType::LockTarget(lock_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 113:
+    ///
+    /// LockTarget: Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn lock_target_1(
+        &mut self,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let lock_target_1_built = LockTargetDropsBuilder::default()
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        let lock_target_1_built = LockTarget::Drops(lock_target_1_built);
+        // Calling user action here
+        self.user_grammar.lock_target(&lock_target_1_built)?;
+        self.push(ASTType::LockTarget(lock_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 114:
+    ///
+    /// ReplaceStmt: WordReplace WordAll WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn replace_stmt(
+        &mut self,
+        _word_replace: &ParseTreeStackEntry<'t>,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let word_replace = pop_item!(self, word_replace, WordReplace, context);
+        let replace_stmt_built = ReplaceStmtBuilder::default()
+            .word_replace(Box::new(word_replace))
+            .word_all(Box::new(word_all))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.replace_stmt(&replace_stmt_built)?;
+        self.push(ASTType::ReplaceStmt(replace_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 115:
+    ///
+    /// EnhanceStmt: WordEnhance EnhanceTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhance_stmt(
+        &mut self,
+        _word_enhance: &ParseTreeStackEntry<'t>,
+        _enhance_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhance_target = pop_item!(self, enhance_target, EnhanceTarget, context);
+        let word_enhance = pop_item!(self, word_enhance, WordEnhance, context);
+        let enhance_stmt_built = EnhanceStmtBuilder::default()
+            .word_enhance(Box::new(word_enhance))
+            .enhance_target(Box::new(enhance_target))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.enhance_stmt(&enhance_stmt_built)?;
+        self.push(ASTType::EnhanceStmt(enhance_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 116:
+    ///
+    /// EnhanceTarget: WordAll WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhance_target_0(
+        &mut self,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let enhance_target_0_built = EnhanceTargetWordAllWordOrbsBuilder::default()
+            .word_all(Box::new(word_all))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        let enhance_target_0_built = EnhanceTarget::WordAllWordOrbs(enhance_target_0_built);
+        // Calling user action here
+        self.user_grammar.enhance_target(&enhance_target_0_built)?;
+        self.push(ASTType::EnhanceTarget(enhance_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 117:
+    ///
+    /// EnhanceTarget: Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhance_target_1(
+        &mut self,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let enhance_target_1_built = EnhanceTargetDropsBuilder::default()
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        let enhance_target_1_built = EnhanceTarget::Drops(enhance_target_1_built);
+        // Calling user action here
+        self.user_grammar.enhance_target(&enhance_target_1_built)?;
+        self.push(ASTType::EnhanceTarget(enhance_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 118:
+    ///
+    /// OrbsStmt: Drops OrbsStmtSuffix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn orbs_stmt(
+        &mut self,
+        _drops: &ParseTreeStackEntry<'t>,
+        _orbs_stmt_suffix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let orbs_stmt_suffix = pop_item!(self, orbs_stmt_suffix, OrbsStmtSuffix, context);
+        let drops = pop_item!(self, drops, Drops, context);
+        let orbs_stmt_built = OrbsStmtBuilder::default()
+            .drops(Box::new(drops))
+            .orbs_stmt_suffix(Box::new(orbs_stmt_suffix))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.orbs_stmt(&orbs_stmt_built)?;
+        self.push(ASTType::OrbsStmt(orbs_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 119:
+    ///
+    /// OrbsStmtSuffix: MoreLikely;
+    ///
+    #[parol_runtime::function_name::named]
+    fn orbs_stmt_suffix_0(
+        &mut self,
+        _more_likely: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let more_likely = pop_item!(self, more_likely, MoreLikely, context);
+        let orbs_stmt_suffix_0_built = OrbsStmtSuffixMoreLikelyBuilder::default()
+            .more_likely(Box::new(more_likely))
+            .build()
+            .into_diagnostic()?;
+        let orbs_stmt_suffix_0_built = OrbsStmtSuffix::MoreLikely(orbs_stmt_suffix_0_built);
+        // Calling user action here
+        self.user_grammar
+            .orbs_stmt_suffix(&orbs_stmt_suffix_0_built)?;
+        self.push(ASTType::OrbsStmtSuffix(orbs_stmt_suffix_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 120:
+    ///
+    /// OrbsStmtSuffix: WordAppear WordLocked;
+    ///
+    #[parol_runtime::function_name::named]
+    fn orbs_stmt_suffix_1(
+        &mut self,
+        _word_appear: &ParseTreeStackEntry<'t>,
+        _word_locked: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_locked = pop_item!(self, word_locked, WordLocked, context);
+        let word_appear = pop_item!(self, word_appear, WordAppear, context);
+        let orbs_stmt_suffix_1_built = OrbsStmtSuffixWordAppearWordLockedBuilder::default()
+            .word_appear(Box::new(word_appear))
+            .word_locked(Box::new(word_locked))
+            .build()
+            .into_diagnostic()?;
+        let orbs_stmt_suffix_1_built =
+            OrbsStmtSuffix::WordAppearWordLocked(orbs_stmt_suffix_1_built);
+        // Calling user action here
+        self.user_grammar
+            .orbs_stmt_suffix(&orbs_stmt_suffix_1_built)?;
+        self.push(ASTType::OrbsStmtSuffix(orbs_stmt_suffix_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 121:
+    ///
+    /// AllOrbsLockedStmt: WordAll WordOrbs WordAppear WordLocked;
+    ///
+    #[parol_runtime::function_name::named]
+    fn all_orbs_locked_stmt(
+        &mut self,
+        _word_all: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _word_appear: &ParseTreeStackEntry<'t>,
+        _word_locked: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_locked = pop_item!(self, word_locked, WordLocked, context);
+        let word_appear = pop_item!(self, word_appear, WordAppear, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_all = pop_item!(self, word_all, WordAll, context);
+        let all_orbs_locked_stmt_built = AllOrbsLockedStmtBuilder::default()
+            .word_all(Box::new(word_all))
+            .word_orbs(Box::new(word_orbs))
+            .word_appear(Box::new(word_appear))
+            .word_locked(Box::new(word_locked))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .all_orbs_locked_stmt(&all_orbs_locked_stmt_built)?;
+        self.push(
+            ASTType::AllOrbsLockedStmt(all_orbs_locked_stmt_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 122:
+    ///
+    /// OnlyStmt: WordOnly OnlyTarget WordWill WordAppear;
+    ///
+    #[parol_runtime::function_name::named]
+    fn only_stmt(
+        &mut self,
+        _word_only: &ParseTreeStackEntry<'t>,
+        _only_target: &ParseTreeStackEntry<'t>,
+        _word_will: &ParseTreeStackEntry<'t>,
+        _word_appear: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_appear = pop_item!(self, word_appear, WordAppear, context);
+        let word_will = pop_item!(self, word_will, WordWill, context);
+        let only_target = pop_item!(self, only_target, OnlyTarget, context);
+        let word_only = pop_item!(self, word_only, WordOnly, context);
+        let only_stmt_built = OnlyStmtBuilder::default()
+            .word_only(Box::new(word_only))
+            .only_target(Box::new(only_target))
+            .word_will(Box::new(word_will))
+            .word_appear(Box::new(word_appear))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.only_stmt(&only_stmt_built)?;
+        self.push(ASTType::OnlyStmt(only_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 123:
+    ///
+    /// OnlyTarget: Drops;
+    ///
+    #[parol_runtime::function_name::named]
+    fn only_target_0(
+        &mut self,
+        _drops: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops = pop_item!(self, drops, Drops, context);
+        let only_target_0_built = OnlyTargetDropsBuilder::default()
+            .drops(Box::new(drops))
+            .build()
+            .into_diagnostic()?;
+        let only_target_0_built = OnlyTarget::Drops(only_target_0_built);
+        // Calling user action here
+        self.user_grammar.only_target(&only_target_0_built)?;
+        self.push(ASTType::OnlyTarget(only_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 124:
+    ///
+    /// OnlyTarget: WordEnhanced WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn only_target_1(
+        &mut self,
+        _word_enhanced: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_enhanced = pop_item!(self, word_enhanced, WordEnhanced, context);
+        let only_target_1_built = OnlyTargetWordEnhancedWordOrbsBuilder::default()
+            .word_enhanced(Box::new(word_enhanced))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        let only_target_1_built = OnlyTarget::WordEnhancedWordOrbs(only_target_1_built);
+        // Calling user action here
+        self.user_grammar.only_target(&only_target_1_built)?;
+        self.push(ASTType::OnlyTarget(only_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 125:
+    ///
+    /// OnlyTarget: WordNail WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn only_target_2(
+        &mut self,
+        _word_nail: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_nail = pop_item!(self, word_nail, WordNail, context);
+        let only_target_2_built = OnlyTargetWordNailWordOrbsBuilder::default()
+            .word_nail(Box::new(word_nail))
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        let only_target_2_built = OnlyTarget::WordNailWordOrbs(only_target_2_built);
+        // Calling user action here
+        self.user_grammar.only_target(&only_target_2_built)?;
+        self.push(ASTType::OnlyTarget(only_target_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 126:
+    ///
+    /// EnhancedOrbsStmt: WordEnhanced WordOrbs EnhancedOrbsSuffix;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhanced_orbs_stmt(
+        &mut self,
+        _word_enhanced: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _enhanced_orbs_suffix: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enhanced_orbs_suffix =
+            pop_item!(self, enhanced_orbs_suffix, EnhancedOrbsSuffix, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_enhanced = pop_item!(self, word_enhanced, WordEnhanced, context);
+        let enhanced_orbs_stmt_built = EnhancedOrbsStmtBuilder::default()
+            .word_enhanced(Box::new(word_enhanced))
+            .word_orbs(Box::new(word_orbs))
+            .enhanced_orbs_suffix(Box::new(enhanced_orbs_suffix))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .enhanced_orbs_stmt(&enhanced_orbs_stmt_built)?;
+        self.push(ASTType::EnhancedOrbsStmt(enhanced_orbs_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 127:
+    ///
+    /// EnhancedOrbsSuffix: WordHave A PosInt Percent WordChance To WordAppear;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhanced_orbs_suffix_0(
+        &mut self,
+        _word_have: &ParseTreeStackEntry<'t>,
+        _a: &ParseTreeStackEntry<'t>,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _percent: &ParseTreeStackEntry<'t>,
+        _word_chance: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _word_appear: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_appear = pop_item!(self, word_appear, WordAppear, context);
+        let to = pop_item!(self, to, To, context);
+        let word_chance = pop_item!(self, word_chance, WordChance, context);
+        let percent = pop_item!(self, percent, Percent, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let a = pop_item!(self, a, A, context);
+        let word_have = pop_item!(self, word_have, WordHave, context);
+        let enhanced_orbs_suffix_0_built =
+            EnhancedOrbsSuffixWordHaveAPosIntPercentWordChanceToWordAppearBuilder::default()
+                .word_have(Box::new(word_have))
+                .a(Box::new(a))
+                .pos_int(Box::new(pos_int))
+                .percent(Box::new(percent))
+                .word_chance(Box::new(word_chance))
+                .to(Box::new(to))
+                .word_appear(Box::new(word_appear))
+                .build()
+                .into_diagnostic()?;
+        let enhanced_orbs_suffix_0_built =
+            EnhancedOrbsSuffix::WordHaveAPosIntPercentWordChanceToWordAppear(
+                enhanced_orbs_suffix_0_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .enhanced_orbs_suffix(&enhanced_orbs_suffix_0_built)?;
+        self.push(
+            ASTType::EnhancedOrbsSuffix(enhanced_orbs_suffix_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 128:
+    ///
+    /// EnhancedOrbsSuffix: MoreLikely;
+    ///
+    #[parol_runtime::function_name::named]
+    fn enhanced_orbs_suffix_1(
+        &mut self,
+        _more_likely: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let more_likely = pop_item!(self, more_likely, MoreLikely, context);
+        let enhanced_orbs_suffix_1_built = EnhancedOrbsSuffixMoreLikelyBuilder::default()
+            .more_likely(Box::new(more_likely))
+            .build()
+            .into_diagnostic()?;
+        let enhanced_orbs_suffix_1_built =
+            EnhancedOrbsSuffix::MoreLikely(enhanced_orbs_suffix_1_built);
+        // Calling user action here
+        self.user_grammar
+            .enhanced_orbs_suffix(&enhanced_orbs_suffix_1_built)?;
+        self.push(
+            ASTType::EnhancedOrbsSuffix(enhanced_orbs_suffix_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 129:
+    ///
+    /// NailOrbsStmt: WordNail WordOrbs MoreLikely;
+    ///
+    #[parol_runtime::function_name::named]
+    fn nail_orbs_stmt(
+        &mut self,
+        _word_nail: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _more_likely: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let more_likely = pop_item!(self, more_likely, MoreLikely, context);
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let word_nail = pop_item!(self, word_nail, WordNail, context);
+        let nail_orbs_stmt_built = NailOrbsStmtBuilder::default()
+            .word_nail(Box::new(word_nail))
+            .word_orbs(Box::new(word_orbs))
+            .more_likely(Box::new(more_likely))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.nail_orbs_stmt(&nail_orbs_stmt_built)?;
+        self.push(ASTType::NailOrbsStmt(nail_orbs_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 130:
+    ///
+    /// MoreLikely: Are MoreLikelyOpt /* Option */ WordMore WordLikely To WordAppear;
+    ///
+    #[parol_runtime::function_name::named]
+    fn more_likely(
+        &mut self,
+        _are: &ParseTreeStackEntry<'t>,
+        _more_likely_opt: &ParseTreeStackEntry<'t>,
+        _word_more: &ParseTreeStackEntry<'t>,
+        _word_likely: &ParseTreeStackEntry<'t>,
+        _to: &ParseTreeStackEntry<'t>,
+        _word_appear: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_appear = pop_item!(self, word_appear, WordAppear, context);
+        let to = pop_item!(self, to, To, context);
+        let word_likely = pop_item!(self, word_likely, WordLikely, context);
+        let word_more = pop_item!(self, word_more, WordMore, context);
+        let more_likely_opt = pop_item!(self, more_likely_opt, MoreLikelyOpt, context);
+        let are = pop_item!(self, are, Are, context);
+        let more_likely_built = MoreLikelyBuilder::default()
+            .are(Box::new(are))
+            .more_likely_opt(more_likely_opt)
+            .word_more(Box::new(word_more))
+            .word_likely(Box::new(word_likely))
+            .to(Box::new(to))
+            .word_appear(Box::new(word_appear))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.more_likely(&more_likely_built)?;
+        self.push(ASTType::MoreLikely(more_likely_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 131:
+    ///
+    /// MoreLikelyOpt /* Option<T>::Some */: Volume;
+    ///
+    #[parol_runtime::function_name::named]
+    fn more_likely_opt_0(
+        &mut self,
+        _volume: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let volume = pop_item!(self, volume, Volume, context);
+        let more_likely_opt_0_built = MoreLikelyOptBuilder::default()
+            .volume(Box::new(volume))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::MoreLikelyOpt(Some(Box::new(more_likely_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 132:
+    ///
+    /// MoreLikelyOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn more_likely_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::MoreLikelyOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 133:
+    ///
+    /// Volume: WordVerySlightly;
+    ///
+    #[parol_runtime::function_name::named]
+    fn volume_0(
+        &mut self,
+        _word_very_slightly: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_very_slightly = pop_item!(self, word_very_slightly, WordVerySlightly, context);
+        let volume_0_built = VolumeWordVerySlightlyBuilder::default()
+            .word_very_slightly(Box::new(word_very_slightly))
+            .build()
+            .into_diagnostic()?;
+        let volume_0_built = Volume::WordVerySlightly(volume_0_built);
+        // Calling user action here
+        self.user_grammar.volume(&volume_0_built)?;
+        self.push(ASTType::Volume(volume_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 134:
+    ///
+    /// Volume: WordSlightly;
+    ///
+    #[parol_runtime::function_name::named]
+    fn volume_1(
+        &mut self,
+        _word_slightly: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_slightly = pop_item!(self, word_slightly, WordSlightly, context);
+        let volume_1_built = VolumeWordSlightlyBuilder::default()
+            .word_slightly(Box::new(word_slightly))
+            .build()
+            .into_diagnostic()?;
+        let volume_1_built = Volume::WordSlightly(volume_1_built);
+        // Calling user action here
+        self.user_grammar.volume(&volume_1_built)?;
+        self.push(ASTType::Volume(volume_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 135:
+    ///
+    /// NoSkyfallStmt: WordNo WordSkyfall WordCombos;
+    ///
+    #[parol_runtime::function_name::named]
+    fn no_skyfall_stmt(
+        &mut self,
+        _word_no: &ParseTreeStackEntry<'t>,
+        _word_skyfall: &ParseTreeStackEntry<'t>,
+        _word_combos: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_combos = pop_item!(self, word_combos, WordCombos, context);
+        let word_skyfall = pop_item!(self, word_skyfall, WordSkyfall, context);
+        let word_no = pop_item!(self, word_no, WordNo, context);
+        let no_skyfall_stmt_built = NoSkyfallStmtBuilder::default()
+            .word_no(Box::new(word_no))
+            .word_skyfall(Box::new(word_skyfall))
+            .word_combos(Box::new(word_combos))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.no_skyfall_stmt(&no_skyfall_stmt_built)?;
+        self.push(ASTType::NoSkyfallStmt(no_skyfall_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 136:
+    ///
+    /// TapeStmt: The TapeTarget WordBecomes WordUnmovable;
+    ///
+    #[parol_runtime::function_name::named]
+    fn tape_stmt(
+        &mut self,
+        _the: &ParseTreeStackEntry<'t>,
+        _tape_target: &ParseTreeStackEntry<'t>,
+        _word_becomes: &ParseTreeStackEntry<'t>,
+        _word_unmovable: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_unmovable = pop_item!(self, word_unmovable, WordUnmovable, context);
+        let word_becomes = pop_item!(self, word_becomes, WordBecomes, context);
+        let tape_target = pop_item!(self, tape_target, TapeTarget, context);
+        let the = pop_item!(self, the, The, context);
+        let tape_stmt_built = TapeStmtBuilder::default()
+            .the(Box::new(the))
+            .tape_target(Box::new(tape_target))
+            .word_becomes(Box::new(word_becomes))
+            .word_unmovable(Box::new(word_unmovable))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.tape_stmt(&tape_stmt_built)?;
+        self.push(ASTType::TapeStmt(tape_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 137:
+    ///
+    /// TapeTarget: RowColPosition;
+    ///
+    #[parol_runtime::function_name::named]
+    fn tape_target_0(
+        &mut self,
+        _row_col_position: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let row_col_position = pop_item!(self, row_col_position, RowColPosition, context);
+        let tape_target_0_built = TapeTargetRowColPositionBuilder::default()
+            .row_col_position(Box::new(row_col_position))
+            .build()
+            .into_diagnostic()?;
+        let tape_target_0_built = TapeTarget::RowColPosition(tape_target_0_built);
+        // Calling user action here
+        self.user_grammar.tape_target(&tape_target_0_built)?;
+        self.push(ASTType::TapeTarget(tape_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 138:
+    ///
+    /// TapeTarget: WordBoard;
+    ///
+    #[parol_runtime::function_name::named]
+    fn tape_target_1(
+        &mut self,
+        _word_board: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_board = pop_item!(self, word_board, WordBoard, context);
+        let tape_target_1_built = TapeTargetWordBoardBuilder::default()
+            .word_board(Box::new(word_board))
+            .build()
+            .into_diagnostic()?;
+        let tape_target_1_built = TapeTarget::WordBoard(tape_target_1_built);
+        // Calling user action here
+        self.user_grammar.tape_target(&tape_target_1_built)?;
+        self.push(ASTType::TapeTarget(tape_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 139:
+    ///
+    /// BoardPosition: At WordRandom;
+    ///
+    #[parol_runtime::function_name::named]
+    fn board_position_0(
+        &mut self,
+        _at: &ParseTreeStackEntry<'t>,
+        _word_random: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_random = pop_item!(self, word_random, WordRandom, context);
+        let at = pop_item!(self, at, At, context);
+        let board_position_0_built = BoardPositionAtWordRandomBuilder::default()
+            .at(Box::new(at))
+            .word_random(Box::new(word_random))
+            .build()
+            .into_diagnostic()?;
+        let board_position_0_built = BoardPosition::AtWordRandom(board_position_0_built);
+        // Calling user action here
+        self.user_grammar.board_position(&board_position_0_built)?;
+        self.push(ASTType::BoardPosition(board_position_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 140:
+    ///
+    /// BoardPosition: In The RowColPosition;
+    ///
+    #[parol_runtime::function_name::named]
+    fn board_position_1(
+        &mut self,
+        _in: &ParseTreeStackEntry<'t>,
+        _the: &ParseTreeStackEntry<'t>,
+        _row_col_position: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let row_col_position = pop_item!(self, row_col_position, RowColPosition, context);
+        let the = pop_item!(self, the, The, context);
+        let r#in = pop_item!(self, r#in, In, context);
+        let board_position_1_built = BoardPositionInTheRowColPositionBuilder::default()
+            .r#in(Box::new(r#in))
+            .the(Box::new(the))
+            .row_col_position(Box::new(row_col_position))
+            .build()
+            .into_diagnostic()?;
+        let board_position_1_built = BoardPosition::InTheRowColPosition(board_position_1_built);
+        // Calling user action here
+        self.user_grammar.board_position(&board_position_1_built)?;
+        self.push(ASTType::BoardPosition(board_position_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 141:
+    ///
+    /// VoidStmt: WordVoid VoidTarget VoidStmtList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_stmt(
+        &mut self,
+        _word_void: &ParseTreeStackEntry<'t>,
+        _void_target: &ParseTreeStackEntry<'t>,
+        _void_stmt_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let void_stmt_list = pop_and_reverse_item!(self, void_stmt_list, VoidStmtList, context);
+        let void_target = pop_item!(self, void_target, VoidTarget, context);
+        let word_void = pop_item!(self, word_void, WordVoid, context);
+        let void_stmt_built = VoidStmtBuilder::default()
+            .word_void(Box::new(word_void))
+            .void_target(Box::new(void_target))
+            .void_stmt_list(void_stmt_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.void_stmt(&void_stmt_built)?;
+        self.push(ASTType::VoidStmt(void_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 142:
+    ///
+    /// VoidStmtList /* Vec<T>::Push */: VoidSep VoidTarget VoidStmtList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_stmt_list_0(
+        &mut self,
+        _void_sep: &ParseTreeStackEntry<'t>,
+        _void_target: &ParseTreeStackEntry<'t>,
+        _void_stmt_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut void_stmt_list = pop_item!(self, void_stmt_list, VoidStmtList, context);
+        let void_target = pop_item!(self, void_target, VoidTarget, context);
+        let void_sep = pop_item!(self, void_sep, VoidSep, context);
+        let void_stmt_list_0_built = VoidStmtListBuilder::default()
+            .void_target(Box::new(void_target))
+            .void_sep(Box::new(void_sep))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        void_stmt_list.push(void_stmt_list_0_built);
+        self.push(ASTType::VoidStmtList(void_stmt_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 143:
+    ///
+    /// VoidStmtList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_stmt_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let void_stmt_list_1_built = Vec::new();
+        self.push(ASTType::VoidStmtList(void_stmt_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 144:
+    ///
+    /// VoidSep: Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_sep_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let void_sep_0_built = VoidSepCammaBuilder::default()
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        let void_sep_0_built = VoidSep::Camma(void_sep_0_built);
+        // Calling user action here
+        self.user_grammar.void_sep(&void_sep_0_built)?;
+        self.push(ASTType::VoidSep(void_sep_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 145:
+    ///
+    /// VoidSep: And;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_sep_1(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let void_sep_1_built = VoidSepAndBuilder::default()
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        let void_sep_1_built = VoidSep::And(void_sep_1_built);
+        // Calling user action here
+        self.user_grammar.void_sep(&void_sep_1_built)?;
+        self.push(ASTType::VoidSep(void_sep_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 146:
+    ///
+    /// VoidTarget: WordAttribute VoidTargetOpt /* Option */ WordAbsorption;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_target_0(
+        &mut self,
+        _word_attribute: &ParseTreeStackEntry<'t>,
+        _void_target_opt: &ParseTreeStackEntry<'t>,
+        _word_absorption: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_absorption = pop_item!(self, word_absorption, WordAbsorption, context);
+        let void_target_opt = pop_item!(self, void_target_opt, VoidTargetOpt, context);
+        let word_attribute = pop_item!(self, word_attribute, WordAttribute, context);
+        let void_target_0_built =
+            VoidTargetWordAttributeVoidTargetOptWordAbsorptionBuilder::default()
+                .word_attribute(Box::new(word_attribute))
+                .void_target_opt(void_target_opt)
+                .word_absorption(Box::new(word_absorption))
+                .build()
+                .into_diagnostic()?;
+        let void_target_0_built =
+            VoidTarget::WordAttributeVoidTargetOptWordAbsorption(void_target_0_built);
+        // Calling user action here
+        self.user_grammar.void_target(&void_target_0_built)?;
+        self.push(ASTType::VoidTarget(void_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 147:
+    ///
+    /// VoidTarget: WordDamage DamageTarget;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_target_1(
+        &mut self,
+        _word_damage: &ParseTreeStackEntry<'t>,
+        _damage_target: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let damage_target = pop_item!(self, damage_target, DamageTarget, context);
+        let word_damage = pop_item!(self, word_damage, WordDamage, context);
+        let void_target_1_built = VoidTargetWordDamageDamageTargetBuilder::default()
+            .word_damage(Box::new(word_damage))
+            .damage_target(Box::new(damage_target))
+            .build()
+            .into_diagnostic()?;
+        let void_target_1_built = VoidTarget::WordDamageDamageTarget(void_target_1_built);
+        // Calling user action here
+        self.user_grammar.void_target(&void_target_1_built)?;
+        self.push(ASTType::VoidTarget(void_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 148:
+    ///
+    /// VoidTargetOpt /* Option<T>::Some */: And WordDamage;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_target_opt_0(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _word_damage: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_damage = pop_item!(self, word_damage, WordDamage, context);
+        let and = pop_item!(self, and, And, context);
+        let void_target_opt_0_built = VoidTargetOptBuilder::default()
+            .and(Box::new(and))
+            .word_damage(Box::new(word_damage))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::VoidTargetOpt(Some(Box::new(void_target_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 149:
+    ///
+    /// VoidTargetOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn void_target_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::VoidTargetOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 150:
+    ///
+    /// DamageTarget: WordAbsorption;
+    ///
+    #[parol_runtime::function_name::named]
+    fn damage_target_0(
+        &mut self,
+        _word_absorption: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_absorption = pop_item!(self, word_absorption, WordAbsorption, context);
+        let damage_target_0_built = DamageTargetWordAbsorptionBuilder::default()
+            .word_absorption(Box::new(word_absorption))
+            .build()
+            .into_diagnostic()?;
+        let damage_target_0_built = DamageTarget::WordAbsorption(damage_target_0_built);
+        // Calling user action here
+        self.user_grammar.damage_target(&damage_target_0_built)?;
+        self.push(ASTType::DamageTarget(damage_target_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 151:
+    ///
+    /// DamageTarget: WordVoid;
+    ///
+    #[parol_runtime::function_name::named]
+    fn damage_target_1(
+        &mut self,
+        _word_void: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_void = pop_item!(self, word_void, WordVoid, context);
+        let damage_target_1_built = DamageTargetWordVoidBuilder::default()
+            .word_void(Box::new(word_void))
+            .build()
+            .into_diagnostic()?;
+        let damage_target_1_built = DamageTarget::WordVoid(damage_target_1_built);
+        // Calling user action here
+        self.user_grammar.damage_target(&damage_target_1_built)?;
+        self.push(ASTType::DamageTarget(damage_target_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 152:
+    ///
+    /// PierceStmt: WordPierce WordThrough WordDamage WordVoid;
+    ///
+    #[parol_runtime::function_name::named]
+    fn pierce_stmt(
+        &mut self,
+        _word_pierce: &ParseTreeStackEntry<'t>,
+        _word_through: &ParseTreeStackEntry<'t>,
+        _word_damage: &ParseTreeStackEntry<'t>,
+        _word_void: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_void = pop_item!(self, word_void, WordVoid, context);
+        let word_damage = pop_item!(self, word_damage, WordDamage, context);
+        let word_through = pop_item!(self, word_through, WordThrough, context);
+        let word_pierce = pop_item!(self, word_pierce, WordPierce, context);
+        let pierce_stmt_built = PierceStmtBuilder::default()
+            .word_pierce(Box::new(word_pierce))
+            .word_through(Box::new(word_through))
+            .word_damage(Box::new(word_damage))
+            .word_void(Box::new(word_void))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.pierce_stmt(&pierce_stmt_built)?;
+        self.push(ASTType::PierceStmt(pierce_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 153:
+    ///
+    /// Drops: DropName DropsList /* Vec */ WordOrbs;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drops(
+        &mut self,
+        _drop_name: &ParseTreeStackEntry<'t>,
+        _drops_list: &ParseTreeStackEntry<'t>,
+        _word_orbs: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = pop_item!(self, word_orbs, WordOrbs, context);
+        let drops_list = pop_and_reverse_item!(self, drops_list, DropsList, context);
+        let drop_name = pop_item!(self, drop_name, DropName, context);
+        let drops_built = DropsBuilder::default()
+            .drop_name(Box::new(drop_name))
+            .drops_list(drops_list)
+            .word_orbs(Box::new(word_orbs))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.drops(&drops_built)?;
+        self.push(ASTType::Drops(drops_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 154:
+    ///
+    /// DropsList /* Vec<T>::Push */: DropSep DropName DropsList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drops_list_0(
+        &mut self,
+        _drop_sep: &ParseTreeStackEntry<'t>,
+        _drop_name: &ParseTreeStackEntry<'t>,
+        _drops_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut drops_list = pop_item!(self, drops_list, DropsList, context);
+        let drop_name = pop_item!(self, drop_name, DropName, context);
+        let drop_sep = pop_item!(self, drop_sep, DropSep, context);
+        let drops_list_0_built = DropsListBuilder::default()
+            .drop_name(Box::new(drop_name))
+            .drop_sep(Box::new(drop_sep))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        drops_list.push(drops_list_0_built);
+        self.push(ASTType::DropsList(drops_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 155:
+    ///
+    /// DropsList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drops_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let drops_list_1_built = Vec::new();
+        self.push(ASTType::DropsList(drops_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 156:
+    ///
+    /// DropSep: Camma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drop_sep_0(
+        &mut self,
+        _camma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = pop_item!(self, camma, Camma, context);
+        let drop_sep_0_built = DropSepCammaBuilder::default()
+            .camma(Box::new(camma))
+            .build()
+            .into_diagnostic()?;
+        let drop_sep_0_built = DropSep::Camma(drop_sep_0_built);
+        // Calling user action here
+        self.user_grammar.drop_sep(&drop_sep_0_built)?;
+        self.push(ASTType::DropSep(drop_sep_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 157:
+    ///
+    /// DropSep: And;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drop_sep_1(
+        &mut self,
+        _and: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let drop_sep_1_built = DropSepAndBuilder::default()
+            .and(Box::new(and))
+            .build()
+            .into_diagnostic()?;
+        let drop_sep_1_built = DropSep::And(drop_sep_1_built);
+        // Calling user action here
+        self.user_grammar.drop_sep(&drop_sep_1_built)?;
+        self.push(ASTType::DropSep(drop_sep_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 158:
+    ///
+    /// DropName: Color;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drop_name_0(
+        &mut self,
+        _color: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let color = pop_item!(self, color, Color, context);
+        let drop_name_0_built = DropNameColorBuilder::default()
+            .color(Box::new(color))
+            .build()
+            .into_diagnostic()?;
+        let drop_name_0_built = DropName::Color(drop_name_0_built);
+        // Calling user action here
+        self.user_grammar.drop_name(&drop_name_0_built)?;
+        self.push(ASTType::DropName(drop_name_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 159:
+    ///
+    /// DropName: NonColoredDrop;
+    ///
+    #[parol_runtime::function_name::named]
+    fn drop_name_1(
+        &mut self,
+        _non_colored_drop: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let non_colored_drop = pop_item!(self, non_colored_drop, NonColoredDrop, context);
+        let drop_name_1_built = DropNameNonColoredDropBuilder::default()
+            .non_colored_drop(Box::new(non_colored_drop))
+            .build()
+            .into_diagnostic()?;
+        let drop_name_1_built = DropName::NonColoredDrop(drop_name_1_built);
+        // Calling user action here
+        self.user_grammar.drop_name(&drop_name_1_built)?;
+        self.push(ASTType::DropName(drop_name_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 160:
+    ///
+    /// Color: Fire;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_0(
+        &mut self,
+        _fire: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let fire = pop_item!(self, fire, Fire, context);
+        let color_0_built = ColorFireBuilder::default()
+            .fire(Box::new(fire))
+            .build()
+            .into_diagnostic()?;
+        let color_0_built = Color::Fire(color_0_built);
+        // Calling user action here
+        self.user_grammar.color(&color_0_built)?;
+        self.push(ASTType::Color(color_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 161:
+    ///
+    /// Color: Water;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_1(
+        &mut self,
+        _water: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let water = pop_item!(self, water, Water, context);
+        let color_1_built = ColorWaterBuilder::default()
+            .water(Box::new(water))
+            .build()
+            .into_diagnostic()?;
+        let color_1_built = Color::Water(color_1_built);
+        // Calling user action here
+        self.user_grammar.color(&color_1_built)?;
+        self.push(ASTType::Color(color_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 162:
+    ///
+    /// Color: Wood;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_2(
+        &mut self,
+        _wood: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wood = pop_item!(self, wood, Wood, context);
+        let color_2_built = ColorWoodBuilder::default()
+            .wood(Box::new(wood))
+            .build()
+            .into_diagnostic()?;
+        let color_2_built = Color::Wood(color_2_built);
+        // Calling user action here
+        self.user_grammar.color(&color_2_built)?;
+        self.push(ASTType::Color(color_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 163:
+    ///
+    /// Color: Lightning;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_3(
+        &mut self,
+        _lightning: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lightning = pop_item!(self, lightning, Lightning, context);
+        let color_3_built = ColorLightningBuilder::default()
+            .lightning(Box::new(lightning))
+            .build()
+            .into_diagnostic()?;
+        let color_3_built = Color::Lightning(color_3_built);
+        // Calling user action here
+        self.user_grammar.color(&color_3_built)?;
+        self.push(ASTType::Color(color_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 164:
+    ///
+    /// Color: Dark;
+    ///
+    #[parol_runtime::function_name::named]
+    fn color_4(
+        &mut self,
+        _dark: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dark = pop_item!(self, dark, Dark, context);
+        let color_4_built = ColorDarkBuilder::default()
+            .dark(Box::new(dark))
+            .build()
+            .into_diagnostic()?;
+        let color_4_built = Color::Dark(color_4_built);
+        // Calling user action here
+        self.user_grammar.color(&color_4_built)?;
+        self.push(ASTType::Color(color_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 165:
+    ///
+    /// NonColoredDrop: Recovery;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_0(
+        &mut self,
+        _recovery: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let recovery = pop_item!(self, recovery, Recovery, context);
+        let non_colored_drop_0_built = NonColoredDropRecoveryBuilder::default()
+            .recovery(Box::new(recovery))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_0_built = NonColoredDrop::Recovery(non_colored_drop_0_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_0_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 166:
+    ///
+    /// NonColoredDrop: Disturb;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_1(
+        &mut self,
+        _disturb: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let disturb = pop_item!(self, disturb, Disturb, context);
+        let non_colored_drop_1_built = NonColoredDropDisturbBuilder::default()
+            .disturb(Box::new(disturb))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_1_built = NonColoredDrop::Disturb(non_colored_drop_1_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_1_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 167:
+    ///
+    /// NonColoredDrop: Bomb;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_2(
+        &mut self,
+        _bomb: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let bomb = pop_item!(self, bomb, Bomb, context);
+        let non_colored_drop_2_built = NonColoredDropBombBuilder::default()
+            .bomb(Box::new(bomb))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_2_built = NonColoredDrop::Bomb(non_colored_drop_2_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_2_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 168:
+    ///
+    /// NonColoredDrop: DeadlyPoison;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_3(
+        &mut self,
+        _deadly_poison: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deadly_poison = pop_item!(self, deadly_poison, DeadlyPoison, context);
+        let non_colored_drop_3_built = NonColoredDropDeadlyPoisonBuilder::default()
+            .deadly_poison(Box::new(deadly_poison))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_3_built = NonColoredDrop::DeadlyPoison(non_colored_drop_3_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_3_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 169:
+    ///
+    /// NonColoredDrop: Poison;
+    ///
+    #[parol_runtime::function_name::named]
+    fn non_colored_drop_4(
+        &mut self,
+        _poison: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let poison = pop_item!(self, poison, Poison, context);
+        let non_colored_drop_4_built = NonColoredDropPoisonBuilder::default()
+            .poison(Box::new(poison))
+            .build()
+            .into_diagnostic()?;
+        let non_colored_drop_4_built = NonColoredDrop::Poison(non_colored_drop_4_built);
+        // Calling user action here
+        self.user_grammar
+            .non_colored_drop(&non_colored_drop_4_built)?;
+        self.push(ASTType::NonColoredDrop(non_colored_drop_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 170:
+    ///
+    /// Size: PosInt Multi PosInt;
+    ///
+    #[parol_runtime::function_name::named]
+    fn size(
+        &mut self,
+        _pos_int: &ParseTreeStackEntry<'t>,
+        _multi: &ParseTreeStackEntry<'t>,
+        _pos_int0: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let pos_int0 = pop_item!(self, pos_int0, PosInt, context);
+        let multi = pop_item!(self, multi, Multi, context);
+        let pos_int = pop_item!(self, pos_int, PosInt, context);
+        let size_built = SizeBuilder::default()
+            .pos_int(Box::new(pos_int))
+            .multi(Box::new(multi))
+            .pos_int0(Box::new(pos_int0))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.size(&size_built)?;
+        self.push(ASTType::Size(size_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 171:
+    ///
+    /// Fire: "Fire\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn fire(
+        &mut self,
+        fire: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let fire = fire.token(parse_tree)?.clone();
+        let fire_built = FireBuilder::default()
+            .fire(fire)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.fire(&fire_built)?;
+        self.push(ASTType::Fire(fire_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 172:
+    ///
+    /// Water: "Water\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn water(
+        &mut self,
+        water: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let water = water.token(parse_tree)?.clone();
+        let water_built = WaterBuilder::default()
+            .water(water)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.water(&water_built)?;
+        self.push(ASTType::Water(water_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 173:
+    ///
+    /// Wood: "Wood\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn wood(
+        &mut self,
+        wood: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let wood = wood.token(parse_tree)?.clone();
+        let wood_built = WoodBuilder::default()
+            .wood(wood)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.wood(&wood_built)?;
+        self.push(ASTType::Wood(wood_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 174:
+    ///
+    /// Lightning: "Light\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn lightning(
+        &mut self,
+        lightning: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let lightning = lightning.token(parse_tree)?.clone();
+        let lightning_built = LightningBuilder::default()
+            .lightning(lightning)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.lightning(&lightning_built)?;
+        self.push(ASTType::Lightning(lightning_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 175:
+    ///
+    /// Dark: "Dark\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn dark(
+        &mut self,
+        dark: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dark = dark.token(parse_tree)?.clone();
+        let dark_built = DarkBuilder::default()
+            .dark(dark)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.dark(&dark_built)?;
+        self.push(ASTType::Dark(dark_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 176:
+    ///
+    /// Recovery: "Heal\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn recovery(
+        &mut self,
+        recovery: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let recovery = recovery.token(parse_tree)?.clone();
+        let recovery_built = RecoveryBuilder::default()
+            .recovery(recovery)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.recovery(&recovery_built)?;
+        self.push(ASTType::Recovery(recovery_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 177:
+    ///
+    /// Disturb: "Jammer\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn disturb(
+        &mut self,
+        disturb: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let disturb = disturb.token(parse_tree)?.clone();
+        let disturb_built = DisturbBuilder::default()
+            .disturb(disturb)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.disturb(&disturb_built)?;
+        self.push(ASTType::Disturb(disturb_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 178:
+    ///
+    /// Bomb: "Bomb\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn bomb(
+        &mut self,
+        bomb: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let bomb = bomb.token(parse_tree)?.clone();
+        let bomb_built = BombBuilder::default()
+            .bomb(bomb)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.bomb(&bomb_built)?;
+        self.push(ASTType::Bomb(bomb_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 179:
+    ///
+    /// DeadlyPoison: "Mortal Poison\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn deadly_poison(
+        &mut self,
+        deadly_poison: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deadly_poison = deadly_poison.token(parse_tree)?.clone();
+        let deadly_poison_built = DeadlyPoisonBuilder::default()
+            .deadly_poison(deadly_poison)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.deadly_poison(&deadly_poison_built)?;
+        self.push(ASTType::DeadlyPoison(deadly_poison_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 180:
+    ///
+    /// Poison: "Poison\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn poison(
+        &mut self,
+        poison: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let poison = poison.token(parse_tree)?.clone();
+        let poison_built = PoisonBuilder::default()
+            .poison(poison)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.poison(&poison_built)?;
+        self.push(ASTType::Poison(poison_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 181:
+    ///
+    /// WordFor: "[Ff]or\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_for(
+        &mut self,
+        word_for: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_for = word_for.token(parse_tree)?.clone();
+        let word_for_built = WordForBuilder::default()
+            .word_for(word_for)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_for(&word_for_built)?;
+        self.push(ASTType::WordFor(word_for_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 182:
+    ///
+    /// WordTurn: "turns?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_turn(
+        &mut self,
+        word_turn: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_turn = word_turn.token(parse_tree)?.clone();
+        let word_turn_built = WordTurnBuilder::default()
+            .word_turn(word_turn)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_turn(&word_turn_built)?;
+        self.push(ASTType::WordTurn(word_turn_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 183:
+    ///
+    /// WordChange: "[Cc]hange\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_change(
+        &mut self,
+        word_change: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_change = word_change.token(parse_tree)?.clone();
+        let word_change_built = WordChangeBuilder::default()
+            .word_change(word_change)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_change(&word_change_built)?;
+        self.push(ASTType::WordChange(word_change_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 184:
+    ///
+    /// WordCreate: "[Cc]reate\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_create(
+        &mut self,
+        word_create: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_create = word_create.token(parse_tree)?.clone();
+        let word_create_built = WordCreateBuilder::default()
+            .word_create(word_create)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_create(&word_create_built)?;
+        self.push(ASTType::WordCreate(word_create_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 185:
+    ///
+    /// WordUnlock: "[Uu]nlock\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_unlock(
+        &mut self,
+        word_unlock: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_unlock = word_unlock.token(parse_tree)?.clone();
+        let word_unlock_built = WordUnlockBuilder::default()
+            .word_unlock(word_unlock)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_unlock(&word_unlock_built)?;
+        self.push(ASTType::WordUnlock(word_unlock_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 186:
+    ///
+    /// WordLock: "[Ll]ock\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_lock(
+        &mut self,
+        word_lock: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_lock = word_lock.token(parse_tree)?.clone();
+        let word_lock_built = WordLockBuilder::default()
+            .word_lock(word_lock)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_lock(&word_lock_built)?;
+        self.push(ASTType::WordLock(word_lock_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 187:
+    ///
+    /// WordReplace: "[Rr]eplace\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_replace(
+        &mut self,
+        word_replace: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_replace = word_replace.token(parse_tree)?.clone();
+        let word_replace_built = WordReplaceBuilder::default()
+            .word_replace(word_replace)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_replace(&word_replace_built)?;
+        self.push(ASTType::WordReplace(word_replace_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 188:
+    ///
+    /// WordEnhance: "[Ee]nhance\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_enhance(
+        &mut self,
+        word_enhance: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_enhance = word_enhance.token(parse_tree)?.clone();
+        let word_enhance_built = WordEnhanceBuilder::default()
+            .word_enhance(word_enhance)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_enhance(&word_enhance_built)?;
+        self.push(ASTType::WordEnhance(word_enhance_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 189:
+    ///
+    /// WordEnhanced: "[Ee]nhanced\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_enhanced(
+        &mut self,
+        word_enhanced: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_enhanced = word_enhanced.token(parse_tree)?.clone();
+        let word_enhanced_built = WordEnhancedBuilder::default()
+            .word_enhanced(word_enhanced)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_enhanced(&word_enhanced_built)?;
+        self.push(ASTType::WordEnhanced(word_enhanced_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 190:
+    ///
+    /// WordAll: "[Aa]ll\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_all(
+        &mut self,
+        word_all: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_all = word_all.token(parse_tree)?.clone();
+        let word_all_built = WordAllBuilder::default()
+            .word_all(word_all)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_all(&word_all_built)?;
+        self.push(ASTType::WordAll(word_all_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 191:
+    ///
+    /// WordOnly: "[Oo]nly\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_only(
+        &mut self,
+        word_only: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_only = word_only.token(parse_tree)?.clone();
+        let word_only_built = WordOnlyBuilder::default()
+            .word_only(word_only)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_only(&word_only_built)?;
+        self.push(ASTType::WordOnly(word_only_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 192:
+    ///
+    /// WordNail: "[Nn]ail\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_nail(
+        &mut self,
+        word_nail: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_nail = word_nail.token(parse_tree)?.clone();
+        let word_nail_built = WordNailBuilder::default()
+            .word_nail(word_nail)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_nail(&word_nail_built)?;
+        self.push(ASTType::WordNail(word_nail_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 193:
+    ///
+    /// WordNo: "[Nn]o\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_no(
+        &mut self,
+        word_no: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_no = word_no.token(parse_tree)?.clone();
+        let word_no_built = WordNoBuilder::default()
+            .word_no(word_no)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_no(&word_no_built)?;
+        self.push(ASTType::WordNo(word_no_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 194:
+    ///
+    /// WordOrbs: "[Oo]rbs?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_orbs(
+        &mut self,
+        word_orbs: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_orbs = word_orbs.token(parse_tree)?.clone();
+        let word_orbs_built = WordOrbsBuilder::default()
+            .word_orbs(word_orbs)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_orbs(&word_orbs_built)?;
+        self.push(ASTType::WordOrbs(word_orbs_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 195:
+    ///
+    /// WordVoid: "[Vv]oids?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_void(
+        &mut self,
+        word_void: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_void = word_void.token(parse_tree)?.clone();
+        let word_void_built = WordVoidBuilder::default()
+            .word_void(word_void)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_void(&word_void_built)?;
+        self.push(ASTType::WordVoid(word_void_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 196:
+    ///
+    /// WordPierce: "[Pp]ierces?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_pierce(
+        &mut self,
+        word_pierce: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_pierce = word_pierce.token(parse_tree)?.clone();
+        let word_pierce_built = WordPierceBuilder::default()
+            .word_pierce(word_pierce)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_pierce(&word_pierce_built)?;
+        self.push(ASTType::WordPierce(word_pierce_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 197:
+    ///
+    /// WordThrough: "through\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_through(
+        &mut self,
+        word_through: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_through = word_through.token(parse_tree)?.clone();
+        let word_through_built = WordThroughBuilder::default()
+            .word_through(word_through)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_through(&word_through_built)?;
+        self.push(ASTType::WordThrough(word_through_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 198:
+    ///
+    /// WordEnemies: "enemies\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_enemies(
+        &mut self,
+        word_enemies: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_enemies = word_enemies.token(parse_tree)?.clone();
+        let word_enemies_built = WordEnemiesBuilder::default()
+            .word_enemies(word_enemies)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_enemies(&word_enemies_built)?;
+        self.push(ASTType::WordEnemies(word_enemies_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 199:
+    ///
+    /// WordAttribute: "attribute\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_attribute(
+        &mut self,
+        word_attribute: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_attribute = word_attribute.token(parse_tree)?.clone();
+        let word_attribute_built = WordAttributeBuilder::default()
+            .word_attribute(word_attribute)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_attribute(&word_attribute_built)?;
+        self.push(ASTType::WordAttribute(word_attribute_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 200:
+    ///
+    /// WordRandom: "random\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_random(
+        &mut self,
+        word_random: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_random = word_random.token(parse_tree)?.clone();
+        let word_random_built = WordRandomBuilder::default()
+            .word_random(word_random)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_random(&word_random_built)?;
+        self.push(ASTType::WordRandom(word_random_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 201:
+    ///
+    /// WordLeftmost: "leftmost\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_leftmost(
+        &mut self,
+        word_leftmost: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_leftmost = word_leftmost.token(parse_tree)?.clone();
+        let word_leftmost_built = WordLeftmostBuilder::default()
+            .word_leftmost(word_leftmost)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_leftmost(&word_leftmost_built)?;
+        self.push(ASTType::WordLeftmost(word_leftmost_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 202:
+    ///
+    /// WordRightmost: "rightmost\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_rightmost(
+        &mut self,
+        word_rightmost: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_rightmost = word_rightmost.token(parse_tree)?.clone();
+        let word_rightmost_built = WordRightmostBuilder::default()
+            .word_rightmost(word_rightmost)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_rightmost(&word_rightmost_built)?;
+        self.push(ASTType::WordRightmost(word_rightmost_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 203:
+    ///
+    /// WordLeft: "left\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_left(
+        &mut self,
+        word_left: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_left = word_left.token(parse_tree)?.clone();
+        let word_left_built = WordLeftBuilder::default()
+            .word_left(word_left)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_left(&word_left_built)?;
+        self.push(ASTType::WordLeft(word_left_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 204:
+    ///
+    /// WordRight: "right\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_right(
+        &mut self,
+        word_right: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_right = word_right.token(parse_tree)?.clone();
+        let word_right_built = WordRightBuilder::default()
+            .word_right(word_right)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_right(&word_right_built)?;
+        self.push(ASTType::WordRight(word_right_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 205:
+    ///
+    /// WordTop: "top\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_top(
+        &mut self,
+        word_top: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_top = word_top.token(parse_tree)?.clone();
+        let word_top_built = WordTopBuilder::default()
+            .word_top(word_top)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_top(&word_top_built)?;
+        self.push(ASTType::WordTop(word_top_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 206:
+    ///
+    /// WordBottom: "bottom\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_bottom(
+        &mut self,
+        word_bottom: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_bottom = word_bottom.token(parse_tree)?.clone();
+        let word_bottom_built = WordBottomBuilder::default()
+            .word_bottom(word_bottom)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_bottom(&word_bottom_built)?;
+        self.push(ASTType::WordBottom(word_bottom_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 207:
+    ///
+    /// WordColumn: "column\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_column(
+        &mut self,
+        word_column: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_column = word_column.token(parse_tree)?.clone();
+        let word_column_built = WordColumnBuilder::default()
+            .word_column(word_column)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_column(&word_column_built)?;
+        self.push(ASTType::WordColumn(word_column_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 208:
+    ///
+    /// WordRow: "row\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_row(
+        &mut self,
+        word_row: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_row = word_row.token(parse_tree)?.clone();
+        let word_row_built = WordRowBuilder::default()
+            .word_row(word_row)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_row(&word_row_built)?;
+        self.push(ASTType::WordRow(word_row_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 209:
+    ///
+    /// WordOuter: "outer\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_outer(
+        &mut self,
+        word_outer: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_outer = word_outer.token(parse_tree)?.clone();
+        let word_outer_built = WordOuterBuilder::default()
+            .word_outer(word_outer)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_outer(&word_outer_built)?;
+        self.push(ASTType::WordOuter(word_outer_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 210:
+    ///
+    /// WordEdges: "edges\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_edges(
+        &mut self,
+        word_edges: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_edges = word_edges.token(parse_tree)?.clone();
+        let word_edges_built = WordEdgesBuilder::default()
+            .word_edges(word_edges)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_edges(&word_edges_built)?;
+        self.push(ASTType::WordEdges(word_edges_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 211:
+    ///
+    /// WordCenter: "center\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_center(
+        &mut self,
+        word_center: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_center = word_center.token(parse_tree)?.clone();
+        let word_center_built = WordCenterBuilder::default()
+            .word_center(word_center)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_center(&word_center_built)?;
+        self.push(ASTType::WordCenter(word_center_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 212:
+    ///
+    /// WordBoard: "board\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_board(
+        &mut self,
+        word_board: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_board = word_board.token(parse_tree)?.clone();
+        let word_board_built = WordBoardBuilder::default()
+            .word_board(word_board)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_board(&word_board_built)?;
+        self.push(ASTType::WordBoard(word_board_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 213:
+    ///
+    /// WordEach: "each\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_each(
+        &mut self,
+        word_each: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_each = word_each.token(parse_tree)?.clone();
+        let word_each_built = WordEachBuilder::default()
+            .word_each(word_each)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_each(&word_each_built)?;
+        self.push(ASTType::WordEach(word_each_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 214:
+    ///
+    /// WordCorner: "corner\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_corner(
+        &mut self,
+        word_corner: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_corner = word_corner.token(parse_tree)?.clone();
+        let word_corner_built = WordCornerBuilder::default()
+            .word_corner(word_corner)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_corner(&word_corner_built)?;
+        self.push(ASTType::WordCorner(word_corner_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 215:
+    ///
+    /// WordNon: "non-";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_non(
+        &mut self,
+        word_non: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_non = word_non.token(parse_tree)?.clone();
+        let word_non_built = WordNonBuilder::default()
+            .word_non(word_non)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_non(&word_non_built)?;
+        self.push(ASTType::WordNon(word_non_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 216:
+    ///
+    /// WordLShape: "L shapes?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_l_shape(
+        &mut self,
+        word_l_shape: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_l_shape = word_l_shape.token(parse_tree)?.clone();
+        let word_l_shape_built = WordLShapeBuilder::default()
+            .word_l_shape(word_l_shape)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_l_shape(&word_l_shape_built)?;
+        self.push(ASTType::WordLShape(word_l_shape_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 217:
+    ///
+    /// WordZShape: "Z shape\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_z_shape(
+        &mut self,
+        word_z_shape: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_z_shape = word_z_shape.token(parse_tree)?.clone();
+        let word_z_shape_built = WordZShapeBuilder::default()
+            .word_z_shape(word_z_shape)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_z_shape(&word_z_shape_built)?;
+        self.push(ASTType::WordZShape(word_z_shape_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 218:
+    ///
+    /// WordCross: "cross(es)?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_cross(
+        &mut self,
+        word_cross: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_cross = word_cross.token(parse_tree)?.clone();
+        let word_cross_built = WordCrossBuilder::default()
+            .word_cross(word_cross)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_cross(&word_cross_built)?;
+        self.push(ASTType::WordCross(word_cross_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 219:
+    ///
+    /// WordLarge: "large\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_large(
+        &mut self,
+        word_large: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_large = word_large.token(parse_tree)?.clone();
+        let word_large_built = WordLargeBuilder::default()
+            .word_large(word_large)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_large(&word_large_built)?;
+        self.push(ASTType::WordLarge(word_large_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 220:
+    ///
+    /// WordAcross: "across\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_across(
+        &mut self,
+        word_across: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_across = word_across.token(parse_tree)?.clone();
+        let word_across_built = WordAcrossBuilder::default()
+            .word_across(word_across)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_across(&word_across_built)?;
+        self.push(ASTType::WordAcross(word_across_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 221:
+    ///
+    /// WordSquare: "squares?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_square(
+        &mut self,
+        word_square: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_square = word_square.token(parse_tree)?.clone();
+        let word_square_built = WordSquareBuilder::default()
+            .word_square(word_square)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_square(&word_square_built)?;
+        self.push(ASTType::WordSquare(word_square_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 222:
+    ///
+    /// WordShape: "shapes?\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_shape(
+        &mut self,
+        word_shape: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_shape = word_shape.token(parse_tree)?.clone();
+        let word_shape_built = WordShapeBuilder::default()
+            .word_shape(word_shape)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_shape(&word_shape_built)?;
+        self.push(ASTType::WordShape(word_shape_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 223:
+    ///
+    /// WordRoulette: "roulette\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_roulette(
+        &mut self,
+        word_roulette: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_roulette = word_roulette.token(parse_tree)?.clone();
+        let word_roulette_built = WordRouletteBuilder::default()
+            .word_roulette(word_roulette)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_roulette(&word_roulette_built)?;
+        self.push(ASTType::WordRoulette(word_roulette_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 224:
+    ///
+    /// WordSpiderweb: "spiderweb\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_spiderweb(
+        &mut self,
+        word_spiderweb: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_spiderweb = word_spiderweb.token(parse_tree)?.clone();
+        let word_spiderweb_built = WordSpiderwebBuilder::default()
+            .word_spiderweb(word_spiderweb)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_spiderweb(&word_spiderweb_built)?;
+        self.push(ASTType::WordSpiderweb(word_spiderweb_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 225:
+    ///
+    /// WordWith: "with\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_with(
+        &mut self,
+        word_with: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_with = word_with.token(parse_tree)?.clone();
+        let word_with_built = WordWithBuilder::default()
+            .word_with(word_with)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_with(&word_with_built)?;
+        self.push(ASTType::WordWith(word_with_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 226:
+    ///
+    /// WordIts: "its\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_its(
+        &mut self,
+        word_its: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_its = word_its.token(parse_tree)?.clone();
+        let word_its_built = WordItsBuilder::default()
+            .word_its(word_its)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_its(&word_its_built)?;
+        self.push(ASTType::WordIts(word_its_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 227:
+    ///
+    /// WordCrescentMoon: "crescent moon\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_crescent_moon(
+        &mut self,
+        word_crescent_moon: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_crescent_moon = word_crescent_moon.token(parse_tree)?.clone();
+        let word_crescent_moon_built = WordCrescentMoonBuilder::default()
+            .word_crescent_moon(word_crescent_moon)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .word_crescent_moon(&word_crescent_moon_built)?;
+        self.push(ASTType::WordCrescentMoon(word_crescent_moon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 228:
+    ///
+    /// WordDiagonalLine: "diagonal line\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_diagonal_line(
+        &mut self,
+        word_diagonal_line: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_diagonal_line = word_diagonal_line.token(parse_tree)?.clone();
+        let word_diagonal_line_built = WordDiagonalLineBuilder::default()
+            .word_diagonal_line(word_diagonal_line)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .word_diagonal_line(&word_diagonal_line_built)?;
+        self.push(ASTType::WordDiagonalLine(word_diagonal_line_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 229:
+    ///
+    /// WordCloud: "cloud\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_cloud(
+        &mut self,
+        word_cloud: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_cloud = word_cloud.token(parse_tree)?.clone();
+        let word_cloud_built = WordCloudBuilder::default()
+            .word_cloud(word_cloud)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_cloud(&word_cloud_built)?;
+        self.push(ASTType::WordCloud(word_cloud_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 230:
+    ///
+    /// WordAppear: "appear\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_appear(
+        &mut self,
+        word_appear: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_appear = word_appear.token(parse_tree)?.clone();
+        let word_appear_built = WordAppearBuilder::default()
+            .word_appear(word_appear)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_appear(&word_appear_built)?;
+        self.push(ASTType::WordAppear(word_appear_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 231:
+    ///
+    /// WordLocked: "locked\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_locked(
+        &mut self,
+        word_locked: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_locked = word_locked.token(parse_tree)?.clone();
+        let word_locked_built = WordLockedBuilder::default()
+            .word_locked(word_locked)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_locked(&word_locked_built)?;
+        self.push(ASTType::WordLocked(word_locked_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 232:
+    ///
+    /// WordWill: "will\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_will(
+        &mut self,
+        word_will: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_will = word_will.token(parse_tree)?.clone();
+        let word_will_built = WordWillBuilder::default()
+            .word_will(word_will)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_will(&word_will_built)?;
+        self.push(ASTType::WordWill(word_will_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 233:
+    ///
+    /// WordHave: "have\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_have(
+        &mut self,
+        word_have: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_have = word_have.token(parse_tree)?.clone();
+        let word_have_built = WordHaveBuilder::default()
+            .word_have(word_have)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_have(&word_have_built)?;
+        self.push(ASTType::WordHave(word_have_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 234:
+    ///
+    /// WordChance: "chance\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_chance(
+        &mut self,
+        word_chance: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_chance = word_chance.token(parse_tree)?.clone();
+        let word_chance_built = WordChanceBuilder::default()
+            .word_chance(word_chance)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_chance(&word_chance_built)?;
+        self.push(ASTType::WordChance(word_chance_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 235:
+    ///
+    /// WordMore: "more\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_more(
+        &mut self,
+        word_more: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_more = word_more.token(parse_tree)?.clone();
+        let word_more_built = WordMoreBuilder::default()
+            .word_more(word_more)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_more(&word_more_built)?;
+        self.push(ASTType::WordMore(word_more_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 236:
+    ///
+    /// WordLikely: "likely\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_likely(
+        &mut self,
+        word_likely: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_likely = word_likely.token(parse_tree)?.clone();
+        let word_likely_built = WordLikelyBuilder::default()
+            .word_likely(word_likely)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_likely(&word_likely_built)?;
+        self.push(ASTType::WordLikely(word_likely_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 237:
+    ///
+    /// WordVerySlightly: "very slightly\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_very_slightly(
+        &mut self,
+        word_very_slightly: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_very_slightly = word_very_slightly.token(parse_tree)?.clone();
+        let word_very_slightly_built = WordVerySlightlyBuilder::default()
+            .word_very_slightly(word_very_slightly)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .word_very_slightly(&word_very_slightly_built)?;
+        self.push(ASTType::WordVerySlightly(word_very_slightly_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 238:
+    ///
+    /// WordSlightly: "slightly\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_slightly(
+        &mut self,
+        word_slightly: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_slightly = word_slightly.token(parse_tree)?.clone();
+        let word_slightly_built = WordSlightlyBuilder::default()
+            .word_slightly(word_slightly)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_slightly(&word_slightly_built)?;
+        self.push(ASTType::WordSlightly(word_slightly_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 239:
+    ///
+    /// WordSkyfall: "skyfall\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_skyfall(
+        &mut self,
+        word_skyfall: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_skyfall = word_skyfall.token(parse_tree)?.clone();
+        let word_skyfall_built = WordSkyfallBuilder::default()
+            .word_skyfall(word_skyfall)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_skyfall(&word_skyfall_built)?;
+        self.push(ASTType::WordSkyfall(word_skyfall_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 240:
+    ///
+    /// WordCombos: "combos\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_combos(
+        &mut self,
+        word_combos: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_combos = word_combos.token(parse_tree)?.clone();
+        let word_combos_built = WordCombosBuilder::default()
+            .word_combos(word_combos)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_combos(&word_combos_built)?;
+        self.push(ASTType::WordCombos(word_combos_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 241:
+    ///
+    /// WordBecomes: "becomes\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_becomes(
+        &mut self,
+        word_becomes: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_becomes = word_becomes.token(parse_tree)?.clone();
+        let word_becomes_built = WordBecomesBuilder::default()
+            .word_becomes(word_becomes)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_becomes(&word_becomes_built)?;
+        self.push(ASTType::WordBecomes(word_becomes_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 242:
+    ///
+    /// WordUnmovable: "unmovable\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_unmovable(
+        &mut self,
+        word_unmovable: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_unmovable = word_unmovable.token(parse_tree)?.clone();
+        let word_unmovable_built = WordUnmovableBuilder::default()
+            .word_unmovable(word_unmovable)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_unmovable(&word_unmovable_built)?;
+        self.push(ASTType::WordUnmovable(word_unmovable_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 243:
+    ///
+    /// WordDamage: "damage\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_damage(
+        &mut self,
+        word_damage: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_damage = word_damage.token(parse_tree)?.clone();
+        let word_damage_built = WordDamageBuilder::default()
+            .word_damage(word_damage)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_damage(&word_damage_built)?;
+        self.push(ASTType::WordDamage(word_damage_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 244:
+    ///
+    /// WordAbsorption: "absorption\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn word_absorption(
+        &mut self,
+        word_absorption: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let word_absorption = word_absorption.token(parse_tree)?.clone();
+        let word_absorption_built = WordAbsorptionBuilder::default()
+            .word_absorption(word_absorption)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.word_absorption(&word_absorption_built)?;
+        self.push(ASTType::WordAbsorption(word_absorption_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 245:
+    ///
+    /// A: "a\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn a(
+        &mut self,
+        a: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let a = a.token(parse_tree)?.clone();
+        let a_built = ABuilder::default().a(a).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.a(&a_built)?;
+        self.push(ASTType::A(a_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 246:
+    ///
+    /// And: "and\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn and(
+        &mut self,
+        and: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = and.token(parse_tree)?.clone();
+        let and_built = AndBuilder::default().and(and).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.and(&and_built)?;
+        self.push(ASTType::And(and_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 247:
+    ///
+    /// Are: "are\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn are(
+        &mut self,
+        are: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let are = are.token(parse_tree)?.clone();
+        let are_built = AreBuilder::default().are(are).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.are(&are_built)?;
+        self.push(ASTType::Are(are_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 248:
+    ///
+    /// At: "at\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn at(
+        &mut self,
+        at: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let at = at.token(parse_tree)?.clone();
+        let at_built = AtBuilder::default().at(at).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.at(&at_built)?;
+        self.push(ASTType::At(at_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 249:
+    ///
+    /// From: "from\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn from(
+        &mut self,
+        from: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let from = from.token(parse_tree)?.clone();
+        let from_built = FromBuilder::default()
+            .from(from)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.from(&from_built)?;
+        self.push(ASTType::From(from_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 250:
+    ///
+    /// In: "in\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn r#in(
+        &mut self,
+        r#in: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#in = r#in.token(parse_tree)?.clone();
+        let r#in_built = InBuilder::default().r#in(r#in).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.r#in(&r#in_built)?;
+        self.push(ASTType::In(r#in_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 251:
+    ///
+    /// Of: "of\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn of(
+        &mut self,
+        of: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let of = of.token(parse_tree)?.clone();
+        let of_built = OfBuilder::default().of(of).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.of(&of_built)?;
+        self.push(ASTType::Of(of_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 252:
+    ///
+    /// The: "[Tt]he\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn the(
+        &mut self,
+        the: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let the = the.token(parse_tree)?.clone();
+        let the_built = TheBuilder::default().the(the).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.the(&the_built)?;
+        self.push(ASTType::The(the_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 253:
+    ///
+    /// To: "to\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn to(
+        &mut self,
+        to: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let to = to.token(parse_tree)?.clone();
+        let to_built = ToBuilder::default().to(to).build().into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.to(&to_built)?;
+        self.push(ASTType::To(to_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 254:
+    ///
+    /// Camma: ',';
+    ///
+    #[parol_runtime::function_name::named]
+    fn camma(
+        &mut self,
+        camma: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let camma = camma.token(parse_tree)?.clone();
+        let camma_built = CammaBuilder::default()
+            .camma(camma)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.camma(&camma_built)?;
+        self.push(ASTType::Camma(camma_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 255:
+    ///
+    /// Semicolon: ';';
+    ///
+    #[parol_runtime::function_name::named]
+    fn semicolon(
+        &mut self,
+        semicolon: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = semicolon.token(parse_tree)?.clone();
+        let semicolon_built = SemicolonBuilder::default()
+            .semicolon(semicolon)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.semicolon(&semicolon_built)?;
+        self.push(ASTType::Semicolon(semicolon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 256:
+    ///
+    /// Period: '.';
+    ///
+    #[parol_runtime::function_name::named]
+    fn period(
+        &mut self,
+        period: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let period = period.token(parse_tree)?.clone();
+        let period_built = PeriodBuilder::default()
+            .period(period)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.period(&period_built)?;
+        self.push(ASTType::Period(period_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 257:
+    ///
+    /// Percent: '%';
+    ///
+    #[parol_runtime::function_name::named]
+    fn percent(
+        &mut self,
+        percent: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent = percent.token(parse_tree)?.clone();
+        let percent_built = PercentBuilder::default()
+            .percent(percent)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.percent(&percent_built)?;
+        self.push(ASTType::Percent(percent_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 258:
+    ///
+    /// Multi: 'x';
+    ///
+    #[parol_runtime::function_name::named]
+    fn multi(
+        &mut self,
+        multi: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let multi = multi.token(parse_tree)?.clone();
+        let multi_built = MultiBuilder::default()
+            .multi(multi)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.multi(&multi_built)?;
+        self.push(ASTType::Multi(multi_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 259:
+    ///
+    /// Ordinal: "[1-9][0-9]*(st|nd|rd|th)\b";
+    ///
+    #[parol_runtime::function_name::named]
+    fn ordinal(
+        &mut self,
+        ordinal: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ordinal = ordinal.token(parse_tree)?.clone();
+        let ordinal_built = OrdinalBuilder::default()
+            .ordinal(ordinal)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.ordinal(&ordinal_built)?;
+        self.push(ASTType::Ordinal(ordinal_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 260:
+    ///
+    /// PosInt: "[1-9][0-9]*";
+    ///
+    #[parol_runtime::function_name::named]
+    fn pos_int(
+        &mut self,
+        pos_int: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let pos_int = pos_int.token(parse_tree)?.clone();
+        let pos_int_built = PosIntBuilder::default()
+            .pos_int(pos_int)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.pos_int(&pos_int_built)?;
+        self.push(ASTType::PosInt(pos_int_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for SkillEnGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item SkillEnGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeStackEntry<'t>],
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.skill_en_lines(&children[0], &children[1], &children[2], parse_tree),
+            1 => self.skill_en_lines_list_0(&children[0], &children[1], &children[2], parse_tree),
+            2 => self.skill_en_lines_list_1(parse_tree),
+            3 => self.skill_en_lines_opt_0(&children[0], parse_tree),
+            4 => self.skill_en_lines_opt_1(parse_tree),
+            5 => self.sentence(&children[0], &children[1], &children[2], parse_tree),
+            6 => self.sentence_opt0_0(&children[0], parse_tree),
+            7 => self.sentence_opt0_1(parse_tree),
+            8 => self.sentence_opt_0(&children[0], parse_tree),
+            9 => self.sentence_opt_1(parse_tree),
+            10 => self.turns_prefix(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            11 => self.turns_suffix(&children[0], &children[1], &children[2], parse_tree),
+            12 => self.effect_0(&children[0], parse_tree),
+            13 => self.effect_1(&children[0], parse_tree),
+            14 => self.effect_2(&children[0], parse_tree),
+            15 => self.effect_3(&children[0], parse_tree),
+            16 => self.effect_4(&children[0], parse_tree),
+            17 => self.effect_5(&children[0], parse_tree),
+            18 => self.effect_6(&children[0], parse_tree),
+            19 => self.effect_7(&children[0], parse_tree),
+            20 => self.effect_8(&children[0], parse_tree),
+            21 => self.effect_9(&children[0], parse_tree),
+            22 => self.effect_10(&children[0], parse_tree),
+            23 => self.effect_11(&children[0], parse_tree),
+            24 => self.effect_12(&children[0], parse_tree),
+            25 => self.effect_13(&children[0], parse_tree),
+            26 => self.effect_14(&children[0], parse_tree),
+            27 => self.change_stmt(&children[0], &children[1], parse_tree),
+            28 => self.change_target_0(&children[0], &children[1], parse_tree),
+            29 => self.change_target_1(&children[0], parse_tree),
+            30 => self.change_target_2(&children[0], &children[1], parse_tree),
+            31 => self.change_all_target_0(&children[0], parse_tree),
+            32 => self.change_all_target_1(&children[0], parse_tree),
+            33 => {
+                self.change_all_of_board_stmt(&children[0], &children[1], &children[2], parse_tree)
+            }
+            34 => self.enemy_attribute_change_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            35 => self.change_drop_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            36 => self.change_drop_stmt_opt_0(&children[0], &children[1], parse_tree),
+            37 => self.change_drop_stmt_opt_1(parse_tree),
+            38 => self.change_the_target_0(&children[0], parse_tree),
+            39 => self.change_the_target_1(&children[0], parse_tree),
+            40 => self.change_the_target_2(&children[0], parse_tree),
+            41 => self.change_the_target_3(&children[0], parse_tree),
+            42 => self.shape_row_col_stmt(&children[0], &children[1], parse_tree),
+            43 => self.shape_row_col_stmt_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            44 => self.shape_row_col_stmt_list_1(parse_tree),
+            45 => self.row_col_block(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            46 => self.row_col_block_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            47 => self.row_col_block_list_1(parse_tree),
+            48 => self.row_col_sep_0(&children[0], parse_tree),
+            49 => self.row_col_sep_1(&children[0], parse_tree),
+            50 => self.row_col_position_0(&children[0], &children[1], parse_tree),
+            51 => self.row_col_position_1(&children[0], &children[1], parse_tree),
+            52 => self.row_col_position_2(&children[0], &children[1], parse_tree),
+            53 => self.row_col_position_3(&children[0], &children[1], parse_tree),
+            54 => self.row_col_position_4(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            55 => self.row_or_column_0(&children[0], parse_tree),
+            56 => self.row_or_column_1(&children[0], parse_tree),
+            57 => self.direction_0(&children[0], parse_tree),
+            58 => self.direction_1(&children[0], parse_tree),
+            59 => self.direction_2(&children[0], parse_tree),
+            60 => self.direction_3(&children[0], parse_tree),
+            61 => self.shape_of_board_perimeter_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                parse_tree,
+            ),
+            62 => self.shape_of_board_center_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                parse_tree,
+            ),
+            63 => self.change_board_size_stmt(&children[0], &children[1], &children[2], parse_tree),
+            64 => self.create_stmt(&children[0], &children[1], parse_tree),
+            65 => self.create_target_0(&children[0], &children[1], parse_tree),
+            66 => self.create_target_1(&children[0], &children[1], parse_tree),
+            67 => self.create_qty_target_0(&children[0], parse_tree),
+            68 => self.create_qty_target_1(&children[0], parse_tree),
+            69 => self.create_qty_target_2(&children[0], parse_tree),
+            70 => self.create_qty_target_3(&children[0], parse_tree),
+            71 => self.create_qty_target_4(&children[0], parse_tree),
+            72 => self.create_qty_target_5(&children[0], parse_tree),
+            73 => self.create_qty_target_6(&children[0], parse_tree),
+            74 => self.gen_random_drop_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            75 => self.gen_random_drop_stmt_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                parse_tree,
+            ),
+            76 => self.gen_random_drop_stmt_list_1(parse_tree),
+            77 => self.qty_drop_name(&children[0], parse_tree),
+            78 => self.qty_drop(&children[0], &children[1], parse_tree),
+            79 => self.qty_sep_0(&children[0], parse_tree),
+            80 => self.qty_sep_1(&children[0], parse_tree),
+            81 => self.gen_each_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            82 => self.gen_source_0(&children[0], &children[1], parse_tree),
+            83 => self.gen_source_1(&children[0], &children[1], parse_tree),
+            84 => self.gen_source_2(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                parse_tree,
+            ),
+            85 => self.gen_source_at_0(&children[0], parse_tree),
+            86 => self.gen_source_at_1(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            87 => self.top_or_bottom_0(&children[0], parse_tree),
+            88 => self.top_or_bottom_1(&children[0], parse_tree),
+            89 => self.non_drops(&children[0], &children[1], &children[2], parse_tree),
+            90 => self.non_drops_list_0(&children[0], &children[1], &children[2], parse_tree),
+            91 => self.non_drops_list_1(parse_tree),
+            92 => self.non_drop(&children[0], &children[1], parse_tree),
+            93 => self.l_shape_stmt(&children[0], &children[1], &children[2], parse_tree),
+            94 => self.small_cross_stmt(&children[0], &children[1], &children[2], parse_tree),
+            95 => self.square_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            96 => self.some_kind_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            97 => self.gen_roulette_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            98 => self.create_a_target_0(&children[0], parse_tree),
+            99 => self.create_a_target_1(&children[0], parse_tree),
+            100 => self.create_a_target_2(&children[0], parse_tree),
+            101 => self.create_a_target_3(&children[0], parse_tree),
+            102 => self.create_a_target_4(&children[0], parse_tree),
+            103 => self.create_a_target_5(&children[0], parse_tree),
+            104 => self.z_shape_stmt(&children[0], &children[1], &children[2], parse_tree),
+            105 => self.cross_shape_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                parse_tree,
+            ),
+            106 => self.spiderweb_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                &children[7],
+                parse_tree,
+            ),
+            107 => self.crescent_moon_stmt(&children[0], &children[1], &children[2], parse_tree),
+            108 => self.oblique_stmt(&children[0], &children[1], &children[2], parse_tree),
+            109 => self.gen_cloud_stmt(&children[0], &children[1], &children[2], parse_tree),
+            110 => self.unlock_stmt(&children[0], &children[1], &children[2], parse_tree),
+            111 => self.lock_stmt(&children[0], &children[1], parse_tree),
+            112 => self.lock_target_0(&children[0], &children[1], parse_tree),
+            113 => self.lock_target_1(&children[0], parse_tree),
+            114 => self.replace_stmt(&children[0], &children[1], &children[2], parse_tree),
+            115 => self.enhance_stmt(&children[0], &children[1], parse_tree),
+            116 => self.enhance_target_0(&children[0], &children[1], parse_tree),
+            117 => self.enhance_target_1(&children[0], parse_tree),
+            118 => self.orbs_stmt(&children[0], &children[1], parse_tree),
+            119 => self.orbs_stmt_suffix_0(&children[0], parse_tree),
+            120 => self.orbs_stmt_suffix_1(&children[0], &children[1], parse_tree),
+            121 => self.all_orbs_locked_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            122 => self.only_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            123 => self.only_target_0(&children[0], parse_tree),
+            124 => self.only_target_1(&children[0], &children[1], parse_tree),
+            125 => self.only_target_2(&children[0], &children[1], parse_tree),
+            126 => self.enhanced_orbs_stmt(&children[0], &children[1], &children[2], parse_tree),
+            127 => self.enhanced_orbs_suffix_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                parse_tree,
+            ),
+            128 => self.enhanced_orbs_suffix_1(&children[0], parse_tree),
+            129 => self.nail_orbs_stmt(&children[0], &children[1], &children[2], parse_tree),
+            130 => self.more_likely(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                parse_tree,
+            ),
+            131 => self.more_likely_opt_0(&children[0], parse_tree),
+            132 => self.more_likely_opt_1(parse_tree),
+            133 => self.volume_0(&children[0], parse_tree),
+            134 => self.volume_1(&children[0], parse_tree),
+            135 => self.no_skyfall_stmt(&children[0], &children[1], &children[2], parse_tree),
+            136 => self.tape_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            137 => self.tape_target_0(&children[0], parse_tree),
+            138 => self.tape_target_1(&children[0], parse_tree),
+            139 => self.board_position_0(&children[0], &children[1], parse_tree),
+            140 => self.board_position_1(&children[0], &children[1], &children[2], parse_tree),
+            141 => self.void_stmt(&children[0], &children[1], &children[2], parse_tree),
+            142 => self.void_stmt_list_0(&children[0], &children[1], &children[2], parse_tree),
+            143 => self.void_stmt_list_1(parse_tree),
+            144 => self.void_sep_0(&children[0], parse_tree),
+            145 => self.void_sep_1(&children[0], parse_tree),
+            146 => self.void_target_0(&children[0], &children[1], &children[2], parse_tree),
+            147 => self.void_target_1(&children[0], &children[1], parse_tree),
+            148 => self.void_target_opt_0(&children[0], &children[1], parse_tree),
+            149 => self.void_target_opt_1(parse_tree),
+            150 => self.damage_target_0(&children[0], parse_tree),
+            151 => self.damage_target_1(&children[0], parse_tree),
+            152 => self.pierce_stmt(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            153 => self.drops(&children[0], &children[1], &children[2], parse_tree),
+            154 => self.drops_list_0(&children[0], &children[1], &children[2], parse_tree),
+            155 => self.drops_list_1(parse_tree),
+            156 => self.drop_sep_0(&children[0], parse_tree),
+            157 => self.drop_sep_1(&children[0], parse_tree),
+            158 => self.drop_name_0(&children[0], parse_tree),
+            159 => self.drop_name_1(&children[0], parse_tree),
+            160 => self.color_0(&children[0], parse_tree),
+            161 => self.color_1(&children[0], parse_tree),
+            162 => self.color_2(&children[0], parse_tree),
+            163 => self.color_3(&children[0], parse_tree),
+            164 => self.color_4(&children[0], parse_tree),
+            165 => self.non_colored_drop_0(&children[0], parse_tree),
+            166 => self.non_colored_drop_1(&children[0], parse_tree),
+            167 => self.non_colored_drop_2(&children[0], parse_tree),
+            168 => self.non_colored_drop_3(&children[0], parse_tree),
+            169 => self.non_colored_drop_4(&children[0], parse_tree),
+            170 => self.size(&children[0], &children[1], &children[2], parse_tree),
+            171 => self.fire(&children[0], parse_tree),
+            172 => self.water(&children[0], parse_tree),
+            173 => self.wood(&children[0], parse_tree),
+            174 => self.lightning(&children[0], parse_tree),
+            175 => self.dark(&children[0], parse_tree),
+            176 => self.recovery(&children[0], parse_tree),
+            177 => self.disturb(&children[0], parse_tree),
+            178 => self.bomb(&children[0], parse_tree),
+            179 => self.deadly_poison(&children[0], parse_tree),
+            180 => self.poison(&children[0], parse_tree),
+            181 => self.word_for(&children[0], parse_tree),
+            182 => self.word_turn(&children[0], parse_tree),
+            183 => self.word_change(&children[0], parse_tree),
+            184 => self.word_create(&children[0], parse_tree),
+            185 => self.word_unlock(&children[0], parse_tree),
+            186 => self.word_lock(&children[0], parse_tree),
+            187 => self.word_replace(&children[0], parse_tree),
+            188 => self.word_enhance(&children[0], parse_tree),
+            189 => self.word_enhanced(&children[0], parse_tree),
+            190 => self.word_all(&children[0], parse_tree),
+            191 => self.word_only(&children[0], parse_tree),
+            192 => self.word_nail(&children[0], parse_tree),
+            193 => self.word_no(&children[0], parse_tree),
+            194 => self.word_orbs(&children[0], parse_tree),
+            195 => self.word_void(&children[0], parse_tree),
+            196 => self.word_pierce(&children[0], parse_tree),
+            197 => self.word_through(&children[0], parse_tree),
+            198 => self.word_enemies(&children[0], parse_tree),
+            199 => self.word_attribute(&children[0], parse_tree),
+            200 => self.word_random(&children[0], parse_tree),
+            201 => self.word_leftmost(&children[0], parse_tree),
+            202 => self.word_rightmost(&children[0], parse_tree),
+            203 => self.word_left(&children[0], parse_tree),
+            204 => self.word_right(&children[0], parse_tree),
+            205 => self.word_top(&children[0], parse_tree),
+            206 => self.word_bottom(&children[0], parse_tree),
+            207 => self.word_column(&children[0], parse_tree),
+            208 => self.word_row(&children[0], parse_tree),
+            209 => self.word_outer(&children[0], parse_tree),
+            210 => self.word_edges(&children[0], parse_tree),
+            211 => self.word_center(&children[0], parse_tree),
+            212 => self.word_board(&children[0], parse_tree),
+            213 => self.word_each(&children[0], parse_tree),
+            214 => self.word_corner(&children[0], parse_tree),
+            215 => self.word_non(&children[0], parse_tree),
+            216 => self.word_l_shape(&children[0], parse_tree),
+            217 => self.word_z_shape(&children[0], parse_tree),
+            218 => self.word_cross(&children[0], parse_tree),
+            219 => self.word_large(&children[0], parse_tree),
+            220 => self.word_across(&children[0], parse_tree),
+            221 => self.word_square(&children[0], parse_tree),
+            222 => self.word_shape(&children[0], parse_tree),
+            223 => self.word_roulette(&children[0], parse_tree),
+            224 => self.word_spiderweb(&children[0], parse_tree),
+            225 => self.word_with(&children[0], parse_tree),
+            226 => self.word_its(&children[0], parse_tree),
+            227 => self.word_crescent_moon(&children[0], parse_tree),
+            228 => self.word_diagonal_line(&children[0], parse_tree),
+            229 => self.word_cloud(&children[0], parse_tree),
+            230 => self.word_appear(&children[0], parse_tree),
+            231 => self.word_locked(&children[0], parse_tree),
+            232 => self.word_will(&children[0], parse_tree),
+            233 => self.word_have(&children[0], parse_tree),
+            234 => self.word_chance(&children[0], parse_tree),
+            235 => self.word_more(&children[0], parse_tree),
+            236 => self.word_likely(&children[0], parse_tree),
+            237 => self.word_very_slightly(&children[0], parse_tree),
+            238 => self.word_slightly(&children[0], parse_tree),
+            239 => self.word_skyfall(&children[0], parse_tree),
+            240 => self.word_combos(&children[0], parse_tree),
+            241 => self.word_becomes(&children[0], parse_tree),
+            242 => self.word_unmovable(&children[0], parse_tree),
+            243 => self.word_damage(&children[0], parse_tree),
+            244 => self.word_absorption(&children[0], parse_tree),
+            245 => self.a(&children[0], parse_tree),
+            246 => self.and(&children[0], parse_tree),
+            247 => self.are(&children[0], parse_tree),
+            248 => self.at(&children[0], parse_tree),
+            249 => self.from(&children[0], parse_tree),
+            250 => self.r#in(&children[0], parse_tree),
+            251 => self.of(&children[0], parse_tree),
+            252 => self.the(&children[0], parse_tree),
+            253 => self.to(&children[0], parse_tree),
+            254 => self.camma(&children[0], parse_tree),
+            255 => self.semicolon(&children[0], parse_tree),
+            256 => self.period(&children[0], parse_tree),
+            257 => self.percent(&children[0], parse_tree),
+            258 => self.multi(&children[0], parse_tree),
+            259 => self.ordinal(&children[0], parse_tree),
+            260 => self.pos_int(&children[0], parse_tree),
+            _ => Err(miette!("Unhandled production number: {}", prod_num)),
+        }
+    }
+}
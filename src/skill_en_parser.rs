@@ -0,0 +1,3386 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// It is not intended for manual editing and changes will be
+// lost after next build.
+// ---------------------------------------------------------
+
+use parol_runtime::id_tree::Tree;
+use parol_runtime::lexer::{TokenStream, Tokenizer};
+use parol_runtime::miette::Result;
+use parol_runtime::once_cell::sync::Lazy;
+#[allow(unused_imports)]
+use parol_runtime::parser::{
+    DFATransition, LLKParser, LookaheadDFA, ParseTreeType, ParseType, Production,
+};
+use std::cell::RefCell;
+use std::path::Path;
+
+use crate::skill_en_grammar::SkillEnGrammar;
+use crate::skill_en_grammar_trait::SkillEnGrammarAuto;
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+
+pub const TERMINALS: &[&str; 96] = &[
+    /*  0 */ UNMATCHABLE_TOKEN,
+    /*  1 */ UNMATCHABLE_TOKEN,
+    /*  2 */ UNMATCHABLE_TOKEN,
+    /*  3 */ UNMATCHABLE_TOKEN,
+    /*  4 */ UNMATCHABLE_TOKEN,
+    /*  5 */ r###"Fire\b"###,
+    /*  6 */ r###"Water\b"###,
+    /*  7 */ r###"Wood\b"###,
+    /*  8 */ r###"Light\b"###,
+    /*  9 */ r###"Dark\b"###,
+    /* 10 */ r###"Heal\b"###,
+    /* 11 */ r###"Jammer\b"###,
+    /* 12 */ r###"Bomb\b"###,
+    /* 13 */ r###"Mortal Poison\b"###,
+    /* 14 */ r###"Poison\b"###,
+    /* 15 */ r###"[Ff]or\b"###,
+    /* 16 */ r###"turns?\b"###,
+    /* 17 */ r###"[Cc]hange\b"###,
+    /* 18 */ r###"[Cc]reate\b"###,
+    /* 19 */ r###"[Uu]nlock\b"###,
+    /* 20 */ r###"[Ll]ock\b"###,
+    /* 21 */ r###"[Rr]eplace\b"###,
+    /* 22 */ r###"[Ee]nhance\b"###,
+    /* 23 */ r###"[Ee]nhanced\b"###,
+    /* 24 */ r###"[Aa]ll\b"###,
+    /* 25 */ r###"[Oo]nly\b"###,
+    /* 26 */ r###"[Nn]ail\b"###,
+    /* 27 */ r###"[Nn]o\b"###,
+    /* 28 */ r###"[Oo]rbs?\b"###,
+    /* 29 */ r###"[Vv]oids?\b"###,
+    /* 30 */ r###"[Pp]ierces?\b"###,
+    /* 31 */ r###"through\b"###,
+    /* 32 */ r###"enemies\b"###,
+    /* 33 */ r###"attribute\b"###,
+    /* 34 */ r###"random\b"###,
+    /* 35 */ r###"leftmost\b"###,
+    /* 36 */ r###"rightmost\b"###,
+    /* 37 */ r###"left\b"###,
+    /* 38 */ r###"right\b"###,
+    /* 39 */ r###"top\b"###,
+    /* 40 */ r###"bottom\b"###,
+    /* 41 */ r###"column\b"###,
+    /* 42 */ r###"row\b"###,
+    /* 43 */ r###"outer\b"###,
+    /* 44 */ r###"edges\b"###,
+    /* 45 */ r###"center\b"###,
+    /* 46 */ r###"board\b"###,
+    /* 47 */ r###"each\b"###,
+    /* 48 */ r###"corner\b"###,
+    /* 49 */ r###"non-"###,
+    /* 50 */ r###"L shapes?\b"###,
+    /* 51 */ r###"Z shape\b"###,
+    /* 52 */ r###"cross(es)?\b"###,
+    /* 53 */ r###"large\b"###,
+    /* 54 */ r###"across\b"###,
+    /* 55 */ r###"squares?\b"###,
+    /* 56 */ r###"shapes?\b"###,
+    /* 57 */ r###"roulette\b"###,
+    /* 58 */ r###"spiderweb\b"###,
+    /* 59 */ r###"with\b"###,
+    /* 60 */ r###"its\b"###,
+    /* 61 */ r###"crescent moon\b"###,
+    /* 62 */ r###"diagonal line\b"###,
+    /* 63 */ r###"cloud\b"###,
+    /* 64 */ r###"appear\b"###,
+    /* 65 */ r###"locked\b"###,
+    /* 66 */ r###"will\b"###,
+    /* 67 */ r###"have\b"###,
+    /* 68 */ r###"chance\b"###,
+    /* 69 */ r###"more\b"###,
+    /* 70 */ r###"likely\b"###,
+    /* 71 */ r###"very slightly\b"###,
+    /* 72 */ r###"slightly\b"###,
+    /* 73 */ r###"skyfall\b"###,
+    /* 74 */ r###"combos\b"###,
+    /* 75 */ r###"becomes\b"###,
+    /* 76 */ r###"unmovable\b"###,
+    /* 77 */ r###"damage\b"###,
+    /* 78 */ r###"absorption\b"###,
+    /* 79 */ r###"a\b"###,
+    /* 80 */ r###"and\b"###,
+    /* 81 */ r###"are\b"###,
+    /* 82 */ r###"at\b"###,
+    /* 83 */ r###"from\b"###,
+    /* 84 */ r###"in\b"###,
+    /* 85 */ r###"of\b"###,
+    /* 86 */ r###"[Tt]he\b"###,
+    /* 87 */ r###"to\b"###,
+    /* 88 */ r###","###,
+    /* 89 */ r###";"###,
+    /* 90 */ r###"\."###,
+    /* 91 */ r###"%"###,
+    /* 92 */ r###"x"###,
+    /* 93 */ r###"[1-9][0-9]*(st|nd|rd|th)\b"###,
+    /* 94 */ r###"[1-9][0-9]*"###,
+    /* 95 */ ERROR_TOKEN,
+];
+
+pub const TERMINAL_NAMES: &[&str; 96] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Fire",
+    /*  6 */ "Water",
+    /*  7 */ "Wood",
+    /*  8 */ "Lightning",
+    /*  9 */ "Dark",
+    /* 10 */ "Recovery",
+    /* 11 */ "Disturb",
+    /* 12 */ "Bomb",
+    /* 13 */ "DeadlyPoison",
+    /* 14 */ "Poison",
+    /* 15 */ "WordFor",
+    /* 16 */ "WordTurn",
+    /* 17 */ "WordChange",
+    /* 18 */ "WordCreate",
+    /* 19 */ "WordUnlock",
+    /* 20 */ "WordLock",
+    /* 21 */ "WordReplace",
+    /* 22 */ "WordEnhance",
+    /* 23 */ "WordEnhanced",
+    /* 24 */ "WordAll",
+    /* 25 */ "WordOnly",
+    /* 26 */ "WordNail",
+    /* 27 */ "WordNo",
+    /* 28 */ "WordOrbs",
+    /* 29 */ "WordVoid",
+    /* 30 */ "WordPierce",
+    /* 31 */ "WordThrough",
+    /* 32 */ "WordEnemies",
+    /* 33 */ "WordAttribute",
+    /* 34 */ "WordRandom",
+    /* 35 */ "WordLeftmost",
+    /* 36 */ "WordRightmost",
+    /* 37 */ "WordLeft",
+    /* 38 */ "WordRight",
+    /* 39 */ "WordTop",
+    /* 40 */ "WordBottom",
+    /* 41 */ "WordColumn",
+    /* 42 */ "WordRow",
+    /* 43 */ "WordOuter",
+    /* 44 */ "WordEdges",
+    /* 45 */ "WordCenter",
+    /* 46 */ "WordBoard",
+    /* 47 */ "WordEach",
+    /* 48 */ "WordCorner",
+    /* 49 */ "WordNon",
+    /* 50 */ "WordLShape",
+    /* 51 */ "WordZShape",
+    /* 52 */ "WordCross",
+    /* 53 */ "WordLarge",
+    /* 54 */ "WordAcross",
+    /* 55 */ "WordSquare",
+    /* 56 */ "WordShape",
+    /* 57 */ "WordRoulette",
+    /* 58 */ "WordSpiderweb",
+    /* 59 */ "WordWith",
+    /* 60 */ "WordIts",
+    /* 61 */ "WordCrescentMoon",
+    /* 62 */ "WordDiagonalLine",
+    /* 63 */ "WordCloud",
+    /* 64 */ "WordAppear",
+    /* 65 */ "WordLocked",
+    /* 66 */ "WordWill",
+    /* 67 */ "WordHave",
+    /* 68 */ "WordChance",
+    /* 69 */ "WordMore",
+    /* 70 */ "WordLikely",
+    /* 71 */ "WordVerySlightly",
+    /* 72 */ "WordSlightly",
+    /* 73 */ "WordSkyfall",
+    /* 74 */ "WordCombos",
+    /* 75 */ "WordBecomes",
+    /* 76 */ "WordUnmovable",
+    /* 77 */ "WordDamage",
+    /* 78 */ "WordAbsorption",
+    /* 79 */ "A",
+    /* 80 */ "And",
+    /* 81 */ "Are",
+    /* 82 */ "At",
+    /* 83 */ "From",
+    /* 84 */ "In",
+    /* 85 */ "Of",
+    /* 86 */ "The",
+    /* 87 */ "To",
+    /* 88 */ "Camma",
+    /* 89 */ "Semicolon",
+    /* 90 */ "Dot",
+    /* 91 */ "Percent",
+    /* 92 */ "Multi",
+    /* 93 */ "Ordinal",
+    /* 94 */ "PosInt",
+    /* 95 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[usize; 90]) = (
+    &[
+        /*  0 */ UNMATCHABLE_TOKEN,
+        /*  1 */ NEW_LINE_TOKEN,
+        /*  2 */ WHITESPACE_TOKEN,
+        /*  3 */ UNMATCHABLE_TOKEN,
+        /*  4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[
+        5,  /* Fire */
+        6,  /* Water */
+        7,  /* Wood */
+        8,  /* Lightning */
+        9,  /* Dark */
+        10, /* Recovery */
+        11, /* Disturb */
+        12, /* Bomb */
+        13, /* DeadlyPoison */
+        14, /* Poison */
+        15, /* WordFor */
+        16, /* WordTurn */
+        17, /* WordChange */
+        18, /* WordCreate */
+        19, /* WordUnlock */
+        20, /* WordLock */
+        21, /* WordReplace */
+        22, /* WordEnhance */
+        23, /* WordEnhanced */
+        24, /* WordAll */
+        25, /* WordOnly */
+        26, /* WordNail */
+        27, /* WordNo */
+        28, /* WordOrbs */
+        29, /* WordVoid */
+        30, /* WordPierce */
+        31, /* WordThrough */
+        32, /* WordEnemies */
+        33, /* WordAttribute */
+        34, /* WordRandom */
+        35, /* WordLeftmost */
+        36, /* WordRightmost */
+        37, /* WordLeft */
+        38, /* WordRight */
+        39, /* WordTop */
+        40, /* WordBottom */
+        41, /* WordColumn */
+        42, /* WordRow */
+        43, /* WordOuter */
+        44, /* WordEdges */
+        45, /* WordCenter */
+        46, /* WordBoard */
+        47, /* WordEach */
+        48, /* WordCorner */
+        49, /* WordNon */
+        50, /* WordLShape */
+        51, /* WordZShape */
+        52, /* WordCross */
+        53, /* WordLarge */
+        54, /* WordAcross */
+        55, /* WordSquare */
+        56, /* WordShape */
+        57, /* WordRoulette */
+        58, /* WordSpiderweb */
+        59, /* WordWith */
+        60, /* WordIts */
+        61, /* WordCrescentMoon */
+        62, /* WordDiagonalLine */
+        63, /* WordCloud */
+        64, /* WordAppear */
+        65, /* WordLocked */
+        66, /* WordWill */
+        67, /* WordHave */
+        68, /* WordChance */
+        69, /* WordMore */
+        70, /* WordLikely */
+        71, /* WordVerySlightly */
+        72, /* WordSlightly */
+        73, /* WordSkyfall */
+        74, /* WordCombos */
+        75, /* WordBecomes */
+        76, /* WordUnmovable */
+        77, /* WordDamage */
+        78, /* WordAbsorption */
+        79, /* A */
+        80, /* And */
+        81, /* Are */
+        82, /* At */
+        83, /* From */
+        84, /* In */
+        85, /* Of */
+        86, /* The */
+        87, /* To */
+        88, /* Camma */
+        89, /* Semicolon */
+        90, /* Dot */
+        91, /* Percent */
+        92, /* Multi */
+        93, /* Ordinal */
+        94, /* PosInt */
+    ],
+);
+
+const MAX_K: usize = 2;
+
+pub const NON_TERMINALS: &[&str; 180] = &[
+    /*   0 */ "A",
+    /*   1 */ "AllOrbsLockedStmt",
+    /*   2 */ "And",
+    /*   3 */ "Are",
+    /*   4 */ "At",
+    /*   5 */ "BoardPosition",
+    /*   6 */ "Bomb",
+    /*   7 */ "Camma",
+    /*   8 */ "ChangeAllOfBoardStmt",
+    /*   9 */ "ChangeAllTarget",
+    /*  10 */ "ChangeBoardSizeStmt",
+    /*  11 */ "ChangeDropStmt",
+    /*  12 */ "ChangeDropStmtOpt",
+    /*  13 */ "ChangeStmt",
+    /*  14 */ "ChangeTarget",
+    /*  15 */ "ChangeTheTarget",
+    /*  16 */ "Color",
+    /*  17 */ "CreateATarget",
+    /*  18 */ "CreateQtyTarget",
+    /*  19 */ "CreateStmt",
+    /*  20 */ "CreateTarget",
+    /*  21 */ "CrescentMoonStmt",
+    /*  22 */ "CrossShapeStmt",
+    /*  23 */ "DamageTarget",
+    /*  24 */ "Dark",
+    /*  25 */ "DeadlyPoison",
+    /*  26 */ "Direction",
+    /*  27 */ "Disturb",
+    /*  28 */ "DropName",
+    /*  29 */ "DropSep",
+    /*  30 */ "Drops",
+    /*  31 */ "DropsList",
+    /*  32 */ "Effect",
+    /*  33 */ "EnemyAttributeChangeStmt",
+    /*  34 */ "EnhanceStmt",
+    /*  35 */ "EnhanceTarget",
+    /*  36 */ "EnhancedOrbsStmt",
+    /*  37 */ "EnhancedOrbsSuffix",
+    /*  38 */ "Fire",
+    /*  39 */ "From",
+    /*  40 */ "GenCloudStmt",
+    /*  41 */ "GenEachStmt",
+    /*  42 */ "GenRandomDropStmt",
+    /*  43 */ "GenRandomDropStmtList",
+    /*  44 */ "GenRouletteStmt",
+    /*  45 */ "GenSource",
+    /*  46 */ "GenSourceAt",
+    /*  47 */ "In",
+    /*  48 */ "LShapeStmt",
+    /*  49 */ "Lightning",
+    /*  50 */ "LockStmt",
+    /*  51 */ "LockTarget",
+    /*  52 */ "MoreLikely",
+    /*  53 */ "MoreLikelyOpt",
+    /*  54 */ "Multi",
+    /*  55 */ "NailOrbsStmt",
+    /*  56 */ "NoSkyfallStmt",
+    /*  57 */ "NonColoredDrop",
+    /*  58 */ "NonDrop",
+    /*  59 */ "NonDrops",
+    /*  60 */ "NonDropsList",
+    /*  61 */ "ObliqueStmt",
+    /*  62 */ "Of",
+    /*  63 */ "OnlyStmt",
+    /*  64 */ "OnlyTarget",
+    /*  65 */ "OrbsStmt",
+    /*  66 */ "OrbsStmtSuffix",
+    /*  67 */ "Ordinal",
+    /*  68 */ "Percent",
+    /*  69 */ "Period",
+    /*  70 */ "PierceStmt",
+    /*  71 */ "Poison",
+    /*  72 */ "PosInt",
+    /*  73 */ "QtyDrop",
+    /*  74 */ "QtyDropName",
+    /*  75 */ "QtySep",
+    /*  76 */ "Recovery",
+    /*  77 */ "ReplaceStmt",
+    /*  78 */ "RowColBlock",
+    /*  79 */ "RowColBlockList",
+    /*  80 */ "RowColPosition",
+    /*  81 */ "RowColSep",
+    /*  82 */ "RowOrColumn",
+    /*  83 */ "Semicolon",
+    /*  84 */ "Sentence",
+    /*  85 */ "SentenceOpt",
+    /*  86 */ "SentenceOpt0",
+    /*  87 */ "ShapeOfBoardCenterStmt",
+    /*  88 */ "ShapeOfBoardPerimeterStmt",
+    /*  89 */ "ShapeRowColStmt",
+    /*  90 */ "ShapeRowColStmtList",
+    /*  91 */ "Size",
+    /*  92 */ "SkillEnLines",
+    /*  93 */ "SkillEnLinesList",
+    /*  94 */ "SkillEnLinesOpt",
+    /*  95 */ "SmallCrossStmt",
+    /*  96 */ "SomeKindStmt",
+    /*  97 */ "SpiderwebStmt",
+    /*  98 */ "SquareStmt",
+    /*  99 */ "TapeStmt",
+    /* 100 */ "TapeTarget",
+    /* 101 */ "The",
+    /* 102 */ "To",
+    /* 103 */ "TopOrBottom",
+    /* 104 */ "TurnsPrefix",
+    /* 105 */ "TurnsSuffix",
+    /* 106 */ "UnlockStmt",
+    /* 107 */ "VoidSep",
+    /* 108 */ "VoidStmt",
+    /* 109 */ "VoidStmtList",
+    /* 110 */ "VoidTarget",
+    /* 111 */ "VoidTargetOpt",
+    /* 112 */ "Volume",
+    /* 113 */ "Water",
+    /* 114 */ "Wood",
+    /* 115 */ "WordAbsorption",
+    /* 116 */ "WordAcross",
+    /* 117 */ "WordAll",
+    /* 118 */ "WordAppear",
+    /* 119 */ "WordAttribute",
+    /* 120 */ "WordBecomes",
+    /* 121 */ "WordBoard",
+    /* 122 */ "WordBottom",
+    /* 123 */ "WordCenter",
+    /* 124 */ "WordChance",
+    /* 125 */ "WordChange",
+    /* 126 */ "WordCloud",
+    /* 127 */ "WordColumn",
+    /* 128 */ "WordCombos",
+    /* 129 */ "WordCorner",
+    /* 130 */ "WordCreate",
+    /* 131 */ "WordCrescentMoon",
+    /* 132 */ "WordCross",
+    /* 133 */ "WordDamage",
+    /* 134 */ "WordDiagonalLine",
+    /* 135 */ "WordEach",
+    /* 136 */ "WordEdges",
+    /* 137 */ "WordEnemies",
+    /* 138 */ "WordEnhance",
+    /* 139 */ "WordEnhanced",
+    /* 140 */ "WordFor",
+    /* 141 */ "WordHave",
+    /* 142 */ "WordIts",
+    /* 143 */ "WordLShape",
+    /* 144 */ "WordLarge",
+    /* 145 */ "WordLeft",
+    /* 146 */ "WordLeftmost",
+    /* 147 */ "WordLikely",
+    /* 148 */ "WordLock",
+    /* 149 */ "WordLocked",
+    /* 150 */ "WordMore",
+    /* 151 */ "WordNail",
+    /* 152 */ "WordNo",
+    /* 153 */ "WordNon",
+    /* 154 */ "WordOnly",
+    /* 155 */ "WordOrbs",
+    /* 156 */ "WordOuter",
+    /* 157 */ "WordPierce",
+    /* 158 */ "WordRandom",
+    /* 159 */ "WordReplace",
+    /* 160 */ "WordRight",
+    /* 161 */ "WordRightmost",
+    /* 162 */ "WordRoulette",
+    /* 163 */ "WordRow",
+    /* 164 */ "WordShape",
+    /* 165 */ "WordSkyfall",
+    /* 166 */ "WordSlightly",
+    /* 167 */ "WordSpiderweb",
+    /* 168 */ "WordSquare",
+    /* 169 */ "WordThrough",
+    /* 170 */ "WordTop",
+    /* 171 */ "WordTurn",
+    /* 172 */ "WordUnlock",
+    /* 173 */ "WordUnmovable",
+    /* 174 */ "WordVerySlightly",
+    /* 175 */ "WordVoid",
+    /* 176 */ "WordWill",
+    /* 177 */ "WordWith",
+    /* 178 */ "WordZShape",
+    /* 179 */ "ZShapeStmt",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 180] = &[
+    /* 0 - "A" */
+    LookaheadDFA {
+        states: &[Some(245)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "AllOrbsLockedStmt" */
+    LookaheadDFA {
+        states: &[Some(121)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "And" */
+    LookaheadDFA {
+        states: &[Some(246)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Are" */
+    LookaheadDFA {
+        states: &[Some(247)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "At" */
+    LookaheadDFA {
+        states: &[Some(248)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "BoardPosition" */
+    LookaheadDFA {
+        states: &[None, Some(139), Some(140)],
+        transitions: &[DFATransition(0, 82, 1), DFATransition(0, 84, 2)],
+        k: 1,
+    },
+    /* 6 - "Bomb" */
+    LookaheadDFA {
+        states: &[Some(178)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "Camma" */
+    LookaheadDFA {
+        states: &[Some(254)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "ChangeAllOfBoardStmt" */
+    LookaheadDFA {
+        states: &[Some(33)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "ChangeAllTarget" */
+    LookaheadDFA {
+        states: &[None, Some(31), Some(32)],
+        transitions: &[DFATransition(0, 28, 1), DFATransition(0, 32, 2)],
+        k: 1,
+    },
+    /* 10 - "ChangeBoardSizeStmt" */
+    LookaheadDFA {
+        states: &[Some(63)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 11 - "ChangeDropStmt" */
+    LookaheadDFA {
+        states: &[Some(35)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 12 - "ChangeDropStmtOpt" */
+    LookaheadDFA {
+        states: &[None, Some(36), Some(37)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 15, 2),
+            DFATransition(0, 82, 1),
+            DFATransition(0, 89, 2),
+            DFATransition(0, 90, 2),
+        ],
+        k: 1,
+    },
+    /* 13 - "ChangeStmt" */
+    LookaheadDFA {
+        states: &[Some(27)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 14 - "ChangeTarget" */
+    LookaheadDFA {
+        states: &[None, Some(28), Some(29), Some(30)],
+        transitions: &[
+            DFATransition(0, 5, 2),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 7, 2),
+            DFATransition(0, 8, 2),
+            DFATransition(0, 9, 2),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 24, 1),
+            DFATransition(0, 86, 3),
+        ],
+        k: 1,
+    },
+    /* 15 - "ChangeTheTarget" */
+    LookaheadDFA {
+        states: &[None, Some(38), Some(39), Some(40), Some(41)],
+        transitions: &[
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 43, 2),
+            DFATransition(0, 45, 3),
+            DFATransition(0, 46, 4),
+            DFATransition(0, 93, 1),
+        ],
+        k: 1,
+    },
+    /* 16 - "Color" */
+    LookaheadDFA {
+        states: &[None, Some(160), Some(161), Some(162), Some(163), Some(164)],
+        transitions: &[
+            DFATransition(0, 5, 1),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 7, 3),
+            DFATransition(0, 8, 4),
+            DFATransition(0, 9, 5),
+        ],
+        k: 1,
+    },
+    /* 17 - "CreateATarget" */
+    LookaheadDFA {
+        states: &[
+            None,
+            Some(98),
+            Some(99),
+            Some(100),
+            Some(101),
+            Some(102),
+            Some(103),
+        ],
+        transitions: &[
+            DFATransition(0, 51, 1),
+            DFATransition(0, 53, 2),
+            DFATransition(0, 58, 3),
+            DFATransition(0, 61, 4),
+            DFATransition(0, 62, 5),
+            DFATransition(0, 94, 6),
+        ],
+        k: 1,
+    },
+    /* 18 - "CreateQtyTarget" */
+    LookaheadDFA {
+        states: &[
+            None,
+            None,
+            Some(67),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(68),
+            None,
+            Some(69),
+            None,
+            Some(70),
+            None,
+            Some(71),
+            Some(72),
+            None,
+            Some(73),
+        ],
+        transitions: &[
+            DFATransition(0, 5, 1),
+            DFATransition(0, 6, 3),
+            DFATransition(0, 7, 4),
+            DFATransition(0, 8, 5),
+            DFATransition(0, 9, 6),
+            DFATransition(0, 10, 7),
+            DFATransition(0, 11, 8),
+            DFATransition(0, 12, 9),
+            DFATransition(0, 13, 10),
+            DFATransition(0, 14, 11),
+            DFATransition(0, 47, 12),
+            DFATransition(0, 50, 14),
+            DFATransition(0, 52, 16),
+            DFATransition(0, 57, 21),
+            DFATransition(0, 94, 18),
+            DFATransition(1, 28, 2),
+            DFATransition(1, 80, 2),
+            DFATransition(1, 88, 2),
+            DFATransition(3, 28, 2),
+            DFATransition(3, 80, 2),
+            DFATransition(3, 88, 2),
+            DFATransition(4, 28, 2),
+            DFATransition(4, 80, 2),
+            DFATransition(4, 88, 2),
+            DFATransition(5, 28, 2),
+            DFATransition(5, 80, 2),
+            DFATransition(5, 88, 2),
+            DFATransition(6, 28, 2),
+            DFATransition(6, 80, 2),
+            DFATransition(6, 88, 2),
+            DFATransition(7, 28, 2),
+            DFATransition(7, 80, 2),
+            DFATransition(7, 88, 2),
+            DFATransition(8, 28, 2),
+            DFATransition(8, 80, 2),
+            DFATransition(8, 88, 2),
+            DFATransition(9, 28, 2),
+            DFATransition(9, 80, 2),
+            DFATransition(9, 88, 2),
+            DFATransition(10, 28, 2),
+            DFATransition(10, 80, 2),
+            DFATransition(10, 88, 2),
+            DFATransition(11, 28, 2),
+            DFATransition(11, 80, 2),
+            DFATransition(11, 88, 2),
+            DFATransition(12, 85, 13),
+            DFATransition(14, 85, 15),
+            DFATransition(16, 85, 17),
+            DFATransition(18, 56, 20),
+            DFATransition(18, 92, 19),
+            DFATransition(21, 28, 22),
+        ],
+        k: 2,
+    },
+    /* 19 - "CreateStmt" */
+    LookaheadDFA {
+        states: &[Some(64)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 20 - "CreateTarget" */
+    LookaheadDFA {
+        states: &[None, Some(65), Some(66)],
+        transitions: &[DFATransition(0, 79, 2), DFATransition(0, 94, 1)],
+        k: 1,
+    },
+    /* 21 - "CrescentMoonStmt" */
+    LookaheadDFA {
+        states: &[Some(107)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "CrossShapeStmt" */
+    LookaheadDFA {
+        states: &[Some(105)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 23 - "DamageTarget" */
+    LookaheadDFA {
+        states: &[None, Some(150), Some(151)],
+        transitions: &[DFATransition(0, 29, 2), DFATransition(0, 78, 1)],
+        k: 1,
+    },
+    /* 24 - "Dark" */
+    LookaheadDFA {
+        states: &[Some(175)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 25 - "DeadlyPoison" */
+    LookaheadDFA {
+        states: &[Some(179)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 26 - "Direction" */
+    LookaheadDFA {
+        states: &[None, Some(57), Some(58), Some(59), Some(60)],
+        transitions: &[
+            DFATransition(0, 37, 1),
+            DFATransition(0, 38, 2),
+            DFATransition(0, 39, 3),
+            DFATransition(0, 40, 4),
+        ],
+        k: 1,
+    },
+    /* 27 - "Disturb" */
+    LookaheadDFA {
+        states: &[Some(177)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 28 - "DropName" */
+    LookaheadDFA {
+        states: &[None, Some(158), Some(159)],
+        transitions: &[
+            DFATransition(0, 5, 1),
+            DFATransition(0, 6, 1),
+            DFATransition(0, 7, 1),
+            DFATransition(0, 8, 1),
+            DFATransition(0, 9, 1),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+        ],
+        k: 1,
+    },
+    /* 29 - "DropSep" */
+    LookaheadDFA {
+        states: &[None, Some(156), Some(157)],
+        transitions: &[DFATransition(0, 80, 2), DFATransition(0, 88, 1)],
+        k: 1,
+    },
+    /* 30 - "Drops" */
+    LookaheadDFA {
+        states: &[Some(153)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 31 - "DropsList" */
+    LookaheadDFA {
+        states: &[None, Some(154), Some(155)],
+        transitions: &[
+            DFATransition(0, 28, 2),
+            DFATransition(0, 80, 1),
+            DFATransition(0, 88, 1),
+        ],
+        k: 1,
+    },
+    /* 32 - "Effect" */
+    LookaheadDFA {
+        states: &[
+            None,
+            Some(12),
+            Some(13),
+            Some(14),
+            Some(15),
+            Some(16),
+            Some(17),
+            Some(18),
+            Some(19),
+            Some(20),
+            Some(21),
+            Some(22),
+            Some(23),
+            Some(24),
+            Some(25),
+            Some(26),
+        ],
+        transitions: &[
+            DFATransition(0, 5, 7),
+            DFATransition(0, 6, 7),
+            DFATransition(0, 7, 7),
+            DFATransition(0, 8, 7),
+            DFATransition(0, 9, 7),
+            DFATransition(0, 10, 7),
+            DFATransition(0, 11, 7),
+            DFATransition(0, 12, 7),
+            DFATransition(0, 13, 7),
+            DFATransition(0, 14, 7),
+            DFATransition(0, 17, 1),
+            DFATransition(0, 18, 2),
+            DFATransition(0, 19, 3),
+            DFATransition(0, 20, 4),
+            DFATransition(0, 21, 5),
+            DFATransition(0, 22, 6),
+            DFATransition(0, 23, 10),
+            DFATransition(0, 24, 8),
+            DFATransition(0, 25, 9),
+            DFATransition(0, 26, 11),
+            DFATransition(0, 27, 12),
+            DFATransition(0, 29, 14),
+            DFATransition(0, 30, 15),
+            DFATransition(0, 86, 13),
+        ],
+        k: 1,
+    },
+    /* 33 - "EnemyAttributeChangeStmt" */
+    LookaheadDFA {
+        states: &[Some(34)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 34 - "EnhanceStmt" */
+    LookaheadDFA {
+        states: &[Some(115)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 35 - "EnhanceTarget" */
+    LookaheadDFA {
+        states: &[None, Some(116), Some(117)],
+        transitions: &[
+            DFATransition(0, 5, 2),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 7, 2),
+            DFATransition(0, 8, 2),
+            DFATransition(0, 9, 2),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 24, 1),
+        ],
+        k: 1,
+    },
+    /* 36 - "EnhancedOrbsStmt" */
+    LookaheadDFA {
+        states: &[Some(126)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 37 - "EnhancedOrbsSuffix" */
+    LookaheadDFA {
+        states: &[None, Some(127), Some(128)],
+        transitions: &[DFATransition(0, 67, 1), DFATransition(0, 81, 2)],
+        k: 1,
+    },
+    /* 38 - "Fire" */
+    LookaheadDFA {
+        states: &[Some(171)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 39 - "From" */
+    LookaheadDFA {
+        states: &[Some(249)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 40 - "GenCloudStmt" */
+    LookaheadDFA {
+        states: &[Some(109)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 41 - "GenEachStmt" */
+    LookaheadDFA {
+        states: &[Some(81)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 42 - "GenRandomDropStmt" */
+    LookaheadDFA {
+        states: &[Some(74)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 43 - "GenRandomDropStmtList" */
+    LookaheadDFA {
+        states: &[None, Some(75), Some(76)],
+        transitions: &[
+            DFATransition(0, 28, 2),
+            DFATransition(0, 80, 1),
+            DFATransition(0, 88, 1),
+        ],
+        k: 1,
+    },
+    /* 44 - "GenRouletteStmt" */
+    LookaheadDFA {
+        states: &[Some(97)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 45 - "GenSource" */
+    LookaheadDFA {
+        states: &[None, Some(82), Some(83), Some(84)],
+        transitions: &[
+            DFATransition(0, 82, 1),
+            DFATransition(0, 83, 2),
+            DFATransition(0, 84, 3),
+        ],
+        k: 1,
+    },
+    /* 46 - "GenSourceAt" */
+    LookaheadDFA {
+        states: &[None, Some(85), Some(86)],
+        transitions: &[DFATransition(0, 34, 1), DFATransition(0, 86, 2)],
+        k: 1,
+    },
+    /* 47 - "In" */
+    LookaheadDFA {
+        states: &[Some(250)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 48 - "LShapeStmt" */
+    LookaheadDFA {
+        states: &[Some(93)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 49 - "Lightning" */
+    LookaheadDFA {
+        states: &[Some(174)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 50 - "LockStmt" */
+    LookaheadDFA {
+        states: &[Some(111)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 51 - "LockTarget" */
+    LookaheadDFA {
+        states: &[None, Some(112), Some(113)],
+        transitions: &[
+            DFATransition(0, 5, 2),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 7, 2),
+            DFATransition(0, 8, 2),
+            DFATransition(0, 9, 2),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 24, 1),
+        ],
+        k: 1,
+    },
+    /* 52 - "MoreLikely" */
+    LookaheadDFA {
+        states: &[Some(130)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 53 - "MoreLikelyOpt" */
+    LookaheadDFA {
+        states: &[None, Some(131), Some(132)],
+        transitions: &[
+            DFATransition(0, 69, 2),
+            DFATransition(0, 71, 1),
+            DFATransition(0, 72, 1),
+        ],
+        k: 1,
+    },
+    /* 54 - "Multi" */
+    LookaheadDFA {
+        states: &[Some(258)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 55 - "NailOrbsStmt" */
+    LookaheadDFA {
+        states: &[Some(129)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 56 - "NoSkyfallStmt" */
+    LookaheadDFA {
+        states: &[Some(135)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 57 - "NonColoredDrop" */
+    LookaheadDFA {
+        states: &[None, Some(165), Some(166), Some(167), Some(168), Some(169)],
+        transitions: &[
+            DFATransition(0, 10, 1),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 3),
+            DFATransition(0, 13, 4),
+            DFATransition(0, 14, 5),
+        ],
+        k: 1,
+    },
+    /* 58 - "NonDrop" */
+    LookaheadDFA {
+        states: &[Some(92)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 59 - "NonDrops" */
+    LookaheadDFA {
+        states: &[Some(89)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 60 - "NonDropsList" */
+    LookaheadDFA {
+        states: &[None, Some(90), Some(91)],
+        transitions: &[
+            DFATransition(0, 28, 2),
+            DFATransition(0, 80, 1),
+            DFATransition(0, 88, 1),
+        ],
+        k: 1,
+    },
+    /* 61 - "ObliqueStmt" */
+    LookaheadDFA {
+        states: &[Some(108)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 62 - "Of" */
+    LookaheadDFA {
+        states: &[Some(251)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 63 - "OnlyStmt" */
+    LookaheadDFA {
+        states: &[Some(122)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 64 - "OnlyTarget" */
+    LookaheadDFA {
+        states: &[None, Some(123), Some(124), Some(125)],
+        transitions: &[
+            DFATransition(0, 5, 1),
+            DFATransition(0, 6, 1),
+            DFATransition(0, 7, 1),
+            DFATransition(0, 8, 1),
+            DFATransition(0, 9, 1),
+            DFATransition(0, 10, 1),
+            DFATransition(0, 11, 1),
+            DFATransition(0, 12, 1),
+            DFATransition(0, 13, 1),
+            DFATransition(0, 14, 1),
+            DFATransition(0, 23, 2),
+            DFATransition(0, 26, 3),
+        ],
+        k: 1,
+    },
+    /* 65 - "OrbsStmt" */
+    LookaheadDFA {
+        states: &[Some(118)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 66 - "OrbsStmtSuffix" */
+    LookaheadDFA {
+        states: &[None, Some(119), Some(120)],
+        transitions: &[DFATransition(0, 64, 2), DFATransition(0, 81, 1)],
+        k: 1,
+    },
+    /* 67 - "Ordinal" */
+    LookaheadDFA {
+        states: &[Some(259)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 68 - "Percent" */
+    LookaheadDFA {
+        states: &[Some(257)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 69 - "Period" */
+    LookaheadDFA {
+        states: &[Some(256)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 70 - "PierceStmt" */
+    LookaheadDFA {
+        states: &[Some(152)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 71 - "Poison" */
+    LookaheadDFA {
+        states: &[Some(180)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 72 - "PosInt" */
+    LookaheadDFA {
+        states: &[Some(260)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 73 - "QtyDrop" */
+    LookaheadDFA {
+        states: &[Some(78)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 74 - "QtyDropName" */
+    LookaheadDFA {
+        states: &[Some(77)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 75 - "QtySep" */
+    LookaheadDFA {
+        states: &[None, Some(79), Some(80)],
+        transitions: &[DFATransition(0, 80, 2), DFATransition(0, 88, 1)],
+        k: 1,
+    },
+    /* 76 - "Recovery" */
+    LookaheadDFA {
+        states: &[Some(176)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 77 - "ReplaceStmt" */
+    LookaheadDFA {
+        states: &[Some(114)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 78 - "RowColBlock" */
+    LookaheadDFA {
+        states: &[Some(45)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 79 - "RowColBlockList" */
+    LookaheadDFA {
+        states: &[None, Some(46), Some(47)],
+        transitions: &[
+            DFATransition(0, 80, 1),
+            DFATransition(0, 87, 2),
+            DFATransition(0, 88, 1),
+        ],
+        k: 1,
+    },
+    /* 80 - "RowColPosition" */
+    LookaheadDFA {
+        states: &[None, Some(50), Some(51), Some(52), Some(53), Some(54)],
+        transitions: &[
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 2),
+            DFATransition(0, 39, 3),
+            DFATransition(0, 40, 4),
+            DFATransition(0, 93, 5),
+        ],
+        k: 1,
+    },
+    /* 81 - "RowColSep" */
+    LookaheadDFA {
+        states: &[None, Some(48), Some(49)],
+        transitions: &[DFATransition(0, 80, 2), DFATransition(0, 88, 1)],
+        k: 1,
+    },
+    /* 82 - "RowOrColumn" */
+    LookaheadDFA {
+        states: &[None, Some(55), Some(56)],
+        transitions: &[DFATransition(0, 41, 1), DFATransition(0, 42, 2)],
+        k: 1,
+    },
+    /* 83 - "Semicolon" */
+    LookaheadDFA {
+        states: &[Some(255)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 84 - "Sentence" */
+    LookaheadDFA {
+        states: &[Some(5)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 85 - "SentenceOpt" */
+    LookaheadDFA {
+        states: &[None, Some(8), Some(9)],
+        transitions: &[
+            DFATransition(0, 5, 2),
+            DFATransition(0, 6, 2),
+            DFATransition(0, 7, 2),
+            DFATransition(0, 8, 2),
+            DFATransition(0, 9, 2),
+            DFATransition(0, 10, 2),
+            DFATransition(0, 11, 2),
+            DFATransition(0, 12, 2),
+            DFATransition(0, 13, 2),
+            DFATransition(0, 14, 2),
+            DFATransition(0, 15, 1),
+            DFATransition(0, 17, 2),
+            DFATransition(0, 18, 2),
+            DFATransition(0, 19, 2),
+            DFATransition(0, 20, 2),
+            DFATransition(0, 21, 2),
+            DFATransition(0, 22, 2),
+            DFATransition(0, 23, 2),
+            DFATransition(0, 24, 2),
+            DFATransition(0, 25, 2),
+            DFATransition(0, 26, 2),
+            DFATransition(0, 27, 2),
+            DFATransition(0, 29, 2),
+            DFATransition(0, 30, 2),
+            DFATransition(0, 86, 2),
+        ],
+        k: 1,
+    },
+    /* 86 - "SentenceOpt0" */
+    LookaheadDFA {
+        states: &[None, Some(6), Some(7)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 15, 1),
+            DFATransition(0, 89, 2),
+            DFATransition(0, 90, 2),
+        ],
+        k: 1,
+    },
+    /* 87 - "ShapeOfBoardCenterStmt" */
+    LookaheadDFA {
+        states: &[Some(62)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 88 - "ShapeOfBoardPerimeterStmt" */
+    LookaheadDFA {
+        states: &[Some(61)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 89 - "ShapeRowColStmt" */
+    LookaheadDFA {
+        states: &[Some(42)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 90 - "ShapeRowColStmtList" */
+    LookaheadDFA {
+        states: &[None, Some(43), Some(44)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 15, 2),
+            DFATransition(0, 88, 1),
+            DFATransition(0, 89, 2),
+            DFATransition(0, 90, 2),
+        ],
+        k: 1,
+    },
+    /* 91 - "Size" */
+    LookaheadDFA {
+        states: &[Some(170)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 92 - "SkillEnLines" */
+    LookaheadDFA {
+        states: &[Some(0)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 93 - "SkillEnLinesList" */
+    LookaheadDFA {
+        states: &[None, Some(1), Some(2)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 89, 1),
+            DFATransition(0, 90, 2),
+        ],
+        k: 1,
+    },
+    /* 94 - "SkillEnLinesOpt" */
+    LookaheadDFA {
+        states: &[None, Some(3), Some(4)],
+        transitions: &[DFATransition(0, 0, 2), DFATransition(0, 90, 1)],
+        k: 1,
+    },
+    /* 95 - "SmallCrossStmt" */
+    LookaheadDFA {
+        states: &[Some(94)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 96 - "SomeKindStmt" */
+    LookaheadDFA {
+        states: &[Some(96)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 97 - "SpiderwebStmt" */
+    LookaheadDFA {
+        states: &[Some(106)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 98 - "SquareStmt" */
+    LookaheadDFA {
+        states: &[Some(95)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 99 - "TapeStmt" */
+    LookaheadDFA {
+        states: &[Some(136)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 100 - "TapeTarget" */
+    LookaheadDFA {
+        states: &[None, Some(137), Some(138)],
+        transitions: &[
+            DFATransition(0, 35, 1),
+            DFATransition(0, 36, 1),
+            DFATransition(0, 39, 1),
+            DFATransition(0, 40, 1),
+            DFATransition(0, 46, 2),
+            DFATransition(0, 93, 1),
+        ],
+        k: 1,
+    },
+    /* 101 - "The" */
+    LookaheadDFA {
+        states: &[Some(252)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 102 - "To" */
+    LookaheadDFA {
+        states: &[Some(253)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 103 - "TopOrBottom" */
+    LookaheadDFA {
+        states: &[None, Some(87), Some(88)],
+        transitions: &[DFATransition(0, 39, 1), DFATransition(0, 40, 2)],
+        k: 1,
+    },
+    /* 104 - "TurnsPrefix" */
+    LookaheadDFA {
+        states: &[Some(10)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 105 - "TurnsSuffix" */
+    LookaheadDFA {
+        states: &[Some(11)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 106 - "UnlockStmt" */
+    LookaheadDFA {
+        states: &[Some(110)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 107 - "VoidSep" */
+    LookaheadDFA {
+        states: &[None, Some(144), Some(145)],
+        transitions: &[DFATransition(0, 80, 2), DFATransition(0, 88, 1)],
+        k: 1,
+    },
+    /* 108 - "VoidStmt" */
+    LookaheadDFA {
+        states: &[Some(141)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 109 - "VoidStmtList" */
+    LookaheadDFA {
+        states: &[None, Some(142), Some(143)],
+        transitions: &[
+            DFATransition(0, 0, 2),
+            DFATransition(0, 15, 2),
+            DFATransition(0, 80, 1),
+            DFATransition(0, 88, 1),
+            DFATransition(0, 89, 2),
+            DFATransition(0, 90, 2),
+        ],
+        k: 1,
+    },
+    /* 110 - "VoidTarget" */
+    LookaheadDFA {
+        states: &[None, Some(146), Some(147)],
+        transitions: &[DFATransition(0, 33, 1), DFATransition(0, 77, 2)],
+        k: 1,
+    },
+    /* 111 - "VoidTargetOpt" */
+    LookaheadDFA {
+        states: &[None, Some(148), Some(149)],
+        transitions: &[DFATransition(0, 78, 2), DFATransition(0, 80, 1)],
+        k: 1,
+    },
+    /* 112 - "Volume" */
+    LookaheadDFA {
+        states: &[None, Some(133), Some(134)],
+        transitions: &[DFATransition(0, 71, 1), DFATransition(0, 72, 2)],
+        k: 1,
+    },
+    /* 113 - "Water" */
+    LookaheadDFA {
+        states: &[Some(172)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 114 - "Wood" */
+    LookaheadDFA {
+        states: &[Some(173)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 115 - "WordAbsorption" */
+    LookaheadDFA {
+        states: &[Some(244)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 116 - "WordAcross" */
+    LookaheadDFA {
+        states: &[Some(220)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 117 - "WordAll" */
+    LookaheadDFA {
+        states: &[Some(190)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 118 - "WordAppear" */
+    LookaheadDFA {
+        states: &[Some(230)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 119 - "WordAttribute" */
+    LookaheadDFA {
+        states: &[Some(199)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 120 - "WordBecomes" */
+    LookaheadDFA {
+        states: &[Some(241)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 121 - "WordBoard" */
+    LookaheadDFA {
+        states: &[Some(212)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 122 - "WordBottom" */
+    LookaheadDFA {
+        states: &[Some(206)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 123 - "WordCenter" */
+    LookaheadDFA {
+        states: &[Some(211)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 124 - "WordChance" */
+    LookaheadDFA {
+        states: &[Some(234)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 125 - "WordChange" */
+    LookaheadDFA {
+        states: &[Some(183)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 126 - "WordCloud" */
+    LookaheadDFA {
+        states: &[Some(229)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 127 - "WordColumn" */
+    LookaheadDFA {
+        states: &[Some(207)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 128 - "WordCombos" */
+    LookaheadDFA {
+        states: &[Some(240)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 129 - "WordCorner" */
+    LookaheadDFA {
+        states: &[Some(214)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 130 - "WordCreate" */
+    LookaheadDFA {
+        states: &[Some(184)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 131 - "WordCrescentMoon" */
+    LookaheadDFA {
+        states: &[Some(227)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 132 - "WordCross" */
+    LookaheadDFA {
+        states: &[Some(218)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 133 - "WordDamage" */
+    LookaheadDFA {
+        states: &[Some(243)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 134 - "WordDiagonalLine" */
+    LookaheadDFA {
+        states: &[Some(228)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 135 - "WordEach" */
+    LookaheadDFA {
+        states: &[Some(213)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 136 - "WordEdges" */
+    LookaheadDFA {
+        states: &[Some(210)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 137 - "WordEnemies" */
+    LookaheadDFA {
+        states: &[Some(198)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 138 - "WordEnhance" */
+    LookaheadDFA {
+        states: &[Some(188)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 139 - "WordEnhanced" */
+    LookaheadDFA {
+        states: &[Some(189)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 140 - "WordFor" */
+    LookaheadDFA {
+        states: &[Some(181)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 141 - "WordHave" */
+    LookaheadDFA {
+        states: &[Some(233)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 142 - "WordIts" */
+    LookaheadDFA {
+        states: &[Some(226)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 143 - "WordLShape" */
+    LookaheadDFA {
+        states: &[Some(216)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 144 - "WordLarge" */
+    LookaheadDFA {
+        states: &[Some(219)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 145 - "WordLeft" */
+    LookaheadDFA {
+        states: &[Some(203)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 146 - "WordLeftmost" */
+    LookaheadDFA {
+        states: &[Some(201)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 147 - "WordLikely" */
+    LookaheadDFA {
+        states: &[Some(236)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 148 - "WordLock" */
+    LookaheadDFA {
+        states: &[Some(186)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 149 - "WordLocked" */
+    LookaheadDFA {
+        states: &[Some(231)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 150 - "WordMore" */
+    LookaheadDFA {
+        states: &[Some(235)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 151 - "WordNail" */
+    LookaheadDFA {
+        states: &[Some(192)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 152 - "WordNo" */
+    LookaheadDFA {
+        states: &[Some(193)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 153 - "WordNon" */
+    LookaheadDFA {
+        states: &[Some(215)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 154 - "WordOnly" */
+    LookaheadDFA {
+        states: &[Some(191)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 155 - "WordOrbs" */
+    LookaheadDFA {
+        states: &[Some(194)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 156 - "WordOuter" */
+    LookaheadDFA {
+        states: &[Some(209)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 157 - "WordPierce" */
+    LookaheadDFA {
+        states: &[Some(196)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 158 - "WordRandom" */
+    LookaheadDFA {
+        states: &[Some(200)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 159 - "WordReplace" */
+    LookaheadDFA {
+        states: &[Some(187)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 160 - "WordRight" */
+    LookaheadDFA {
+        states: &[Some(204)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 161 - "WordRightmost" */
+    LookaheadDFA {
+        states: &[Some(202)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 162 - "WordRoulette" */
+    LookaheadDFA {
+        states: &[Some(223)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 163 - "WordRow" */
+    LookaheadDFA {
+        states: &[Some(208)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 164 - "WordShape" */
+    LookaheadDFA {
+        states: &[Some(222)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 165 - "WordSkyfall" */
+    LookaheadDFA {
+        states: &[Some(239)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 166 - "WordSlightly" */
+    LookaheadDFA {
+        states: &[Some(238)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 167 - "WordSpiderweb" */
+    LookaheadDFA {
+        states: &[Some(224)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 168 - "WordSquare" */
+    LookaheadDFA {
+        states: &[Some(221)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 169 - "WordThrough" */
+    LookaheadDFA {
+        states: &[Some(197)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 170 - "WordTop" */
+    LookaheadDFA {
+        states: &[Some(205)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 171 - "WordTurn" */
+    LookaheadDFA {
+        states: &[Some(182)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 172 - "WordUnlock" */
+    LookaheadDFA {
+        states: &[Some(185)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 173 - "WordUnmovable" */
+    LookaheadDFA {
+        states: &[Some(242)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 174 - "WordVerySlightly" */
+    LookaheadDFA {
+        states: &[Some(237)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 175 - "WordVoid" */
+    LookaheadDFA {
+        states: &[Some(195)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 176 - "WordWill" */
+    LookaheadDFA {
+        states: &[Some(232)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 177 - "WordWith" */
+    LookaheadDFA {
+        states: &[Some(225)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 178 - "WordZShape" */
+    LookaheadDFA {
+        states: &[Some(217)],
+        transitions: &[],
+        k: 0,
+    },
+    /* 179 - "ZShapeStmt" */
+    LookaheadDFA {
+        states: &[Some(104)],
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 261] = &[
+    // 0 - SkillEnLines: Sentence SkillEnLinesList /* Vec */ SkillEnLinesOpt /* Option */;
+    Production {
+        lhs: 92,
+        production: &[ParseType::N(94), ParseType::N(93), ParseType::N(84)],
+    },
+    // 1 - SkillEnLinesList: Semicolon Sentence SkillEnLinesList;
+    Production {
+        lhs: 93,
+        production: &[ParseType::N(93), ParseType::N(84), ParseType::N(83)],
+    },
+    // 2 - SkillEnLinesList: ;
+    Production {
+        lhs: 93,
+        production: &[],
+    },
+    // 3 - SkillEnLinesOpt: Period;
+    Production {
+        lhs: 94,
+        production: &[ParseType::N(69)],
+    },
+    // 4 - SkillEnLinesOpt: ;
+    Production {
+        lhs: 94,
+        production: &[],
+    },
+    // 5 - Sentence: SentenceOpt /* Option */ Effect SentenceOpt0 /* Option */;
+    Production {
+        lhs: 84,
+        production: &[ParseType::N(86), ParseType::N(32), ParseType::N(85)],
+    },
+    // 6 - SentenceOpt0: TurnsSuffix;
+    Production {
+        lhs: 86,
+        production: &[ParseType::N(105)],
+    },
+    // 7 - SentenceOpt0: ;
+    Production {
+        lhs: 86,
+        production: &[],
+    },
+    // 8 - SentenceOpt: TurnsPrefix;
+    Production {
+        lhs: 85,
+        production: &[ParseType::N(104)],
+    },
+    // 9 - SentenceOpt: ;
+    Production {
+        lhs: 85,
+        production: &[],
+    },
+    // 10 - TurnsPrefix: WordFor PosInt WordTurn Camma;
+    Production {
+        lhs: 104,
+        production: &[
+            ParseType::N(7),
+            ParseType::N(171),
+            ParseType::N(72),
+            ParseType::N(140),
+        ],
+    },
+    // 11 - TurnsSuffix: WordFor PosInt WordTurn;
+    Production {
+        lhs: 105,
+        production: &[ParseType::N(171), ParseType::N(72), ParseType::N(140)],
+    },
+    // 12 - Effect: ChangeStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(13)],
+    },
+    // 13 - Effect: CreateStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(19)],
+    },
+    // 14 - Effect: UnlockStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(106)],
+    },
+    // 15 - Effect: LockStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(50)],
+    },
+    // 16 - Effect: ReplaceStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(77)],
+    },
+    // 17 - Effect: EnhanceStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(34)],
+    },
+    // 18 - Effect: OrbsStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(65)],
+    },
+    // 19 - Effect: AllOrbsLockedStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(1)],
+    },
+    // 20 - Effect: OnlyStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(63)],
+    },
+    // 21 - Effect: EnhancedOrbsStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(36)],
+    },
+    // 22 - Effect: NailOrbsStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(55)],
+    },
+    // 23 - Effect: NoSkyfallStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(56)],
+    },
+    // 24 - Effect: TapeStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(99)],
+    },
+    // 25 - Effect: VoidStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(108)],
+    },
+    // 26 - Effect: PierceStmt;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(70)],
+    },
+    // 27 - ChangeStmt: WordChange ChangeTarget;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(14), ParseType::N(125)],
+    },
+    // 28 - ChangeTarget: WordAll ChangeAllTarget;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(9), ParseType::N(117)],
+    },
+    // 29 - ChangeTarget: ChangeDropStmt;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(11)],
+    },
+    // 30 - ChangeTarget: The ChangeTheTarget;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(15), ParseType::N(101)],
+    },
+    // 31 - ChangeAllTarget: ChangeAllOfBoardStmt;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(8)],
+    },
+    // 32 - ChangeAllTarget: EnemyAttributeChangeStmt;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(33)],
+    },
+    // 33 - ChangeAllOfBoardStmt: WordOrbs To Drops;
+    Production {
+        lhs: 8,
+        production: &[ParseType::N(30), ParseType::N(102), ParseType::N(155)],
+    },
+    // 34 - EnemyAttributeChangeStmt: WordEnemies To Color WordAttribute;
+    Production {
+        lhs: 33,
+        production: &[
+            ParseType::N(119),
+            ParseType::N(16),
+            ParseType::N(102),
+            ParseType::N(137),
+        ],
+    },
+    // 35 - ChangeDropStmt: Drops To Drops ChangeDropStmtOpt /* Option */;
+    Production {
+        lhs: 11,
+        production: &[
+            ParseType::N(12),
+            ParseType::N(30),
+            ParseType::N(102),
+            ParseType::N(30),
+        ],
+    },
+    // 36 - ChangeDropStmtOpt: At WordRandom;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(158), ParseType::N(4)],
+    },
+    // 37 - ChangeDropStmtOpt: ;
+    Production {
+        lhs: 12,
+        production: &[],
+    },
+    // 38 - ChangeTheTarget: ShapeRowColStmt;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(89)],
+    },
+    // 39 - ChangeTheTarget: ShapeOfBoardPerimeterStmt;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(88)],
+    },
+    // 40 - ChangeTheTarget: ShapeOfBoardCenterStmt;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(87)],
+    },
+    // 41 - ChangeTheTarget: ChangeBoardSizeStmt;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(10)],
+    },
+    // 42 - ShapeRowColStmt: RowColBlock ShapeRowColStmtList /* Vec */;
+    Production {
+        lhs: 89,
+        production: &[ParseType::N(90), ParseType::N(78)],
+    },
+    // 43 - ShapeRowColStmtList: Camma And The RowColBlock ShapeRowColStmtList;
+    Production {
+        lhs: 90,
+        production: &[
+            ParseType::N(90),
+            ParseType::N(78),
+            ParseType::N(101),
+            ParseType::N(2),
+            ParseType::N(7),
+        ],
+    },
+    // 44 - ShapeRowColStmtList: ;
+    Production {
+        lhs: 90,
+        production: &[],
+    },
+    // 45 - RowColBlock: RowColPosition RowColBlockList /* Vec */ To Drops;
+    Production {
+        lhs: 78,
+        production: &[
+            ParseType::N(30),
+            ParseType::N(102),
+            ParseType::N(79),
+            ParseType::N(80),
+        ],
+    },
+    // 46 - RowColBlockList: RowColSep The RowColPosition RowColBlockList;
+    Production {
+        lhs: 79,
+        production: &[
+            ParseType::N(79),
+            ParseType::N(80),
+            ParseType::N(101),
+            ParseType::N(81),
+        ],
+    },
+    // 47 - RowColBlockList: ;
+    Production {
+        lhs: 79,
+        production: &[],
+    },
+    // 48 - RowColSep: Camma;
+    Production {
+        lhs: 81,
+        production: &[ParseType::N(7)],
+    },
+    // 49 - RowColSep: And;
+    Production {
+        lhs: 81,
+        production: &[ParseType::N(2)],
+    },
+    // 50 - RowColPosition: WordLeftmost WordColumn;
+    Production {
+        lhs: 80,
+        production: &[ParseType::N(127), ParseType::N(146)],
+    },
+    // 51 - RowColPosition: WordRightmost WordColumn;
+    Production {
+        lhs: 80,
+        production: &[ParseType::N(127), ParseType::N(161)],
+    },
+    // 52 - RowColPosition: WordTop WordRow;
+    Production {
+        lhs: 80,
+        production: &[ParseType::N(163), ParseType::N(170)],
+    },
+    // 53 - RowColPosition: WordBottom WordRow;
+    Production {
+        lhs: 80,
+        production: &[ParseType::N(163), ParseType::N(122)],
+    },
+    // 54 - RowColPosition: Ordinal RowOrColumn From The Direction;
+    Production {
+        lhs: 80,
+        production: &[
+            ParseType::N(26),
+            ParseType::N(101),
+            ParseType::N(39),
+            ParseType::N(82),
+            ParseType::N(67),
+        ],
+    },
+    // 55 - RowOrColumn: WordColumn;
+    Production {
+        lhs: 82,
+        production: &[ParseType::N(127)],
+    },
+    // 56 - RowOrColumn: WordRow;
+    Production {
+        lhs: 82,
+        production: &[ParseType::N(163)],
+    },
+    // 57 - Direction: WordLeft;
+    Production {
+        lhs: 26,
+        production: &[ParseType::N(145)],
+    },
+    // 58 - Direction: WordRight;
+    Production {
+        lhs: 26,
+        production: &[ParseType::N(160)],
+    },
+    // 59 - Direction: WordTop;
+    Production {
+        lhs: 26,
+        production: &[ParseType::N(170)],
+    },
+    // 60 - Direction: WordBottom;
+    Production {
+        lhs: 26,
+        production: &[ParseType::N(122)],
+    },
+    // 61 - ShapeOfBoardPerimeterStmt: WordOuter WordEdges Of The WordBoard To Drops;
+    Production {
+        lhs: 88,
+        production: &[
+            ParseType::N(30),
+            ParseType::N(102),
+            ParseType::N(121),
+            ParseType::N(101),
+            ParseType::N(62),
+            ParseType::N(136),
+            ParseType::N(156),
+        ],
+    },
+    // 62 - ShapeOfBoardCenterStmt: WordCenter Of The WordBoard To Drops;
+    Production {
+        lhs: 87,
+        production: &[
+            ParseType::N(30),
+            ParseType::N(102),
+            ParseType::N(121),
+            ParseType::N(101),
+            ParseType::N(62),
+            ParseType::N(123),
+        ],
+    },
+    // 63 - ChangeBoardSizeStmt: WordBoard To Size;
+    Production {
+        lhs: 10,
+        production: &[ParseType::N(91), ParseType::N(102), ParseType::N(121)],
+    },
+    // 64 - CreateStmt: WordCreate CreateTarget;
+    Production {
+        lhs: 19,
+        production: &[ParseType::N(20), ParseType::N(130)],
+    },
+    // 65 - CreateTarget: PosInt CreateQtyTarget;
+    Production {
+        lhs: 20,
+        production: &[ParseType::N(18), ParseType::N(72)],
+    },
+    // 66 - CreateTarget: A CreateATarget;
+    Production {
+        lhs: 20,
+        production: &[ParseType::N(17), ParseType::N(0)],
+    },
+    // 67 - CreateQtyTarget: GenRandomDropStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(42)],
+    },
+    // 68 - CreateQtyTarget: GenEachStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(41)],
+    },
+    // 69 - CreateQtyTarget: LShapeStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(48)],
+    },
+    // 70 - CreateQtyTarget: SmallCrossStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(95)],
+    },
+    // 71 - CreateQtyTarget: SquareStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(98)],
+    },
+    // 72 - CreateQtyTarget: SomeKindStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(96)],
+    },
+    // 73 - CreateQtyTarget: GenRouletteStmt;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(44)],
+    },
+    // 74 - GenRandomDropStmt: QtyDropName GenRandomDropStmtList /* Vec */ WordOrbs GenSource;
+    Production {
+        lhs: 42,
+        production: &[
+            ParseType::N(45),
+            ParseType::N(155),
+            ParseType::N(43),
+            ParseType::N(74),
+        ],
+    },
+    // 75 - GenRandomDropStmtList: QtySep QtyDrop GenRandomDropStmtList;
+    Production {
+        lhs: 43,
+        production: &[ParseType::N(43), ParseType::N(73), ParseType::N(75)],
+    },
+    // 76 - GenRandomDropStmtList: ;
+    Production {
+        lhs: 43,
+        production: &[],
+    },
+    // 77 - QtyDropName: DropName;
+    Production {
+        lhs: 74,
+        production: &[ParseType::N(28)],
+    },
+    // 78 - QtyDrop: PosInt DropName;
+    Production {
+        lhs: 73,
+        production: &[ParseType::N(28), ParseType::N(72)],
+    },
+    // 79 - QtySep: Camma;
+    Production {
+        lhs: 75,
+        production: &[ParseType::N(7)],
+    },
+    // 80 - QtySep: And;
+    Production {
+        lhs: 75,
+        production: &[ParseType::N(2)],
+    },
+    // 81 - GenEachStmt: WordEach Of Drops GenSource;
+    Production {
+        lhs: 41,
+        production: &[
+            ParseType::N(45),
+            ParseType::N(30),
+            ParseType::N(62),
+            ParseType::N(135),
+        ],
+    },
+    // 82 - GenSource: At GenSourceAt;
+    Production {
+        lhs: 45,
+        production: &[ParseType::N(46), ParseType::N(4)],
+    },
+    // 83 - GenSource: From NonDrops;
+    Production {
+        lhs: 45,
+        production: &[ParseType::N(59), ParseType::N(39)],
+    },
+    // 84 - GenSource: In WordEach WordCorner Of The WordBoard;
+    Production {
+        lhs: 45,
+        production: &[
+            ParseType::N(121),
+            ParseType::N(101),
+            ParseType::N(62),
+            ParseType::N(129),
+            ParseType::N(135),
+            ParseType::N(47),
+        ],
+    },
+    // 85 - GenSourceAt: WordRandom;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(158)],
+    },
+    // 86 - GenSourceAt: The TopOrBottom Of The WordBoard;
+    Production {
+        lhs: 46,
+        production: &[
+            ParseType::N(121),
+            ParseType::N(101),
+            ParseType::N(62),
+            ParseType::N(103),
+            ParseType::N(101),
+        ],
+    },
+    // 87 - TopOrBottom: WordTop;
+    Production {
+        lhs: 103,
+        production: &[ParseType::N(170)],
+    },
+    // 88 - TopOrBottom: WordBottom;
+    Production {
+        lhs: 103,
+        production: &[ParseType::N(122)],
+    },
+    // 89 - NonDrops: NonDrop NonDropsList /* Vec */ WordOrbs;
+    Production {
+        lhs: 59,
+        production: &[ParseType::N(155), ParseType::N(60), ParseType::N(58)],
+    },
+    // 90 - NonDropsList: DropSep NonDrop NonDropsList;
+    Production {
+        lhs: 60,
+        production: &[ParseType::N(60), ParseType::N(58), ParseType::N(29)],
+    },
+    // 91 - NonDropsList: ;
+    Production {
+        lhs: 60,
+        production: &[],
+    },
+    // 92 - NonDrop: WordNon DropName;
+    Production {
+        lhs: 58,
+        production: &[ParseType::N(28), ParseType::N(153)],
+    },
+    // 93 - LShapeStmt: WordLShape Of Drops;
+    Production {
+        lhs: 48,
+        production: &[ParseType::N(30), ParseType::N(62), ParseType::N(143)],
+    },
+    // 94 - SmallCrossStmt: WordCross Of Drops;
+    Production {
+        lhs: 95,
+        production: &[ParseType::N(30), ParseType::N(62), ParseType::N(132)],
+    },
+    // 95 - SquareStmt: Size WordSquare Of Drops;
+    Production {
+        lhs: 98,
+        production: &[
+            ParseType::N(30),
+            ParseType::N(62),
+            ParseType::N(168),
+            ParseType::N(91),
+        ],
+    },
+    // 96 - SomeKindStmt: PosInt WordShape Of Drops;
+    Production {
+        lhs: 96,
+        production: &[
+            ParseType::N(30),
+            ParseType::N(62),
+            ParseType::N(164),
+            ParseType::N(72),
+        ],
+    },
+    // 97 - GenRouletteStmt: WordRoulette WordOrbs At WordRandom;
+    Production {
+        lhs: 44,
+        production: &[
+            ParseType::N(158),
+            ParseType::N(4),
+            ParseType::N(155),
+            ParseType::N(162),
+        ],
+    },
+    // 98 - CreateATarget: ZShapeStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(179)],
+    },
+    // 99 - CreateATarget: CrossShapeStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(22)],
+    },
+    // 100 - CreateATarget: SpiderwebStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(97)],
+    },
+    // 101 - CreateATarget: CrescentMoonStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(21)],
+    },
+    // 102 - CreateATarget: ObliqueStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(61)],
+    },
+    // 103 - CreateATarget: GenCloudStmt;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(40)],
+    },
+    // 104 - ZShapeStmt: WordZShape Of Drops;
+    Production {
+        lhs: 179,
+        production: &[ParseType::N(30), ParseType::N(62), ParseType::N(178)],
+    },
+    // 105 - CrossShapeStmt: WordLarge WordCross Of Drops WordAcross The WordBoard;
+    Production {
+        lhs: 22,
+        production: &[
+            ParseType::N(121),
+            ParseType::N(101),
+            ParseType::N(116),
+            ParseType::N(30),
+            ParseType::N(62),
+            ParseType::N(132),
+            ParseType::N(144),
+        ],
+    },
+    // 106 - SpiderwebStmt: WordSpiderweb Of Drops WordWith Drops At WordIts WordCenter;
+    Production {
+        lhs: 97,
+        production: &[
+            ParseType::N(123),
+            ParseType::N(142),
+            ParseType::N(4),
+            ParseType::N(30),
+            ParseType::N(177),
+            ParseType::N(30),
+            ParseType::N(62),
+            ParseType::N(167),
+        ],
+    },
+    // 107 - CrescentMoonStmt: WordCrescentMoon Of Drops;
+    Production {
+        lhs: 21,
+        production: &[ParseType::N(30), ParseType::N(62), ParseType::N(131)],
+    },
+    // 108 - ObliqueStmt: WordDiagonalLine Of Drops;
+    Production {
+        lhs: 61,
+        production: &[ParseType::N(30), ParseType::N(62), ParseType::N(134)],
+    },
+    // 109 - GenCloudStmt: Size WordCloud BoardPosition;
+    Production {
+        lhs: 40,
+        production: &[ParseType::N(5), ParseType::N(126), ParseType::N(91)],
+    },
+    // 110 - UnlockStmt: WordUnlock WordAll WordOrbs;
+    Production {
+        lhs: 106,
+        production: &[ParseType::N(155), ParseType::N(117), ParseType::N(172)],
+    },
+    // 111 - LockStmt: WordLock LockTarget;
+    Production {
+        lhs: 50,
+        production: &[ParseType::N(51), ParseType::N(148)],
+    },
+    // 112 - LockTarget: WordAll WordOrbs;
+    Production {
+        lhs: 51,
+        production: &[ParseType::N(155), ParseType::N(117)],
+    },
+    // 113 - LockTarget: Drops;
+    Production {
+        lhs: 51,
+        production: &[ParseType::N(30)],
+    },
+    // 114 - ReplaceStmt: WordReplace WordAll WordOrbs;
+    Production {
+        lhs: 77,
+        production: &[ParseType::N(155), ParseType::N(117), ParseType::N(159)],
+    },
+    // 115 - EnhanceStmt: WordEnhance EnhanceTarget;
+    Production {
+        lhs: 34,
+        production: &[ParseType::N(35), ParseType::N(138)],
+    },
+    // 116 - EnhanceTarget: WordAll WordOrbs;
+    Production {
+        lhs: 35,
+        production: &[ParseType::N(155), ParseType::N(117)],
+    },
+    // 117 - EnhanceTarget: Drops;
+    Production {
+        lhs: 35,
+        production: &[ParseType::N(30)],
+    },
+    // 118 - OrbsStmt: Drops OrbsStmtSuffix;
+    Production {
+        lhs: 65,
+        production: &[ParseType::N(66), ParseType::N(30)],
+    },
+    // 119 - OrbsStmtSuffix: MoreLikely;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(52)],
+    },
+    // 120 - OrbsStmtSuffix: WordAppear WordLocked;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(149), ParseType::N(118)],
+    },
+    // 121 - AllOrbsLockedStmt: WordAll WordOrbs WordAppear WordLocked;
+    Production {
+        lhs: 1,
+        production: &[
+            ParseType::N(149),
+            ParseType::N(118),
+            ParseType::N(155),
+            ParseType::N(117),
+        ],
+    },
+    // 122 - OnlyStmt: WordOnly OnlyTarget WordWill WordAppear;
+    Production {
+        lhs: 63,
+        production: &[
+            ParseType::N(118),
+            ParseType::N(176),
+            ParseType::N(64),
+            ParseType::N(154),
+        ],
+    },
+    // 123 - OnlyTarget: Drops;
+    Production {
+        lhs: 64,
+        production: &[ParseType::N(30)],
+    },
+    // 124 - OnlyTarget: WordEnhanced WordOrbs;
+    Production {
+        lhs: 64,
+        production: &[ParseType::N(155), ParseType::N(139)],
+    },
+    // 125 - OnlyTarget: WordNail WordOrbs;
+    Production {
+        lhs: 64,
+        production: &[ParseType::N(155), ParseType::N(151)],
+    },
+    // 126 - EnhancedOrbsStmt: WordEnhanced WordOrbs EnhancedOrbsSuffix;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(37), ParseType::N(155), ParseType::N(139)],
+    },
+    // 127 - EnhancedOrbsSuffix: WordHave A PosInt Percent WordChance To WordAppear;
+    Production {
+        lhs: 37,
+        production: &[
+            ParseType::N(118),
+            ParseType::N(102),
+            ParseType::N(124),
+            ParseType::N(68),
+            ParseType::N(72),
+            ParseType::N(0),
+            ParseType::N(141),
+        ],
+    },
+    // 128 - EnhancedOrbsSuffix: MoreLikely;
+    Production {
+        lhs: 37,
+        production: &[ParseType::N(52)],
+    },
+    // 129 - NailOrbsStmt: WordNail WordOrbs MoreLikely;
+    Production {
+        lhs: 55,
+        production: &[ParseType::N(52), ParseType::N(155), ParseType::N(151)],
+    },
+    // 130 - MoreLikely: Are MoreLikelyOpt /* Option */ WordMore WordLikely To WordAppear;
+    Production {
+        lhs: 52,
+        production: &[
+            ParseType::N(118),
+            ParseType::N(102),
+            ParseType::N(147),
+            ParseType::N(150),
+            ParseType::N(53),
+            ParseType::N(3),
+        ],
+    },
+    // 131 - MoreLikelyOpt: Volume;
+    Production {
+        lhs: 53,
+        production: &[ParseType::N(112)],
+    },
+    // 132 - MoreLikelyOpt: ;
+    Production {
+        lhs: 53,
+        production: &[],
+    },
+    // 133 - Volume: WordVerySlightly;
+    Production {
+        lhs: 112,
+        production: &[ParseType::N(174)],
+    },
+    // 134 - Volume: WordSlightly;
+    Production {
+        lhs: 112,
+        production: &[ParseType::N(166)],
+    },
+    // 135 - NoSkyfallStmt: WordNo WordSkyfall WordCombos;
+    Production {
+        lhs: 56,
+        production: &[ParseType::N(128), ParseType::N(165), ParseType::N(152)],
+    },
+    // 136 - TapeStmt: The TapeTarget WordBecomes WordUnmovable;
+    Production {
+        lhs: 99,
+        production: &[
+            ParseType::N(173),
+            ParseType::N(120),
+            ParseType::N(100),
+            ParseType::N(101),
+        ],
+    },
+    // 137 - TapeTarget: RowColPosition;
+    Production {
+        lhs: 100,
+        production: &[ParseType::N(80)],
+    },
+    // 138 - TapeTarget: WordBoard;
+    Production {
+        lhs: 100,
+        production: &[ParseType::N(121)],
+    },
+    // 139 - BoardPosition: At WordRandom;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(158), ParseType::N(4)],
+    },
+    // 140 - BoardPosition: In The RowColPosition;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(80), ParseType::N(101), ParseType::N(47)],
+    },
+    // 141 - VoidStmt: WordVoid VoidTarget VoidStmtList /* Vec */;
+    Production {
+        lhs: 108,
+        production: &[ParseType::N(109), ParseType::N(110), ParseType::N(175)],
+    },
+    // 142 - VoidStmtList: VoidSep VoidTarget VoidStmtList;
+    Production {
+        lhs: 109,
+        production: &[ParseType::N(109), ParseType::N(110), ParseType::N(107)],
+    },
+    // 143 - VoidStmtList: ;
+    Production {
+        lhs: 109,
+        production: &[],
+    },
+    // 144 - VoidSep: Camma;
+    Production {
+        lhs: 107,
+        production: &[ParseType::N(7)],
+    },
+    // 145 - VoidSep: And;
+    Production {
+        lhs: 107,
+        production: &[ParseType::N(2)],
+    },
+    // 146 - VoidTarget: WordAttribute VoidTargetOpt /* Option */ WordAbsorption;
+    Production {
+        lhs: 110,
+        production: &[ParseType::N(115), ParseType::N(111), ParseType::N(119)],
+    },
+    // 147 - VoidTarget: WordDamage DamageTarget;
+    Production {
+        lhs: 110,
+        production: &[ParseType::N(23), ParseType::N(133)],
+    },
+    // 148 - VoidTargetOpt: And WordDamage;
+    Production {
+        lhs: 111,
+        production: &[ParseType::N(133), ParseType::N(2)],
+    },
+    // 149 - VoidTargetOpt: ;
+    Production {
+        lhs: 111,
+        production: &[],
+    },
+    // 150 - DamageTarget: WordAbsorption;
+    Production {
+        lhs: 23,
+        production: &[ParseType::N(115)],
+    },
+    // 151 - DamageTarget: WordVoid;
+    Production {
+        lhs: 23,
+        production: &[ParseType::N(175)],
+    },
+    // 152 - PierceStmt: WordPierce WordThrough WordDamage WordVoid;
+    Production {
+        lhs: 70,
+        production: &[
+            ParseType::N(175),
+            ParseType::N(133),
+            ParseType::N(169),
+            ParseType::N(157),
+        ],
+    },
+    // 153 - Drops: DropName DropsList /* Vec */ WordOrbs;
+    Production {
+        lhs: 30,
+        production: &[ParseType::N(155), ParseType::N(31), ParseType::N(28)],
+    },
+    // 154 - DropsList: DropSep DropName DropsList;
+    Production {
+        lhs: 31,
+        production: &[ParseType::N(31), ParseType::N(28), ParseType::N(29)],
+    },
+    // 155 - DropsList: ;
+    Production {
+        lhs: 31,
+        production: &[],
+    },
+    // 156 - DropSep: Camma;
+    Production {
+        lhs: 29,
+        production: &[ParseType::N(7)],
+    },
+    // 157 - DropSep: And;
+    Production {
+        lhs: 29,
+        production: &[ParseType::N(2)],
+    },
+    // 158 - DropName: Color;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(16)],
+    },
+    // 159 - DropName: NonColoredDrop;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(57)],
+    },
+    // 160 - Color: Fire;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(38)],
+    },
+    // 161 - Color: Water;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(113)],
+    },
+    // 162 - Color: Wood;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(114)],
+    },
+    // 163 - Color: Lightning;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(49)],
+    },
+    // 164 - Color: Dark;
+    Production {
+        lhs: 16,
+        production: &[ParseType::N(24)],
+    },
+    // 165 - NonColoredDrop: Recovery;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(76)],
+    },
+    // 166 - NonColoredDrop: Disturb;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(27)],
+    },
+    // 167 - NonColoredDrop: Bomb;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(6)],
+    },
+    // 168 - NonColoredDrop: DeadlyPoison;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(25)],
+    },
+    // 169 - NonColoredDrop: Poison;
+    Production {
+        lhs: 57,
+        production: &[ParseType::N(71)],
+    },
+    // 170 - Size: PosInt Multi PosInt;
+    Production {
+        lhs: 91,
+        production: &[ParseType::N(72), ParseType::N(54), ParseType::N(72)],
+    },
+    // 171 - Fire: "Fire\b";
+    Production {
+        lhs: 38,
+        production: &[ParseType::T(5)],
+    },
+    // 172 - Water: "Water\b";
+    Production {
+        lhs: 113,
+        production: &[ParseType::T(6)],
+    },
+    // 173 - Wood: "Wood\b";
+    Production {
+        lhs: 114,
+        production: &[ParseType::T(7)],
+    },
+    // 174 - Lightning: "Light\b";
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(8)],
+    },
+    // 175 - Dark: "Dark\b";
+    Production {
+        lhs: 24,
+        production: &[ParseType::T(9)],
+    },
+    // 176 - Recovery: "Heal\b";
+    Production {
+        lhs: 76,
+        production: &[ParseType::T(10)],
+    },
+    // 177 - Disturb: "Jammer\b";
+    Production {
+        lhs: 27,
+        production: &[ParseType::T(11)],
+    },
+    // 178 - Bomb: "Bomb\b";
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(12)],
+    },
+    // 179 - DeadlyPoison: "Mortal Poison\b";
+    Production {
+        lhs: 25,
+        production: &[ParseType::T(13)],
+    },
+    // 180 - Poison: "Poison\b";
+    Production {
+        lhs: 71,
+        production: &[ParseType::T(14)],
+    },
+    // 181 - WordFor: "[Ff]or\b";
+    Production {
+        lhs: 140,
+        production: &[ParseType::T(15)],
+    },
+    // 182 - WordTurn: "turns?\b";
+    Production {
+        lhs: 171,
+        production: &[ParseType::T(16)],
+    },
+    // 183 - WordChange: "[Cc]hange\b";
+    Production {
+        lhs: 125,
+        production: &[ParseType::T(17)],
+    },
+    // 184 - WordCreate: "[Cc]reate\b";
+    Production {
+        lhs: 130,
+        production: &[ParseType::T(18)],
+    },
+    // 185 - WordUnlock: "[Uu]nlock\b";
+    Production {
+        lhs: 172,
+        production: &[ParseType::T(19)],
+    },
+    // 186 - WordLock: "[Ll]ock\b";
+    Production {
+        lhs: 148,
+        production: &[ParseType::T(20)],
+    },
+    // 187 - WordReplace: "[Rr]eplace\b";
+    Production {
+        lhs: 159,
+        production: &[ParseType::T(21)],
+    },
+    // 188 - WordEnhance: "[Ee]nhance\b";
+    Production {
+        lhs: 138,
+        production: &[ParseType::T(22)],
+    },
+    // 189 - WordEnhanced: "[Ee]nhanced\b";
+    Production {
+        lhs: 139,
+        production: &[ParseType::T(23)],
+    },
+    // 190 - WordAll: "[Aa]ll\b";
+    Production {
+        lhs: 117,
+        production: &[ParseType::T(24)],
+    },
+    // 191 - WordOnly: "[Oo]nly\b";
+    Production {
+        lhs: 154,
+        production: &[ParseType::T(25)],
+    },
+    // 192 - WordNail: "[Nn]ail\b";
+    Production {
+        lhs: 151,
+        production: &[ParseType::T(26)],
+    },
+    // 193 - WordNo: "[Nn]o\b";
+    Production {
+        lhs: 152,
+        production: &[ParseType::T(27)],
+    },
+    // 194 - WordOrbs: "[Oo]rbs?\b";
+    Production {
+        lhs: 155,
+        production: &[ParseType::T(28)],
+    },
+    // 195 - WordVoid: "[Vv]oids?\b";
+    Production {
+        lhs: 175,
+        production: &[ParseType::T(29)],
+    },
+    // 196 - WordPierce: "[Pp]ierces?\b";
+    Production {
+        lhs: 157,
+        production: &[ParseType::T(30)],
+    },
+    // 197 - WordThrough: "through\b";
+    Production {
+        lhs: 169,
+        production: &[ParseType::T(31)],
+    },
+    // 198 - WordEnemies: "enemies\b";
+    Production {
+        lhs: 137,
+        production: &[ParseType::T(32)],
+    },
+    // 199 - WordAttribute: "attribute\b";
+    Production {
+        lhs: 119,
+        production: &[ParseType::T(33)],
+    },
+    // 200 - WordRandom: "random\b";
+    Production {
+        lhs: 158,
+        production: &[ParseType::T(34)],
+    },
+    // 201 - WordLeftmost: "leftmost\b";
+    Production {
+        lhs: 146,
+        production: &[ParseType::T(35)],
+    },
+    // 202 - WordRightmost: "rightmost\b";
+    Production {
+        lhs: 161,
+        production: &[ParseType::T(36)],
+    },
+    // 203 - WordLeft: "left\b";
+    Production {
+        lhs: 145,
+        production: &[ParseType::T(37)],
+    },
+    // 204 - WordRight: "right\b";
+    Production {
+        lhs: 160,
+        production: &[ParseType::T(38)],
+    },
+    // 205 - WordTop: "top\b";
+    Production {
+        lhs: 170,
+        production: &[ParseType::T(39)],
+    },
+    // 206 - WordBottom: "bottom\b";
+    Production {
+        lhs: 122,
+        production: &[ParseType::T(40)],
+    },
+    // 207 - WordColumn: "column\b";
+    Production {
+        lhs: 127,
+        production: &[ParseType::T(41)],
+    },
+    // 208 - WordRow: "row\b";
+    Production {
+        lhs: 163,
+        production: &[ParseType::T(42)],
+    },
+    // 209 - WordOuter: "outer\b";
+    Production {
+        lhs: 156,
+        production: &[ParseType::T(43)],
+    },
+    // 210 - WordEdges: "edges\b";
+    Production {
+        lhs: 136,
+        production: &[ParseType::T(44)],
+    },
+    // 211 - WordCenter: "center\b";
+    Production {
+        lhs: 123,
+        production: &[ParseType::T(45)],
+    },
+    // 212 - WordBoard: "board\b";
+    Production {
+        lhs: 121,
+        production: &[ParseType::T(46)],
+    },
+    // 213 - WordEach: "each\b";
+    Production {
+        lhs: 135,
+        production: &[ParseType::T(47)],
+    },
+    // 214 - WordCorner: "corner\b";
+    Production {
+        lhs: 129,
+        production: &[ParseType::T(48)],
+    },
+    // 215 - WordNon: "non-";
+    Production {
+        lhs: 153,
+        production: &[ParseType::T(49)],
+    },
+    // 216 - WordLShape: "L shapes?\b";
+    Production {
+        lhs: 143,
+        production: &[ParseType::T(50)],
+    },
+    // 217 - WordZShape: "Z shape\b";
+    Production {
+        lhs: 178,
+        production: &[ParseType::T(51)],
+    },
+    // 218 - WordCross: "cross(es)?\b";
+    Production {
+        lhs: 132,
+        production: &[ParseType::T(52)],
+    },
+    // 219 - WordLarge: "large\b";
+    Production {
+        lhs: 144,
+        production: &[ParseType::T(53)],
+    },
+    // 220 - WordAcross: "across\b";
+    Production {
+        lhs: 116,
+        production: &[ParseType::T(54)],
+    },
+    // 221 - WordSquare: "squares?\b";
+    Production {
+        lhs: 168,
+        production: &[ParseType::T(55)],
+    },
+    // 222 - WordShape: "shapes?\b";
+    Production {
+        lhs: 164,
+        production: &[ParseType::T(56)],
+    },
+    // 223 - WordRoulette: "roulette\b";
+    Production {
+        lhs: 162,
+        production: &[ParseType::T(57)],
+    },
+    // 224 - WordSpiderweb: "spiderweb\b";
+    Production {
+        lhs: 167,
+        production: &[ParseType::T(58)],
+    },
+    // 225 - WordWith: "with\b";
+    Production {
+        lhs: 177,
+        production: &[ParseType::T(59)],
+    },
+    // 226 - WordIts: "its\b";
+    Production {
+        lhs: 142,
+        production: &[ParseType::T(60)],
+    },
+    // 227 - WordCrescentMoon: "crescent moon\b";
+    Production {
+        lhs: 131,
+        production: &[ParseType::T(61)],
+    },
+    // 228 - WordDiagonalLine: "diagonal line\b";
+    Production {
+        lhs: 134,
+        production: &[ParseType::T(62)],
+    },
+    // 229 - WordCloud: "cloud\b";
+    Production {
+        lhs: 126,
+        production: &[ParseType::T(63)],
+    },
+    // 230 - WordAppear: "appear\b";
+    Production {
+        lhs: 118,
+        production: &[ParseType::T(64)],
+    },
+    // 231 - WordLocked: "locked\b";
+    Production {
+        lhs: 149,
+        production: &[ParseType::T(65)],
+    },
+    // 232 - WordWill: "will\b";
+    Production {
+        lhs: 176,
+        production: &[ParseType::T(66)],
+    },
+    // 233 - WordHave: "have\b";
+    Production {
+        lhs: 141,
+        production: &[ParseType::T(67)],
+    },
+    // 234 - WordChance: "chance\b";
+    Production {
+        lhs: 124,
+        production: &[ParseType::T(68)],
+    },
+    // 235 - WordMore: "more\b";
+    Production {
+        lhs: 150,
+        production: &[ParseType::T(69)],
+    },
+    // 236 - WordLikely: "likely\b";
+    Production {
+        lhs: 147,
+        production: &[ParseType::T(70)],
+    },
+    // 237 - WordVerySlightly: "very slightly\b";
+    Production {
+        lhs: 174,
+        production: &[ParseType::T(71)],
+    },
+    // 238 - WordSlightly: "slightly\b";
+    Production {
+        lhs: 166,
+        production: &[ParseType::T(72)],
+    },
+    // 239 - WordSkyfall: "skyfall\b";
+    Production {
+        lhs: 165,
+        production: &[ParseType::T(73)],
+    },
+    // 240 - WordCombos: "combos\b";
+    Production {
+        lhs: 128,
+        production: &[ParseType::T(74)],
+    },
+    // 241 - WordBecomes: "becomes\b";
+    Production {
+        lhs: 120,
+        production: &[ParseType::T(75)],
+    },
+    // 242 - WordUnmovable: "unmovable\b";
+    Production {
+        lhs: 173,
+        production: &[ParseType::T(76)],
+    },
+    // 243 - WordDamage: "damage\b";
+    Production {
+        lhs: 133,
+        production: &[ParseType::T(77)],
+    },
+    // 244 - WordAbsorption: "absorption\b";
+    Production {
+        lhs: 115,
+        production: &[ParseType::T(78)],
+    },
+    // 245 - A: "a\b";
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(79)],
+    },
+    // 246 - And: "and\b";
+    Production {
+        lhs: 2,
+        production: &[ParseType::T(80)],
+    },
+    // 247 - Are: "are\b";
+    Production {
+        lhs: 3,
+        production: &[ParseType::T(81)],
+    },
+    // 248 - At: "at\b";
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(82)],
+    },
+    // 249 - From: "from\b";
+    Production {
+        lhs: 39,
+        production: &[ParseType::T(83)],
+    },
+    // 250 - In: "in\b";
+    Production {
+        lhs: 47,
+        production: &[ParseType::T(84)],
+    },
+    // 251 - Of: "of\b";
+    Production {
+        lhs: 62,
+        production: &[ParseType::T(85)],
+    },
+    // 252 - The: "[Tt]he\b";
+    Production {
+        lhs: 101,
+        production: &[ParseType::T(86)],
+    },
+    // 253 - To: "to\b";
+    Production {
+        lhs: 102,
+        production: &[ParseType::T(87)],
+    },
+    // 254 - Camma: ',';
+    Production {
+        lhs: 7,
+        production: &[ParseType::T(88)],
+    },
+    // 255 - Semicolon: ';';
+    Production {
+        lhs: 83,
+        production: &[ParseType::T(89)],
+    },
+    // 256 - Period: '.';
+    Production {
+        lhs: 69,
+        production: &[ParseType::T(90)],
+    },
+    // 257 - Percent: '%';
+    Production {
+        lhs: 68,
+        production: &[ParseType::T(91)],
+    },
+    // 258 - Multi: 'x';
+    Production {
+        lhs: 54,
+        production: &[ParseType::T(92)],
+    },
+    // 259 - Ordinal: "[1-9][0-9]*(st|nd|rd|th)\b";
+    Production {
+        lhs: 67,
+        production: &[ParseType::T(93)],
+    },
+    // 260 - PosInt: "[1-9][0-9]*";
+    Production {
+        lhs: 72,
+        production: &[ParseType::T(94)],
+    },
+];
+
+static TOKENIZERS: Lazy<Vec<(&'static str, Tokenizer)>> = Lazy::new(|| {
+    vec![(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+    )]
+});
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut SkillEnGrammar<'t>,
+) -> Result<Tree<ParseTreeType<'t>>>
+where
+    T: AsRef<Path>,
+{
+    let mut llk_parser = LLKParser::new(
+        92,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let token_stream =
+        RefCell::new(TokenStream::new(input, file_name, &TOKENIZERS, MAX_K).unwrap());
+    // Initialize wrapper
+    let mut user_actions = SkillEnGrammarAuto::new(user_actions);
+    let result = llk_parser.parse(token_stream, &mut user_actions);
+    match result {
+        Ok(()) => Ok(llk_parser.parse_tree),
+        Err(e) => Err(e),
+    }
+}
@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 use std::ops::Neg;
 
+use super::drop_set::DropSet;
 use super::schema::*;
 use super::skill::*;
 use super::skill_grammar_trait::SkillGrammarTrait;
@@ -24,18 +25,17 @@ pub(super) enum TmpItem {
     SkillEffect(SkillEffect),
 }
 
-impl<'t> SkillGrammar<'_> {
-    pub(super) fn pop(self: &mut Self) -> StackItem {
-        self.stack
-            .pop()
-            .expect("from SkillGrammar::pop. stack is 0.")
+impl SkillGrammar<'_> {
+    /// 末尾を取り出す。スタックが空の場合は、本文に必要な語が足りないのでエラー
+    pub(super) fn pop(self: &mut Self) -> miette::Result<StackItem> {
+        self.stack.pop().ok_or_else(missing_item)
     }
 
     pub(super) fn pop_if<F>(self: &mut Self, fun: F) -> Option<StackItem>
     where
         F: FnOnce(&StackItem) -> bool,
     {
-        let last = self.pop();
+        let last = self.stack.pop()?;
 
         if fun(&last) {
             Some(last)
@@ -60,7 +60,7 @@ impl<'t> SkillGrammar<'_> {
     }
 
     pub(super) fn is_zero(self: &mut Self) -> bool {
-        self.stack.len() == 0
+        self.stack.is_empty()
     }
 
     pub(super) fn push(self: &mut Self, item: StackItem) {
@@ -75,10 +75,10 @@ impl<'t> SkillGrammar<'_> {
             TmpItem::SkillEffect(item) => Some(TmpItem::SkillEffect(item)),
         };
 
-        tmp.map(|item| {
+        if tmp.is_some() {
             self.tmp = TmpItem::None;
-            item
-        })
+        }
+        tmp
     }
 
     #[allow(dead_code)]
@@ -122,29 +122,34 @@ impl SkillGrammar<'_> {
             skill_list: Vec::new(),
             stack: Vec::new(),
             tmp: TmpItem::default(),
-            pd: PhantomData::default(),
+            pd: PhantomData,
         }
     }
 
+    /// スキル本文を正規化(`normalize::normalize`)してからパースする
+    pub fn parse_skills(input: &str) -> miette::Result<Vec<Skill>> {
+        let input = super::normalize::normalize(input);
+
+        let grammar = &mut SkillGrammar::new();
+        super::skill_parser::parse(&input, "<input>", grammar)?;
+
+        Ok(grammar.skill_list.to_vec())
+    }
+
     #[allow(dead_code)]
     fn show_stack(self: &Self) {
         self.show_stack_("")
     }
 
     #[allow(dead_code)]
-    fn show_stack_<'a>(self: &Self, from: &'a str) {
-        print!("---------------- START ----------------\n");
-        print!("from: {from}\n");
-        self.stack
-            .to_vec()
-            .into_iter()
-            .enumerate()
-            .rev()
-            .for_each(|(idx, item)| {
-                print!("{idx}  | {:?}\n", item);
-            });
-        print!("tmp| {:?}\n", self.tmp);
-        print!("----------------  END  ----------------\n");
+    fn show_stack_(self: &Self, from: &str) {
+        println!("---------------- START ----------------");
+        println!("from: {from}");
+        self.stack.iter().enumerate().rev().for_each(|(idx, item)| {
+            println!("{idx}  | {:?}", item);
+        });
+        println!("tmp| {:?}", self.tmp);
+        println!("----------------  END  ----------------");
     }
 }
 
@@ -152,7 +157,7 @@ impl SkillGrammar<'_> {
 impl SkillGrammar<'_> {
     /// stack の中身を全て取得し、空にする
     #[allow(dead_code)]
-    fn steal<'a>(self: &'a mut Self) -> Vec<StackItem> {
+    fn steal(self: &mut Self) -> Vec<StackItem> {
         let ret = self.stack.to_vec();
 
         self.stack.clear();
@@ -161,24 +166,24 @@ impl SkillGrammar<'_> {
 
     /// stack の中身を逆順にして全て取得し、空にする
     #[allow(dead_code)]
-    fn steal_rev<'a>(self: &'a mut Self) -> Vec<StackItem> {
+    fn steal_rev(self: &mut Self) -> Vec<StackItem> {
         let mut ret = self.steal();
         ret.reverse();
         ret
     }
 
     /// 条件に合う要素を終端から取得し、取得した要素はスタックから取り除く
-    fn steal_if<'a, F, M, R>(self: &'a mut Self, type_check_fun: F, map_fun: M) -> Vec<R>
+    fn steal_if<F, M, R>(self: &mut Self, type_check_fun: F, map_fun: M) -> miette::Result<Vec<R>>
     where
         F: Fn(&StackItem) -> bool,
-        M: Fn(&StackItem) -> R,
+        M: Fn(&StackItem) -> miette::Result<R>,
         R: Clone,
     {
         let ret: &mut Vec<R> = &mut Vec::new();
 
-        let mut result = self.steal_if_(ret, type_check_fun, map_fun).to_vec();
+        let mut result = self.steal_if_(ret, type_check_fun, map_fun)?.to_vec();
         result.reverse();
-        result
+        Ok(result)
     }
 
     fn steal_if_<'a, F, M, R>(
@@ -186,37 +191,37 @@ impl SkillGrammar<'_> {
         list: &'a mut Vec<R>,
         type_check_fun: F,
         map_fun: M,
-    ) -> &'a mut Vec<R>
+    ) -> miette::Result<&'a mut Vec<R>>
     where
         F: Fn(&StackItem) -> bool,
-        M: Fn(&StackItem) -> R,
+        M: Fn(&StackItem) -> miette::Result<R>,
     {
         if self.is_zero() {
             // 全て取得した
-            list
+            Ok(list)
         } else {
-            let last = self.pop();
+            let last = self.pop()?;
 
             if type_check_fun(&last) {
-                list.push(map_fun(&last));
+                list.push(map_fun(&last)?);
                 self.steal_if_(list, type_check_fun, map_fun)
             } else {
                 self.push(last);
-                list
+                Ok(list)
             }
         }
     }
 
     /// 単色変換用
-    fn build_change_drop_a_to_b<'a>(self: &'a mut Self, a: Drops, b: Drops) -> Skill {
+    fn build_change_drop_a_to_b(self: &mut Self, a: Drops, b: Drops) -> Skill {
         Skill {
-            effect: SkillEffect::ChangeDropAToB(a, b),
+            effect: SkillEffect::ChangeDropAToB(a.into(), b),
             ..Default::default()
         }
     }
 
     /// ドロップ全てに生成数を付与する
-    fn build_gen_drop_and_qty_list(to: Drops, qty: usize) -> GenDropsWithQty {
+    pub(super) fn build_gen_drop_and_qty_list(to: Drops, qty: usize) -> GenDropsWithQty {
         to.into_iter().map(|drop| (drop, qty)).collect()
     }
 
@@ -226,22 +231,23 @@ impl SkillGrammar<'_> {
     /// * 生成が4色以下: 指定している色＋生成色
     /// * 生成が5色以上: 指定している色のみ
     /// * 生成数が30  : なし
-    fn build_gen_random_drop_exc_from(specified: &mut Drops, to: &mut GenDropsWithQty) -> Drops {
-        let to_drops: &mut Drops = &mut to.into_iter().map(|e| e.clone().0).collect();
-        let gen_drop_sum: usize = to.into_iter().map(|e| e.1).sum();
-
-        print!("sum:{}\n", gen_drop_sum);
-
-        if dbg!((to_drops.to_vec().len() < 5) && (gen_drop_sum != 30)) {
+    pub(super) fn build_gen_random_drop_exc_from(
+        specified: &mut Drops,
+        to: &mut GenDropsWithQty,
+    ) -> DropSet {
+        let to_drops: &mut Drops = &mut to.iter().map(|e| e.clone().0).collect();
+        let gen_drop_sum: usize = to.iter().map(|e| e.1).sum();
+
+        if (to_drops.len() < 5) && (gen_drop_sum != 30) {
             // 4色以下の生成 & 生成数が30ではない
             specified.append(to_drops);
         }
-        specified.to_vec()
+        specified.iter().collect()
     }
 
-    fn push_gen_drop_and_qty_list<'a>(
-        self: &'a mut Self,
-        from: Drops,
+    fn push_gen_drop_and_qty_list(
+        self: &mut Self,
+        from: DropSet,
         gen_drop_and_qty_list: GenDropsWithQty,
     ) {
         let se = SkillEffect::GenRandomDrop(from, gen_drop_and_qty_list);
@@ -254,7 +260,7 @@ impl SkillGrammar<'_> {
         self.skill_list.push(skill);
     }
 
-    fn g_s_s_p_side_<'a>(self: &'a mut Self, gen_count: usize, position: Position) -> GenPositions {
+    fn g_s_s_p_side_(self: &mut Self, gen_count: usize, position: Position) -> GenPositions {
         let mut result: GenPositions = Vec::new();
 
         // N列分繰り返す
@@ -273,22 +279,6 @@ impl SkillGrammar<'_> {
 
         result
     }
-
-    /// 5属性＋回復、お邪魔、毒、猛毒、爆弾
-    fn all_drops_10() -> Drops {
-        vec![
-            Drop::Colored(Color::Fire),
-            Drop::Colored(Color::Water),
-            Drop::Colored(Color::Wood),
-            Drop::Colored(Color::Lightning),
-            Drop::Colored(Color::Dark),
-            Drop::NonColored(NonColoredDrop::Recovery),
-            Drop::NonColored(NonColoredDrop::Disturb),
-            Drop::NonColored(NonColoredDrop::Bomb),
-            Drop::NonColored(NonColoredDrop::Poison),
-            Drop::NonColored(NonColoredDrop::DeadlyPoison),
-        ]
-    }
 }
 
 impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
@@ -296,12 +286,12 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::StartsWithDropLine<'t>,
     ) -> miette::Result<()> {
-        let item = self.pop();
+        let item = self.pop()?;
 
         if item.is_drop() {
             // 単色変換
-            let to = item.drop();
-            let from = self.pop().drops();
+            let to = item.drop()?;
+            let from = self.pop()?.drops()?;
 
             let skill = self.build_change_drop_a_to_b(from, vec![to]);
             self.skill_list.push(skill);
@@ -317,29 +307,29 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
             }
         } else if item.is_drops() {
             // ランダムで複数色に変換
-            let from = self.pop().drops();
+            let from = self.pop()?.drops()?;
 
-            let skill = self.build_change_drop_a_to_b(from, item.drops());
+            let skill = self.build_change_drop_a_to_b(from, item.drops()?);
             self.skill_list.push(skill);
         } else if item.is_gen_drops_with_qty() {
             let mut list: Vec<GenDropsWithQty> = self.steal_if(
                 |e| e.is_gen_drops_with_qty(),
                 |e| e.clone().gen_drop_with_qty(),
-            );
+            )?;
 
-            list.push(item.gen_drop_with_qty());
+            list.push(item.gen_drop_with_qty()?);
 
             let first: &mut GenDropsWithQty = &mut Vec::new();
             let exc: &mut Drops = &mut Vec::new();
-            let qty_or_drops = self.pop();
+            let qty_or_drops = self.pop()?;
 
             if qty_or_drops.is_pos_int() {
-                let qty = qty_or_drops.pos_int();
-                let drops = self.pop().drops();
+                let qty = qty_or_drops.pos_int()?;
+                let drops = self.pop()?.drops()?;
 
                 first.append(&mut Self::build_gen_drop_and_qty_list(drops, qty))
             } else {
-                exc.append(&mut qty_or_drops.drops());
+                exc.append(&mut qty_or_drops.drops()?);
             };
 
             first.append(&mut list.concat());
@@ -348,7 +338,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
 
             self.push_gen_drop_and_qty_list(exc_from_drops, first.to_owned());
         } else if item.is_drop_powerup() {
-            let drops = self.pop().drops();
+            let drops = self.pop()?.drops()?;
 
             let skill = Skill {
                 effect: SkillEffect::DropPowerUp(drops),
@@ -358,13 +348,13 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
             self.skill_list.push(skill);
         } else {
             // ランダム生成
-            let qty = item.pos_int();
-            let drops = self.pop().drops();
+            let qty = item.pos_int()?;
+            let drops = self.pop()?.drops()?;
 
             let to = &mut Self::build_gen_drop_and_qty_list(drops, qty);
 
             let exc: &mut Drops = &mut if !self.is_zero() {
-                self.pop().drops()
+                self.pop()?.drops()?
             } else {
                 vec![]
             };
@@ -382,11 +372,11 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
     ) -> miette::Result<()> {
         // 指定型生成はすでにスキルリストにプッシュされているため、スタックは0となる
         if !self.is_zero() {
-            let item = self.pop();
+            let item = self.pop()?;
 
             if item.is_drops() {
                 // N色陣のみ
-                let drops = item.drops();
+                let drops = item.drops()?;
 
                 let skill = Skill {
                     effect: SkillEffect::ChangeAllOfBoard(drops),
@@ -396,14 +386,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
                 self.skill_list.push(skill);
             } else if item.is_drop_powerup() {
                 // 全ドロップ強化
-                let drops = vec![
-                    Drop::Colored(Color::Fire),
-                    Drop::Colored(Color::Water),
-                    Drop::Colored(Color::Wood),
-                    Drop::Colored(Color::Lightning),
-                    Drop::Colored(Color::Dark),
-                    Drop::NonColored(NonColoredDrop::Recovery),
-                ];
+                let drops = DropSet::five_attributes_and_recovery().to_drops();
 
                 let skill = Skill {
                     effect: SkillEffect::DropPowerUp(drops),
@@ -413,8 +396,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
                 self.skill_list.push(skill);
             } else {
                 // 陣→ランダム生成
-                let gen_drops_with_qty = item.gen_drop_with_qty();
-                let drops = self.pop().drops();
+                let gen_drops_with_qty = item.gen_drop_with_qty()?;
+                let drops = self.pop()?.drops()?;
 
                 let skill = Skill {
                     effect: SkillEffect::ChangeAllOfBoard(drops),
@@ -422,7 +405,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
                 };
 
                 self.skill_list.push(skill);
-                self.push_gen_drop_and_qty_list(vec![], gen_drops_with_qty);
+                self.push_gen_drop_and_qty_list(DropSet::empty(), gen_drops_with_qty);
             }
         }
         Ok(())
@@ -433,7 +416,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         _arg: &crate::skill_grammar_trait::DropUnLockStmt<'t>,
     ) -> miette::Result<()> {
         // Stackから削除する
-        let _ = self.pop();
+        self.pop()?;
         let skill = Skill {
             effect: SkillEffect::DropUnLock,
             ..Default::default()
@@ -460,10 +443,10 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::TowardsTheEnemyStmt<'t>,
     ) -> miette::Result<()> {
-        let se = self.pop().apply_in_turns_skill();
+        let se = self.pop()?.apply_in_turns_skill()?;
         let skill = Skill {
             effect: se,
-            turns_of_apply: Some(999),
+            turns_of_apply: Some(PERMANENT_TURNS),
             ..Default::default()
         };
         self.skill_list.push(skill);
@@ -475,7 +458,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::ChangeEnemyAttributeBlock<'t>,
     ) -> miette::Result<()> {
-        let se = SkillEffect::EnemyAttributeChange(self.pop().color());
+        let se = SkillEffect::EnemyAttributeChange(self.pop()?.color()?);
         self.push(StackItem::ApplyInTurnsSkill(se));
 
         Ok(())
@@ -486,18 +469,23 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         _arg: &crate::skill_grammar_trait::TurnsOfApplyStmt<'t>,
     ) -> miette::Result<()> {
         // 個数が指定されているならSome
-        let qty = self.pop_if(|i| i.is_pos_int()).map(|i| i.pos_int());
+        let qty = self
+            .pop_if(|i| i.is_pos_int())
+            .map(|i| i.pos_int())
+            .transpose()?;
 
         let se_list = self.steal_if(
             |i| i.is_apply_in_turns_skill(),
             |i| i.clone().apply_in_turns_skill(),
-        );
+        )?;
 
-        let turn = self.pop().pos_int();
+        let turn = self.pop()?.pos_int()?;
 
-        se_list.into_iter().for_each(|se| {
+        for se in se_list {
             let se = match se {
-                SkillEffect::GenRoulette(_) => SkillEffect::GenRoulette(qty.unwrap()),
+                SkillEffect::GenRoulette(_) => {
+                    SkillEffect::GenRoulette(qty.ok_or_else(missing_item)?)
+                }
                 other => other,
             };
 
@@ -508,7 +496,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
             };
 
             self.skill_list.push(skill);
-        });
+        }
 
         Ok(())
     }
@@ -519,12 +507,12 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::ChangeDropBlockOtherFirst<'t>,
     ) -> miette::Result<()> {
-        let to = self.pop().drop();
-        let mut from = self.pop().drops();
+        let to = self.pop()?.drop()?;
+        let mut from = self.pop()?.drops()?;
 
         // 0要素目は変換1色目で扱うドロップのため、スタックに積み直す
         from.reverse();
-        let for_before_group_drop = from.pop().unwrap();
+        let for_before_group_drop = from.pop().ok_or_else(missing_item)?;
         self.push(StackItem::Drop(for_before_group_drop));
         from.reverse();
 
@@ -541,7 +529,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
             .steal_if(
                 |e| e.is_gen_drops_with_qty(),
                 |e| e.clone().gen_drop_with_qty(),
-            )
+            )?
             .concat();
 
         let exc_from_drops = Self::build_gen_random_drop_exc_from(&mut vec![], list);
@@ -560,8 +548,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::GenRandomDropBlock<'t>,
     ) -> miette::Result<()> {
-        let gen_quantity = self.pop().pos_int();
-        let gen_drops = self.pop().drops();
+        let gen_quantity = self.pop()?.pos_int()?;
+        let gen_drops = self.pop()?.drops()?;
 
         let gen_drop_qty_list: &mut GenDropsWithQty =
             &mut Self::build_gen_drop_and_qty_list(gen_drops, gen_quantity);
@@ -580,7 +568,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         let drop_shape_gen_list: Vec<ShapeType> = shape_type_list
             .into_iter()
             .map(|se| se.shape_type())
-            .collect();
+            .collect::<miette::Result<_>>()?;
 
         let se = SkillEffect::DropShapeGen(drop_shape_gen_list);
 
@@ -596,8 +584,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::GenShapeBlockRowCol<'t>,
     ) -> miette::Result<()> {
-        let drop = self.pop().drop();
-        let positions = self.pop().gen_positions();
+        let drop = self.pop()?.drop()?;
+        let positions = self.pop()?.gen_positions()?;
 
         positions.into_iter().for_each(|p| {
             let shape_type = match p {
@@ -615,36 +603,35 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::GenShapeBlockOtherRowCol<'t>,
     ) -> miette::Result<()> {
-        self.show_stack_("gen_shape_block_other_row_col");
-        if self.stack.len() == 2 {
-            // 形状とドロップのみ
-            let drop = self.pop().drops().pop().unwrap();
-            let shape = self.pop().shape_type();
+        if !self.peek_check(|i| i.is_pos_int()) {
+            // 形状とドロップのみ(正方形の大きさ等が残っている場合も、生成個数が無いので`set_drop()`でエラー)
+            let drop = self.pop()?.drops()?.pop().ok_or_else(missing_item)?;
+            let shape = self.pop()?.shape_type()?;
 
             if shape.is_spiderweb() {
                 // 蜘蛛の巣状はデフォルト値で設定しているので未処理
                 self.push(StackItem::DropShapeGenShapeType(shape))
             } else {
-                self.push(StackItem::DropShapeGenShapeType(shape.set_drop(drop)));
+                self.push(StackItem::DropShapeGenShapeType(shape.set_drop(drop)?));
             }
         } else {
-            let qty = self.pop().pos_int();
-            let drop = self.pop().drops().pop().unwrap();
-            let shape = self.pop().shape_type();
+            let qty = self.pop()?.pos_int()?;
+            let drop = self.pop()?.drops()?.pop().ok_or_else(missing_item)?;
+            let shape = self.pop()?.shape_type()?;
 
             let shape = if shape.is_square() {
                 // 1辺だけ分かればいいので、片方は捨てる
-                let size = self.pop().size().0;
+                let size = self.pop()?.size()?.0;
 
                 shape.set_for_square(drop, size, qty)
             } else if shape.is_some_kind() {
                 // 現在では`7`の形のみなので通る。
                 // UnsignedInt 以外でスキルが追加された場合は対応が必要
 
-                let some_kind = self.pop().pos_int().to_string();
+                let some_kind = self.pop()?.pos_int()?.to_string();
                 shape.set_for_some_kind(drop, some_kind, qty)
             } else {
-                shape.set_with_qty(drop, qty)
+                shape.set_with_qty(drop, qty)?
             };
 
             self.push(StackItem::DropShapeGenShapeType(shape));
@@ -660,8 +647,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         let mut gen_positions_list: Vec<GenPositions> = Vec::new();
 
         while self.peek_check(|i| i.is_pos_int()) {
-            let gen_count = self.pop().pos_int();
-            let position = self.pop().position();
+            let gen_count = self.pop()?.pos_int()?;
+            let position = self.pop()?.position()?;
 
             gen_positions_list.push(self.g_s_s_p_side_(gen_count, position));
         }
@@ -678,8 +665,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::GSSPCenter<'t>,
     ) -> miette::Result<()> {
-        let gen_count = self.pop().pos_int();
-        let gen_positions = self.pop().gen_positions();
+        let gen_count = self.pop()?.pos_int()?;
+        let gen_positions = self.pop()?.gen_positions()?;
 
         let mut new_gen_positions: GenPositions = Vec::new();
 
@@ -709,7 +696,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         if self.stack.len() > 1 {
             // 2要素以上の場合、1つのリストにまとめる
             let list: GenPositions = self
-                .steal_if(|i| i.is_gen_positions(), |i| i.to_owned().gen_positions())
+                .steal_if(|i| i.is_gen_positions(), |i| i.to_owned().gen_positions())?
                 .concat();
 
             self.push(StackItem::GenPositions(list));
@@ -721,15 +708,15 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::GSSPCenterBlock<'t>,
     ) -> miette::Result<()> {
-        let gen_idx = self.pop().pos_int();
-        let position = self.pop().position();
+        let gen_idx = self.pop()?.pos_int()?;
+        let position = self.pop()?.position()?;
 
         let item = match position {
             Position::Left => GenShapeRowCol::Col(gen_idx as isize),
             Position::Right => GenShapeRowCol::Col((gen_idx as isize).neg()),
             Position::Top => GenShapeRowCol::Row(gen_idx as isize),
             Position::Bottom => GenShapeRowCol::Row((gen_idx as isize).neg()),
-            _ => panic!("from g_s_s_p_center_block. unexpected pattern!"),
+            Position::LeftAndRight => return Err(missing_item()),
         };
 
         self.push(StackItem::GenPositions(vec![item]));
@@ -741,7 +728,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         _arg: &crate::skill_grammar_trait::DropUnlockBlock<'t>,
     ) -> miette::Result<()> {
         // Stackから削除する
-        let _ = self.pop();
+        self.pop()?;
         let skill = Skill {
             effect: SkillEffect::DropUnLock,
             ..Default::default()
@@ -775,7 +762,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         _arg: &crate::skill_grammar_trait::Drops<'t>,
     ) -> parol_runtime::miette::Result<()> {
         let drops: Drops = self
-            .steal_if(|i| i.is_drop(), |i| i.clone().drop())
+            .steal_if(|i| i.is_drop(), |i| i.clone().drop())?
             .to_vec();
 
         self.push(StackItem::Drops(drops));
@@ -787,9 +774,10 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         _arg: &crate::skill_grammar_trait::Drop<'t>,
     ) -> parol_runtime::miette::Result<()> {
         // 色ならドロップに変換、色なしドロップはそのまま
-        let drop = self
-            .pop_if(|i| i.is_color())
-            .map_or_else(|| self.pop().drop(), |item| Drop::Colored(item.color()));
+        let drop = match self.pop_if(|i| i.is_color()) {
+            Some(item) => Drop::Colored(item.color()?),
+            None => self.pop()?.drop()?,
+        };
 
         self.push(StackItem::Drop(drop));
         Ok(())
@@ -1013,19 +1001,21 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
     ) -> miette::Result<()> {
         if self.pop_if(|i| i.is_drop_lock()).is_some() {
             // ロック目覚め
-            let drops = self.pop().drops();
+            let drops = self.pop()?.drops()?;
             let se = SkillEffect::FallLockDrop(drops);
 
             self.push(StackItem::ApplyInTurnsSkill(se));
         } else {
             let volume = self
                 .pop_if(|i| i.is_volume_variation())
-                .map_or_else(|| VolumeVariation::Normal, |i| i.volume_variation());
+                .map(|i| i.volume_variation())
+                .transpose()?
+                .unwrap_or(VolumeVariation::Normal);
 
             // 強化ドロップが複合しているならSome
             let powerup_drop = self.pop_if(|i| i.is_drop_powerup());
 
-            let drops = self.pop().drops();
+            let drops = self.pop()?.drops()?;
 
             let effect = SkillEffect::DropFalloff(drops, volume.clone());
 
@@ -1050,13 +1040,16 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         let mut drop_falloff: Option<Drops> = None;
 
         let kind = if let Some(percent) = self.pop_if(|i| i.is_pos_int()) {
-            let _ = self.pop(); // WordPowerup
+            self.pop()?; // WordPowerup
 
-            PowerupDropFalloffKind::Num(percent.pos_int())
+            PowerupDropFalloffKind::Num(percent.pos_int()?)
         } else {
-            let _ = self.pop(); // WordLittle
-            drop_falloff = self.pop_if(|i| i.is_drops()).map(|i| i.drops());
-            let _ = self.pop(); // WordPowerup
+            self.pop()?; // WordLittle
+            drop_falloff = self
+                .pop_if(|i| i.is_drops())
+                .map(|i| i.drops())
+                .transpose()?;
+            self.pop()?; // WordPowerup
 
             PowerupDropFalloffKind::VolumeVariation(VolumeVariation::Little)
         };
@@ -1071,7 +1064,6 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
             self.push(StackItem::ApplyInTurnsSkill(se));
         }
 
-        self.show_stack();
         Ok(())
     }
 
@@ -1079,8 +1071,8 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::FallLockDropOfAll<'t>,
     ) -> miette::Result<()> {
-        let _ = self.pop(); // WordLock
-        let se = SkillEffect::FallLockDrop(Self::all_drops_10());
+        self.pop()?; // WordLock
+        let se = SkillEffect::FallLockDrop(DropSet::all().to_drops());
 
         self.push(StackItem::ApplyInTurnsSkill(se));
         Ok(())
@@ -1092,7 +1084,9 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
     ) -> miette::Result<()> {
         let volume = self
             .pop_if(|i| i.is_volume_variation())
-            .map_or_else(|| VolumeVariation::Normal, |i| i.volume_variation());
+            .map(|i| i.volume_variation())
+            .transpose()?
+            .unwrap_or(VolumeVariation::Normal);
 
         let se = SkillEffect::FallNailDropEasierToFalloff(volume);
         self.push(StackItem::ApplyInTurnsSkill(se));
@@ -1103,34 +1097,45 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
         &mut self,
         _arg: &crate::skill_grammar_trait::BoardChange<'t>,
     ) -> miette::Result<()> {
-        let se = self.pop().apply_in_turns_skill();
-        let size = self.pop_if(|i| i.is_size()).map(|i| i.size());
+        let se = self.pop()?.apply_in_turns_skill()?;
+        let size = self.pop_if(|i| i.is_size()).map(|i| i.size()).transpose()?;
 
         if size.is_none() {
-            let _ = self.pop();
+            self.pop()?;
         } // PosInt N列
 
-        let position = self.pop_if(|i| i.is_position()).map_or_else(
-            || BoardPosition::Random,
-            |i| BoardPosition::from(i.position()),
-        );
+        let position = match self.pop_if(|i| i.is_position()) {
+            Some(i) => match i.position()? {
+                // 両端の雲、テープは無い
+                Position::LeftAndRight => {
+                    return Err(StackItem::Position(Position::LeftAndRight).unexpected("one side"))
+                }
+                position => BoardPosition::from(position),
+            },
+            None => BoardPosition::Random,
+        };
 
         let se = match se {
-            SkillEffect::GenCloud(_, _) => SkillEffect::GenCloud(position, size.unwrap()),
+            SkillEffect::GenCloud(_, _) => {
+                SkillEffect::GenCloud(position, size.ok_or_else(missing_item)?)
+            }
             SkillEffect::GenTeap(_) => SkillEffect::GenTeap(position),
-            SkillEffect::ChangeBoardSize(_) => SkillEffect::ChangeBoardSize(size.unwrap()),
-            _ => todo!(),
+            SkillEffect::ChangeBoardSize(_) => {
+                SkillEffect::ChangeBoardSize(size.ok_or_else(missing_item)?)
+            }
+            other => return Err(StackItem::ApplyInTurnsSkill(other).unexpected("a board effect")),
         };
 
         self.push(StackItem::ApplyInTurnsSkill(se));
         Ok(())
     }
 
+    /// 7×6
     fn size(&mut self, _arg: &crate::skill_grammar_trait::Size<'t>) -> miette::Result<()> {
-        let col = self.pop().pos_int();
-        let row = self.pop().pos_int();
+        let height = self.pop()?.pos_int()?;
+        let width = self.pop()?.pos_int()?;
 
-        self.push(StackItem::Size(Size(row, col)));
+        self.push(StackItem::Size(Size(width, height)));
 
         Ok(())
     }
@@ -1298,7 +1303,9 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
 
     fn pos_int(&mut self, arg: &crate::skill_grammar_trait::PosInt<'t>) -> miette::Result<()> {
         let text = arg.pos_int.text();
-        let num = text.parse::<usize>().unwrap();
+        let num = text
+            .parse::<usize>()
+            .map_err(|e| miette::miette!("invalid number {text:?}: {e}"))?;
 
         self.push(StackItem::PosInt(num));
 
@@ -1307,7 +1314,7 @@ impl<'t> SkillGrammarTrait<'t> for SkillGrammar<'t> {
 
     fn on_board(&mut self, _arg: &crate::skill_grammar_trait::OnBoard<'t>) -> miette::Result<()> {
         // パターンを引っ掛けたいだけなのでスタックから削除する
-        let _ = self.pop();
+        self.pop()?;
 
         Ok(())
     }
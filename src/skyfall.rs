@@ -0,0 +1,216 @@
+use miette::bail;
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::skill::*;
+
+/// 通常時に落ちてくるドロップの重み
+pub const BASE_WEIGHT: f64 = 1.0;
+
+/// `ほんの少し`、`少し`、度合いの指定なし毎の値
+/// ゲーム内で実数値が公開されていないため、既定値(`DEFAULT_SKYFALL_BONUS`、`DEFAULT_POWERUP_RATE`)は目安。
+/// 実測値などがあれば`Skyfall::with_tables()`で差し替える。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolumeTable {
+    /// ほんの少し
+    pub little_more: f64,
+    /// 少し
+    pub little: f64,
+    /// 度合いの指定なし
+    pub normal: f64,
+}
+
+impl VolumeTable {
+    /// 度合いに対応する値。`のみ`は度合いを持たないため`None`
+    pub fn get(self: &Self, volume: &VolumeVariation) -> Option<f64> {
+        match volume {
+            VolumeVariation::LittleMore => Some(self.little_more),
+            VolumeVariation::Little => Some(self.little),
+            VolumeVariation::Normal => Some(self.normal),
+            VolumeVariation::Only => None,
+        }
+    }
+
+    fn values(self: &Self) -> [f64; 3] {
+        [self.little_more, self.little, self.normal]
+    }
+}
+
+/// 落ちやすくなる度合いの、通常の重みへの加算値の既定値
+pub const DEFAULT_SKYFALL_BONUS: VolumeTable = VolumeTable {
+    little_more: 0.25,
+    little: 0.5,
+    normal: 1.0,
+};
+
+/// 強化ドロップの出現率が`少し`の場合など、実数値が明記されていない場合の確率の既定値
+pub const DEFAULT_POWERUP_RATE: VolumeTable = VolumeTable {
+    little_more: 0.1,
+    little: 0.2,
+    normal: 0.3,
+};
+
+impl PowerupDropFalloffKind {
+    /// 強化ドロップが落ちてくる確率。度合いで指定されている場合は`rates`の値を使う
+    pub fn rate(self: &Self, rates: &VolumeTable) -> f64 {
+        match self {
+            Self::Num(percent) => (*percent as f64 / 100.0).min(1.0),
+            Self::VolumeVariation(volume) => rates.get(volume).unwrap_or(1.0),
+        }
+    }
+}
+
+/// 落ちてくるドロップの出現モデル
+/// `DropFalloff`、`PowerupDropFalloff`を適用した結果を保持する。
+/// 値は`with_tables()`で検証済みのものだけを持つよう、スキルの適用以外では変更できない。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Skyfall {
+    /// ドロップ毎の出現の重み。重みが0のドロップは落ちてこない。
+    weights: Vec<(Drop, f64)>,
+    /// 落ちてくるドロップが強化ドロップである確率
+    powerup_rate: f64,
+    /// 落ちやすくなる度合い毎の、通常の重みへの加算値
+    skyfall_bonus: VolumeTable,
+    /// 度合いで指定された場合の強化ドロップの出現率
+    powerup_rates: VolumeTable,
+}
+
+impl Default for Skyfall {
+    /// 5属性+回復が均等に落ちてくる
+    fn default() -> Self {
+        let weights = DropSet::five_attributes_and_recovery()
+            .iter()
+            .map(|drop| (drop, BASE_WEIGHT))
+            .collect();
+
+        Skyfall {
+            weights,
+            powerup_rate: 0.0,
+            skyfall_bonus: DEFAULT_SKYFALL_BONUS,
+            powerup_rates: DEFAULT_POWERUP_RATE,
+        }
+    }
+}
+
+impl Skyfall {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 落ちやすくなる度合いの加算値と、強化ドロップの出現率を指定する。
+    /// 加算値は0以上、出現率は0〜1でなければエラー(NaNもエラー)
+    pub fn with_tables(
+        skyfall_bonus: VolumeTable,
+        powerup_rates: VolumeTable,
+    ) -> miette::Result<Self> {
+        if !skyfall_bonus
+            .values()
+            .iter()
+            .all(|bonus| bonus.is_finite() && *bonus >= 0.0)
+        {
+            bail!("skyfall bonus must be a finite non-negative number: {skyfall_bonus:?}");
+        }
+        if !powerup_rates
+            .values()
+            .iter()
+            .all(|rate| (0.0..=1.0).contains(rate))
+        {
+            bail!("powerup rate must be between 0 and 1: {powerup_rates:?}");
+        }
+
+        Ok(Skyfall {
+            skyfall_bonus,
+            powerup_rates,
+            ..Self::default()
+        })
+    }
+
+    /// ドロップ毎の出現の重み。重みが0のドロップは落ちてこない。
+    pub fn weights(self: &Self) -> &[(Drop, f64)] {
+        &self.weights
+    }
+
+    /// 落ちてくるドロップが強化ドロップである確率
+    pub fn powerup_rate(self: &Self) -> f64 {
+        self.powerup_rate
+    }
+
+    /// 落ちやすくなる度合い毎の、通常の重みへの加算値
+    pub fn skyfall_bonus(self: &Self) -> &VolumeTable {
+        &self.skyfall_bonus
+    }
+
+    /// 度合いで指定された場合の強化ドロップの出現率
+    pub fn powerup_rates(self: &Self) -> &VolumeTable {
+        &self.powerup_rates
+    }
+
+    /// スキルリストのうち、落ちてくるドロップに関わるスキルを順に適用する
+    pub fn from_skills(skills: &[Skill]) -> Self {
+        let mut skyfall = Self::new();
+
+        skills.iter().for_each(|skill| skyfall.apply(skill));
+        skyfall
+    }
+
+    /// スキルを適用する。同じドロップに対する指定は後から適用したスキルで上書きする。
+    pub fn apply(self: &mut Self, skill: &Skill) {
+        match &skill.effect {
+            SkillEffect::DropFalloff(drops, VolumeVariation::Only) => {
+                self.weights
+                    .iter_mut()
+                    .for_each(|(_, weight)| *weight = 0.0);
+
+                drops
+                    .iter()
+                    .for_each(|drop| self.set_weight(drop, BASE_WEIGHT));
+            }
+            SkillEffect::DropFalloff(drops, volume) => {
+                drops.iter().for_each(|drop| {
+                    let base = if DropSet::five_attributes_and_recovery().contains(drop) {
+                        BASE_WEIGHT
+                    } else {
+                        0.0
+                    };
+
+                    let bonus = self.skyfall_bonus.get(volume).unwrap_or(0.0);
+                    self.set_weight(drop, base + bonus);
+                });
+            }
+            SkillEffect::PowerupDropFalloff(kind) => {
+                self.powerup_rate = kind.rate(&self.powerup_rates)
+            }
+            _ => (),
+        }
+    }
+
+    /// 次に落ちてくるドロップの確率分布。落ちてこないドロップは含まない。
+    /// 全ての重みが0の場合は、落ちてくるドロップが無いため空になる。
+    pub fn distribution(self: &Self) -> Vec<(Drop, f64)> {
+        let falling = || self.weights.iter().filter(|(_, weight)| *weight > 0.0);
+        let total: f64 = falling().map(|(_, weight)| weight).sum();
+
+        if total <= 0.0 || !total.is_finite() {
+            return Vec::new();
+        }
+
+        falling()
+            .map(|(drop, weight)| (drop.clone(), weight / total))
+            .collect()
+    }
+
+    /// 指定したドロップが次に落ちてくる確率
+    pub fn probability(self: &Self, drop: &Drop) -> f64 {
+        self.distribution()
+            .into_iter()
+            .find(|(d, _)| d == drop)
+            .map_or(0.0, |(_, p)| p)
+    }
+
+    fn set_weight(self: &mut Self, drop: &Drop, weight: f64) {
+        match self.weights.iter_mut().find(|(d, _)| d == drop) {
+            Some((_, w)) => *w = weight,
+            None => self.weights.push((drop.clone(), weight)),
+        }
+    }
+}
@@ -1,5 +1,13 @@
 use super::{schema::*, skill::*};
 
+/// 文法上は読めたが、スキルを組み立てるのに必要な語が足りない本文のエラー
+pub(super) fn missing_item() -> miette::Report {
+    miette::miette!(
+        help = "the text matches the grammar but no known skill has this form",
+        "incomplete skill text: a drop, number or target is missing"
+    )
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub(super) enum StackItem {
     Color(Color),
@@ -19,80 +27,88 @@ pub(super) enum StackItem {
 
 #[allow(dead_code)]
 impl StackItem {
-    pub(super) fn color(self: Self) -> Color {
+    /// 想定と異なる語が積まれていた場合のエラー
+    pub(super) fn unexpected(self: &Self, expected: &str) -> miette::Report {
+        miette::miette!(
+            help = "the text matches the grammar but no known skill has this form",
+            "unsupported skill text: expected {expected} but found {self:?}"
+        )
+    }
+
+    pub(super) fn color(self: Self) -> miette::Result<Color> {
         match self {
-            Self::Color(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't Color!"),
+            Self::Color(elem) => Ok(elem),
+            other => Err(other.unexpected("Color")),
         }
     }
 
-    pub(super) fn drop(self: Self) -> Drop {
+    pub(super) fn drop(self: Self) -> miette::Result<Drop> {
         match self {
-            Self::Drop(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't Drop!"),
+            Self::Drop(elem) => Ok(elem),
+            other => Err(other.unexpected("Drop")),
         }
     }
 
-    pub(super) fn drops(self: Self) -> Drops {
+    pub(super) fn drops(self: Self) -> miette::Result<Drops> {
         match self {
-            Self::Drops(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't Drops!"),
+            Self::Drops(elem) => Ok(elem),
+            other => Err(other.unexpected("Drops")),
         }
     }
 
-    pub(super) fn gen_drop_with_qty(self: Self) -> GenDropsWithQty {
+    pub(super) fn gen_drop_with_qty(self: Self) -> miette::Result<GenDropsWithQty> {
         match self {
-            Self::GenDropsWithQty(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't GenDropsWithQty!"),
+            Self::GenDropsWithQty(elem) => Ok(elem),
+            other => Err(other.unexpected("GenDropsWithQty")),
         }
     }
 
-    pub(super) fn pos_int(self: Self) -> usize {
+    pub(super) fn pos_int(self: Self) -> miette::Result<usize> {
         match self {
-            Self::PosInt(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't PosInt!"),
+            Self::PosInt(elem) => Ok(elem),
+            other => Err(other.unexpected("PosInt")),
         }
     }
 
-    pub(super) fn position(self: Self) -> Position {
+    pub(super) fn position(self: Self) -> miette::Result<Position> {
         match self {
-            Self::Position(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't Position!"),
+            Self::Position(elem) => Ok(elem),
+            other => Err(other.unexpected("Position")),
         }
     }
 
-    pub(super) fn gen_positions(self: Self) -> GenPositions {
+    pub(super) fn gen_positions(self: Self) -> miette::Result<GenPositions> {
         match self {
-            Self::GenPositions(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't GenPositions!"),
+            Self::GenPositions(elem) => Ok(elem),
+            other => Err(other.unexpected("GenPositions")),
         }
     }
 
-    pub(super) fn shape_type(self: Self) -> ShapeType {
+    pub(super) fn shape_type(self: Self) -> miette::Result<ShapeType> {
         match self {
-            Self::DropShapeGenShapeType(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't ShapeType!"),
+            Self::DropShapeGenShapeType(elem) => Ok(elem),
+            other => Err(other.unexpected("ShapeType")),
         }
     }
 
-    pub(super) fn apply_in_turns_skill(self: Self) -> SkillEffect {
+    pub(super) fn apply_in_turns_skill(self: Self) -> miette::Result<SkillEffect> {
         match self {
-            Self::ApplyInTurnsSkill(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't ApplyInTurnsSkill!"),
+            Self::ApplyInTurnsSkill(elem) => Ok(elem),
+            other => Err(other.unexpected("ApplyInTurnsSkill")),
         }
     }
 
-    pub(super) fn volume_variation(self: Self) -> VolumeVariation {
+    pub(super) fn volume_variation(self: Self) -> miette::Result<VolumeVariation> {
         match self {
-            Self::VolumeVariation(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't VolumeVariation!"),
+            Self::VolumeVariation(elem) => Ok(elem),
+            other => Err(other.unexpected("VolumeVariation")),
         }
     }
 
-    pub(super) fn size(self: Self) -> Size {
+    pub(super) fn size(self: Self) -> miette::Result<Size> {
         match self {
-            Self::Size(elem) => elem,
-            _ => panic!("from StackItem::pop(). this Item isn't Size!"),
+            Self::Size(elem) => Ok(elem),
+            other => Err(other.unexpected("Size")),
         }
     }
 
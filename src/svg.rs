@@ -0,0 +1,83 @@
+//! 形状生成スキルのSVG画像
+//!
+//! 盤面のマスを格子で描き、形状生成が埋めるマスをドロップの色の円で描く。
+//! 外部ファイルを参照しない単体のSVGとして出力する。
+
+use super::schema::*;
+use super::shape::ShapeMask;
+use super::skill::*;
+
+/// 1マスの大きさ(px)
+pub const CELL_SIZE: usize = 40;
+
+/// 盤面のマスの色。市松模様になるよう交互に使う
+const CELL_COLORS: [&str; 2] = ["#5b3a1e", "#6e4826"];
+
+impl Drop {
+    /// SVGで塗るドロップの色
+    pub fn svg_color(self: &Self) -> &'static str {
+        match self {
+            Drop::Colored(Color::Fire) => "#e8452c",
+            Drop::Colored(Color::Water) => "#2f8be6",
+            Drop::Colored(Color::Wood) => "#3cb043",
+            Drop::Colored(Color::Lightning) => "#f2c80f",
+            Drop::Colored(Color::Dark) => "#9b4fd1",
+            Drop::NonColored(NonColoredDrop::Recovery) => "#f27eb6",
+            Drop::NonColored(NonColoredDrop::Disturb) => "#8a8a8a",
+            Drop::NonColored(NonColoredDrop::Poison) => "#7b3fa0",
+            Drop::NonColored(NonColoredDrop::DeadlyPoison) => "#3d1a4f",
+            Drop::NonColored(NonColoredDrop::Bomb) => "#2b2b2b",
+        }
+    }
+}
+
+/// 形状生成が埋めるマスをSVGで描画する
+pub fn render_mask(mask: &ShapeMask, size: &Size) -> String {
+    let width = size.width() * CELL_SIZE;
+    let height = size.height() * CELL_SIZE;
+    let radius = CELL_SIZE * 2 / 5;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+
+    for row in 0..size.height() {
+        for col in 0..size.width() {
+            svg.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{CELL_SIZE}\" height=\"{CELL_SIZE}\" fill=\"{}\"/>\n",
+                col * CELL_SIZE,
+                row * CELL_SIZE,
+                CELL_COLORS[(row + col) % 2]
+            ));
+        }
+    }
+
+    for (row, col, drop) in mask {
+        svg.push_str(&format!(
+            "  <circle cx=\"{}\" cy=\"{}\" r=\"{radius}\" fill=\"{}\"/>\n",
+            col * CELL_SIZE + CELL_SIZE / 2,
+            row * CELL_SIZE + CELL_SIZE / 2,
+            drop.svg_color()
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+impl ShapeType {
+    /// 指定サイズの盤面で、形状生成が埋めるマスをSVGで描画する
+    pub fn to_svg(self: &Self, size: &Size) -> miette::Result<String> {
+        Ok(render_mask(&self.mask(size)?, size))
+    }
+}
+
+impl SkillEffect {
+    /// 形状生成スキルが埋めるマスをSVGで描画する。形状生成以外のスキルは`None`
+    pub fn to_svg(self: &Self, size: &Size) -> miette::Result<Option<String>> {
+        match self {
+            SkillEffect::DropShapeGen(_) => Ok(Some(render_mask(&self.shape_mask(size)?, size))),
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! 盤面と形状生成の端末表示
+//!
+//! `cargo run --example preview -- --emoji <スキル本文>`で、スキルを空の盤面へ適用した結果を確認できる。
+
+use super::board::*;
+use super::schema::*;
+use super::skill::*;
+
+/// 端末へ盤面を表示する際の形式
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TerminalStyle {
+    /// テキスト表記の文字をANSIカラーで色付けする。
+    /// 雲は白背景、テープは下線、ルーレットは点滅、強化は`+`、ロックは`*`で表す。
+    #[default]
+    Ansi,
+    /// ドロップを絵文字で表す。
+    /// 強化は`+`、ロックは`*`、雲は`?`、テープは`#`、ルーレットは`@`で表す。
+    Emoji,
+}
+
+impl Drop {
+    /// ANSIカラーの前景色
+    fn ansi_color(self: &Self) -> &'static str {
+        match self {
+            Drop::Colored(Color::Fire) => "31",
+            Drop::Colored(Color::Water) => "34",
+            Drop::Colored(Color::Wood) => "32",
+            Drop::Colored(Color::Lightning) => "33",
+            Drop::Colored(Color::Dark) => "35",
+            Drop::NonColored(NonColoredDrop::Recovery) => "95",
+            Drop::NonColored(NonColoredDrop::Disturb) => "90",
+            Drop::NonColored(NonColoredDrop::Poison) => "94",
+            Drop::NonColored(NonColoredDrop::DeadlyPoison) => "91",
+            Drop::NonColored(NonColoredDrop::Bomb) => "37",
+        }
+    }
+
+    /// 絵文字表記
+    pub fn emoji(self: &Self) -> &'static str {
+        match self {
+            Drop::Colored(Color::Fire) => "🔥",
+            Drop::Colored(Color::Water) => "💧",
+            Drop::Colored(Color::Wood) => "🌿",
+            Drop::Colored(Color::Lightning) => "🌟",
+            Drop::Colored(Color::Dark) => "🌙",
+            Drop::NonColored(NonColoredDrop::Recovery) => "💗",
+            Drop::NonColored(NonColoredDrop::Disturb) => "🧱",
+            Drop::NonColored(NonColoredDrop::Poison) => "🟣",
+            Drop::NonColored(NonColoredDrop::DeadlyPoison) => "💀",
+            Drop::NonColored(NonColoredDrop::Bomb) => "💣",
+        }
+    }
+}
+
+impl Board {
+    /// 端末表示用の文字列。段は改行で区切る
+    pub fn render(self: &Self, style: &TerminalStyle) -> String {
+        let markers = |cell: &Cell| -> String {
+            let mut result = String::new();
+            if cell.powered_up {
+                result.push('+');
+            }
+            if cell.locked {
+                result.push('*');
+            }
+            if *style == TerminalStyle::Emoji {
+                if cell.cloud {
+                    result.push('?');
+                }
+                if cell.tape {
+                    result.push('#');
+                }
+                if cell.roulette {
+                    result.push('@');
+                }
+            }
+            result
+        };
+
+        // 記号の数が違っても列が揃うように、最大の記号数で埋める
+        let width = self
+            .cells()
+            .iter()
+            .map(|cell| markers(cell).len())
+            .max()
+            .unwrap_or(0);
+
+        self.rows()
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| {
+                        let text = format!("{:<width$}", markers(cell));
+
+                        match style {
+                            TerminalStyle::Ansi => Self::render_ansi(cell, &text),
+                            TerminalStyle::Emoji => {
+                                let drop = cell.drop.as_ref().map_or("⬛", |drop| drop.emoji());
+                                format!("{drop}{text}")
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_ansi(cell: &Cell, markers: &str) -> String {
+        let mut codes: Vec<&str> = Vec::new();
+
+        if let Some(drop) = &cell.drop {
+            codes.push("1");
+            codes.push(drop.ansi_color());
+        } else {
+            codes.push("2");
+        }
+        if cell.cloud {
+            codes.push("47");
+        }
+        if cell.tape {
+            codes.push("4");
+        }
+        if cell.roulette {
+            codes.push("5");
+        }
+
+        let letter = cell.drop.as_ref().map_or('.', |drop| drop.notation());
+        format!("\x1b[{}m{letter}{markers}\x1b[0m", codes.join(";"))
+    }
+}
+
+impl ShapeType {
+    /// 空の盤面に形状生成したマスのみを描画する
+    pub fn render(self: &Self, size: &Size, style: &TerminalStyle) -> miette::Result<String> {
+        let mut board = Board::new(size.clone());
+
+        for (row, col, drop) in self.mask(size)? {
+            board.get_mut(row, col)?.replace(drop);
+        }
+
+        Ok(board.render(style))
+    }
+}
@@ -0,0 +1,49 @@
+//! 日本語、英語のスキル本文への変換で共通する、表記の判断
+//!
+//! 言語毎の文言は`japanese.rs`、`english.rs`に置き、どの値をどう記載するかの判断をここにまとめる。
+
+use super::drop_set::DropSet;
+use super::schema::*;
+use super::skill::*;
+
+/// 本文に記載するターン数。ターン数の記載が無い敵の属性変化(`PERMANENT_TURNS`)は`None`
+pub(crate) fn stated_turns(skill: &Skill) -> Option<usize> {
+    match (&skill.effect, skill.turns_of_apply) {
+        (SkillEffect::EnemyAttributeChange(_), Some(PERMANENT_TURNS)) => None,
+        (_, turns) => turns,
+    }
+}
+
+/// `SubEffect::Floor`、`SubEffect::HitPoint`の値が表す範囲。負の値は以前、以下を表す
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Threshold {
+    /// 以降、以上
+    AtLeast(usize),
+    /// 以前、以下
+    AtMost(usize),
+}
+
+impl From<isize> for Threshold {
+    fn from(value: isize) -> Self {
+        if value.is_negative() {
+            Threshold::AtMost(value.unsigned_abs())
+        } else {
+            Threshold::AtLeast(value.unsigned_abs())
+        }
+    }
+}
+
+/// ランダム生成で`X以外から`として記載するドロップ
+/// * 除外するドロップが生成するドロップを全て含む場合は、生成するドロップを除いたもの
+/// * それ以外は除外するドロップ
+///
+/// 空の場合は`ランダムで`と記載する。
+pub(crate) fn gen_random_source(exc: &DropSet, to: &GenDropsWithQty) -> DropSet {
+    let to_set: DropSet = to.iter().map(|(drop, _)| drop).collect();
+
+    if to_set.is_subset(exc) {
+        exc.difference(&to_set)
+    } else {
+        *exc
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod active_skill_test {
+    use pad_skill_parser::active_skill::*;
+    use pad_skill_parser::skill_grammar::*;
+
+    #[test]
+    fn single_line() {
+        let skill = ActiveSkill::parse(
+            "スキル名: 紅蓮の陣 / ターン: 12→6 / 効果: 全ドロップを火と光に変化。",
+        )
+        .unwrap();
+
+        assert_eq!("紅蓮の陣", skill.name);
+        assert_eq!(12, skill.max_cooldown);
+        assert_eq!(6, skill.min_cooldown);
+        assert_eq!(
+            SkillGrammar::parse_skills("全ドロップを火と光に変化。").unwrap(),
+            skill.effects
+        );
+    }
+
+    #[test]
+    fn multi_line() {
+        let skill = ActiveSkill::parse(
+            "名前：落ちコン封じ\nターン数：８\nスキル効果：1ターンの間、落ちコンなし。\n",
+        )
+        .unwrap();
+
+        assert_eq!("落ちコン封じ", skill.name);
+        assert_eq!((8, 8), (skill.max_cooldown, skill.min_cooldown));
+        assert_eq!(1, skill.effects.len());
+    }
+
+    #[test]
+    fn multi_line_effects() {
+        let skill = ActiveSkill::parse(
+            "スキル名: 紅蓮/蒼海の陣 / ターン: 8\n効果: ドロップのロックを解除し、\n右端1列を光に変化。\n",
+        )
+        .unwrap();
+
+        assert_eq!("紅蓮/蒼海の陣", skill.name);
+        assert_eq!(
+            SkillGrammar::parse_skills("ドロップのロックを解除し、右端1列を光に変化。").unwrap(),
+            skill.effects
+        );
+    }
+
+    #[test]
+    fn cooldown_formats() {
+        let cooldown = |turns: &str| {
+            let skill = ActiveSkill::parse(&format!(
+                "スキル名: a / ターン: {turns} / 効果: 全ドロップを強化。"
+            ))
+            .unwrap();
+            (skill.max_cooldown, skill.min_cooldown)
+        };
+
+        assert_eq!((12, 6), cooldown("12 -> 6"));
+        assert_eq!((10, 5), cooldown("10ターン→5ターン"));
+        assert_eq!((7, 7), cooldown("7"));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let input = "スキル名: 紅蓮の陣 / ターン: 12→6 / 効果: 全ドロップを火と光ドロップに変化。";
+        let skill: ActiveSkill = input.parse().unwrap();
+
+        assert_eq!(input, skill.to_string());
+        assert_eq!(skill, skill.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn invalid_listing() {
+        // ターンが無い
+        assert!(ActiveSkill::parse("スキル名: a / 効果: 全ドロップを強化。").is_err());
+        // 最短ターンが最大ターンより長い
+        assert!(
+            ActiveSkill::parse("スキル名: a / ターン: 5→8 / 効果: 全ドロップを強化。").is_err()
+        );
+        // 未知の項目は効果の続きになり、パースできない
+        assert!(ActiveSkill::parse(
+            "スキル名: a / ターン: 8 / 効果: 全ドロップを強化。 / 属性: 火"
+        )
+        .is_err());
+        // 効果をパースできない
+        assert!(ActiveSkill::parse("スキル名: a / ターン: 8 / 効果: だめ").is_err());
+    }
+
+    #[test]
+    fn with_assist() {
+        let base: ActiveSkill = "スキル名: 陣 / ターン: 12→6 / 効果: 全ドロップを火と光に変化。"
+            .parse()
+            .unwrap();
+        let assist: ActiveSkill =
+            "スキル名: 落ちコン封じ / ターン: 8 / 効果: 1ターンの間、落ちコンなし。"
+                .parse()
+                .unwrap();
+
+        let combined = base.with_assist(&assist);
+
+        assert_eq!("陣+落ちコン封じ", combined.name);
+        assert_eq!((20, 14), (combined.max_cooldown, combined.min_cooldown));
+        assert_eq!(
+            [base.effects.clone(), assist.effects.clone()].concat(),
+            combined.effects
+        );
+        // ベースの効果が先
+        assert_eq!(base.effects[0], combined.effects[0]);
+        assert_eq!(assist.effects, combined.effects[base.effects.len()..]);
+    }
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "batch")]
+
+#[cfg(test)]
+mod batch_test {
+    use std::io::{BufReader, Read};
+
+    use pad_skill_parser::batch::*;
+    use pad_skill_parser::skill::*;
+
+    /// 常に読み込みに失敗する入力(読めないファイルやディレクトリ)
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("always fails"))
+        }
+    }
+
+    #[test]
+    fn jsonl() {
+        let input = r#"{"id": 1, "name": "a", "skill": "ランダムでドロップを入れ替える。"}
+
+not json
+{"id": 4, "name": "d", "skill": "だめ"}
+{"id": 5, "name": "e", "skill": "1ターンの間、落ちコンなし。"}
+"#;
+        let results: Vec<_> = parse_records(input.as_bytes(), InputFormat::Jsonl).collect();
+
+        assert_eq!(4, results.len());
+        assert_eq!(
+            RecordResult::Ok {
+                id: 1,
+                name: "a".to_owned(),
+                skills: vec![Skill {
+                    effect: SkillEffect::DropRefresh,
+                    ..Default::default()
+                }],
+            },
+            results[0]
+        );
+        assert!(matches!(
+            results[1],
+            RecordResult::Err {
+                id: None,
+                line: 3,
+                ..
+            }
+        ));
+        assert!(matches!(
+            results[2],
+            RecordResult::Err {
+                id: Some(4),
+                line: 4,
+                ..
+            }
+        ));
+        assert!(results[3].is_ok());
+    }
+
+    #[test]
+    fn semantic_errors_are_records() {
+        // 生成個数の無いL字型、変換先の無い`木ドロップを。`はセマンティックアクションのエラー
+        let input = r#"{"id": 1, "name": "a", "skill": "L字型に光を生成。"}
+{"id": 2, "name": "b", "skill": "木ドロップを。"}
+{"id": 3, "name": "c", "skill": "1ターンの間、落ちコンなし。"}
+"#;
+        let results: Vec<_> = parse_records(input.as_bytes(), InputFormat::Jsonl).collect();
+
+        assert!(matches!(
+            &results[0],
+            RecordResult::Err { id: Some(1), error, .. } if error.contains("LShape")
+        ));
+        assert!(matches!(
+            &results[1],
+            RecordResult::Err { id: Some(2), error, .. } if error.contains("incomplete skill text")
+        ));
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn csv() {
+        let input = "id,name,skill\n1,\"a, b\",全ドロップを強化。\n2,b\n3,c,木ドロップを強化。\n";
+        let results: Vec<_> = parse_records(input.as_bytes(), InputFormat::Csv).collect();
+
+        assert_eq!(3, results.len());
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            RecordResult::Err {
+                id: None,
+                line: 3,
+                ..
+            }
+        ));
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn io_error_ends_reading() {
+        let results: Vec<_> =
+            parse_records(BufReader::new(FailingReader), InputFormat::Jsonl).collect();
+        assert!(matches!(
+            &results[..],
+            [RecordResult::Err { id: None, line: 1, error }] if error.contains("always fails")
+        ));
+
+        let input = "id,name,skill\n1,a,全ドロップを強化。\n"
+            .as_bytes()
+            .chain(FailingReader);
+        let results: Vec<_> = parse_records(BufReader::new(input), InputFormat::Csv).collect();
+        assert_eq!(2, results.len());
+        assert!(results[0].is_ok());
+        assert!(matches!(&results[1], RecordResult::Err { id: None, .. }));
+    }
+
+    #[test]
+    fn invalid_utf8_line_is_record() {
+        let mut input = b"{\"id\": 1, \"name\": \"\xff\", \"skill\": \"\"}\r\n".to_vec();
+        input.extend_from_slice(
+            r#"{"id": 2, "name": "b", "skill": "全ドロップを強化。"}"#.as_bytes(),
+        );
+        let results: Vec<_> = parse_records(input.as_slice(), InputFormat::Jsonl).collect();
+
+        assert_eq!(2, results.len());
+        assert!(matches!(
+            &results[0],
+            RecordResult::Err { id: None, line: 1, error } if error.contains("utf-8")
+        ));
+        assert!(matches!(results[1], RecordResult::Ok { id: 2, .. }));
+    }
+
+    #[test]
+    fn run_writes_jsonl() {
+        let input = "id,name,skill\n1,a,全ドロップを強化。\n2,b,だめ\n";
+        let mut output = Vec::new();
+
+        let summary = run(input.as_bytes(), InputFormat::Csv, &mut output).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(Summary { ok: 1, failed: 1 }, summary);
+        assert_eq!("DropPowerUp", lines[0]["skills"][0]["effect"]["type"]);
+        assert_eq!(2, lines[1]["id"]);
+        assert!(lines[1]["error"].is_string());
+    }
+}
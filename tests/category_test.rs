@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod category_test {
+    use pad_skill_parser::category::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::skill_grammar::*;
+
+    fn categories_of(input: &str) -> Vec<Category> {
+        categories(&SkillGrammar::parse_skills(input).unwrap())
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn main_category() {
+        assert_eq!(
+            vec![Category::Change],
+            categories_of("木ドロップを水ドロップに変化。")
+        );
+        assert_eq!(
+            vec![Category::Generate],
+            categories_of("ランダムで火ドロップを1個生成。")
+        );
+        assert_eq!(
+            vec![Category::Generate],
+            categories_of("十字型に火を1つ生成。")
+        );
+        assert_eq!(
+            vec![Category::BoardIntervention],
+            categories_of("ランダムでドロップを入れ替える。")
+        );
+        assert_eq!(
+            vec![Category::Falloff],
+            categories_of("1ターンの間、火ドロップが落ちやすくなる。")
+        );
+        assert_eq!(
+            vec![Category::BoardIntervention],
+            categories_of("ドロップのロック状態を解除。")
+        );
+    }
+
+    #[test]
+    fn with_unlock() {
+        assert_eq!(
+            vec![Category::Generate, Category::WithUnlock],
+            categories_of("ドロップのロックを解除し、火と回復を6個ずつ生成。")
+        );
+        assert_eq!(
+            vec![Category::Change, Category::WithUnlock],
+            categories_of("ドロップのロックを解除し、光を闇に変化。")
+        );
+    }
+
+    #[test]
+    fn can_clear_board() {
+        assert_eq!(
+            vec![Category::Change, Category::CanClearBoard],
+            categories_of("全ドロップを火と光に変化。")
+        );
+        assert_eq!(
+            vec![
+                Category::Change,
+                Category::CanClearBoard,
+                Category::WithUnlock
+            ],
+            categories_of("全ドロップのロックを解除し、5属性+回復に変化。")
+        );
+        assert_eq!(
+            vec![Category::Generate, Category::CanClearBoard],
+            categories_of("ランダムで火と光を15個ずつ生成。")
+        );
+        // 盤面全体を変化させた後に生成する。回復3個、火、水、光9個ずつ
+        assert_eq!(
+            vec![
+                Category::Change,
+                Category::Generate,
+                Category::CanClearBoard
+            ],
+            categories_of("全ドロップを回復に変化し、火、水、光を9個ずつ生成。")
+        );
+        // 残りの6マスはランダムだが、5属性+回復が4個以上ずつある
+        assert_eq!(
+            vec![Category::Generate, Category::CanClearBoard],
+            categories_of("5属性+回復を4個ずつ生成。")
+        );
+        assert_eq!(
+            vec![Category::Generate],
+            categories_of("ランダムで火ドロップを1個生成。")
+        );
+        assert_eq!(
+            vec![Category::Change],
+            categories_of("木ドロップを水ドロップに変化。")
+        );
+    }
+
+    #[test]
+    fn skill_category() {
+        let skills =
+            SkillGrammar::parse_skills("ドロップのロックを解除し、光を闇に変化。").unwrap();
+
+        assert_eq!(
+            vec![Category::BoardIntervention],
+            skills[0].category().iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Category::Change],
+            skills[1].category().iter().collect::<Vec<_>>()
+        );
+        assert!(Skill::default().category().is_empty());
+    }
+
+    #[test]
+    fn to_japanese() {
+        let set: CategorySet = [Category::WithUnlock, Category::Generate]
+            .into_iter()
+            .collect();
+        let names: Vec<String> = set.iter().map(|category| category.to_string()).collect();
+
+        assert_eq!(2, set.len());
+        assert_eq!(vec!["生成", "ロック解除付き"], names);
+    }
+}
@@ -0,0 +1,262 @@
+#![cfg(feature = "cli")]
+
+#[cfg(test)]
+mod cli_test {
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+
+    fn pad_skill(args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_pad-skill"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(output: &Output) -> String {
+        String::from_utf8(output.stdout.clone()).unwrap()
+    }
+
+    #[test]
+    fn lenient_with_english_is_rejected() {
+        let output = pad_skill(
+            &[
+                "parse",
+                "--lenient",
+                "--lang",
+                "en",
+                "Change Wood orbs to Water orbs",
+            ],
+            "",
+        );
+
+        assert_eq!(Some(2), output.status.code());
+        assert!(stdout(&output).is_empty());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("--lenient"));
+    }
+
+    #[test]
+    fn parse_from_args() {
+        let output = pad_skill(
+            &["parse", "--format", "en", "木ドロップを水ドロップに変化。"],
+            "",
+        );
+
+        assert!(output.status.success());
+        assert_eq!("Change Wood orbs to Water orbs\n", stdout(&output));
+    }
+
+    #[test]
+    fn parse_from_stdin() {
+        let output = pad_skill(
+            &["parse", "--lang", "en", "--format", "ja"],
+            "Change Wood orbs to Water orbs\n\nFor 1 turn, no skyfall combos\n",
+        );
+
+        assert!(output.status.success());
+        assert_eq!(
+            "木ドロップを水ドロップに変化。\n1ターンの間、落ちコンなし。\n",
+            stdout(&output)
+        );
+    }
+
+    #[test]
+    fn parse_json() {
+        let output = pad_skill(
+            &[
+                "parse",
+                "--format",
+                "json",
+                "ランダムでドロップを入れ替える。",
+            ],
+            "",
+        );
+        let json: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!("DropRefresh", json[0]["effect"]["type"]);
+    }
+
+    #[test]
+    fn parse_error() {
+        let output = pad_skill(&["parse", "火ドロップを木ドロップに変化しない"], "");
+
+        assert!(!output.status.success());
+        assert!(stdout(&output).is_empty());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Error location"));
+    }
+
+    #[test]
+    fn batch_from_stdin() {
+        let output = pad_skill(
+            &["batch", "--input-format", "csv"],
+            "id,name,skill\n1,a,全ドロップを強化。\n2,b,だめ\n",
+        );
+
+        assert!(output.status.success());
+        assert_eq!(2, stdout(&output).lines().count());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("ok: 1, failed: 1"));
+    }
+
+    #[test]
+    fn batch_does_not_print_panics() {
+        let output = pad_skill(
+            &["batch"],
+            "{\"id\": 1, \"name\": \"a\", \"skill\": \"L字型に光を生成。\"}\n{\"id\": 2, \"name\": \"b\", \"skill\": \"木ドロップを。\"}\n",
+        );
+
+        assert!(output.status.success());
+        assert_eq!(2, stdout(&output).lines().count());
+        assert_eq!(
+            "ok: 0, failed: 2\n",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn query_from_stdin() {
+        let output = pad_skill(
+            &["query", "converts_to:water and not converts_to:fire"],
+            "木ドロップを水ドロップに変化。\n火を水に、光を回復に変化。\n全ドロップを5属性に変化。\nだめ\n",
+        );
+
+        assert!(output.status.success());
+        assert_eq!(
+            "木ドロップを水ドロップに変化。\n火を水に、光を回復に変化。\n",
+            stdout(&output)
+        );
+        assert!(String::from_utf8_lossy(&output.stderr).contains("matched: 2, failed: 1"));
+    }
+
+    #[test]
+    fn diff_from_args() {
+        let output = pad_skill(
+            &[
+                "diff",
+                "ランダムで火と水を2個ずつ生成。",
+                "ランダムで火と水を3個ずつ生成。",
+            ],
+            "",
+        );
+
+        assert!(output.status.success());
+        assert_eq!(
+            "~ ランダムで火と水ドロップを2個ずつ生成。 → ランダムで火と水ドロップを3個ずつ生成。\n  火: 2個 → 3個\n  水: 2個 → 3個\n",
+            stdout(&output)
+        );
+    }
+
+    #[test]
+    fn diff_snapshot() {
+        let dir = std::env::temp_dir().join(format!("pad_skill_diff_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let before = dir.join("before.jsonl");
+        let after = dir.join("after.csv");
+        std::fs::write(
+            &before,
+            "{\"id\":1,\"name\":\"a\",\"skill\":\"1ターンの間、落ちコンなし。\"}\n\
+             {\"id\":2,\"name\":\"b\",\"skill\":\"全ドロップを強化。\"}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &after,
+            "id,name,skill\n1,a,1ターンの間、落ちコンなし。\n3,c,全ドロップを強化。\n4,d,だめ\n",
+        )
+        .unwrap();
+
+        let output = pad_skill(
+            &[
+                "diff",
+                "--snapshot",
+                before.to_str().unwrap(),
+                after.to_str().unwrap(),
+            ],
+            "",
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(
+            "#2 b\n- 全ドロップを強化。\n#3 c\n+ 全ドロップを強化。\n",
+            stdout(&output)
+        );
+        assert!(String::from_utf8_lossy(&output.stderr).contains("changed: 2, failed: 1"));
+    }
+
+    #[test]
+    fn diff_snapshot_with_failure_on_one_side() {
+        let dir =
+            std::env::temp_dir().join(format!("pad_skill_diff_failed_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let before = dir.join("before.jsonl");
+        let after = dir.join("after.jsonl");
+        std::fs::write(
+            &before,
+            "{\"id\":1,\"name\":\"a\",\"skill\":\"1ターンの間、落ちコンなし。\"}\n\
+             {\"id\":2,\"name\":\"b\",\"skill\":\"だめ\"}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &after,
+            "{\"id\":1,\"name\":\"a\",\"skill\":\"だめ\"}\n\
+             {\"id\":2,\"name\":\"b\",\"skill\":\"全ドロップを強化。\"}\n",
+        )
+        .unwrap();
+
+        let output = pad_skill(
+            &[
+                "diff",
+                "--snapshot",
+                before.to_str().unwrap(),
+                after.to_str().unwrap(),
+            ],
+            "",
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // 片側の失敗を、スキルの全削除、全追加として表示しない
+        assert!(output.status.success());
+        assert_eq!("", stdout(&output));
+        assert!(stderr.contains("#1: failed to parse after"));
+        assert!(stderr.contains("#2: failed to parse before"));
+        assert!(stderr.contains("changed: 0, failed: 2"));
+    }
+
+    #[test]
+    fn diff_snapshot_unreadable_path() {
+        let dir = std::env::temp_dir();
+        let after = dir.join(format!(
+            "pad_skill_diff_unreadable_{}.jsonl",
+            std::process::id()
+        ));
+        std::fs::write(&after, "").unwrap();
+
+        // ディレクトリはファイルとして読めない
+        let output = pad_skill(
+            &[
+                "diff",
+                "--snapshot",
+                dir.to_str().unwrap(),
+                after.to_str().unwrap(),
+            ],
+            "",
+        );
+        std::fs::remove_file(&after).unwrap();
+
+        assert!(!output.status.success());
+        assert_eq!("", stdout(&output));
+        assert!(!String::from_utf8_lossy(&output.stderr).contains("changed:"));
+    }
+}
@@ -0,0 +1,37 @@
+//! テスト間で共通して使うスキル本文とパース
+
+#![allow(dead_code)]
+
+use pad_skill_parser::skill::Skill;
+use pad_skill_parser::skill_grammar::SkillGrammar;
+use pad_skill_parser::skill_parser::parse;
+
+/// スキル本文のコーパス。1行に1つ。パーサのテスト(`parser_test.rs`、`towards_the_enemy_test.rs`)の入力を集めたもの
+const CORPUS: &str = include_str!("corpus.txt");
+
+/// コーパスの最低件数。読み込みを誤って空や数件になった場合に、コーパスを使うテストが素通りしないようにする
+const MIN_CORPUS_SIZE: usize = 100;
+
+/// パーサのテストで使用しているスキル本文
+pub fn corpus() -> Vec<&'static str> {
+    let corpus: Vec<&'static str> = CORPUS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    assert!(
+        corpus.len() >= MIN_CORPUS_SIZE,
+        "corpus has only {} skills",
+        corpus.len()
+    );
+    corpus
+}
+
+/// 正規化せずにスキル本文をパースする
+pub fn skills(input: &str) -> Vec<Skill> {
+    let grammar = &mut SkillGrammar::new();
+    let _parsed = parse(input, "<input>", grammar).unwrap();
+
+    grammar.skill_list.to_vec()
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod coverage_test {
+    use pad_skill_parser::coverage::*;
+
+    #[test]
+    fn counts() {
+        let corpus = "火ドロップを木ドロップに変化。\n\n左端縦1列を火ドロップに変化。\nだめ\n";
+        let report = CoverageReport::from_corpus(corpus.lines());
+
+        assert_eq!(3, report.total);
+        assert_eq!(2, report.parsed);
+        assert_eq!(1, report.failed());
+        assert_eq!(Some(&1), report.effects.get("ChangeDropAToB"));
+        assert_eq!(Some(&1), report.effects.get("DropShapeGen"));
+        assert_eq!(Some(&1), report.shapes.get("Col"));
+    }
+
+    #[test]
+    fn failure_location() {
+        let input = "火ドロップを木ドロップに変化しない";
+        let report = CoverageReport::from_corpus([input, input, "だめ"]);
+        let failure = &report.failures[0];
+
+        assert_eq!(input, failure.input);
+        assert_eq!(Some("し".to_owned()), failure.token);
+        assert_eq!(Some(input.find('し').unwrap()), failure.offset);
+        assert_eq!(Some("Period".to_owned()), failure.rule);
+        assert_eq!(
+            (("Period".to_owned(), "し".to_owned()), 2),
+            report.failure_groups()[0]
+        );
+    }
+
+    #[test]
+    fn failure_location_of_full_width_input() {
+        // 全角の空白は正規化で削除されるので、元の本文とはバイト位置がずれる
+        let input = "火ドロップを　木ドロップに変化しない";
+        let report = CoverageReport::from_corpus([input]);
+        let failure = &report.failures[0];
+
+        assert_eq!(input, failure.input);
+        assert_eq!("火ドロップを木ドロップに変化しない。", failure.normalized);
+        assert_eq!(Some("し".to_owned()), failure.token);
+        assert_eq!(Some(failure.normalized.find('し').unwrap()), failure.offset);
+        assert_ne!(input.find('し'), failure.offset);
+    }
+
+    #[test]
+    fn display() {
+        let report = CoverageReport::from_corpus(["火ドロップを木ドロップに変化。", "だめ"]);
+        let text = report.to_string();
+
+        assert!(text.starts_with("parsed: 1/2 (50.0%), failed: 1\n"));
+        assert!(text.contains("+50.0%"));
+        assert!(text.contains("ChangeDropAToB\t1"));
+    }
+}
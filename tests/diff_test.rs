@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod diff_test {
+    use pad_skill_parser::diff::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::skill_grammar::*;
+
+    const FIRE: Drop = Drop::Colored(Color::Fire);
+    const WATER: Drop = Drop::Colored(Color::Water);
+    const WOOD: Drop = Drop::Colored(Color::Wood);
+
+    fn skills(input: &str) -> Vec<Skill> {
+        SkillGrammar::parse_skills(input).unwrap()
+    }
+
+    fn diff(before: &str, after: &str) -> SkillDiff {
+        SkillDiff::new(&skills(before), &skills(after))
+    }
+
+    fn details(diff: &SkillDiff) -> Vec<Detail> {
+        match diff.changes.as_slice() {
+            [Change::Modified { details, .. }] => details.clone(),
+            changes => panic!("not a single modification: {changes:?}"),
+        }
+    }
+
+    #[test]
+    fn same_skills() {
+        let diff = diff(
+            "ランダムで火と水を2個ずつ生成。",
+            "ランダムで火と水を2個ずつ生成。",
+        );
+
+        assert!(diff.is_empty());
+        assert_eq!("", diff.to_string());
+    }
+
+    #[test]
+    fn quantities() {
+        let diff = diff(
+            "ランダムで火と水を2個ずつ生成。",
+            "ランダムで火と木を3個ずつ生成。",
+        );
+
+        assert_eq!(
+            vec![
+                Detail::DropsRemoved("除外", vec![WATER]),
+                Detail::DropsAdded("除外", vec![WOOD]),
+                Detail::Quantity(FIRE, 2, 3),
+                Detail::Quantity(WATER, 2, 0),
+                Detail::Quantity(WOOD, 0, 3),
+            ],
+            details(&diff)
+        );
+    }
+
+    #[test]
+    fn change_drops() {
+        let diff = diff(
+            "回復をランダムで木と水に変化。",
+            "回復とお邪魔をランダムで木と光に変化。",
+        );
+
+        assert_eq!(
+            vec![
+                Detail::DropsAdded("変換元", vec![Drop::NonColored(NonColoredDrop::Disturb)]),
+                Detail::DropsRemoved("変換先", vec![WATER]),
+                Detail::DropsAdded("変換先", vec![Drop::Colored(Color::Lightning)]),
+            ],
+            details(&diff)
+        );
+    }
+
+    /// 並び替えただけのスキルは、値は異なっても差分にならない
+    fn assert_reordered(before: &str, after: &str) {
+        assert_ne!(skills(before), skills(after));
+
+        let diff = diff(before, after);
+        assert!(diff.is_empty(), "{diff}");
+        assert_eq!("", diff.to_string());
+    }
+
+    #[test]
+    fn reordered_drops_are_not_changes() {
+        assert_reordered("全ドロップを火と光に変化。", "全ドロップを光と火に変化。");
+        assert_reordered(
+            "回復をランダムで木と水に変化。",
+            "回復をランダムで水と木に変化。",
+        );
+    }
+
+    #[test]
+    fn reordered_shapes_are_not_changes() {
+        assert_reordered(
+            "左端1列を闇ドロップに、右端1列を光ドロップに変化。",
+            "右端1列を光ドロップに、左端1列を闇ドロップに変化。",
+        );
+    }
+
+    #[test]
+    fn turns_of_apply() {
+        let diff = diff(
+            "1ターンの間、火ドロップが落ちやすくなる。",
+            "3ターンの間、火と水ドロップが落ちやすくなる。",
+        );
+
+        assert_eq!(
+            vec![
+                Detail::DropsAdded("対象", vec![WATER]),
+                Detail::Turns(Some(1), Some(3)),
+            ],
+            details(&diff)
+        );
+    }
+
+    #[test]
+    fn sub_effect() {
+        let before = skills("全ドロップを強化。");
+        let mut after = before.clone();
+        after[0].sub_effects = Some(SubEffect::HitPoint(50, SubEffectAttribute::IfApply));
+
+        let diff = SkillDiff::new(&before, &after);
+
+        assert_eq!(
+            vec![Detail::SubEffect(
+                None,
+                Some(SubEffect::HitPoint(50, SubEffectAttribute::IfApply))
+            )],
+            details(&diff)
+        );
+        assert!(diff
+            .to_string()
+            .contains("副次効果: なし → HP50%以上の場合"));
+    }
+
+    #[test]
+    fn added_and_removed_effects() {
+        let diff = diff(
+            "1ターンの間、落ちコンなし。",
+            "ドロップのロックを解除し、ランダムで火と水を2個ずつ生成。",
+        );
+
+        assert_eq!(3, diff.changes.len());
+        assert!(matches!(diff.changes[0], Change::Removed(_)));
+        assert!(matches!(diff.changes[1], Change::Added(_)));
+        assert!(matches!(diff.changes[2], Change::Added(_)));
+    }
+
+    #[test]
+    fn effects_are_aligned_by_variant() {
+        let diff = diff(
+            "左端1列を闇ドロップに変化。",
+            "ドロップのロックを解除し、左端1列を闇ドロップに、右端1列を光ドロップに変化。",
+        );
+
+        assert_eq!(2, diff.changes.len());
+        assert!(matches!(diff.changes[0], Change::Added(_)));
+        assert_eq!(
+            vec![Detail::ShapesAdded(vec![ShapeType::Col(
+                -1,
+                Drop::Colored(Color::Lightning)
+            )])],
+            match &diff.changes[1] {
+                Change::Modified { details, .. } => details.clone(),
+                change => panic!("not a modification: {change:?}"),
+            }
+        );
+    }
+
+    #[test]
+    fn unsplittable_effect() {
+        let diff = diff(
+            "1ターンの間、火ドロップが落ちやすくなる。",
+            "1ターンの間、火ドロップが少し落ちやすくなる。",
+        );
+
+        assert!(matches!(details(&diff)[..], [Detail::Effect(_, _)]));
+    }
+
+    #[test]
+    fn display() {
+        let diff = diff(
+            "ランダムで火と水を2個ずつ生成。",
+            "ドロップのロックを解除し、ランダムで火、水、木を3個ずつ生成。",
+        );
+
+        assert_eq!(
+            "+ ドロップのロック状態を解除。\n\
+             ~ ランダムで火と水ドロップを2個ずつ生成。 → ランダムで火、水、木ドロップを3個ずつ生成。\n  \
+             除外: 木ドロップを追加\n  \
+             火: 2個 → 3個\n  \
+             水: 2個 → 3個\n  \
+             木: 追加(3個)\n",
+            diff.to_string()
+        );
+    }
+}
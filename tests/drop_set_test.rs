@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod drop_set_test {
+    use pad_skill_parser::drop_set::DropSet;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::skill_grammar::*;
+
+    const FIRE: Drop = Drop::Colored(Color::Fire);
+    const WOOD: Drop = Drop::Colored(Color::Wood);
+    const DARK: Drop = Drop::Colored(Color::Dark);
+    const RECOVERY: Drop = Drop::NonColored(NonColoredDrop::Recovery);
+
+    #[test]
+    fn order_insensitive() {
+        assert_eq!(
+            DropSet::from(vec![FIRE, WOOD]),
+            DropSet::from(vec![WOOD, FIRE])
+        );
+        assert_eq!(
+            DropSet::from(vec![FIRE, WOOD, FIRE]),
+            DropSet::from(vec![WOOD, FIRE])
+        );
+        assert_eq!(2, DropSet::from(vec![FIRE, WOOD, FIRE]).len());
+    }
+
+    #[test]
+    fn canonical_order() {
+        let set = DropSet::from(vec![RECOVERY, DARK, FIRE, WOOD]);
+
+        assert_eq!(vec![FIRE, WOOD, DARK, RECOVERY], set.to_drops());
+        assert_eq!(Drop::all(), DropSet::all().to_drops());
+        assert_eq!(Drop::all()[..5], DropSet::five_attributes().to_drops()[..]);
+        assert_eq!(
+            Drop::all()[..6],
+            DropSet::five_attributes_and_recovery().to_drops()[..]
+        );
+    }
+
+    #[test]
+    fn each_drop_has_own_bit() {
+        let all = Drop::all();
+
+        assert_eq!(10, all.len());
+        all.into_iter()
+            .for_each(|drop| assert_eq!(vec![drop.clone()], DropSet::from(drop).to_drops()));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = DropSet::from(vec![FIRE, WOOD]);
+        let b = DropSet::from(vec![WOOD, DARK]);
+
+        assert_eq!(DropSet::from(vec![FIRE, WOOD, DARK]), a | b);
+        assert_eq!(DropSet::from(WOOD), a & b);
+        assert_eq!(DropSet::from(FIRE), a - b);
+        assert_eq!(8, (!a).len());
+        assert!(!(!a).contains(&FIRE));
+        assert!(DropSet::from(FIRE).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        assert!(DropSet::empty().is_empty());
+        assert_eq!(DropSet::all(), a | !a);
+    }
+
+    #[test]
+    fn parsed_change_drop_is_order_insensitive() {
+        let parse = |input: &str| SkillGrammar::parse_skills(input).unwrap();
+
+        assert_eq!(
+            parse("火と木を回復ドロップに変化。"),
+            parse("木と火を回復ドロップに変化。")
+        );
+        assert_eq!(
+            vec![Skill {
+                effect: SkillEffect::ChangeDropAToB(
+                    DropSet::from(vec![WOOD, FIRE]),
+                    vec![RECOVERY]
+                ),
+                ..Default::default()
+            }],
+            parse("火と木を回復ドロップに変化。")
+        );
+    }
+}
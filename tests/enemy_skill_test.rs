@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod enemy_skill_test {
+    use pad_skill_parser::enemy_skill::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+
+    fn parse(input: &str) -> Vec<EnemyEffect> {
+        parse_enemy_skill(input).unwrap()
+    }
+
+    fn board(effect: SkillEffect, turns_of_apply: Option<usize>) -> EnemyEffect {
+        EnemyEffect::Board(Skill {
+            effect,
+            turns_of_apply,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn move_time_decrease() {
+        assert_eq!(
+            vec![EnemyEffect::MoveTimeDecrease(Some(3), 2.0)],
+            parse("3ターンの間、ドロップ操作時間が2秒減少。")
+        );
+        assert_eq!(
+            vec![EnemyEffect::MoveTimeDecrease(None, 1.5)],
+            parse("ドロップ操作時間を1.5秒減少。")
+        );
+    }
+
+    #[test]
+    fn skill_delay() {
+        assert_eq!(vec![EnemyEffect::SkillDelay(None)], parse("スキル遅延。"));
+        assert_eq!(
+            vec![EnemyEffect::SkillDelay(Some(2))],
+            parse("スキル遅延（2ターン）")
+        );
+        assert_eq!(
+            vec![EnemyEffect::SkillDelay(Some(3))],
+            parse("スキルが3ターン遅延。")
+        );
+    }
+
+    #[test]
+    fn fall_lock_drop() {
+        assert_eq!(
+            vec![board(
+                SkillEffect::FallLockDrop(vec![Drop::Colored(Color::Fire)]),
+                Some(1)
+            )],
+            parse("1ターンの間、火ドロップがロック状態で落ちてくる。")
+        );
+    }
+
+    #[test]
+    fn board_change() {
+        assert_eq!(
+            vec![
+                board(
+                    SkillEffect::GenCloud(BoardPosition::Random, Size(2, 2)),
+                    Some(1)
+                ),
+                board(SkillEffect::GenTeap(BoardPosition::Col(1)), Some(1)),
+                board(SkillEffect::ChangeBoardSize(Size(7, 6)), Some(3)),
+            ],
+            parse(
+                "1ターンの間、盤面に2×2の雲が発生。\
+                 1ターンの間、左端1列が操作不可になる。\
+                 3ターンの間、盤面を7×6マスにする。"
+            )
+        );
+    }
+
+    #[test]
+    fn shape_and_change() {
+        assert_eq!(
+            vec![
+                board(
+                    SkillEffect::DropShapeGen(vec![ShapeType::Col(
+                        1,
+                        Drop::NonColored(NonColoredDrop::Poison)
+                    )]),
+                    None
+                ),
+                EnemyEffect::SkillDelay(Some(1)),
+            ],
+            parse("左端1列を毒ドロップに変化。スキルを1ターン遅延。")
+        );
+    }
+
+    #[test]
+    fn unparsable_sentence() {
+        assert!(parse_enemy_skill("スキル遅延。よく分からない効果。").is_err());
+    }
+
+    #[test]
+    fn fractional_turns_is_error() {
+        assert!(parse_enemy_skill("スキルが2.7ターン遅延。").is_err());
+        assert!(parse_enemy_skill("スキル遅延（2.7ターン）。").is_err());
+        assert!(parse_enemy_skill("2.5ターンの間、ドロップ操作時間が2秒減少。").is_err());
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+#[cfg(test)]
+mod english_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::drop_set::DropSet;
+    use pad_skill_parser::english::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+
+    fn english(input: &str) -> String {
+        skills_to_english(&skills(input))
+    }
+
+    #[test]
+    fn corpus_has_no_japanese() {
+        for input in corpus() {
+            let rendered = english(input);
+
+            assert!(!rendered.is_empty(), "{input}");
+            assert!(rendered.is_ascii(), "{input} -> {rendered}");
+        }
+    }
+
+    #[test]
+    fn change_drop() {
+        assert_eq!(
+            "Change Wood orbs to Water orbs",
+            english("木ドロップを水ドロップに変化。")
+        );
+        assert_eq!(
+            "Change Fire and Wood orbs to Light and Heal orbs at random",
+            english("火と木をランダムで光と回復に変化。")
+        );
+        assert_eq!(
+            "Unlock all orbs; change all orbs to Fire, Water, Wood, Light, Dark and Heal orbs",
+            english("全ドロップのロックを解除し、5属性+回復に変化。")
+        );
+    }
+
+    #[test]
+    fn gen_random_drop() {
+        assert_eq!(
+            "Create 5 Fire orbs at random",
+            skills_to_english(&[Skill {
+                effect: SkillEffect::GenRandomDrop(
+                    DropSet::from(Drop::Colored(Color::Fire)),
+                    vec![(Drop::Colored(Color::Fire), 5)]
+                ),
+                ..Default::default()
+            }])
+        );
+        assert_eq!(
+            "Create 2 each of Fire and Water orbs at random",
+            english("ランダムで火と水を2個ずつ生成。")
+        );
+        assert_eq!(
+            "Create 11 Wood, 7 Dark and 5 Heal orbs at random",
+            english("ランダムで木ドロップを11個、闇ドロップを7個、回復ドロップを5個生成。")
+        );
+        assert_eq!(
+            "Create 6 Fire orbs from non-Heal orbs",
+            english("回復ドロップ以外から火ドロップを6個生成。")
+        );
+    }
+
+    #[test]
+    fn shape_gen() {
+        assert_eq!(
+            "Change the leftmost column, the 2nd column from the left, the rightmost column and the 2nd column from the right to Water orbs",
+            english("左端2列と右端2列を水ドロップに変化。")
+        );
+        assert_eq!(
+            "Change the top row to Fire orbs, and the 2nd row from the bottom to Wood orbs",
+            english("最上段横1列を火に、下から2段目横1列を木ドロップに変化。")
+        );
+        assert_eq!(
+            "Create 1 3x3 square of Wood orbs",
+            english("3×3の正方形に木ドロップを1つ生成。")
+        );
+    }
+
+    #[test]
+    fn with_turns() {
+        assert_eq!(
+            "For 1 turn, no skyfall combos",
+            english("1ターンの間、落ちコンなし。")
+        );
+        assert_eq!(
+            "For 3 turns, Water and Heal orbs are slightly more likely to appear",
+            english("3ターンの間、水と回復ドロップが少し落ちやすくなる。")
+        );
+        assert_eq!(
+            "For 1 turn, only Fire, Water, Light and Heal orbs will appear",
+            english("1ターンの間、火、水、光、回復ドロップのみ落ちてくる。")
+        );
+        assert_eq!(
+            "Change all enemies to Fire attribute",
+            english("敵全体が火属性に変化。")
+        );
+    }
+
+    #[test]
+    fn sub_effects() {
+        let skill = Skill {
+            effect: SkillEffect::DropRefresh,
+            sub_effects: Some(SubEffect::Floor(6, SubEffectAttribute::Available)),
+            turns_of_apply: None,
+        };
+
+        assert_eq!(
+            "Replace all orbs (usable on battle 6 or later)",
+            skill.to_english()
+        );
+    }
+}
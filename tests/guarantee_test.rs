@@ -0,0 +1,148 @@
+mod common;
+
+#[cfg(test)]
+mod guarantee_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::guarantee::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::simulator::Simulator;
+
+    const FIRE: Drop = Drop::Colored(Color::Fire);
+    const WATER: Drop = Drop::Colored(Color::Water);
+    const WOOD: Drop = Drop::Colored(Color::Wood);
+    const LIGHT: Drop = Drop::Colored(Color::Lightning);
+    const DARK: Drop = Drop::Colored(Color::Dark);
+    const RECOVERY: Drop = Drop::NonColored(NonColoredDrop::Recovery);
+
+    fn standard(input: &str) -> BoardAnalysis {
+        BoardAnalysis::analyze(&skills(input), &Size::standard())
+    }
+
+    #[test]
+    fn unknown_board() {
+        let analysis = standard("1ターンの間、落ちコンなし。");
+
+        assert_eq!(CountRange::new(0, 30), analysis.get(&FIRE));
+        assert_eq!(
+            CountRange::exact(0),
+            analysis.get(&Drop::NonColored(NonColoredDrop::Poison))
+        );
+        assert_eq!(0, analysis.guaranteed_combos());
+        assert!(!analysis.can_clear());
+    }
+
+    #[test]
+    fn change_all_of_board() {
+        let analysis = standard("全ドロップを火と光に変化。");
+
+        assert_eq!(CountRange::new(3, 27), analysis.get(&FIRE));
+        assert_eq!(CountRange::new(3, 27), analysis.get(&LIGHT));
+        assert_eq!(CountRange::exact(0), analysis.get(&WATER));
+        assert!(analysis.can_clear());
+
+        let analysis = standard("全ドロップを回復に変化。");
+        assert_eq!(CountRange::exact(30), analysis.get(&RECOVERY));
+        assert_eq!(10, analysis.guaranteed_combos());
+    }
+
+    #[test]
+    fn gen_random_drop() {
+        let analysis = standard("ランダムで火ドロップを1個生成。");
+        assert_eq!(CountRange::new(1, 30), analysis.get(&FIRE));
+        assert_eq!(CountRange::new(0, 29), analysis.get(&WATER));
+
+        let analysis = standard("全ドロップを回復に変化し、火、水、光を9個ずつ生成。");
+        assert_eq!(CountRange::exact(9), analysis.get(&FIRE));
+        assert_eq!(CountRange::exact(9), analysis.get(&WATER));
+        assert_eq!(CountRange::exact(9), analysis.get(&LIGHT));
+        assert_eq!(CountRange::exact(3), analysis.get(&RECOVERY));
+        assert_eq!(10, analysis.guaranteed_combos());
+
+        let analysis = standard("5属性+回復を4個ずつ生成。");
+        assert_eq!(CountRange::new(4, 10), analysis.get(&DARK));
+        assert_eq!(6, analysis.guaranteed_combos());
+    }
+
+    #[test]
+    fn drop_shape_gen() {
+        let analysis = standard("左端1列を闇ドロップに、右端1列を光ドロップに変化。");
+
+        assert_eq!(CountRange::new(5, 25), analysis.get(&DARK));
+        assert_eq!(CountRange::new(5, 25), analysis.get(&LIGHT));
+        assert_eq!(CountRange::new(0, 20), analysis.get(&FIRE));
+
+        let analysis = standard("盤面外周を火ドロップに変化。");
+        assert_eq!(CountRange::new(18, 30), analysis.get(&FIRE));
+    }
+
+    #[test]
+    fn change_drop_a_to_b() {
+        let analysis = standard("木ドロップを水ドロップに変化。");
+        assert_eq!(CountRange::exact(0), analysis.get(&WOOD));
+        assert_eq!(CountRange::new(0, 30), analysis.get(&WATER));
+
+        // 全ドロップ変化の後なら変換元の数が分かる
+        let combined = [
+            skills("全ドロップを火と光に変化。"),
+            skills("火ドロップを木ドロップに変化。"),
+        ]
+        .concat();
+        let analysis = BoardAnalysis::analyze(&combined, &Size::standard());
+        assert_eq!(CountRange::exact(0), analysis.get(&FIRE));
+        assert_eq!(CountRange::new(3, 27), analysis.get(&WOOD));
+    }
+
+    #[test]
+    fn board_sizes() {
+        let analyses = BoardAnalysis::analyze_sizes(&skills("全ドロップを5属性+回復に変化。"));
+
+        assert_eq!(
+            vec![Size(5, 4), Size(6, 5), Size(7, 6)],
+            analyses.iter().map(|a| a.size.clone()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                CountRange::new(3, 5),
+                CountRange::new(3, 15),
+                CountRange::new(3, 27)
+            ],
+            analyses.iter().map(|a| a.get(&FIRE)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![6, 6, 6],
+            analyses
+                .iter()
+                .map(|a| a.guaranteed_combos())
+                .collect::<Vec<_>>()
+        );
+
+        let analysis = standard("1ターンの間、盤面を7×6マスにする。");
+        assert_eq!(Size(7, 6), analysis.size);
+        assert_eq!(CountRange::new(0, 42), analysis.get(&FIRE));
+    }
+
+    /// シミュレータで使用した直後の盤面が、分析した範囲に収まる
+    #[test]
+    fn contains_simulated_boards() {
+        for input in corpus() {
+            let skills = skills(input);
+
+            for size in BoardAnalysis::sizes() {
+                let analysis = BoardAnalysis::analyze(&skills, &size);
+
+                for seed in 0..8 {
+                    let mut simulator = Simulator::with_random_board(size.clone(), seed);
+                    simulator.apply_skills(&skills).unwrap();
+
+                    for (drop, range) in &analysis.counts {
+                        let count = simulator.board.count(drop);
+                        assert!(
+                            range.min <= count && count <= range.max,
+                            "{input} {size:?} {drop:?}: {count} not in {range:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+#[cfg(test)]
+mod japanese_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::japanese::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+
+    #[test]
+    fn round_trip_corpus() {
+        let corpus = corpus();
+        assert!(corpus.len() > 90);
+
+        for input in corpus {
+            let expected = skills(input);
+            let rendered = skills_to_japanese(&expected);
+
+            assert_eq!(expected, skills(&rendered), "{input} -> {rendered}");
+        }
+    }
+
+    #[test]
+    fn canonical_wording() {
+        let cases = [
+            ("回復を水に変化。", "回復ドロップを水ドロップに変化。"),
+            (
+                "全ドロップを5属性ドロップ+回復+毒+猛毒に変化。",
+                "全ドロップを5属性+回復+毒+猛毒ドロップに変化。",
+            ),
+            (
+                "左端2列と右端2列を水ドロップに変化。",
+                "左端縦2列と右端縦2列を水ドロップに変化。",
+            ),
+            (
+                "ロックを解除し、右端1列を光に変化。",
+                "ドロップのロックを解除し、右端1列を光ドロップに変化。",
+            ),
+            (
+                "火とお邪魔以外から水と木を2個生成。",
+                "火とお邪魔ドロップ以外から水と木ドロップを2個ずつ生成。",
+            ),
+            ("敵全体が火属性に変化。", "敵全体が火属性に変化。"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(expected, skills_to_japanese(&skills(input)));
+        }
+    }
+
+    #[test]
+    fn display_with_turns() {
+        let skill = Skill {
+            effect: SkillEffect::DropFalloff(
+                vec![
+                    Drop::Colored(Color::Water),
+                    Drop::NonColored(NonColoredDrop::Recovery),
+                ],
+                VolumeVariation::Little,
+            ),
+            turns_of_apply: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "3ターンの間、水と回復ドロップが少し落ちやすくなる。",
+            skill.to_string()
+        );
+    }
+
+    #[test]
+    fn shape_type() {
+        let fire = Drop::Colored(Color::Fire);
+
+        assert_eq!(
+            "3×3の正方形に火ドロップを1つ生成",
+            ShapeType::Square(fire.clone(), 3, 1).to_japanese()
+        );
+        assert_eq!(
+            "7の形に火ドロップを1個生成",
+            ShapeType::ShapeOfSomeKind(fire.clone(), "7".to_owned(), 1).to_japanese()
+        );
+        assert_eq!(
+            "右から2列目縦1列を火ドロップに変化",
+            ShapeType::Col(-2, fire).to_japanese()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "schema")]
+
+#[cfg(test)]
+mod json_schema_test {
+    use pad_skill_parser::json_schema::skills_schema;
+    use pad_skill_parser::skill::SkillEffect;
+    use pad_skill_parser::skill_grammar::*;
+    use pad_skill_parser::skill_parser::parse;
+    use serde_json::Value;
+
+    fn schema() -> Value {
+        serde_json::to_value(skills_schema()).unwrap()
+    }
+
+    /// `SkillEffect`の`type`に指定できるバリアント名
+    fn skill_effect_types() -> Vec<String> {
+        schema()["definitions"]["SkillEffect"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| {
+                variant["properties"]["type"]["enum"][0]
+                    .as_str()
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn checked_in_schema_is_up_to_date() {
+        let checked_in: Value =
+            serde_json::from_str(include_str!("../schema/skills.schema.json")).unwrap();
+
+        assert_eq!(
+            schema(),
+            checked_in,
+            "cargo run --example json_schema --features schema > schema/skills.schema.json"
+        );
+    }
+
+    #[test]
+    fn effect_names_match_schema_types() {
+        assert_eq!(skill_effect_types(), SkillEffect::NAMES.to_vec());
+    }
+
+    #[test]
+    fn parsed_skill_matches_schema_type() {
+        let input = "火ドロップを木ドロップに変化。";
+        let grammar = &mut SkillGrammar::new();
+        let _parsed = parse(input, "<input>", grammar).unwrap();
+
+        let json = serde_json::to_value(&grammar.skill_list).unwrap();
+        let effect_type = json[0]["effect"]["type"].as_str().unwrap();
+
+        assert!(skill_effect_types().contains(&effect_type.to_owned()));
+        assert!(skill_effect_types().contains(&"DropShapeGen".to_owned()));
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod leader_skill_test {
+    use pad_skill_parser::leader_skill::*;
+    use pad_skill_parser::leader_skill_grammar::*;
+    use pad_skill_parser::schema::*;
+
+    fn parse(input: &str) -> LeaderSkill {
+        LeaderSkillGrammar::parse_leader_skill(input).unwrap()
+    }
+
+    fn effects(input: &str) -> Vec<LeaderEffect> {
+        parse(input).effects
+    }
+
+    #[test]
+    fn attribute_multiplier() {
+        assert_eq!(
+            vec![LeaderEffect::Multiplier(
+                LeaderCondition::Always,
+                LeaderTarget::Attributes(vec![Color::Fire]),
+                Stats::new(1.0, 4.0, 1.0)
+            )],
+            effects("火属性の攻撃力が4倍。")
+        );
+        assert_eq!(
+            vec![LeaderEffect::Multiplier(
+                LeaderCondition::Always,
+                LeaderTarget::Attributes(vec![Color::Fire, Color::Water]),
+                Stats::new(2.0, 2.0, 1.0)
+            )],
+            effects("火と水属性のHPと攻撃力が2倍。")
+        );
+    }
+
+    #[test]
+    fn type_multiplier() {
+        assert_eq!(
+            vec![
+                LeaderEffect::Multiplier(
+                    LeaderCondition::Always,
+                    LeaderTarget::Types(vec![MonsterType::Dragon, MonsterType::Healer]),
+                    Stats::new(1.5, 1.0, 1.0)
+                ),
+                LeaderEffect::Multiplier(
+                    LeaderCondition::Always,
+                    LeaderTarget::Types(vec![MonsterType::Dragon, MonsterType::Healer]),
+                    Stats::new(1.0, 2.0, 2.0)
+                ),
+            ],
+            effects("ドラゴンと回復タイプのHPが1.5倍、攻撃力と回復力は2倍。")
+        );
+        assert_eq!(
+            vec![LeaderEffect::Multiplier(
+                LeaderCondition::Always,
+                LeaderTarget::Types(vec![MonsterType::Attacker, MonsterType::Physical]),
+                Stats::new(1.5, 1.5, 1.5)
+            )],
+            effects("攻撃と体力タイプの全パラメータが1.5倍。")
+        );
+    }
+
+    #[test]
+    fn combo_condition() {
+        assert_eq!(
+            vec![
+                LeaderEffect::Multiplier(
+                    LeaderCondition::Combos(6),
+                    LeaderTarget::All,
+                    Stats::new(1.0, 10.0, 1.0)
+                ),
+                LeaderEffect::DamageReduction(LeaderCondition::Combos(6), 50.0),
+            ],
+            effects("6コンボ以上で攻撃力が10倍、ダメージを50%軽減。")
+        );
+    }
+
+    #[test]
+    fn color_match_condition() {
+        assert_eq!(
+            vec![LeaderEffect::Multiplier(
+                LeaderCondition::ColorMatch(vec![
+                    Drop::Colored(Color::Fire),
+                    Drop::Colored(Color::Water),
+                    Drop::NonColored(NonColoredDrop::Recovery),
+                ]),
+                LeaderTarget::All,
+                Stats::new(1.0, 3.0, 1.0)
+            )],
+            effects("火水回復の同時攻撃で攻撃力が3倍。")
+        );
+        assert_eq!(
+            vec![LeaderEffect::Multiplier(
+                LeaderCondition::Colors(4),
+                LeaderTarget::Attributes(vec![Color::Lightning]),
+                Stats::new(1.0, 5.0, 1.0)
+            )],
+            effects("4色以上同時攻撃で光属性の攻撃力が5倍。")
+        );
+    }
+
+    #[test]
+    fn damage_reduction_and_time_extension() {
+        assert_eq!(
+            vec![
+                LeaderEffect::DamageReduction(LeaderCondition::Always, 25.0),
+                LeaderEffect::TimeExtension(LeaderCondition::Always, 2.5),
+            ],
+            effects("受けるダメージを25%軽減。ドロップ操作時間を2.5秒延長。")
+        );
+        // 小数の軽減率を切り捨てない
+        assert_eq!(
+            vec![LeaderEffect::DamageReduction(LeaderCondition::Always, 12.5)],
+            effects("ダメージを12.5%軽減。")
+        );
+        // 延長にも発動条件が付く
+        assert_eq!(
+            vec![
+                LeaderEffect::TimeExtension(LeaderCondition::Combos(6), 2.0),
+                LeaderEffect::DamageReduction(LeaderCondition::Combos(6), 30.0),
+            ],
+            effects("6コンボ以上でドロップ操作時間を2秒延長、ダメージを30%軽減。")
+        );
+    }
+
+    #[test]
+    fn board_size() {
+        let skill = parse("【7×6マス】火属性の攻撃力が3倍。");
+
+        assert_eq!(Some(Size(7, 6)), skill.board_size);
+        assert_eq!(1, skill.effects.len());
+        assert_eq!(None, parse("火属性の攻撃力が3倍。").board_size);
+    }
+
+    #[test]
+    fn condition_does_not_inherit_target() {
+        assert_eq!(
+            vec![
+                LeaderEffect::Multiplier(
+                    LeaderCondition::Always,
+                    LeaderTarget::Attributes(vec![Color::Dark]),
+                    Stats::new(1.0, 3.0, 1.0)
+                ),
+                LeaderEffect::Multiplier(
+                    LeaderCondition::Combos(8),
+                    LeaderTarget::All,
+                    Stats::new(1.0, 8.0, 1.0)
+                ),
+            ],
+            effects("闇属性の攻撃力が3倍、8コンボ以上で攻撃力が8倍。")
+        );
+    }
+
+    #[test]
+    fn normalized_input() {
+        assert_eq!(
+            Some(Size(7, 6)),
+            parse("【７x６マス】火属性の攻撃力が３倍").board_size
+        );
+    }
+
+    #[test]
+    fn invalid_attribute() {
+        assert!(LeaderSkillGrammar::parse_leader_skill("ドラゴン属性の攻撃力が2倍。").is_err());
+        assert!(LeaderSkillGrammar::parse_leader_skill("火タイプの攻撃力が2倍。").is_err());
+        assert!(LeaderSkillGrammar::parse_leader_skill("神の同時攻撃で攻撃力が2倍。").is_err());
+    }
+
+    #[test]
+    fn fractional_count_is_error() {
+        assert!(
+            LeaderSkillGrammar::parse_leader_skill("【7.5×6マス】火属性の攻撃力が3倍。").is_err()
+        );
+        assert!(LeaderSkillGrammar::parse_leader_skill("1.5コンボ以上で攻撃力が2倍。").is_err());
+        assert!(
+            LeaderSkillGrammar::parse_leader_skill("2.5色以上同時攻撃で攻撃力が2倍。").is_err()
+        );
+    }
+}
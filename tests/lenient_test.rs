@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod lenient_test {
+    use pad_skill_parser::drop_set::DropSet;
+    use pad_skill_parser::lenient::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+
+    fn change_fire_to_wood() -> Skill {
+        Skill {
+            effect: SkillEffect::ChangeDropAToB(
+                DropSet::from(Drop::Colored(Color::Fire)),
+                vec![Drop::Colored(Color::Wood)],
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fully_parsed() {
+        let input = "火ドロップを木ドロップに変化。";
+
+        assert_eq!(vec![change_fire_to_wood()], parse_lenient(input));
+    }
+
+    #[test]
+    fn partially_parsed() {
+        let input = "火ドロップを木ドロップに変化。よく分からない効果。1ターンの間、落ちコンなし。";
+        let start = input.find('よ').unwrap();
+        let end = input.find("。1").unwrap();
+
+        assert_eq!(
+            vec![
+                change_fire_to_wood(),
+                Skill {
+                    effect: SkillEffect::Unrecognized {
+                        text: "よく分からない効果".to_owned(),
+                        span: start..end,
+                    },
+                    ..Default::default()
+                },
+                Skill {
+                    effect: SkillEffect::DropsNotFalling,
+                    turns_of_apply: Some(1),
+                    ..Default::default()
+                },
+            ],
+            parse_lenient(input)
+        );
+    }
+
+    #[test]
+    fn trailing_text_without_period() {
+        let input = "火ドロップを木ドロップに変化。 よく分からない効果";
+        let start = input.find('よ').unwrap();
+
+        assert_eq!(
+            vec![
+                change_fire_to_wood(),
+                Skill {
+                    effect: SkillEffect::Unrecognized {
+                        text: "よく分からない効果".to_owned(),
+                        span: start..input.len(),
+                    },
+                    ..Default::default()
+                },
+            ],
+            parse_lenient(input)
+        );
+    }
+
+    #[test]
+    fn unlock_clause_is_kept() {
+        let input = "ロックを解除し、よく分からない効果。";
+        let start = input.find('よ').unwrap();
+        let end = input.find('。').unwrap();
+
+        assert_eq!(
+            vec![
+                Skill {
+                    effect: SkillEffect::DropUnLock,
+                    ..Default::default()
+                },
+                Skill {
+                    effect: SkillEffect::Unrecognized {
+                        text: "よく分からない効果".to_owned(),
+                        span: start..end,
+                    },
+                    ..Default::default()
+                },
+            ],
+            parse_lenient(input)
+        );
+    }
+
+    #[test]
+    fn change_clause_is_kept() {
+        let input = "火ドロップを木ドロップに変化し、よく分からない効果。";
+
+        let skills = parse_lenient(input);
+
+        assert_eq!(2, skills.len());
+        assert_eq!(change_fire_to_wood(), skills[0]);
+        assert!(matches!(
+            &skills[1].effect,
+            SkillEffect::Unrecognized { text, .. } if text == "よく分からない効果"
+        ));
+    }
+
+    #[test]
+    fn unparsable_first_clause_keeps_sentence() {
+        let input = "1ターンの間、よく分からない効果。";
+
+        assert_eq!(
+            vec![Skill {
+                effect: SkillEffect::Unrecognized {
+                    text: "1ターンの間、よく分からない効果".to_owned(),
+                    span: 0..input.find('。').unwrap(),
+                },
+                ..Default::default()
+            }],
+            parse_lenient(input)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+mod common;
+
+#[cfg(test)]
+mod monte_carlo_test {
+    use crate::common::skills;
+    use pad_skill_parser::monte_carlo::*;
+    use pad_skill_parser::schema::*;
+
+    #[test]
+    fn gen_random_drop_each() {
+        let evaluation = MonteCarlo::new(200, 0)
+            .evaluate(&skills("5属性+回復を4個ずつ生成。"))
+            .unwrap();
+
+        assert_eq!(200, evaluation.trials);
+        assert_eq!(
+            1.0,
+            evaluation.probability_at_least(&Drop::Colored(Color::Fire), 4)
+        );
+        assert_eq!(
+            1.0,
+            evaluation.probability_at_least(&Drop::NonColored(NonColoredDrop::Recovery), 4)
+        );
+    }
+
+    #[test]
+    fn change_all_of_board_two_colors() {
+        let evaluation = MonteCarlo::new(200, 0)
+            .evaluate(&skills("全ドロップを火と光に変化。"))
+            .unwrap();
+
+        let fire = evaluation.expected_drops(&Drop::Colored(Color::Fire));
+        let lightning = evaluation.expected_drops(&Drop::Colored(Color::Lightning));
+
+        assert!((fire + lightning - 30.0).abs() < 1e-9);
+        assert!((fire - 15.0).abs() < 1.0);
+        assert_eq!(0.0, evaluation.expected_drops(&Drop::Colored(Color::Water)));
+        assert!(evaluation.probability_combos_at_least(1) > 0.99);
+    }
+
+    #[test]
+    fn drop_refresh_is_even() {
+        let evaluation = MonteCarlo::new(500, 0)
+            .evaluate(&skills("ランダムでドロップを入れ替える。"))
+            .unwrap();
+
+        evaluation
+            .expected_drops_all()
+            .into_iter()
+            .take(6)
+            .for_each(|(_, expected)| assert!((expected - 5.0).abs() < 0.5));
+        // 入れ替え後の盤面には消せる組み合わせが無い
+        assert_eq!(0.0, evaluation.expected_combos());
+    }
+
+    #[test]
+    fn gen_roulette() {
+        // ルーレットのマスは落ちてくるドロップのいずれかになる
+        let input = [
+            skills("全ドロップを火に変化。"),
+            skills("3ターンの間、ランダムでルーレットを3個生成。"),
+        ]
+        .concat();
+        let evaluation = MonteCarlo::new(600, 0).evaluate(&input).unwrap();
+
+        let fire = evaluation.expected_drops(&Drop::Colored(Color::Fire));
+        let water = evaluation.expected_drops(&Drop::Colored(Color::Water));
+
+        assert!((fire - 27.5).abs() < 0.2);
+        assert!((water - 0.5).abs() < 0.2);
+        assert!(evaluation.probability_at_least(&Drop::Colored(Color::Fire), 27) == 1.0);
+    }
+
+    #[test]
+    fn single_color_skyfall_is_truncated() {
+        // 火しか落ちてこないので消化が終わらない
+        let input = [
+            skills("1ターンの間、火ドロップのみ落ちてくる。"),
+            skills("全ドロップを火と水に変化。"),
+        ]
+        .concat();
+        let evaluation = MonteCarlo::new(20, 0).evaluate(&input).unwrap();
+
+        assert_eq!(20, evaluation.truncated);
+        assert!(evaluation.combos.is_empty());
+        assert_eq!(1.0, evaluation.probability_truncated());
+        assert_eq!(0.0, evaluation.expected_combos());
+    }
+
+    #[test]
+    fn evaluate_is_reproducible() {
+        let input = skills("ランダムで火と水を2個ずつ生成。");
+
+        assert_eq!(
+            MonteCarlo::new(100, 42).evaluate(&input).unwrap(),
+            MonteCarlo::new(100, 42).evaluate(&input).unwrap()
+        );
+    }
+}
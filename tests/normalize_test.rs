@@ -0,0 +1,89 @@
+mod common;
+
+#[cfg(test)]
+mod normalize_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::normalize::normalize;
+    use pad_skill_parser::skill_grammar::*;
+
+    #[test]
+    fn characters() {
+        assert_eq!(
+            "ランダムで火ドロップを5個生成。",
+            normalize("ランダムで火ドロップを５個生成")
+        );
+        assert_eq!(
+            "3ターンの間、盤面を7×6マスにする。",
+            normalize("３ターンの間, 盤面を7x6マスにする．")
+        );
+        assert_eq!(
+            "3ターンの間、強化ドロップが20%の確率で落ちてくる。",
+            normalize("3ターンの間、強化ドロップが２０％の確率で落ちてくる。")
+        );
+        assert_eq!("5属性+回復に変化。", normalize("5属性＋回復に\n変化。"));
+    }
+
+    #[test]
+    fn note() {
+        assert_eq!(
+            "火ドロップを木ドロップに変化。",
+            normalize("火ドロップを木ドロップに変化。 ※進化前のみ")
+        );
+        assert_eq!(
+            "火ドロップを木ドロップに変化。",
+            normalize("火ドロップを木ドロップに変化。※進化前のみ。究極進化後は対象外。")
+        );
+    }
+
+    #[test]
+    fn note_mark_in_sentence() {
+        assert_eq!(
+            "火ドロップを※木ドロップに変化。1ターンの間、落ちコンなし。",
+            normalize("火ドロップを※木ドロップに変化。1ターンの間、落ちコンなし。")
+        );
+    }
+
+    #[test]
+    fn wording() {
+        assert_eq!(
+            "ランダムでお邪魔ドロップを3個生成。",
+            normalize("ランダムでおじゃまドロップを3個生成。")
+        );
+        assert_eq!(
+            "1ターンの間、落ちコンなし。",
+            normalize("1ターン間、落ちコン無し")
+        );
+    }
+
+    #[test]
+    fn parse_skills() {
+        let inputs = [
+            (
+                "ランダムで火ドロップを５個生成",
+                "ランダムで火ドロップを5個生成。",
+            ),
+            (
+                "３ターンの間、盤面を７ｘ６マスにする",
+                "3ターンの間、盤面を7×6マスにする。",
+            ),
+            (
+                "１ターン間、落ちコン無し。※注記",
+                "1ターンの間、落ちコンなし。",
+            ),
+        ];
+
+        for (input, canonical) in inputs {
+            assert_eq!(
+                skills(canonical),
+                SkillGrammar::parse_skills(input).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_text_is_unchanged() {
+        for input in corpus() {
+            assert_eq!(input, normalize(input));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+mod common;
+
+#[cfg(test)]
+mod notation_test {
+    use crate::common::skills;
+    use pad_skill_parser::board::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::simulator::*;
+
+    fn apply(board: &str, input: &str) -> Board {
+        let mut simulator = Simulator::new(Board::from_notation(board).unwrap(), 0);
+        simulator.apply_skills(&skills(input)).unwrap();
+        simulator.board
+    }
+
+    #[test]
+    fn parse_drops_and_markers() {
+        let board = Board::from_notation("R+B*G?L#DH@\nJPMX../RBGLDH").unwrap();
+
+        assert_eq!(Size(6, 3), board.size);
+        assert!(board.get(0, 0).unwrap().powered_up);
+        assert!(board.get(0, 1).unwrap().locked);
+        assert!(board.get(0, 2).unwrap().cloud);
+        assert!(board.get(0, 3).unwrap().tape);
+        assert!(board.get(0, 5).unwrap().roulette);
+        assert_eq!(
+            Some(Drop::NonColored(NonColoredDrop::Disturb)),
+            board.get(1, 0).unwrap().drop
+        );
+        assert_eq!(
+            Some(Drop::NonColored(NonColoredDrop::Bomb)),
+            board.get(1, 3).unwrap().drop
+        );
+        assert!(board.get(1, 4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "R+BGLDH\nBGLDHR*\nGL?DHRB\nLDH#RBG\n.HRB@GL";
+
+        assert_eq!(text, Board::from_notation(text).unwrap().to_notation());
+        assert_eq!(text, Board::from_notation(text).unwrap().to_string());
+    }
+
+    #[test]
+    fn ignore_whitespace() {
+        let board = Board::from_notation(
+            "
+            R B G
+            L D H
+            ",
+        )
+        .unwrap();
+
+        assert_eq!("RBG\nLDH", board.to_notation());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Board::from_notation("RBZ").is_err());
+        assert!(Board::from_notation("+RB").is_err());
+        assert!(Board::from_notation("RBG\nRB").is_err());
+        assert!(Board::from_notation("").is_err());
+
+        // 空きマスは強化もロックもできない
+        assert!(Board::from_notation(".+RB").is_err());
+        assert!(Board::from_notation("R.*B").is_err());
+        assert!(Board::from_notation(".?R#B").is_ok());
+    }
+
+    #[test]
+    fn parse_error_reports_source_line() {
+        // 空行を読み飛ばしても、エラーの行番号は入力の行番号
+        let error = Board::from_notation("RBG\n\nRB").unwrap_err();
+        assert!(error.to_string().starts_with("row 3:"), "{error}");
+    }
+
+    #[test]
+    fn apply_shape_gen() {
+        let board = apply(
+            "RBGLDH
+             BGLDHR
+             GLD*HRB
+             LDHRBG
+             DHRBGL",
+            "右端1列を光ドロップに変化。",
+        );
+
+        assert_eq!(
+            "RBGLDL
+             BGLDHL
+             GLD*HRL
+             LDHRBL
+             DHRBGL"
+                .replace(' ', ""),
+            board.to_notation()
+        );
+    }
+
+    #[test]
+    fn apply_change_drop_a_to_b() {
+        let board = apply("RBGLDH\nBGL*DHR", "光と回復をランダムで火に変化。");
+
+        assert_eq!("RBGRDR\nBGL*DRR", board.to_notation());
+    }
+}
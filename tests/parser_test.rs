@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod parser_test {
+    use pad_skill_parser::drop_set::DropSet;
     use pad_skill_parser::schema::*;
     use pad_skill_parser::skill::*;
     use pad_skill_parser::skill_grammar::*;
@@ -23,7 +24,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::ChangeDropAToB(
-                vec![Drop::Colored(Color::Wood)],
+                DropSet::from(Drop::Colored(Color::Wood)),
                 vec![Drop::Colored(Color::Water)],
             ),
         }]);
@@ -41,7 +42,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::ChangeDropAToB(
-                vec![Drop::NonColored(NonColoredDrop::Recovery)],
+                DropSet::from(Drop::NonColored(NonColoredDrop::Recovery)),
                 vec![Drop::Colored(Color::Water)],
             ),
         }]);
@@ -59,12 +60,12 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::ChangeDropAToB(
-                vec![
+                DropSet::from(vec![
                     Drop::NonColored(NonColoredDrop::Disturb),
                     Drop::NonColored(NonColoredDrop::Bomb),
                     Drop::NonColored(NonColoredDrop::Poison),
                     Drop::NonColored(NonColoredDrop::DeadlyPoison),
-                ],
+                ]),
                 vec![Drop::Colored(Color::Lightning)],
             ),
         }]);
@@ -83,7 +84,7 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::ChangeDropAToB(
-                    vec![Drop::Colored(Color::Fire)],
+                    DropSet::from(Drop::Colored(Color::Fire)),
                     vec![Drop::Colored(Color::Water)],
                 ),
             },
@@ -91,7 +92,7 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::ChangeDropAToB(
-                    vec![Drop::Colored(Color::Lightning)],
+                    DropSet::from(Drop::Colored(Color::Lightning)),
                     vec![Drop::NonColored(NonColoredDrop::Recovery)],
                 ),
             },
@@ -110,7 +111,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::ChangeDropAToB(
-                vec![Drop::Colored(Color::Fire), Drop::Colored(Color::Wood)],
+                DropSet::from(vec![Drop::Colored(Color::Fire), Drop::Colored(Color::Wood)]),
                 vec![
                     Drop::Colored(Color::Lightning),
                     Drop::NonColored(NonColoredDrop::Recovery),
@@ -131,10 +132,10 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::ChangeDropAToB(
-                vec![
+                DropSet::from(vec![
                     Drop::Colored(Color::Lightning),
                     Drop::NonColored(NonColoredDrop::Recovery),
-                ],
+                ]),
                 vec![
                     Drop::Colored(Color::Fire),
                     Drop::Colored(Color::Water),
@@ -254,7 +255,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![Drop::Colored(Color::Fire)],
+                DropSet::from(Drop::Colored(Color::Fire)),
                 vec![(Drop::Colored(Color::Fire), 1)],
             ),
         }]);
@@ -272,7 +273,10 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![Drop::Colored(Color::Fire), Drop::Colored(Color::Water)],
+                DropSet::from(vec![
+                    Drop::Colored(Color::Fire),
+                    Drop::Colored(Color::Water),
+                ]),
                 vec![
                     (Drop::Colored(Color::Fire), 2),
                     (Drop::Colored(Color::Water), 2),
@@ -293,7 +297,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![],
+                DropSet::empty(),
                 vec![
                     (Drop::Colored(Color::Fire), 4),
                     (Drop::Colored(Color::Water), 4),
@@ -318,10 +322,10 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![
+                DropSet::from(vec![
                     Drop::NonColored(NonColoredDrop::Recovery),
                     Drop::Colored(Color::Fire),
-                ],
+                ]),
                 vec![(Drop::Colored(Color::Fire), 6)],
             ),
         }]);
@@ -339,12 +343,12 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![
+                DropSet::from(vec![
                     Drop::Colored(Color::Fire),
                     Drop::NonColored(NonColoredDrop::Disturb),
                     Drop::Colored(Color::Water),
                     Drop::Colored(Color::Wood),
-                ],
+                ]),
                 vec![
                     (Drop::Colored(Color::Water), 2),
                     (Drop::Colored(Color::Wood), 2),
@@ -365,7 +369,7 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![],
+                DropSet::empty(),
                 vec![
                     (Drop::Colored(Color::Fire), 15),
                     (Drop::Colored(Color::Lightning), 15),
@@ -392,7 +396,7 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::GenRandomDrop(
-                    vec![],
+                    DropSet::empty(),
                     vec![
                         (Drop::Colored(Color::Water), 10),
                         (Drop::Colored(Color::Lightning), 10),
@@ -415,11 +419,11 @@ mod parser_test {
             sub_effects: None,
             turns_of_apply: None,
             effect: SkillEffect::GenRandomDrop(
-                vec![
+                DropSet::from(vec![
                     Drop::Colored(Color::Wood),
                     Drop::Colored(Color::Dark),
                     Drop::NonColored(NonColoredDrop::Recovery),
-                ],
+                ]),
                 vec![
                     (Drop::Colored(Color::Wood), 11),
                     (Drop::Colored(Color::Dark), 7),
@@ -447,10 +451,10 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::GenRandomDrop(
-                    vec![
+                    DropSet::from(vec![
                         Drop::Colored(Color::Water),
                         Drop::NonColored(NonColoredDrop::Recovery),
-                    ],
+                    ]),
                     vec![
                         (Drop::Colored(Color::Water), 3),
                         (Drop::NonColored(NonColoredDrop::Recovery), 9),
@@ -1270,10 +1274,10 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::GenRandomDrop(
-                    vec![
+                    DropSet::from(vec![
                         Drop::Colored(Color::Fire),
                         Drop::NonColored(NonColoredDrop::Recovery),
-                    ],
+                    ]),
                     vec![
                         (Drop::Colored(Color::Fire), 6),
                         (Drop::NonColored(NonColoredDrop::Recovery), 6),
@@ -1301,11 +1305,11 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::GenRandomDrop(
-                    vec![
+                    DropSet::from(vec![
                         Drop::NonColored(NonColoredDrop::Recovery),
                         Drop::Colored(Color::Fire),
                         Drop::Colored(Color::Dark),
-                    ],
+                    ]),
                     vec![
                         (Drop::Colored(Color::Fire), 3),
                         (Drop::Colored(Color::Dark), 3),
@@ -1333,7 +1337,7 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::ChangeDropAToB(
-                    vec![Drop::Colored(Color::Lightning)],
+                    DropSet::from(Drop::Colored(Color::Lightning)),
                     vec![Drop::Colored(Color::Dark)],
                 ),
             },
@@ -1390,7 +1394,7 @@ mod parser_test {
                 sub_effects: None,
                 turns_of_apply: None,
                 effect: SkillEffect::GenRandomDrop(
-                    vec![],
+                    DropSet::empty(),
                     vec![
                         (Drop::Colored(Color::Fire), 9),
                         (Drop::Colored(Color::Water), 9),
@@ -1867,4 +1871,13 @@ mod parser_test {
 
         assert_eq!(except, grammar);
     }
+
+    #[test]
+    fn incomplete_skill_is_error() {
+        let input = "木ドロップを。";
+        let grammar = &mut SkillGrammar::new();
+        let error = parse(input, FILE_NAME, grammar).unwrap_err();
+
+        assert!(error.to_string().contains("incomplete skill text"));
+    }
 }
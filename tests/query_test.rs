@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod query_test {
+    use pad_skill_parser::query::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill_grammar::*;
+
+    fn matches(query: &str, input: &str) -> bool {
+        let query = Query::parse(query).unwrap();
+        query.matches(&SkillGrammar::parse_skills(input).unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Query::Or(
+                Box::new(Query::ConvertsTo(Drop::Colored(Color::Water))),
+                Box::new(Query::And(
+                    Box::new(Query::Generates(
+                        Drop::Colored(Color::Lightning),
+                        Comparison(Op::Ge, 10)
+                    )),
+                    Box::new(Query::Not(Box::new(Query::Turns(Comparison(Op::Lt, 2)))))
+                ))
+            ),
+            Query::parse("converts_to:water or generates(light)>=10 and not turns<2").unwrap()
+        );
+        assert_eq!(
+            Query::Generates(
+                Drop::NonColored(NonColoredDrop::DeadlyPoison),
+                Comparison(Op::Ge, 1)
+            ),
+            Query::parse("generates(Mortal_Poison)").unwrap()
+        );
+        assert_eq!(
+            Query::Falloff(Drop::NonColored(NonColoredDrop::Recovery)),
+            Query::parse("falloff( 回復 )").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!(Query::parse("").is_err());
+        assert!(Query::parse("converts_to:rainbow").is_err());
+        assert!(Query::parse("shape:circle").is_err());
+        assert!(Query::parse("turns>=many").is_err());
+        assert!(Query::parse("(turns>=1").is_err());
+        assert!(Query::parse("turns>=1 turns>=2").is_err());
+        assert!(Query::parse("heals:yes").is_err());
+        assert!(Query::parse("effect:DropUnlocked").is_err());
+    }
+
+    #[test]
+    fn converts_to() {
+        assert!(matches(
+            "converts_to:water",
+            "木ドロップを水ドロップに変化。"
+        ));
+        assert!(matches("converts_to:水", "全ドロップを5属性に変化。"));
+        assert!(!matches(
+            "converts_to:wood",
+            "木ドロップを水ドロップに変化。"
+        ));
+    }
+
+    #[test]
+    fn generates() {
+        let input = "ドロップのロックを解除し、水、光、回復を10個ずつ生成。";
+
+        assert!(matches("generates(light)>=10", input));
+        assert!(matches("generates(light)=10", input));
+        assert!(!matches("generates(light)>10", input));
+        assert!(!matches("generates(fire)", input));
+        assert!(matches("generates(light)>=10 and effect:DropUnLock", input));
+
+        // 指定型生成は6×5の盤面で数える
+        assert!(matches(
+            "generates(water)=6",
+            "最上段横1列を水ドロップに変化。"
+        ));
+        assert!(matches(
+            "generates(dark)=5 and generates(light)=5",
+            "左端1列を闇ドロップに、右端1列を光ドロップに変化。"
+        ));
+    }
+
+    #[test]
+    fn turns_and_falloff() {
+        let input = "3ターンの間、水と回復ドロップが少し落ちやすくなる。";
+
+        assert!(matches("turns>=2", input));
+        assert!(!matches("turns>3", input));
+        assert!(matches("falloff(heal)", input));
+        assert!(!matches("falloff(fire)", input));
+        assert!(!matches("turns>=1", "木ドロップを水ドロップに変化。"));
+    }
+
+    #[test]
+    fn shape_and_effect() {
+        assert!(matches("shape:cross", "十字型に火を1つ生成。"));
+        assert!(matches("shape:small_cross", "十字型に火を1つ生成。"));
+        assert!(matches("shape:cross", "十字型に光ドロップを生成。"));
+        assert!(!matches("shape:small_cross", "十字型に光ドロップを生成。"));
+        assert!(matches("shape:col", "左端1列を光ドロップに変化。"));
+        assert!(matches(
+            "effect:dropshapegen",
+            "左端1列を光ドロップに変化。"
+        ));
+        assert!(!matches("effect:DropUnLock", "左端1列を光ドロップに変化。"));
+    }
+
+    #[test]
+    fn combination() {
+        let input = "ドロップのロックを解除し、光を闇に変化。";
+
+        assert!(matches("effect:DropUnLock and converts_to:dark", input));
+        assert!(matches("converts_to:fire or converts_to:dark", input));
+        assert!(!matches("not (effect:DropUnLock or turns>=1)", input));
+        assert!(matches("NOT converts_to:fire AND effect:DropUnLock", input));
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+#[cfg(test)]
+mod serde_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::category::*;
+    use pad_skill_parser::leader_skill::*;
+    use pad_skill_parser::leader_skill_grammar::*;
+    use pad_skill_parser::skill::*;
+    use serde_json::json;
+
+    #[test]
+    fn change_drop() {
+        let input = "火ドロップを木ドロップに変化。";
+
+        assert_eq!(
+            json!([{
+                "effect": {
+                    "type": "ChangeDropAToB",
+                    "value": [
+                        [{ "type": "Colored", "value": "Fire" }],
+                        [{ "type": "Colored", "value": "Wood" }]
+                    ]
+                },
+                "sub_effects": null,
+                "turns_of_apply": null
+            }]),
+            serde_json::to_value(skills(input)).unwrap()
+        );
+    }
+
+    #[test]
+    fn unit_variant_and_size() {
+        let input = "3ターンの間、盤面を7×6マスにする。";
+
+        assert_eq!(
+            json!([{
+                "effect": { "type": "ChangeBoardSize", "value": [7, 6] },
+                "sub_effects": null,
+                "turns_of_apply": 3
+            }]),
+            serde_json::to_value(skills(input)).unwrap()
+        );
+        assert_eq!(
+            json!({ "type": "DropRefresh" }),
+            serde_json::to_value(SkillEffect::DropRefresh).unwrap()
+        );
+    }
+
+    #[test]
+    fn round_trip_corpus() {
+        for input in corpus() {
+            let expected = skills(input);
+            let json = serde_json::to_string(&expected).unwrap();
+
+            assert_eq!(
+                expected,
+                serde_json::from_str::<Vec<Skill>>(&json).unwrap(),
+                "{input} -> {json}"
+            );
+        }
+    }
+
+    #[test]
+    fn category_set() {
+        let set = categories(&skills("ドロップのロックを解除し、光を闇に変化。"));
+        let value = serde_json::to_value(set).unwrap();
+
+        assert_eq!(json!(["Change", "WithUnlock"]), value);
+        assert_eq!(set, serde_json::from_value::<CategorySet>(value).unwrap());
+    }
+
+    #[test]
+    fn leader_skill() {
+        let skill = LeaderSkillGrammar::parse_leader_skill(
+            "【7×6マス】6コンボ以上で火属性の攻撃力が10倍。",
+        )
+        .unwrap();
+        let value = serde_json::to_value(&skill).unwrap();
+
+        assert_eq!(
+            json!({
+                "board_size": [7, 6],
+                "effects": [{
+                    "type": "Multiplier",
+                    "value": [
+                        {"type": "Combos", "value": 6},
+                        {"type": "Attributes", "value": ["Fire"]},
+                        {"hp": 1.0, "attack": 10.0, "recovery": 1.0}
+                    ]
+                }]
+            }),
+            value
+        );
+        assert_eq!(skill, serde_json::from_value::<LeaderSkill>(value).unwrap());
+    }
+}
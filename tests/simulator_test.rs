@@ -0,0 +1,412 @@
+mod common;
+
+#[cfg(test)]
+mod simulator_test {
+    use crate::common::skills;
+    use pad_skill_parser::board::*;
+    use pad_skill_parser::drop_set::DropSet;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::simulator::*;
+    use pad_skill_parser::skill::*;
+
+    /// R: 火, B: 水, G: 木, L: 光, D: 闇, H: 回復
+    fn board(rows: &[&str]) -> Board {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| {
+                        Cell::new(match c {
+                            'R' => Drop::Colored(Color::Fire),
+                            'B' => Drop::Colored(Color::Water),
+                            'G' => Drop::Colored(Color::Wood),
+                            'L' => Drop::Colored(Color::Lightning),
+                            'D' => Drop::Colored(Color::Dark),
+                            _ => Drop::NonColored(NonColoredDrop::Recovery),
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Board::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn find_combos_row_and_col() {
+        let simulator = Simulator::new(
+            board(&[
+                "RRRBGL", //
+                "BGLDHB", //
+                "BLDHRG", //
+                "BDHRGL", //
+                "GHRGLD", //
+            ]),
+            0,
+        );
+
+        let combos = simulator.find_combos();
+
+        assert_eq!(2, combos.len());
+        assert_eq!(Drop::Colored(Color::Fire), combos[0].drop);
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2)], combos[0].cells);
+        assert_eq!(Drop::Colored(Color::Water), combos[1].drop);
+        assert_eq!(vec![(1, 0), (2, 0), (3, 0)], combos[1].cells);
+    }
+
+    #[test]
+    fn find_combos_connected_is_one_combo() {
+        let simulator = Simulator::new(
+            board(&[
+                "RRRBGL", //
+                "RGLDHB", //
+                "RLDHRG", //
+                "GDHRGL", //
+                "BHRGLD", //
+            ]),
+            0,
+        );
+
+        let combos = simulator.find_combos();
+
+        assert_eq!(1, combos.len());
+        assert_eq!(5, combos[0].cells.len());
+    }
+
+    #[test]
+    fn cascade_without_skyfall_combo() {
+        let mut simulator = Simulator::with_random_board(Size::standard(), 1);
+        simulator
+            .apply_skills(&skills("全ドロップを火と光に変化。"))
+            .unwrap();
+        simulator
+            .apply_skills(&skills("1ターンの間、落ちコンなし。"))
+            .unwrap();
+
+        let report = simulator.cascade();
+
+        assert_eq!(0, report.cascades);
+        assert!(!report.truncated);
+        assert_eq!(
+            report.total(),
+            report.combos_of(&Drop::Colored(Color::Fire))
+                + report.combos_of(&Drop::Colored(Color::Lightning))
+        );
+        assert!(simulator.find_combos().is_empty());
+    }
+
+    #[test]
+    fn cascade_with_single_color_skyfall_is_truncated() {
+        // 火しか落ちてこないので、補充の度に消せる組み合わせができる
+        let mut simulator = Simulator::with_random_board(Size::standard(), 0);
+        simulator
+            .apply_skills(&skills("1ターンの間、火ドロップのみ落ちてくる。"))
+            .unwrap();
+        simulator
+            .apply_skills(&skills("全ドロップを火に変化。"))
+            .unwrap();
+
+        let report = simulator.cascade();
+
+        assert!(report.truncated);
+        assert_eq!(MAX_CASCADE_ROUNDS - 1, report.cascades);
+        assert_eq!(
+            report.total(),
+            report.combos_of(&Drop::Colored(Color::Fire))
+        );
+    }
+
+    #[test]
+    fn cascade_is_reproducible() {
+        let run = |seed| {
+            let mut simulator = Simulator::with_random_board(Size::standard(), seed);
+            simulator
+                .apply_skills(&skills("全ドロップを5属性+回復に変化。"))
+                .unwrap();
+            simulator.cascade()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert!(run(7).total() > 0);
+    }
+
+    #[test]
+    fn change_all_of_board_guarantees_drops() {
+        let mut simulator = Simulator::with_random_board(Size::standard(), 3);
+        simulator
+            .apply_skills(&skills("全ドロップを5属性+回復に変化。"))
+            .unwrap();
+
+        let counts = simulator.board.counts();
+
+        assert_eq!(6, counts.len());
+        assert!(counts
+            .iter()
+            .all(|(_, qty)| *qty >= MIN_DROPS_OF_BOARD_CHANGE));
+    }
+
+    #[test]
+    fn gen_random_drop() {
+        let mut simulator = Simulator::with_random_board(Size::standard(), 5);
+        let before = simulator.board.count(&Drop::Colored(Color::Fire));
+        simulator
+            .apply_skills(&skills("ランダムで火ドロップを6個生成。"))
+            .unwrap();
+
+        assert_eq!(
+            before + 6,
+            simulator.board.count(&Drop::Colored(Color::Fire))
+        );
+    }
+
+    #[test]
+    fn gen_random_drop_from_excluded_drops() {
+        // 木、闇、回復以外のマスが足りない場合も生成数を生成する
+        let mut simulator = Simulator::new(
+            board(&[
+                "GGGGGG", //
+                "GGDDDD", //
+                "DDDHHH", //
+                "HHHHHH", //
+                "HHRRRB", //
+            ]),
+            0,
+        );
+        simulator
+            .apply_skills(&skills(
+                "ランダムで木ドロップを11個、闇ドロップを7個、回復ドロップを5個生成。",
+            ))
+            .unwrap();
+
+        assert!(simulator.board.count(&Drop::Colored(Color::Wood)) >= 11);
+        assert!(simulator.board.count(&Drop::Colored(Color::Dark)) >= 7);
+        assert!(
+            simulator
+                .board
+                .count(&Drop::NonColored(NonColoredDrop::Recovery))
+                >= 5
+        );
+    }
+
+    #[test]
+    fn shape_gen_with_locked_drop() {
+        let mut simulator = Simulator::new(
+            board(&[
+                "RBGLDH", //
+                "BGLDHR", //
+                "GLDHRB", //
+                "LDHRBG", //
+                "DHRBGL", //
+            ]),
+            0,
+        );
+        simulator.board.get_mut(4, 0).unwrap().locked = true;
+        simulator
+            .apply_skills(&skills("左端1列を光ドロップに変化。"))
+            .unwrap();
+
+        assert_eq!(
+            vec![Some(Drop::Colored(Color::Lightning)); 4],
+            (0..4)
+                .map(|row| simulator.board.get(row, 0).unwrap().drop.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(Drop::Colored(Color::Dark)),
+            simulator.board.get(4, 0).unwrap().drop
+        );
+
+        simulator
+            .apply_skills(&skills("ドロップのロックを解除し、右端1列を光に変化。"))
+            .unwrap();
+        assert!(!simulator.board.get(4, 0).unwrap().locked);
+    }
+
+    #[test]
+    fn shape_masks_on_standard_board() {
+        let fire = Drop::Colored(Color::Fire);
+        let size = Size::standard();
+
+        assert_eq!(
+            10,
+            ShapeType::CrossShape(fire.clone())
+                .mask(&size)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            18,
+            ShapeType::ShapeOfBoardPerimeter(fire.clone())
+                .mask(&size)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            12,
+            ShapeType::ShapeOfBoardCenter(fire.clone())
+                .mask(&size)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            10,
+            ShapeType::LShape(fire.clone(), 2)
+                .mask(&size)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            4,
+            ShapeType::ShapeOfBoardCorners(fire.clone(), 1)
+                .mask(&size)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            vec![(0, 5, fire.clone()), (1, 5, fire.clone())],
+            ShapeType::Col(-1, fire.clone()).mask(&Size(6, 2)).unwrap()
+        );
+    }
+
+    #[test]
+    fn shape_masks_on_small_boards() {
+        let fire = Drop::Colored(Color::Fire);
+
+        assert_eq!(
+            3,
+            ShapeType::ZShape(fire.clone())
+                .mask(&Size(3, 1))
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            6,
+            ShapeType::ZShape(fire.clone())
+                .mask(&Size(3, 2))
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn unsupported_shape_is_error() {
+        let shape = ShapeType::ShapeOfSomeKind(Drop::Colored(Color::Fire), "8".to_owned(), 1);
+        let skill = Skill {
+            effect: SkillEffect::DropShapeGen(vec![shape.clone()]),
+            ..Default::default()
+        };
+
+        assert!(shape.mask(&Size::standard()).is_err());
+        assert!(Simulator::with_random_board(Size::standard(), 0)
+            .apply_skill(&skill)
+            .is_err());
+    }
+
+    #[test]
+    fn without_targets_or_falling_drops() {
+        let fire = Drop::Colored(Color::Fire);
+        let mut simulator = Simulator::new(board(&["RRB", "BBR"]), 0);
+
+        // 変換先が無い変換は何もしない
+        simulator
+            .apply_skill(&Skill {
+                effect: SkillEffect::ChangeDropAToB(DropSet::from(fire.clone()), vec![]),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(3, simulator.board.count(&fire));
+
+        // 落ちてくるドロップが無い場合は空きマスのまま残す
+        simulator.skyfall.apply(&Skill {
+            effect: SkillEffect::DropFalloff(vec![], VolumeVariation::Only),
+            ..Default::default()
+        });
+        simulator.board.get_mut(0, 0).unwrap().clear();
+        simulator.fill(false);
+
+        assert!(simulator.board.get(0, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn gen_on_board_without_cols() {
+        let mut simulator = Simulator::new(Board::new(Size(0, 5)), 0);
+
+        for effect in [
+            SkillEffect::GenTeap(BoardPosition::Random),
+            SkillEffect::GenCloud(BoardPosition::Random, Size(2, 2)),
+        ] {
+            simulator
+                .apply_skill(&Skill {
+                    effect,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        assert_eq!(0, simulator.board.width());
+        assert_eq!(5, simulator.board.rows().len());
+        assert!(simulator.board.get(0, 0).is_err());
+    }
+
+    #[test]
+    fn board_from_uneven_rows_is_error() {
+        let rows = vec![vec![Cell::default(); 2], vec![Cell::default(); 1]];
+
+        assert!(Board::from_rows(rows).is_err());
+    }
+
+    #[test]
+    fn gen_roulette() {
+        let mut simulator = Simulator::with_random_board(Size::standard(), 3);
+        let roulettes = |simulator: &Simulator| {
+            simulator
+                .board
+                .cells()
+                .iter()
+                .filter(|cell| cell.roulette)
+                .count()
+        };
+
+        simulator
+            .apply_skills(&skills("3ターンの間、ランダムでルーレットを2個生成。"))
+            .unwrap();
+        assert_eq!(2, roulettes(&simulator));
+
+        // 既にルーレットのマスとは重ならない
+        simulator
+            .apply_skills(&skills("3ターンの間、ランダムでルーレットを2個生成。"))
+            .unwrap();
+        assert_eq!(4, roulettes(&simulator));
+        assert_eq!(
+            30,
+            simulator
+                .board
+                .cells()
+                .iter()
+                .filter(|c| !c.is_empty())
+                .count()
+        );
+    }
+
+    #[test]
+    fn gen_cloud_and_tape() {
+        let mut simulator = Simulator::with_random_board(Size::standard(), 9);
+        simulator
+            .apply_skills(&skills("4ターンの間、最上段に5×1の雲が発生。"))
+            .unwrap();
+        simulator
+            .apply_skills(&skills("1ターンの間、左端1列が操作不可になる。"))
+            .unwrap();
+
+        assert_eq!(
+            5,
+            simulator.board.cells().iter().filter(|c| c.cloud).count()
+        );
+        assert!(
+            (0..6)
+                .filter(|col| simulator.board.get(0, *col).unwrap().cloud)
+                .count()
+                == 5
+        );
+        assert!((0..5).all(|row| simulator.board.get(row, 0).unwrap().tape));
+    }
+}
@@ -0,0 +1,192 @@
+mod common;
+
+#[cfg(test)]
+mod skill_en_test {
+    use crate::common::{corpus, skills};
+    use pad_skill_parser::drop_set::DropSet;
+    use pad_skill_parser::english::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::skill_en_grammar::SkillEnGrammar;
+    use pad_skill_parser::skill_en_parser;
+
+    fn en_skills(input: &str) -> Vec<Skill> {
+        let grammar = &mut SkillEnGrammar::new();
+        let _parsed = skill_en_parser::parse(input, "<input>", grammar).unwrap();
+
+        grammar.skill_list.to_vec()
+    }
+
+    #[test]
+    fn round_trip_corpus() {
+        for input in corpus() {
+            let expected = skills(input);
+            let english = skills_to_english(&expected);
+
+            assert_eq!(expected, en_skills(&english), "{input} -> {english}");
+        }
+    }
+
+    /// 北米版の本文と、対応する日本版の本文
+    const NA_FIXTURES: [(&str, &str); 9] = [
+        (
+            "Voids damage absorption for 1 turn.",
+            "1ターンの間、ダメージ吸収を無効化。",
+        ),
+        (
+            "Voids attribute absorption for 1 turn.",
+            "1ターンの間、属性吸収を無効化。",
+        ),
+        (
+            "Voids damage absorption and attribute absorption for 1 turn.",
+            "1ターンの間、ダメージ吸収と属性吸収を無効化。",
+        ),
+        (
+            "Pierces through damage void for 1 turn.",
+            "1ターンの間、ダメージ無効を貫通。",
+        ),
+        (
+            "The leftmost column becomes unmovable for 1 turn.",
+            "1ターンの間、左端1列が操作不可になる。",
+        ),
+        (
+            "The bottom row becomes unmovable for 1 turn.",
+            "1ターンの間、最下段横1列が操作不可になる。",
+        ),
+        (
+            "Change the board to 7x6 for 1 turn.",
+            "1ターンの間、盤面を7×6マスにする。",
+        ),
+        (
+            "No skyfall combos for 1 turn.",
+            "1ターンの間、落ちコンなし。",
+        ),
+        (
+            "Create 2 roulette orbs at random for 3 turns.",
+            "3ターンの間、ランダムでルーレットを2個生成。",
+        ),
+    ];
+
+    #[test]
+    fn na_fixtures() {
+        for (na, jp) in NA_FIXTURES {
+            assert_eq!(skills(jp), en_skills(na), "{na}");
+        }
+    }
+
+    #[test]
+    fn void_attribute_and_damage_absorption() {
+        let input = "Voids attribute and damage absorption for 1 turn.";
+
+        assert_eq!(
+            vec![
+                Skill {
+                    effect: SkillEffect::NullificationAttributeAbsorption,
+                    turns_of_apply: Some(1),
+                    ..Default::default()
+                },
+                Skill {
+                    effect: SkillEffect::NullificationDamageAbsorption,
+                    turns_of_apply: Some(1),
+                    ..Default::default()
+                },
+            ],
+            en_skills(input)
+        );
+    }
+
+    #[test]
+    fn change_drop() {
+        let input = "Change Wood orbs to Water orbs.";
+
+        assert_eq!(
+            vec![Skill {
+                effect: SkillEffect::ChangeDropAToB(
+                    DropSet::from(Drop::Colored(Color::Wood)),
+                    vec![Drop::Colored(Color::Water)]
+                ),
+                ..Default::default()
+            }],
+            en_skills(input)
+        );
+    }
+
+    #[test]
+    fn gen_random_drop_from() {
+        let input = "Create 6 Fire orbs from non-Heal and non-Dark orbs";
+
+        assert_eq!(
+            vec![Skill {
+                effect: SkillEffect::GenRandomDrop(
+                    DropSet::from(vec![
+                        Drop::NonColored(NonColoredDrop::Recovery),
+                        Drop::Colored(Color::Dark),
+                        Drop::Colored(Color::Fire)
+                    ]),
+                    vec![(Drop::Colored(Color::Fire), 6)]
+                ),
+                ..Default::default()
+            }],
+            en_skills(input)
+        );
+    }
+
+    #[test]
+    fn with_turns() {
+        let input = "For 1 turn, no skyfall combos; for 3 turns, Water orbs are slightly more likely to appear";
+
+        assert_eq!(
+            vec![
+                Skill {
+                    effect: SkillEffect::DropsNotFalling,
+                    turns_of_apply: Some(1),
+                    ..Default::default()
+                },
+                Skill {
+                    effect: SkillEffect::DropFalloff(
+                        vec![Drop::Colored(Color::Water)],
+                        VolumeVariation::Little
+                    ),
+                    turns_of_apply: Some(3),
+                    ..Default::default()
+                },
+            ],
+            en_skills(input)
+        );
+    }
+
+    #[test]
+    fn shape_gen() {
+        let input = "Change the leftmost column and the 2nd row from the bottom to Fire orbs, and the rightmost column to Wood orbs";
+
+        assert_eq!(
+            vec![Skill {
+                effect: SkillEffect::DropShapeGen(vec![
+                    ShapeType::Col(1, Drop::Colored(Color::Fire)),
+                    ShapeType::Row(-2, Drop::Colored(Color::Fire)),
+                    ShapeType::Col(-1, Drop::Colored(Color::Wood)),
+                ]),
+                ..Default::default()
+            }],
+            en_skills(input)
+        );
+    }
+
+    #[test]
+    fn shape_gen_with_multiple_drops_is_error() {
+        let inputs = [
+            "Create 6 Fire and 3 Water orbs at the top of the board",
+            "Create 6 Fire and 3 Water orbs at the bottom of the board",
+            "Create 1 each of Fire and Water orbs in each corner of the board",
+            "Create 1 L shape of Fire and Water orbs",
+            "Change the outer edges of the board to Fire and Water orbs",
+        ];
+
+        for input in inputs {
+            let grammar = &mut SkillEnGrammar::new();
+            let parsed = skill_en_parser::parse(input, "<input>", grammar);
+
+            assert!(parsed.is_err(), "{input} -> {:?}", grammar.skill_list);
+        }
+    }
+}
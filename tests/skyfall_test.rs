@@ -0,0 +1,150 @@
+mod common;
+
+#[cfg(test)]
+mod skyfall_test {
+    use crate::common::skills;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::skyfall::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn default_distribution() {
+        let skyfall = Skyfall::new();
+        let distribution = skyfall.distribution();
+
+        assert_eq!(6, distribution.len());
+        assert!(distribution.iter().all(|(_, p)| approx(*p, 1.0 / 6.0)));
+        assert!(approx(0.0, skyfall.powerup_rate()));
+    }
+
+    #[test]
+    fn drop_falloff_normal() {
+        let skyfall = Skyfall::from_skills(&skills("1ターンの間、火ドロップが落ちやすくなる。"));
+
+        assert!(approx(
+            2.0 / 7.0,
+            skyfall.probability(&Drop::Colored(Color::Fire))
+        ));
+        assert!(approx(
+            1.0 / 7.0,
+            skyfall.probability(&Drop::Colored(Color::Water))
+        ));
+    }
+
+    #[test]
+    fn drop_falloff_compare_volume() {
+        let little_more = Skyfall::from_skills(&skills(
+            "99ターンの間、光、闇、回復ドロップがほんの少し落ちやすくなる。",
+        ));
+        let little = Skyfall::from_skills(&skills(
+            "3ターンの間、水と回復ドロップが少し落ちやすくなる。",
+        ));
+        let recovery = Drop::NonColored(NonColoredDrop::Recovery);
+
+        assert!(approx(1.25 / 6.75, little_more.probability(&recovery)));
+        assert!(approx(1.5 / 7.0, little.probability(&recovery)));
+        assert!(little_more.probability(&recovery) < little.probability(&recovery));
+    }
+
+    #[test]
+    fn drop_falloff_only() {
+        let skyfall = Skyfall::from_skills(&skills(
+            "1ターンの間、火、水、光、回復ドロップのみ落ちてくる。",
+        ));
+
+        assert_eq!(4, skyfall.distribution().len());
+        assert!(approx(
+            0.25,
+            skyfall.probability(&Drop::Colored(Color::Lightning))
+        ));
+        assert!(approx(
+            0.0,
+            skyfall.probability(&Drop::Colored(Color::Wood))
+        ));
+    }
+
+    #[test]
+    fn powerup_drop_falloff() {
+        let num = Skyfall::from_skills(&skills(
+            "2ターンの間、強化ドロップが25%の確率で落ちてくる。",
+        ));
+        let little =
+            Skyfall::from_skills(&skills("1ターンの間、強化ドロップが少し落ちやすくなる。"));
+
+        assert!(approx(0.25, num.powerup_rate()));
+        assert!(approx(DEFAULT_POWERUP_RATE.little, little.powerup_rate()));
+    }
+
+    #[test]
+    fn drop_falloff_with_powerup() {
+        let skyfall = Skyfall::from_skills(&skills(
+            "1ターンの間、水と光ドロップ、強化ドロップが少し落ちやすくなる。",
+        ));
+
+        assert!(approx(
+            1.5 / 7.0,
+            skyfall.probability(&Drop::Colored(Color::Water))
+        ));
+        assert!(approx(DEFAULT_POWERUP_RATE.little, skyfall.powerup_rate()));
+    }
+
+    #[test]
+    fn distribution_without_falling_drops() {
+        let mut skyfall = Skyfall::new();
+        skyfall.apply(&Skill {
+            effect: SkillEffect::DropFalloff(vec![], VolumeVariation::Only),
+            ..Default::default()
+        });
+
+        assert!(skyfall.distribution().is_empty());
+        assert!(approx(
+            0.0,
+            skyfall.probability(&Drop::Colored(Color::Fire))
+        ));
+    }
+
+    #[test]
+    fn with_tables() {
+        let bonus = VolumeTable {
+            little_more: 0.5,
+            little: 1.0,
+            normal: 2.0,
+        };
+        let rates = VolumeTable {
+            little_more: 0.5,
+            little: 1.0,
+            normal: 1.0,
+        };
+        let mut skyfall = Skyfall::with_tables(bonus, rates).unwrap();
+        skills("1ターンの間、火ドロップ、強化ドロップが少し落ちやすくなる。")
+            .iter()
+            .for_each(|skill| skyfall.apply(skill));
+
+        assert!(approx(
+            2.0 / 7.0,
+            skyfall.probability(&Drop::Colored(Color::Fire))
+        ));
+        assert!(approx(1.0, skyfall.powerup_rate()));
+    }
+
+    #[test]
+    fn with_tables_rejects_invalid_values() {
+        let valid = DEFAULT_POWERUP_RATE;
+        let rates = |normal| VolumeTable { normal, ..valid };
+
+        // gen_boolに渡せない出現率
+        assert!(Skyfall::with_tables(valid, rates(1.5)).is_err());
+        assert!(Skyfall::with_tables(valid, rates(-0.1)).is_err());
+        assert!(Skyfall::with_tables(valid, rates(f64::NAN)).is_err());
+        // 重みが負やNaNになる加算値
+        assert!(Skyfall::with_tables(rates(-1.0), valid).is_err());
+        assert!(Skyfall::with_tables(rates(f64::NAN), valid).is_err());
+        assert!(Skyfall::with_tables(rates(f64::INFINITY), valid).is_err());
+
+        assert!(Skyfall::with_tables(rates(2.0), rates(0.0)).is_ok());
+    }
+}
@@ -0,0 +1,63 @@
+mod common;
+
+#[cfg(test)]
+mod svg_test {
+    use crate::common::skills;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+
+    use pad_skill_parser::svg::*;
+
+    #[test]
+    fn shape_gen_to_svg() {
+        let size = Size::standard();
+        let skill = &skills("左端2列と右端2列を水ドロップに変化。")[0];
+        let svg = skill.effect.to_svg(&size).unwrap().unwrap();
+
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"240\" height=\"200\""));
+        assert_eq!(30, svg.matches("<rect ").count());
+        assert_eq!(20, svg.matches("<circle ").count());
+        assert_eq!(
+            20,
+            svg.matches(Drop::Colored(Color::Water).svg_color()).count()
+        );
+    }
+
+    #[test]
+    fn shape_gen_to_svg_on_large_board() {
+        let size = Size(7, 6);
+        let svg = ShapeType::ShapeOfBoardPerimeter(Drop::Colored(Color::Fire))
+            .to_svg(&size)
+            .unwrap();
+
+        assert!(svg.contains(&format!(
+            "viewBox=\"0 0 {} {}\"",
+            7 * CELL_SIZE,
+            6 * CELL_SIZE
+        )));
+        assert_eq!(22, svg.matches("<circle ").count());
+    }
+
+    #[test]
+    fn multiple_drops_are_coloured_separately() {
+        let skill = &skills("最上段横1列を火に、下から2段目横1列を木ドロップに変化。")[0];
+        let svg = skill.effect.to_svg(&Size::standard()).unwrap().unwrap();
+
+        assert_eq!(
+            6,
+            svg.matches(Drop::Colored(Color::Fire).svg_color()).count()
+        );
+        assert_eq!(
+            6,
+            svg.matches(Drop::Colored(Color::Wood).svg_color()).count()
+        );
+    }
+
+    #[test]
+    fn not_shape_gen() {
+        let skill = &skills("火ドロップを木ドロップに変化。")[0];
+
+        assert_eq!(None, skill.effect.to_svg(&Size::standard()).unwrap());
+    }
+}
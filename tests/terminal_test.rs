@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod terminal_test {
+    use pad_skill_parser::board::*;
+    use pad_skill_parser::schema::*;
+    use pad_skill_parser::skill::*;
+    use pad_skill_parser::terminal::*;
+
+    #[test]
+    fn render_emoji() {
+        let board = Board::from_notation("RB/.H").unwrap();
+
+        assert_eq!("🔥 💧\n⬛ 💗", board.render(&TerminalStyle::Emoji));
+    }
+
+    #[test]
+    fn render_emoji_overlays_are_aligned() {
+        let board = Board::from_notation("R+*B/G?L#").unwrap();
+
+        assert_eq!("🔥+* 💧  \n🌿?  🌟# ", board.render(&TerminalStyle::Emoji));
+    }
+
+    #[test]
+    fn render_shape_preview_without_cols() {
+        let shape = ShapeType::Col(1, Drop::Colored(Color::Fire));
+
+        assert_eq!(
+            "\n\n",
+            shape.render(&Size(0, 3), &TerminalStyle::Emoji).unwrap()
+        );
+    }
+
+    #[test]
+    fn render_ansi() {
+        let board = Board::from_notation("R*B?/.L#").unwrap();
+        let rendered = board.render(&TerminalStyle::Ansi);
+        let rows: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            vec![
+                "\x1b[1;31mR*\x1b[0m \x1b[1;34;47mB \x1b[0m",
+                "\x1b[2m. \x1b[0m \x1b[1;33;4mL \x1b[0m",
+            ],
+            rows
+        );
+    }
+
+    #[test]
+    fn render_shape_preview() {
+        let wood = Drop::Colored(Color::Wood);
+
+        assert_eq!(
+            "🌿 ⬛ ⬛\n🌿 ⬛ ⬛",
+            ShapeType::Col(1, wood)
+                .render(&Size(3, 2), &TerminalStyle::Emoji)
+                .unwrap()
+        );
+    }
+}